@@ -2,14 +2,17 @@
 #![doc = include_str!("../CONTRIBUTING.md")]
 
 use std::cmp::Ordering;
-use std::collections::{BTreeMap, BinaryHeap};
+use std::collections::{BTreeMap, BTreeSet, BinaryHeap};
 use std::fmt::{Debug, Formatter};
-use std::ops;
+use std::{iter, ops};
 
 mod categories;
 pub mod context;
 mod matcher;
+mod module_graph;
 mod options;
+mod plugin;
+mod profiling;
 mod query;
 mod registry;
 mod rule;
@@ -23,26 +26,34 @@ pub use rome_diagnostics::v2::category_concat;
 
 pub use crate::categories::{ActionCategory, RuleCategories, RuleCategory};
 pub use crate::matcher::{InspectMatcher, MatchQueryParams, QueryMatcher, RuleKey, SignalEntry};
-pub use crate::options::{AnalyzerConfiguration, AnalyzerOptions, AnalyzerRules};
+pub use crate::module_graph::{
+    ImportComponents, ImportKind, ModuleGraph, ModuleImport, ModuleResolution,
+};
+pub use crate::options::{AnalyzerConfiguration, AnalyzerOptions, AnalyzerRules, CustomRule};
+pub use crate::plugin::{AnalyzerPlugin, PluginRule, PluginVisitor};
+pub use crate::profiling::{
+    Profile, ProfileKey, ProfileMatcher, ProfiledVisitor, Profiler, Timings,
+};
 pub use crate::query::{Ast, QueryKey, QueryMatch, Queryable};
 pub use crate::registry::{
-    LanguageRoot, MetadataRegistry, Phase, Phases, RegistryRuleMetadata, RegistryVisitor,
-    RuleRegistry, RuleRegistryBuilder, RuleSuppressions,
+    intern_rule_name, LanguageRoot, MetadataRegistry, Phase, Phases, RegistryRuleMetadata,
+    RegistryVisitor, RuleRegistry, RuleRegistryBuilder, RuleSuppressions,
 };
 pub use crate::rule::{
-    CategoryLanguage, GroupCategory, GroupLanguage, Rule, RuleAction, RuleDiagnostic, RuleGroup,
-    RuleMeta, RuleMetadata,
+    CategoryLanguage, GroupCategory, GroupLanguage, Rule, RuleAction, RuleDiagnostic, RuleError,
+    RuleGroup, RuleMeta, RuleMetadata,
 };
 pub use crate::services::{FromServices, MissingServicesDiagnostic, ServiceBag};
-use crate::signals::DiagnosticSignal;
 pub use crate::signals::{AnalyzerAction, AnalyzerSignal};
+use crate::signals::{DiagnosticSignal, SuppressionRewriteSignal, UnusedSuppressionSignal};
 pub use crate::syntax::SyntaxVisitor;
 pub use crate::visitor::{NodeVisitor, Visitor, VisitorContext, VisitorFinishContext};
 use rome_console::{markup, MarkupBuf};
 use rome_diagnostics::file::FileId;
 use rome_diagnostics::v2::advice::CodeSuggestionAdvice;
 use rome_diagnostics::v2::{
-    category, Advices, Category, Diagnostic, DiagnosticTags, Error, Location, Severity, Visit,
+    category, Advices, Category, Diagnostic, DiagnosticTags, Error, Location, LogCategory,
+    Severity, Visit,
 };
 use rome_rowan::{
     AstNode, Direction, Language, SyntaxElement, SyntaxToken, TextRange, TextSize, TriviaPieceKind,
@@ -60,10 +71,19 @@ pub struct Analyzer<'analyzer, L: Language, Matcher, Break> {
     phases: BTreeMap<Phases, Vec<Box<dyn Visitor<Language = L> + 'analyzer>>>,
     /// Holds the metadata for all the rules statically known to the analyzer
     metadata: &'analyzer MetadataRegistry,
+    /// Holds the metadata of the rules only known at runtime, like the rules
+    /// defined in the configuration or provided by plugins
+    dynamic_metadata: Option<&'analyzer MetadataRegistry>,
     /// Executor for the query matches emitted by the visitors
     query_matcher: Matcher,
     /// Language-specific suppression comment parsing function
     parse_suppression_comment: SuppressionParser,
+    /// Language-specific function rewriting the suppression comments of other
+    /// tools, if any
+    rewrite_suppression_comment: Option<SuppressionRewriter>,
+    /// The rules being run by this instance of the analyzer, if known: the
+    /// suppression comments are only reported as unused if their rules ran
+    enabled_rules: Option<BTreeSet<RuleKey>>,
     /// Handles analyzer signals emitted by individual rules
     emit_signal: SignalHandler<'analyzer, L, Break>,
 }
@@ -92,12 +112,33 @@ where
         Self {
             phases: BTreeMap::new(),
             metadata,
+            dynamic_metadata: None,
             query_matcher,
             parse_suppression_comment,
+            rewrite_suppression_comment: None,
+            enabled_rules: None,
             emit_signal,
         }
     }
 
+    /// Emit a code action rewriting the comments that `rewriter` recognizes
+    /// into Rome suppression comments
+    pub fn set_suppression_rewriter(&mut self, rewriter: SuppressionRewriter) {
+        self.rewrite_suppression_comment = Some(rewriter);
+    }
+
+    /// Set the metadata of the rules only known at runtime, so the suppression
+    /// comments can refer to them
+    pub fn set_dynamic_metadata(&mut self, metadata: &'analyzer MetadataRegistry) {
+        self.dynamic_metadata = Some(metadata);
+    }
+
+    /// Set the rules being run by the analyzer, the suppression comments of
+    /// the other rules are never reported as unused
+    pub fn set_enabled_rules(&mut self, rules: BTreeSet<RuleKey>) {
+        self.enabled_rules = Some(rules);
+    }
+
     pub fn add_visitor<V>(&mut self, phase: Phases, visitor: V)
     where
         V: Visitor<Language = L> + 'analyzer,
@@ -112,24 +153,42 @@ where
         let Self {
             phases,
             metadata,
+            dynamic_metadata,
             mut query_matcher,
             parse_suppression_comment,
+            rewrite_suppression_comment,
+            enabled_rules,
             mut emit_signal,
         } = self;
 
+        // A suppression comment is only known to be unused if the rules it
+        // suppresses were run, `None` standing for all the lint rules
+        let is_enabled = |filter: Option<RuleFilter>| match (&enabled_rules, filter) {
+            (None, _) => true,
+            (Some(rules), None) => !rules.is_empty(),
+            (Some(rules), Some(filter)) => rules.iter().any(|key| filter.match_rule_key(*key)),
+        };
+
         let mut line_index = 0;
         let mut line_suppressions = Vec::new();
+        let mut range_suppressions = Vec::new();
+        let mut lint_ranges = Vec::new();
+        let mut comments = rewrite_suppression_comment.map(|_| Vec::new());
 
         for (index, (phase, mut visitors)) in phases.into_iter().enumerate() {
             let runner = PhaseRunner {
                 phase,
                 visitors: &mut visitors,
                 metadata,
+                dynamic_metadata,
                 query_matcher: &mut query_matcher,
                 signal_queue: BinaryHeap::new(),
                 parse_suppression_comment,
+                comments: comments.as_mut(),
                 line_index: &mut line_index,
                 line_suppressions: &mut line_suppressions,
+                range_suppressions: &mut range_suppressions,
+                lint_ranges: &mut lint_ranges,
                 emit_signal: &mut emit_signal,
                 file_id: ctx.file_id,
                 root: &ctx.root,
@@ -162,17 +221,94 @@ where
             }
         }
 
+        if let (Some(rewrite_suppression_comment), Some(comments)) =
+            (rewrite_suppression_comment, comments)
+        {
+            let texts: Vec<_> = comments
+                .iter()
+                .map(|comment| SuppressionComment {
+                    text: &comment.text,
+                    is_top_of_file: comment.is_top_of_file,
+                })
+                .collect();
+
+            for rewrite in rewrite_suppression_comment(&texts) {
+                let signal = SuppressionRewriteSignal::new(
+                    ctx.file_id,
+                    ctx.root.syntax(),
+                    comments[rewrite.index].range,
+                    rewrite.replacement,
+                    rewrite.end_of_file,
+                );
+
+                if let ControlFlow::Break(br) = (emit_signal)(&signal) {
+                    return Some(br);
+                }
+            }
+        }
+
         for suppression in line_suppressions {
             if suppression.did_suppress_signal {
                 continue;
             }
 
+            let is_checked = if suppression.suppress_all {
+                is_enabled(None)
+            } else {
+                suppression
+                    .suppressed_rules
+                    .iter()
+                    .all(|filter| is_enabled(Some(*filter)))
+            };
+
+            if !is_checked {
+                continue;
+            }
+
+            let signal = UnusedSuppressionSignal::new(
+                ctx.file_id,
+                ctx.root.syntax(),
+                suppression.comment_span,
+                suppression.comments,
+            );
+
+            if let ControlFlow::Break(br) = (emit_signal)(&signal) {
+                return Some(br);
+            }
+        }
+
+        for suppression in lint_ranges {
+            if suppression.did_suppress_signal || !is_enabled(suppression.filter) {
+                continue;
+            }
+
+            let signal = UnusedSuppressionSignal::new(
+                ctx.file_id,
+                ctx.root.syntax(),
+                suppression.comment_span,
+                suppression.comments,
+            );
+
+            if let ControlFlow::Break(br) = (emit_signal)(&signal) {
+                return Some(br);
+            }
+        }
+
+        // Any range that is still open at the end of the file is missing its
+        // closing comment, unless it implicitly extends to the end of the file
+        for suppression in range_suppressions {
+            if !suppression.requires_end {
+                continue;
+            }
+
             let signal = DiagnosticSignal::new(|| {
                 let diag = SuppressionDiagnostic::new(
                     ctx.file_id,
-                    category!("suppressions/unused"),
+                    category!("suppressions/unmatchedRange"),
                     suppression.comment_span,
-                    "Suppression comment is not being used",
+                    markup! {
+                        "This suppression comment doesn't have a matching "<Emphasis>"rome-ignore-end "{suppression.category}</Emphasis>" comment"
+                    },
                 );
 
                 AnalyzerDiagnostic::from_error(diag.into())
@@ -195,16 +331,25 @@ struct PhaseRunner<'analyzer, 'phase, L: Language, Matcher, Break> {
     visitors: &'phase mut [Box<dyn Visitor<Language = L> + 'analyzer>],
     /// Holds the metadata for all the rules statically known to the analyzer
     metadata: &'analyzer MetadataRegistry,
+    /// Holds the metadata of the rules only known at runtime
+    dynamic_metadata: Option<&'analyzer MetadataRegistry>,
     /// Executor for the query matches emitted by the visitors
     query_matcher: &'phase mut Matcher,
     /// Queue for pending analyzer signals
     signal_queue: BinaryHeap<SignalEntry<'phase, L>>,
     /// Language-specific suppression comment parsing function
     parse_suppression_comment: SuppressionParser,
+    /// Buffer collecting the comments of the file, if they're rewritten by a
+    /// [SuppressionRewriter] once the file has been traversed
+    comments: Option<&'phase mut Vec<CollectedComment>>,
     /// Line index at the current position of the traversal
     line_index: &'phase mut usize,
     /// Track active suppression comments per-line, ordered by line index
     line_suppressions: &'phase mut Vec<LineSuppression>,
+    /// Stack of the range suppression comments that haven't been closed yet
+    range_suppressions: &'phase mut Vec<RangeSuppression>,
+    /// Lint rules suppressed for a range of the file or the whole file
+    lint_ranges: &'phase mut Vec<LintRangeSuppression>,
     /// Handles analyzer signals emitted by invidual rules
     emit_signal: &'phase mut SignalHandler<'analyzer, L, Break>,
     /// ID if the file being analyzed
@@ -219,6 +364,14 @@ struct PhaseRunner<'analyzer, 'phase, L: Language, Matcher, Break> {
     options: &'phase AnalyzerOptions,
 }
 
+/// Single entry for a comment of the file in the `comments` buffer
+#[derive(Debug)]
+struct CollectedComment {
+    text: String,
+    range: TextRange,
+    is_top_of_file: bool,
+}
+
 /// Single entry for a suppression comment in the `line_suppressions` buffer
 #[derive(Debug)]
 struct LineSuppression {
@@ -226,6 +379,9 @@ struct LineSuppression {
     line_index: usize,
     /// Range of source text covered by the suppression comment
     comment_span: TextRange,
+    /// Ranges of all the comments merged into this suppression, they're
+    /// removed together if the suppression is unused
+    comments: Vec<TextRange>,
     /// Range of source text this comment is suppressing lint rules for
     text_range: TextRange,
     /// Set to true if this comment has set the `suppress_all` flag to true
@@ -239,6 +395,54 @@ struct LineSuppression {
     did_suppress_signal: bool,
 }
 
+/// Single entry for an open suppression range in the `range_suppressions` buffer
+#[derive(Debug)]
+struct RangeSuppression {
+    /// Category of the feature being suppressed in this range
+    category: String,
+    /// Range of source text covered by the opening suppression comment
+    comment_span: TextRange,
+    /// Index of the corresponding entry in the `lint_ranges` buffer, if this
+    /// range suppresses lint rules
+    lint_range: Option<usize>,
+    /// Set to `false` if the range extends to the end of the file when it's
+    /// not closed, instead of being reported as unmatched
+    requires_end: bool,
+}
+
+/// Single entry for a lint suppression in the `lint_ranges` buffer: either a
+/// range of code between a pair of range suppression comments, or the whole
+/// file for file suppression comments
+#[derive(Debug)]
+struct LintRangeSuppression {
+    /// The rule or group suppressed in this range, all the lint rules are
+    /// suppressed if it's `None`
+    filter: Option<RuleFilter<'static>>,
+    /// Range of source text covered by the opening suppression comment
+    comment_span: TextRange,
+    /// Ranges of the opening and closing comments, they're removed together
+    /// if the suppression is unused
+    comments: Vec<TextRange>,
+    /// Start of the suppressed range
+    start: TextSize,
+    /// End of the suppressed range, the range extends to the end of the file
+    /// if it's `None`
+    end: Option<TextSize>,
+    /// Set to `true` when a signal matching this suppression was emitted and
+    /// suppressed
+    did_suppress_signal: bool,
+}
+
+impl LintRangeSuppression {
+    /// Returns `true` if a signal emitted by `rule` at `position` is suppressed
+    /// by this entry
+    fn suppresses(&self, rule: RuleKey, position: TextSize) -> bool {
+        self.start <= position
+            && self.end.map_or(true, |end| position < end)
+            && self.filter.map_or(true, |filter| filter == rule)
+    }
+}
+
 impl<'a, 'phase, L, Matcher, Break> PhaseRunner<'a, 'phase, L, Matcher, Break>
 where
     L: Language,
@@ -403,12 +607,23 @@ where
                     .any(|filter| *filter == entry.rule)
             });
 
-            // If the signal is being suppressed mark the line suppression as
-            // hit, otherwise emit the signal
+            // If the signal is being suppressed mark the line suppression or
+            // all the overlapping range suppressions as hit, otherwise emit
+            // the signal
             if let Some(suppression) = suppression {
                 suppression.did_suppress_signal = true;
             } else {
-                (self.emit_signal)(&*entry.signal)?;
+                let mut is_suppressed = false;
+                for suppression in self.lint_ranges.iter_mut() {
+                    if suppression.suppresses(entry.rule, start) {
+                        suppression.did_suppress_signal = true;
+                        is_suppressed = true;
+                    }
+                }
+
+                if !is_suppressed {
+                    (self.emit_signal)(&*entry.signal)?;
+                }
             }
 
             // SAFETY: This removes `query` from the queue, it is known to
@@ -427,52 +642,61 @@ where
         text: &str,
         range: TextRange,
     ) -> ControlFlow<Break> {
-        let mut suppress_all = false;
-        let mut suppressions = Vec::new();
-
-        for rule in (self.parse_suppression_comment)(text) {
-            if let Some(rule) = rule {
-                let group_rule = rule.find('/').map(|index| {
-                    let (start, end) = rule.split_at(index);
-                    (start, &end[1..])
+        if self.comments.is_some() {
+            let is_top_of_file = self.is_top_of_file(range);
+            if let Some(comments) = self.comments.as_mut() {
+                comments.push(CollectedComment {
+                    text: text.to_string(),
+                    range,
+                    is_top_of_file,
                 });
+            }
+        }
 
-                let key = match group_rule {
-                    None => self.metadata.find_group(rule).map(RuleFilter::from),
-                    Some((group, rule)) => {
-                        self.metadata.find_rule(group, rule).map(RuleFilter::from)
-                    }
-                };
+        let mut suppress_all = false;
+        let mut suppressions = Vec::new();
 
-                if let Some(key) = key {
-                    suppressions.push(key);
-                } else {
-                    // Emit a warning for the unknown rule
-                    let signal = DiagnosticSignal::new(move || {
-                        let diag = match group_rule {
-                            Some((group, rule)) => SuppressionDiagnostic::new(
-                                file_id,
-                                category!("suppressions/unknownRule"),
-                                range,
-                                markup! {
-                                    "Unknown lint rule "{group}"/"{rule}" in suppression comment"
-                                },
-                            ),
-
-                            None => SuppressionDiagnostic::new(
-                                file_id,
-                                category!("suppressions/unknownGroup"),
-                                range,
-                                markup! {
-                                    "Unknown lint rule group "{rule}" in suppression comment"
-                                },
-                            ),
-                        };
-
-                        AnalyzerDiagnostic::from_error(diag.into())
+        for suppression in (self.parse_suppression_comment)(text) {
+            let rule = match suppression {
+                AnalyzerSuppression::Line(rule) => rule,
+                AnalyzerSuppression::All(rule) => {
+                    self.handle_file_suppression(file_id, rule, range)?;
+                    continue;
+                }
+                AnalyzerSuppression::RangeStart(category) => {
+                    self.range_suppressions.push(RangeSuppression {
+                        category: category.to_string(),
+                        comment_span: range,
+                        lint_range: None,
+                        requires_end: true,
                     });
+                    continue;
+                }
+                AnalyzerSuppression::RangeEnd(category) => {
+                    self.handle_range_end(file_id, category, range)?;
+                    continue;
+                }
+                AnalyzerSuppression::LintRangeStart(rule) => {
+                    self.handle_lint_range_start(file_id, rule, range, true)?;
+                    continue;
+                }
+                AnalyzerSuppression::LintDisable(rule) => {
+                    self.handle_lint_range_start(file_id, rule, range, false)?;
+                    continue;
+                }
+                AnalyzerSuppression::LintEnable(rule) => {
+                    self.handle_lint_enable(file_id, rule, range)?;
+                    continue;
+                }
+                AnalyzerSuppression::LintRangeEnd(rule) => {
+                    self.handle_range_end(file_id, &lint_range_category(rule), range)?;
+                    continue;
+                }
+            };
 
-                    (self.emit_signal)(&signal)?;
+            if let Some(rule) = rule {
+                if let Some(key) = self.resolve_rule_filter(file_id, rule, range)? {
+                    suppressions.push(key);
                 }
             } else {
                 suppressions.clear();
@@ -497,6 +721,7 @@ where
                 || last_suppression.line_index + 1 == line_index
             {
                 last_suppression.line_index = line_index;
+                last_suppression.comments.push(range);
                 last_suppression.text_range = last_suppression.text_range.cover(range);
                 last_suppression.suppress_all |= suppress_all;
                 if !last_suppression.suppress_all {
@@ -511,6 +736,7 @@ where
         let entry = LineSuppression {
             line_index,
             comment_span: range,
+            comments: vec![range],
             text_range: range,
             suppress_all,
             suppressed_rules: suppressions,
@@ -522,6 +748,251 @@ where
         ControlFlow::Continue(())
     }
 
+    /// Resolve the name of the rule or group `rule` written in a suppression
+    /// comment, emitting a diagnostic if it isn't known
+    fn resolve_rule_filter(
+        &mut self,
+        file_id: FileId,
+        rule: &str,
+        range: TextRange,
+    ) -> ops::ControlFlow<Break, Option<RuleFilter<'static>>> {
+        let group_rule = rule.find('/').map(|index| {
+            let (start, end) = rule.split_at(index);
+            (start, &end[1..])
+        });
+
+        let registries = iter::once(self.metadata).chain(self.dynamic_metadata);
+
+        let key = registries.clone().find_map(|metadata| match group_rule {
+            None => metadata.find_group(rule).map(RuleFilter::from),
+            Some((group, rule)) => metadata.find_rule(group, rule).map(RuleFilter::from),
+        });
+
+        if key.is_some() {
+            return ops::ControlFlow::Continue(key);
+        }
+
+        let suggestion = registries.find_map(|metadata| match group_rule {
+            None => metadata.suggest_group(rule).map(String::from),
+            Some((group, rule)) => metadata
+                .suggest_rule(group, rule)
+                .map(|key| format!("{}/{}", key.group(), key.rule_name())),
+        });
+
+        // Emit a warning for the unknown rule
+        let signal = DiagnosticSignal::new(move || {
+            let diag = match group_rule {
+                Some((group, rule)) => SuppressionDiagnostic::new(
+                    file_id,
+                    category!("suppressions/unknownRule"),
+                    range,
+                    markup! {
+                        "Unknown lint rule "{group}"/"{rule}" in suppression comment"
+                    },
+                ),
+
+                None => SuppressionDiagnostic::new(
+                    file_id,
+                    category!("suppressions/unknownGroup"),
+                    range,
+                    markup! {
+                        "Unknown lint rule group "{rule}" in suppression comment"
+                    },
+                ),
+            };
+
+            let diag = match &suggestion {
+                Some(suggestion) => diag.with_suggestion(suggestion),
+                None => diag,
+            };
+
+            AnalyzerDiagnostic::from_error(diag.into())
+        });
+
+        (self.emit_signal)(&signal)?;
+
+        ops::ControlFlow::Continue(None)
+    }
+
+    /// Returns `true` if the comment at `range` is placed before the first
+    /// token of the file
+    fn is_top_of_file(&self, range: TextRange) -> bool {
+        self.root.syntax().first_token().map_or(true, |token| {
+            range.end() <= token.text_trimmed_range().start()
+        })
+    }
+
+    /// Suppress `rule`, or all the lint rules if it's `None`, for the whole
+    /// file. The comment must be placed before the first token of the file,
+    /// a diagnostic is emitted otherwise
+    fn handle_file_suppression(
+        &mut self,
+        file_id: FileId,
+        rule: Option<&str>,
+        range: TextRange,
+    ) -> ControlFlow<Break> {
+        if !self.is_top_of_file(range) {
+            let signal = DiagnosticSignal::new(move || {
+                let diag = SuppressionDiagnostic::new(
+                    file_id,
+                    category!("suppressions/misplaced"),
+                    range,
+                    markup! {
+                        "File suppression comments must be placed at the top of the file, before any code"
+                    },
+                );
+
+                AnalyzerDiagnostic::from_error(diag.into())
+            });
+
+            return (self.emit_signal)(&signal);
+        }
+
+        let filter = match rule {
+            Some(rule) => match self.resolve_rule_filter(file_id, rule, range)? {
+                Some(filter) => Some(filter),
+                None => return ControlFlow::Continue(()),
+            },
+            None => None,
+        };
+
+        self.lint_ranges.push(LintRangeSuppression {
+            filter,
+            comment_span: range,
+            comments: vec![range],
+            start: TextSize::from(0),
+            end: None,
+            did_suppress_signal: false,
+        });
+
+        ControlFlow::Continue(())
+    }
+
+    /// Open a suppression range for `rule`, or all the lint rules if it's `None`.
+    /// If `requires_end` is `false`, the range extends to the end of the file
+    /// if it's not closed
+    fn handle_lint_range_start(
+        &mut self,
+        file_id: FileId,
+        rule: Option<&str>,
+        range: TextRange,
+        requires_end: bool,
+    ) -> ControlFlow<Break> {
+        let filter = match rule {
+            Some(rule) => self.resolve_rule_filter(file_id, rule, range)?.map(Some),
+            None => Some(None),
+        };
+
+        // The range is still tracked if the rule is unknown, so the closing
+        // comment isn't reported as unmatched
+        let lint_range = filter.map(|filter| {
+            self.lint_ranges.push(LintRangeSuppression {
+                filter,
+                comment_span: range,
+                comments: vec![range],
+                start: range.end(),
+                end: None,
+                did_suppress_signal: false,
+            });
+
+            self.lint_ranges.len() - 1
+        });
+
+        self.range_suppressions.push(RangeSuppression {
+            category: lint_range_category(rule),
+            comment_span: range,
+            lint_range,
+            requires_end,
+        });
+
+        ControlFlow::Continue(())
+    }
+
+    /// Close the innermost open suppression range for `category`, or emit a
+    /// diagnostic if there's no such range
+    fn handle_range_end(
+        &mut self,
+        file_id: FileId,
+        category: &str,
+        range: TextRange,
+    ) -> ControlFlow<Break> {
+        let index = self
+            .range_suppressions
+            .iter()
+            .rposition(|suppression| suppression.category == category);
+
+        if let Some(index) = index {
+            self.close_range(index, range);
+            return ControlFlow::Continue(());
+        }
+
+        self.unmatched_range_end(file_id, category, range)
+    }
+
+    /// Close all the ranges opened by a [AnalyzerSuppression::LintDisable] for
+    /// `rule`, or all of them if it's `None`, or emit a diagnostic if there's
+    /// no such range
+    fn handle_lint_enable(
+        &mut self,
+        file_id: FileId,
+        rule: Option<&str>,
+        range: TextRange,
+    ) -> ControlFlow<Break> {
+        let category = lint_range_category(rule);
+        let mut did_close = false;
+
+        for index in (0..self.range_suppressions.len()).rev() {
+            let suppression = &self.range_suppressions[index];
+            if suppression.requires_end || (rule.is_some() && suppression.category != category) {
+                continue;
+            }
+
+            self.close_range(index, range);
+            did_close = true;
+        }
+
+        if did_close {
+            return ControlFlow::Continue(());
+        }
+
+        self.unmatched_range_end(file_id, &category, range)
+    }
+
+    /// Remove the open range at `index` in the `range_suppressions` stack, the
+    /// range is closed by the comment at `range`
+    fn close_range(&mut self, index: usize, range: TextRange) {
+        let suppression = self.range_suppressions.remove(index);
+        if let Some(lint_range) = suppression.lint_range {
+            let lint_range = &mut self.lint_ranges[lint_range];
+            lint_range.end = Some(range.start());
+            lint_range.comments.push(range);
+        }
+    }
+
+    /// Emit a diagnostic for the closing range comment at `range` that doesn't
+    /// match any open range for `category`
+    fn unmatched_range_end(
+        &mut self,
+        file_id: FileId,
+        category: &str,
+        range: TextRange,
+    ) -> ControlFlow<Break> {
+        let signal = DiagnosticSignal::new(move || {
+            let diag = SuppressionDiagnostic::new(
+                file_id,
+                category!("suppressions/unmatchedRange"),
+                range,
+                markup! {
+                    "This suppression comment doesn't have a matching "<Emphasis>"rome-ignore-start "{category}</Emphasis>" comment"
+                },
+            );
+
+            AnalyzerDiagnostic::from_error(diag.into())
+        });
+
+        (self.emit_signal)(&signal)
+    }
+
     /// Check a piece of source text (token or trivia) for line breaks and
     /// increment the line index accordingly, extending the range of the
     /// current suppression as required
@@ -552,19 +1023,94 @@ where
     }
 }
 
+/// Name of the category of a lint suppression range, used to match its opening
+/// and closing comments
+fn lint_range_category(rule: Option<&str>) -> String {
+    match rule {
+        Some(rule) => format!("lint({rule})"),
+        None => String::from("lint"),
+    }
+}
+
 /// Signature for a suppression comment parser function
 ///
 /// This function receives the text content of a comment and returns a list of
-/// lint suppressions as an optional lint rule (if the lint rule is `None` the
-/// comment is interpreted as suppressing all lints)
+/// [AnalyzerSuppression]: lint suppressions hold an optional lint rule (if the
+/// lint rule is `None` the comment is interpreted as suppressing all lints),
+/// while range suppressions hold the category of the feature being suppressed
 ///
 /// # Examples
 ///
 /// - `// rome-ignore format` -> `vec![]`
-/// - `// rome-ignore lint` -> `vec![None]`
-/// - `// rome-ignore lint(correctness/useWhile)` -> `vec![Some("correctness/useWhile")]`
-/// - `// rome-ignore lint(correctness/useWhile) lint(nursery/noUnreachable)` -> `vec![Some("correctness/useWhile"), Some("nursery/noUnreachable")]`
-type SuppressionParser = fn(&str) -> Vec<Option<&str>>;
+/// - `// rome-ignore lint` -> `vec![Line(None)]`
+/// - `// rome-ignore lint(correctness/useWhile)` -> `vec![Line(Some("correctness/useWhile"))]`
+/// - `// rome-ignore lint(correctness/useWhile) lint(nursery/noUnreachable)` -> `vec![Line(Some("correctness/useWhile")), Line(Some("nursery/noUnreachable"))]`
+/// - `// rome-ignore-start format: reason` -> `vec![RangeStart("format")]`
+/// - `// rome-ignore-end format` -> `vec![RangeEnd("format")]`
+/// - `// rome-ignore-start lint(style/noVar): reason` -> `vec![LintRangeStart(Some("style/noVar"))]`
+/// - `// rome-ignore-all lint: reason` -> `vec![All(None)]`
+type SuppressionParser = fn(&str) -> Vec<AnalyzerSuppression>;
+
+/// Signature for a function rewriting comments into Rome suppression comments,
+/// used to migrate the suppression comments of other tools
+///
+/// The function receives all the comments of the file, so the comments opening
+/// a range can be paired with the comments closing it, and returns a
+/// [SuppressionRewrite] for each comment that should be rewritten
+type SuppressionRewriter = fn(&[SuppressionComment]) -> Vec<SuppressionRewrite>;
+
+/// Comment of the file passed to a [SuppressionRewriter]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SuppressionComment<'a> {
+    /// Text of the comment, including its delimiters
+    pub text: &'a str,
+    /// Whether the comment is placed at the top of the file, before any code
+    pub is_top_of_file: bool,
+}
+
+/// Rewrite of a comment returned by a [SuppressionRewriter]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SuppressionRewrite {
+    /// Index of the rewritten comment in the comments passed to the rewriter
+    pub index: usize,
+    /// Text of the equivalent Rome suppression comment
+    pub replacement: String,
+    /// Comment inserted at the end of the file to close the range opened by
+    /// the replacement, if it extends to the end of the file
+    pub end_of_file: Option<String>,
+}
+
+/// Single suppression parsed from the text of a comment by a [SuppressionParser]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnalyzerSuppression<'a> {
+    /// Suppresses a lint rule or group for the next line, or all the lint
+    /// rules if the value is `None`
+    Line(Option<&'a str>),
+    /// Opens a range of code where the feature with the given category is
+    /// suppressed. The analyzer checks that each of these comments is closed
+    /// by a matching [AnalyzerSuppression::RangeEnd]
+    RangeStart(&'a str),
+    /// Closes the innermost range opened by a [AnalyzerSuppression::RangeStart]
+    /// with the same category
+    RangeEnd(&'a str),
+    /// Suppresses a lint rule or group, or all the lint rules if the value is
+    /// `None`, for the whole file. The comment must be placed at the top of the file
+    All(Option<&'a str>),
+    /// Opens a range of code where a lint rule or group, or all the lint rules
+    /// if the value is `None`, is suppressed
+    LintRangeStart(Option<&'a str>),
+    /// Closes the innermost range opened by a [AnalyzerSuppression::LintRangeStart]
+    /// or a [AnalyzerSuppression::LintDisable] for the same rule
+    LintRangeEnd(Option<&'a str>),
+    /// Opens a range like [AnalyzerSuppression::LintRangeStart], that extends to
+    /// the end of the file if it's not closed instead of being reported. This
+    /// matches the behavior of the `eslint-disable` comments
+    LintDisable(Option<&'a str>),
+    /// Closes all the ranges opened by a [AnalyzerSuppression::LintDisable] for
+    /// the same rule, or all of them if the value is `None`. This matches the
+    /// behavior of the `eslint-enable` comments
+    LintEnable(Option<&'a str>),
+}
 
 type SignalHandler<'a, L, Break> = &'a mut dyn FnMut(&dyn AnalyzerSignal<L>) -> ControlFlow<Break>;
 
@@ -596,6 +1142,14 @@ impl RuleFilter<'_> {
             }
         }
     }
+
+    /// Return `true` if the rule identified by `key` matches this filter
+    fn match_rule_key(self, key: RuleKey) -> bool {
+        match self {
+            RuleFilter::Group(group) => group == key.group(),
+            RuleFilter::Rule(group, rule) => group == key.group() && rule == key.rule_name(),
+        }
+    }
 }
 
 /// Allows filtering the list of rules that will be executed in a run of the analyzer,
@@ -645,6 +1199,18 @@ impl<'analysis> AnalysisFilter<'analysis> {
             })
     }
 
+    /// Return `true` if the lint rule identified by `key` matches this filter,
+    /// for the rules that aren't statically known to the analyzer: these rules
+    /// are always enabled, unless they're disabled by this filter
+    pub fn match_dynamic_rule(&self, key: RuleKey) -> bool {
+        self.categories.contains(RuleCategories::LINT)
+            && self.disabled_rules.map_or(true, |disabled_rules| {
+                !disabled_rules
+                    .iter()
+                    .any(|filter| filter.match_rule_key(key))
+            })
+    }
+
     /// It creates a new filter with the set of [enabled rules](RuleFilter) passed as argument
     pub fn from_enabled_rules(enabled_rules: Option<&'analysis [RuleFilter<'analysis>]>) -> Self {
         Self {
@@ -752,11 +1318,18 @@ impl Diagnostic for AnalyzerDiagnostic {
                         detail.log_category,
                         &markup! { {detail.message} }.to_owned(),
                     )?;
-                    if let Some(location) = Location::builder()
-                        .span(&detail.range)
-                        .resource(file_id)
-                        .build()
-                    {
+                    let location = match &detail.file {
+                        Some(file) => Location::builder()
+                            .span(&detail.range)
+                            .resource(&file.path)
+                            .source_code(&file.source_code)
+                            .build(),
+                        None => Location::builder()
+                            .span(&detail.range)
+                            .resource(file_id)
+                            .build(),
+                    };
+                    if let Some(location) = location {
                         visitor.record_frame(location)?;
                     }
                 }
@@ -835,6 +1408,8 @@ pub(crate) struct SuppressionDiagnostic {
     file_id: FileId,
     #[message]
     message: MarkupBuf,
+    #[advice]
+    advice: SuppressionAdvice,
 }
 
 impl SuppressionDiagnostic {
@@ -850,8 +1425,35 @@ impl SuppressionDiagnostic {
             severity: Severity::Warning,
             range,
             message: markup!({ message }).to_owned(),
+            advice: SuppressionAdvice::default(),
         }
     }
+
+    /// Suggests the name of a known rule or group to replace the one written in
+    /// the suppression comment
+    pub(crate) fn with_suggestion(mut self, suggestion: &str) -> Self {
+        self.advice.suggestion = Some(suggestion.to_string());
+        self
+    }
+}
+
+#[derive(Debug, Default)]
+struct SuppressionAdvice {
+    /// Name of the rule or group the suppression comment was probably meant to use
+    suggestion: Option<String>,
+}
+
+impl Advices for SuppressionAdvice {
+    fn record(&self, visitor: &mut dyn Visit) -> std::io::Result<()> {
+        if let Some(suggestion) = &self.suggestion {
+            visitor.record_log(
+                LogCategory::Info,
+                &markup! { "Did you mean "<Emphasis>{suggestion}</Emphasis>"?" },
+            )?;
+        }
+
+        Ok(())
+    }
 }
 
 /// Utility type to be used as a default value for the `B` generic type on
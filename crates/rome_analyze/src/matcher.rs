@@ -162,8 +162,8 @@ mod tests {
 
     use crate::{
         signals::DiagnosticSignal, Analyzer, AnalyzerContext, AnalyzerDiagnostic, AnalyzerOptions,
-        AnalyzerSignal, ControlFlow, MetadataRegistry, Never, Phases, QueryMatch, QueryMatcher,
-        RuleKey, ServiceBag, SignalEntry, SyntaxVisitor,
+        AnalyzerSignal, AnalyzerSuppression, ControlFlow, MetadataRegistry, Never, Phases,
+        QueryMatch, QueryMatcher, RuleKey, ServiceBag, SignalEntry, SyntaxVisitor,
     };
 
     use super::MatchQueryParams;
@@ -316,11 +316,11 @@ mod tests {
             ControlFlow::Continue(())
         };
 
-        fn parse_suppression_comment(comment: &str) -> Vec<Option<&str>> {
+        fn parse_suppression_comment(comment: &str) -> Vec<AnalyzerSuppression> {
             comment
                 .trim_start_matches("//")
                 .split(' ')
-                .map(Some)
+                .map(|rule| AnalyzerSuppression::Line(Some(rule)))
                 .collect()
         }
 
@@ -0,0 +1,239 @@
+use std::collections::{BTreeSet, HashMap};
+use std::fmt::Debug;
+use std::path::{Component, Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use rome_rowan::TextRange;
+
+/// The modules of a project and the files their imports resolve to, provided
+/// to the analyzer by the workspace
+///
+/// The analyzer only sees a single file at a time: the graph gives the lint
+/// rules access to the imports of the other modules of the project, loading
+/// them lazily if needed.
+pub trait ModuleGraph: Debug + Send + Sync {
+    /// Return the imports of the module at `path`, or `None` if it isn't a
+    /// module that can be part of the graph
+    fn imports(&self, path: &Path) -> Option<Vec<ModuleImport>>;
+
+    /// Return the paths of the modules of the graph importing the module at
+    /// `path`. Only the modules loaded in the graph so far are returned.
+    fn importers(&self, path: &Path) -> Vec<PathBuf>;
+
+    /// Return the source text of the module at `path`, used by the diagnostics
+    /// pointing to the code of other modules
+    fn source_text(&self, path: &Path) -> Option<String>;
+
+    /// Return the modules importing the module at `path` and imported by it,
+    /// directly or not, following the [cycle imports](ModuleGraph::cycle_imports).
+    /// The component always contains `path`.
+    ///
+    /// The default implementation computes the component on each call, the
+    /// graphs shared by the modules of a project should cache their
+    /// [ImportComponents] instead.
+    fn import_component(&self, path: &Path, include_type_imports: bool) -> Arc<BTreeSet<PathBuf>> {
+        ImportComponents::default().component(path, include_type_imports, |path| {
+            self.cycle_imports(path, include_type_imports)
+                .into_iter()
+                .map(|(target, _)| target)
+                .collect()
+        })
+    }
+
+    /// Return the imports of the module at `path` that can be part of a cycle,
+    /// with the path of the module they resolve to. The dynamic imports, that
+    /// are evaluated later, the modules of the `node_modules` directories and,
+    /// unless `include_type_imports` is `true`, the type imports are ignored.
+    fn cycle_imports(
+        &self,
+        path: &Path,
+        include_type_imports: bool,
+    ) -> Vec<(PathBuf, ModuleImport)> {
+        self.imports(path)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|import| match import.kind {
+                ImportKind::Dynamic => false,
+                ImportKind::TypeOnly => include_type_imports,
+                ImportKind::Static | ImportKind::ReExport | ImportKind::Require => true,
+            })
+            .filter_map(|import| {
+                let target = import.resolved_path()?;
+                let is_dependency = target
+                    .components()
+                    .any(|component| component == Component::Normal("node_modules".as_ref()));
+
+                if is_dependency {
+                    None
+                } else {
+                    Some((target.to_path_buf(), import))
+                }
+            })
+            .collect()
+    }
+}
+
+/// The strongly connected components of the imports of a module graph,
+/// computed once for all the modules reachable from the first module they're
+/// requested for
+#[derive(Debug, Default)]
+pub struct ImportComponents {
+    /// The component of each module visited so far, without and with the type
+    /// imports
+    components: Mutex<[HashMap<PathBuf, Arc<BTreeSet<PathBuf>>>; 2]>,
+}
+
+/// A module being visited by [ImportComponents::component]
+struct ComponentFrame {
+    path: PathBuf,
+    targets: std::vec::IntoIter<PathBuf>,
+}
+
+impl ImportComponents {
+    /// Return the component of the module at `path`, `imports` returning the
+    /// modules imported by a module
+    pub fn component(
+        &self,
+        path: &Path,
+        include_type_imports: bool,
+        imports: impl Fn(&Path) -> Vec<PathBuf>,
+    ) -> Arc<BTreeSet<PathBuf>> {
+        let mut components = self.components.lock().unwrap();
+        let components = &mut components[usize::from(include_type_imports)];
+
+        if let Some(component) = components.get(path) {
+            return component.clone();
+        }
+
+        // Tarjan's algorithm, the components found by the previous calls are
+        // complete and can't contain the modules that weren't visited yet
+        let mut indices: HashMap<PathBuf, (usize, usize)> = HashMap::new();
+        let mut stack = Vec::new();
+        let mut frames = Vec::new();
+
+        // Assign the next index to the module at `path` and visit its imports
+        let visit = |path: PathBuf,
+                     indices: &mut HashMap<PathBuf, (usize, usize)>,
+                     stack: &mut Vec<PathBuf>,
+                     frames: &mut Vec<ComponentFrame>| {
+            let index = indices.len();
+            indices.insert(path.clone(), (index, index));
+            stack.push(path.clone());
+            frames.push(ComponentFrame {
+                targets: imports(&path).into_iter(),
+                path,
+            });
+        };
+
+        visit(path.to_path_buf(), &mut indices, &mut stack, &mut frames);
+
+        while let Some(frame) = frames.last_mut() {
+            if let Some(target) = frame.targets.next() {
+                if components.contains_key(&target) {
+                    continue;
+                }
+
+                // The visited modules without a component are on the stack
+                match indices.get(&target) {
+                    Some(&(target_index, _)) => {
+                        let (_, low_link) = indices.get_mut(&frame.path).unwrap();
+                        *low_link = (*low_link).min(target_index);
+                    }
+                    None => visit(target, &mut indices, &mut stack, &mut frames),
+                }
+
+                continue;
+            }
+
+            let frame = frames.pop().unwrap();
+            let (index, low_link) = indices[&frame.path];
+
+            if let Some(parent) = frames.last() {
+                let (_, parent_low_link) = indices.get_mut(&parent.path).unwrap();
+                *parent_low_link = (*parent_low_link).min(low_link);
+            }
+
+            if index == low_link {
+                let mut component = BTreeSet::new();
+                while let Some(module) = stack.pop() {
+                    let is_root = module == frame.path;
+                    component.insert(module);
+                    if is_root {
+                        break;
+                    }
+                }
+
+                let component = Arc::new(component);
+                for module in component.iter() {
+                    components.insert(module.clone(), component.clone());
+                }
+            }
+        }
+
+        components[path].clone()
+    }
+
+    /// Returns `true` if the module at `path` was visited by the components
+    /// computed so far, whose imports are then part of the components
+    pub fn contains(&self, path: &Path) -> bool {
+        let components = self.components.lock().unwrap();
+        components
+            .iter()
+            .any(|components| components.contains_key(path))
+    }
+
+    /// Forget the components, after the imports of a module they contain changed
+    pub fn clear(&self) {
+        let mut components = self.components.lock().unwrap();
+        for components in components.iter_mut() {
+            components.clear();
+        }
+    }
+}
+
+/// An import of a module, resolved to the file it refers to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModuleImport {
+    /// The specifier of the import, like `./utils` or `react`
+    pub specifier: String,
+    /// The range of the specifier in the importing module
+    pub range: TextRange,
+    /// How the module is imported
+    pub kind: ImportKind,
+    /// The file the specifier resolves to
+    pub resolution: ModuleResolution,
+}
+
+impl ModuleImport {
+    /// Return the path of the file the import resolves to, if any
+    pub fn resolved_path(&self) -> Option<&Path> {
+        match &self.resolution {
+            ModuleResolution::File(path) => Some(path),
+            ModuleResolution::Builtin | ModuleResolution::Unresolved => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportKind {
+    /// `import a from "a"` or `import "a"`
+    Static,
+    /// `import type { A } from "a"`, erased at runtime
+    TypeOnly,
+    /// `export { a } from "a"` or `export * from "a"`
+    ReExport,
+    /// `import("a")`
+    Dynamic,
+    /// `require("a")` or `import a = require("a")`
+    Require,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ModuleResolution {
+    /// The specifier resolves to this file
+    File(PathBuf),
+    /// The specifier refers to a module of the runtime, like `node:fs`
+    Builtin,
+    /// No file was found for the specifier
+    Unresolved,
+}
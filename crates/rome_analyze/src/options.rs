@@ -1,9 +1,14 @@
-use crate::{RuleKey, TextRange, TextSize};
-use rome_diagnostics::v2::{Diagnostic, LineIndexBuf, Resource, SourceCode};
+use crate::{AnalyzerPlugin, ModuleGraph, Profile, RuleKey, TextRange, TextSize};
+use regex::Regex;
+use rome_diagnostics::v2::{Diagnostic, LineIndexBuf, Resource, Severity, SourceCode};
+use rome_rowan::SendNode;
 use serde::Deserialize;
 use serde_json::Error;
 use serde_json::Value;
 use std::collections::HashMap;
+use std::fmt::{Debug, Formatter};
+use std::path::PathBuf;
+use std::sync::Arc;
 
 /// A convenient new type data structure to store the options that belong to a rule
 #[derive(Debug, Clone, Deserialize)]
@@ -47,6 +52,51 @@ pub struct AnalyzerConfiguration {
     ///
     /// For example, lint rules should ignore them.
     pub globals: Vec<String>,
+
+    /// Whether the suppression comments of ESLint should be recognized, and
+    /// rewritten into Rome suppression comments
+    pub eslint_suppression_compat: bool,
+
+    /// The lint rules defined in the configuration
+    pub custom_rules: Vec<Arc<CustomRule>>,
+
+    /// The plugins providing lint rules
+    pub plugins: Vec<Arc<dyn AnalyzerPlugin>>,
+}
+
+/// A lint rule defined in the configuration as a code pattern, compiled once
+/// when the configuration is loaded by the analyzer of the language it applies to
+#[derive(Clone)]
+pub struct CustomRule {
+    /// Name of the rule, used in the suppression comments and in the
+    /// `linter.rules.custom` configuration
+    pub name: &'static str,
+    /// Syntax tree of the code matched by the rule, where the `$name`
+    /// metavariables match any node
+    pub pattern: SendNode,
+    /// Message of the diagnostic emitted for each match
+    pub message: Arc<str>,
+    /// Regular expressions that the text of the nodes matched by a
+    /// metavariable must match, by name of the metavariable
+    pub constraints: Vec<(String, Regex)>,
+    /// Code replacing each match in the fix of the rule, the metavariables are
+    /// replaced with the code they matched
+    pub rewrite: Option<String>,
+    /// Severity of the diagnostics emitted by the rule, the rule is disabled
+    /// if it's `None`
+    pub severity: Option<Severity>,
+}
+
+impl Debug for CustomRule {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CustomRule")
+            .field("name", &self.name)
+            .field("message", &self.message)
+            .field("constraints", &self.constraints)
+            .field("rewrite", &self.rewrite)
+            .field("severity", &self.severity)
+            .finish_non_exhaustive()
+    }
 }
 
 /// A set of information useful to the analyzer infrastructure
@@ -54,6 +104,15 @@ pub struct AnalyzerConfiguration {
 pub struct AnalyzerOptions {
     /// A data structured derived from the [`rome.json`] file
     pub configuration: AnalyzerConfiguration,
+
+    /// The path of the file being analyzed
+    pub file_path: PathBuf,
+
+    /// The modules of the project containing the file, if known
+    pub module_graph: Option<Arc<dyn ModuleGraph>>,
+
+    /// The profile recording the time spent in the rules, if they're profiled
+    pub profile: Option<Arc<Profile>>,
 }
 
 #[derive(Debug, Diagnostic)]
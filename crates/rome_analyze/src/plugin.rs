@@ -0,0 +1,545 @@
+//! Lint rules provided by plugins
+//!
+//! A plugin is loaded by the workspace and exchanges JSON documents with the
+//! analyzer: it receives the syntax tree of a file along with the list of its
+//! enabled rules, and returns the diagnostics of these rules. The [PluginVisitor]
+//! runs the plugins on the root of each file, and emits their diagnostics as
+//! signals of the rules `<plugin>/<rule>`, so they are suppressed by the same
+//! comments as the built-in rules.
+
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use rome_console::markup;
+use rome_diagnostics::file::FileId;
+use rome_diagnostics::v2::{category, Severity};
+use rome_diagnostics::Applicability;
+use rome_rowan::{
+    BatchMutation, Direction, Language, SyntaxElement, SyntaxNode, SyntaxToken, TextRange,
+    TextSize, WalkEvent,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    ActionCategory, AnalysisFilter, AnalyzerAction, AnalyzerDiagnostic, AnalyzerSignal,
+    MetadataRegistry, RuleDiagnostic, RuleKey, Visitor, VisitorContext,
+};
+
+/// A lint rule plugin, its rules are registered in the [MetadataRegistry] under
+/// the group named after the plugin
+pub trait AnalyzerPlugin: Debug + Send + Sync {
+    /// Name of the plugin, used as the group of its rules
+    fn name(&self) -> &'static str;
+
+    /// The rules declared by the plugin
+    fn rules(&self) -> &[PluginRule];
+
+    /// Run the plugin on a file: `input` is the JSON serialization of the syntax
+    /// tree of the file and of the enabled rules, the result must be a JSON array
+    /// of diagnostics
+    fn evaluate(&self, input: &str) -> Result<String, String>;
+}
+
+/// A rule declared by an [AnalyzerPlugin]
+#[derive(Debug, Clone, Copy)]
+pub struct PluginRule {
+    pub name: &'static str,
+    /// Severity of the diagnostics of the rule, `None` if the rule is disabled
+    pub severity: Option<Severity>,
+}
+
+/// The document sent to a plugin for each file
+#[derive(Debug, Serialize)]
+struct PluginInput {
+    /// The enabled rules of the plugin
+    rules: Vec<&'static str>,
+    root: PluginSyntaxElement,
+}
+
+/// A node or token of the syntax tree sent to the plugins, with its trimmed range
+#[derive(Debug, Serialize)]
+struct PluginSyntaxElement {
+    kind: String,
+    start: u32,
+    end: u32,
+    /// The text of a token, without its trivia
+    #[serde(skip_serializing_if = "Option::is_none")]
+    text: Option<String>,
+    /// The children of a node
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    children: Vec<PluginSyntaxElement>,
+}
+
+/// A diagnostic returned by a plugin
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PluginDiagnostic {
+    /// Name of the rule emitting the diagnostic
+    rule: String,
+    start: u32,
+    end: u32,
+    message: String,
+    #[serde(default)]
+    fix: Option<PluginFix>,
+}
+
+/// A fix of a [PluginDiagnostic]
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PluginFix {
+    message: String,
+    edits: Vec<PluginTextEdit>,
+}
+
+/// Replaces the text of the tokens between `start` and `end`, the range must
+/// start and end at the boundaries of tokens, excluding their trivia
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PluginTextEdit {
+    start: u32,
+    end: u32,
+    text: String,
+}
+
+/// Serialize `element` and all its descendants
+fn serialize_element<L: Language>(element: SyntaxElement<L>) -> PluginSyntaxElement {
+    match element {
+        SyntaxElement::Node(node) => {
+            let range = node.text_trimmed_range();
+            PluginSyntaxElement {
+                kind: format!("{:?}", node.kind()),
+                start: range.start().into(),
+                end: range.end().into(),
+                text: None,
+                children: node.children_with_tokens().map(serialize_element).collect(),
+            }
+        }
+        SyntaxElement::Token(token) => {
+            let range = token.text_trimmed_range();
+            PluginSyntaxElement {
+                kind: format!("{:?}", token.kind()),
+                start: range.start().into(),
+                end: range.end().into(),
+                text: Some(token.text_trimmed().to_string()),
+                children: Vec::new(),
+            }
+        }
+    }
+}
+
+fn text_range(start: u32, end: u32) -> Option<TextRange> {
+    if start <= end {
+        Some(TextRange::new(TextSize::from(start), TextSize::from(end)))
+    } else {
+        None
+    }
+}
+
+/// Visitor running the plugins on the root of the syntax tree
+pub struct PluginVisitor<L> {
+    /// The plugins with at least one enabled rule, along with the
+    /// identifiers and severities of their enabled rules
+    plugins: Vec<(Arc<dyn AnalyzerPlugin>, Vec<(RuleKey, Severity)>)>,
+    _language: PhantomData<L>,
+}
+
+impl<L> PluginVisitor<L> {
+    /// Register the rules of `plugins` in `metadata`, and create a visitor
+    /// running the plugins whose rules are enabled by `filter`
+    pub fn new(
+        plugins: &[Arc<dyn AnalyzerPlugin>],
+        filter: &AnalysisFilter,
+        metadata: &mut MetadataRegistry,
+    ) -> Self {
+        let mut enabled_plugins = Vec::new();
+
+        for plugin in plugins {
+            let mut enabled_rules = Vec::new();
+
+            // The disabled rules are registered too, their suppression comments are valid
+            for rule in plugin.rules() {
+                let key = metadata.insert_dynamic_rule(plugin.name(), rule.name);
+                if let Some(severity) = rule.severity {
+                    if filter.match_dynamic_rule(key) {
+                        enabled_rules.push((key, severity));
+                    }
+                }
+            }
+
+            if !enabled_rules.is_empty() {
+                enabled_plugins.push((plugin.clone(), enabled_rules));
+            }
+        }
+
+        Self {
+            plugins: enabled_plugins,
+            _language: PhantomData,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.plugins.is_empty()
+    }
+
+    /// Returns the identifiers of the enabled rules of the plugins
+    pub fn rule_keys(&self) -> impl Iterator<Item = RuleKey> + '_ {
+        self.plugins
+            .iter()
+            .flat_map(|(_, rules)| rules.iter().map(|(key, _)| *key))
+    }
+}
+
+impl<L: Language + 'static> Visitor for PluginVisitor<L> {
+    type Language = L;
+
+    fn visit(&mut self, event: &WalkEvent<SyntaxNode<L>>, mut ctx: VisitorContext<L>) {
+        let root = match event {
+            WalkEvent::Enter(node) if node.parent().is_none() => node,
+            _ => return,
+        };
+
+        for (plugin, rules) in &self.plugins {
+            let input = PluginInput {
+                rules: rules.iter().map(|(key, _)| key.rule_name()).collect(),
+                root: serialize_element(SyntaxElement::Node(root.clone())),
+            };
+
+            let diagnostics = serde_json::to_string(&input)
+                .map_err(|error| error.to_string())
+                .and_then(|input| plugin.evaluate(&input))
+                .and_then(|output| {
+                    serde_json::from_str::<Vec<PluginDiagnostic>>(&output)
+                        .map_err(|error| format!("invalid diagnostics: {error}"))
+                });
+
+            let diagnostics = match diagnostics {
+                Ok(diagnostics) => diagnostics,
+                Err(error) => {
+                    // The failures are reported under the first enabled rule of the plugin
+                    let (key, _) = rules[0];
+                    let signal = PluginFailureSignal {
+                        file_id: ctx.file_id,
+                        plugin: plugin.name(),
+                        range: TextRange::default(),
+                        error,
+                    };
+                    ctx.emit_signal(key, TextRange::default(), Box::new(signal));
+                    continue;
+                }
+            };
+
+            for diagnostic in diagnostics {
+                let (key, severity) = match rules
+                    .iter()
+                    .find(|(key, _)| key.rule_name() == diagnostic.rule)
+                {
+                    Some(rule) => *rule,
+                    None => continue,
+                };
+
+                let range = match text_range(diagnostic.start, diagnostic.end) {
+                    Some(range) if range.end() <= root.text_range().end() => range,
+                    _ => continue,
+                };
+
+                if let Some(filter_range) = ctx.range {
+                    if filter_range.intersect(range).is_none() {
+                        continue;
+                    }
+                }
+
+                // The invalid fixes are reported, the diagnostic is still emitted without them
+                let fix = match diagnostic.fix {
+                    Some(fix) => match apply_edits(root, &fix.edits) {
+                        Ok(mutation) => Some((fix.message, mutation)),
+                        Err(error) => {
+                            let signal = PluginFailureSignal {
+                                file_id: ctx.file_id,
+                                plugin: plugin.name(),
+                                range,
+                                error: format!(
+                                    "the fix of the rule {} is invalid, {error}",
+                                    diagnostic.rule
+                                ),
+                            };
+                            ctx.emit_signal(key, range, Box::new(signal));
+                            None
+                        }
+                    },
+                    None => None,
+                };
+
+                let signal = PluginSignal {
+                    file_id: ctx.file_id,
+                    key,
+                    severity,
+                    range,
+                    message: diagnostic.message,
+                    fix,
+                };
+
+                ctx.emit_signal(key, range, Box::new(signal));
+            }
+        }
+    }
+}
+
+/// Signal emitted for each diagnostic returned by a plugin
+struct PluginSignal<L: Language> {
+    file_id: FileId,
+    key: RuleKey,
+    severity: Severity,
+    range: TextRange,
+    message: String,
+    /// The message of the fix and the mutation applying its edits
+    fix: Option<(String, BatchMutation<L>)>,
+}
+
+impl<L: Language> AnalyzerSignal<L> for PluginSignal<L> {
+    fn diagnostic(&self) -> Option<AnalyzerDiagnostic> {
+        let group = self.key.group();
+        let rule = self.key.rule_name();
+        let diagnostic =
+            RuleDiagnostic::new(category!("lint/plugin"), self.range, &self.message).note(
+                markup! {
+                    "This diagnostic is emitted by the rule "<Emphasis>{group}"/"{rule}</Emphasis>" of a plugin."
+                },
+            );
+
+        let mut diagnostic = AnalyzerDiagnostic::from_rule_diagnostic(self.file_id, diagnostic);
+        diagnostic.set_severity(self.severity);
+        Some(diagnostic)
+    }
+
+    fn actions(&self) -> Vec<AnalyzerAction<L>> {
+        match &self.fix {
+            Some((message, mutation)) => vec![AnalyzerAction {
+                group_name: self.key.group(),
+                rule_name: self.key.rule_name(),
+                file_id: self.file_id,
+                category: ActionCategory::QuickFix,
+                applicability: Applicability::MaybeIncorrect,
+                message: markup! { {message} }.to_owned(),
+                mutation: mutation.clone(),
+            }],
+            None => Vec::new(),
+        }
+    }
+}
+
+/// Convert the text edits of a plugin into a mutation of the syntax tree: the
+/// first token covered by each edit gets the new text, the other ones are
+/// removed. The leading trivia of the first token and the trailing trivia of
+/// the last one are kept, the trivia between them is removed. Returns a
+/// description of the error if the edits aren't sorted, if they overlap, if an
+/// edit doesn't start and end at the boundaries of tokens, or if it would
+/// remove comments
+fn apply_edits<L: Language>(
+    root: &SyntaxNode<L>,
+    edits: &[PluginTextEdit],
+) -> Result<BatchMutation<L>, String> {
+    let mut mutation = BatchMutation::new(root.clone());
+    let mut previous_end = None;
+
+    for edit in edits {
+        let range = text_range(edit.start, edit.end).ok_or_else(|| {
+            format!(
+                "the edit {}..{} ends before its start",
+                edit.start, edit.end
+            )
+        })?;
+
+        if previous_end.map_or(false, |previous_end| range.start() < previous_end) {
+            return Err(format!(
+                "the edit {}..{} overlaps or precedes the previous edit, the edits must be sorted",
+                edit.start, edit.end
+            ));
+        }
+        previous_end = Some(range.end());
+
+        let tokens: Vec<_> = root
+            .descendants_tokens(Direction::Next)
+            .filter(|token| range.contains_range(token.text_trimmed_range()))
+            .filter(|token| !token.text_trimmed_range().is_empty())
+            .collect();
+
+        let boundaries_error = || {
+            format!(
+                "the edit {}..{} doesn't start and end at the boundaries of tokens",
+                edit.start, edit.end
+            )
+        };
+
+        let (first, last) = match (tokens.first(), tokens.last()) {
+            (Some(first), Some(last)) => (first, last),
+            _ => return Err(boundaries_error()),
+        };
+
+        if first.text_trimmed_range().start() != range.start()
+            || last.text_trimmed_range().end() != range.end()
+        {
+            return Err(boundaries_error());
+        }
+
+        let removes_comments = tokens.iter().enumerate().any(|(index, token)| {
+            (index > 0 && token.has_leading_comments())
+                || (index + 1 < tokens.len() && token.has_trailing_comments())
+        });
+
+        if removes_comments {
+            return Err(format!(
+                "the edit {}..{} would remove comments",
+                edit.start, edit.end
+            ));
+        }
+
+        let next_token = SyntaxToken::new_detached(first.kind(), &edit.text, [], [])
+            .with_leading_trivia_pieces(first.leading_trivia().pieces())
+            .with_trailing_trivia_pieces(last.trailing_trivia().pieces());
+
+        let mut tokens = tokens.into_iter();
+        if let Some(first) = tokens.next() {
+            mutation.replace_token(first, next_token);
+        }
+        for token in tokens {
+            mutation.remove_token(token);
+        }
+    }
+
+    Ok(mutation)
+}
+
+/// Signal emitted when a plugin fails to run on a file
+struct PluginFailureSignal {
+    file_id: FileId,
+    plugin: &'static str,
+    /// The range of the diagnostic whose fix is invalid, or an empty range if
+    /// the plugin failed to run
+    range: TextRange,
+    error: String,
+}
+
+impl<L: Language> AnalyzerSignal<L> for PluginFailureSignal {
+    fn diagnostic(&self) -> Option<AnalyzerDiagnostic> {
+        let plugin = self.plugin;
+        let error = &self.error;
+        let diagnostic = RuleDiagnostic::new(
+            category!("lint/plugin"),
+            self.range,
+            markup! { "The plugin "<Emphasis>{plugin}</Emphasis>" failed to run on this file: "{error} },
+        );
+
+        Some(AnalyzerDiagnostic::from_rule_diagnostic(
+            self.file_id,
+            diagnostic,
+        ))
+    }
+
+    fn actions(&self) -> Vec<AnalyzerAction<L>> {
+        Vec::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{apply_edits, serialize_element, PluginTextEdit};
+    use rome_rowan::raw_language::{RawLanguageKind, RawSyntaxTreeBuilder};
+    use rome_rowan::{SyntaxElement, TriviaPiece};
+
+    #[test]
+    fn serializes_tree_and_applies_edits() {
+        let mut builder = RawSyntaxTreeBuilder::new();
+        builder.start_node(RawLanguageKind::ROOT);
+        builder.start_node(RawLanguageKind::EXPRESSION_LIST);
+        builder.start_node(RawLanguageKind::LITERAL_EXPRESSION);
+        builder.token(RawLanguageKind::NUMBER_TOKEN, "1");
+        builder.finish_node();
+        builder.token(RawLanguageKind::COMMA_TOKEN, ",");
+        builder.start_node(RawLanguageKind::LITERAL_EXPRESSION);
+        builder.token(RawLanguageKind::NUMBER_TOKEN, "2");
+        builder.finish_node();
+        builder.finish_node();
+        builder.finish_node();
+        let root = builder.finish();
+
+        let serialized =
+            serde_json::to_string(&serialize_element(SyntaxElement::Node(root.clone()))).unwrap();
+        assert_eq!(
+            serialized,
+            r#"{"kind":"ROOT","start":0,"end":3,"children":[{"kind":"EXPRESSION_LIST","start":0,"end":3,"children":[{"kind":"LITERAL_EXPRESSION","start":0,"end":1,"children":[{"kind":"NUMBER_TOKEN","start":0,"end":1,"text":"1"}]},{"kind":"COMMA_TOKEN","start":1,"end":2,"text":","},{"kind":"LITERAL_EXPRESSION","start":2,"end":3,"children":[{"kind":"NUMBER_TOKEN","start":2,"end":3,"text":"2"}]}]}]}"#
+        );
+
+        let edit = |start, end, text: &str| PluginTextEdit {
+            start,
+            end,
+            text: text.to_string(),
+        };
+
+        let mutation = apply_edits(&root, &[edit(0, 2, "3")]).unwrap();
+        assert_eq!(mutation.commit().to_string(), "32");
+
+        let mutation = apply_edits(&root, &[edit(0, 1, "3"), edit(2, 3, "4")]).unwrap();
+        assert_eq!(mutation.commit().to_string(), "3,4");
+
+        // The edits must start and end at the boundaries of tokens
+        assert!(apply_edits(&root, &[edit(1, 3, "")]).is_ok());
+        assert!(apply_edits(&root, &[edit(0, 0, "3")]).is_err());
+
+        // The edits must be sorted and can't overlap
+        assert!(apply_edits(&root, &[edit(2, 3, "4"), edit(0, 1, "3")]).is_err());
+        assert!(apply_edits(&root, &[edit(0, 2, "3"), edit(1, 3, "")]).is_err());
+    }
+
+    #[test]
+    fn applies_edits_with_trivia() {
+        // /* a */ 1 /* keep */, 2
+        let mut builder = RawSyntaxTreeBuilder::new();
+        builder.start_node(RawLanguageKind::ROOT);
+        builder.start_node(RawLanguageKind::EXPRESSION_LIST);
+        builder.start_node(RawLanguageKind::LITERAL_EXPRESSION);
+        builder.token_with_trivia(
+            RawLanguageKind::NUMBER_TOKEN,
+            "/* a */ 1 /* keep */",
+            &[
+                TriviaPiece::multi_line_comment(7),
+                TriviaPiece::whitespace(1),
+            ],
+            &[
+                TriviaPiece::whitespace(1),
+                TriviaPiece::multi_line_comment(10),
+            ],
+        );
+        builder.finish_node();
+        builder.token_with_trivia(
+            RawLanguageKind::COMMA_TOKEN,
+            ", ",
+            &[],
+            &[TriviaPiece::whitespace(1)],
+        );
+        builder.start_node(RawLanguageKind::LITERAL_EXPRESSION);
+        builder.token(RawLanguageKind::NUMBER_TOKEN, "2");
+        builder.finish_node();
+        builder.finish_node();
+        builder.finish_node();
+        let root = builder.finish();
+
+        let edit = |start, end, text: &str| PluginTextEdit {
+            start,
+            end,
+            text: text.to_string(),
+        };
+
+        // The trivia around the edited tokens is kept
+        let mutation = apply_edits(&root, &[edit(8, 9, "3")]).unwrap();
+        assert_eq!(mutation.commit().to_string(), "/* a */ 3 /* keep */, 2");
+
+        // The trivia between the edited tokens is removed
+        let mutation = apply_edits(&root, &[edit(20, 23, "4")]).unwrap();
+        assert_eq!(mutation.commit().to_string(), "/* a */ 1 /* keep */4");
+
+        // The edits can't remove comments
+        assert!(apply_edits(&root, &[edit(8, 21, "")]).is_err());
+        assert!(apply_edits(&root, &[edit(8, 23, "3")]).is_err());
+    }
+}
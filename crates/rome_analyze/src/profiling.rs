@@ -0,0 +1,380 @@
+//! Measure the time spent in each lint rule and in the visitors of the analyzer
+//!
+//! The profiling is disabled by default. Once a [Profile] is enabled and passed
+//! to the analyzer through the [AnalyzerOptions](crate::AnalyzerOptions), each
+//! run of the analyzer records its timings in a [Profiler], and merges them in
+//! the profile when the run finishes. The accumulated timings are returned by
+//! [Profile::take], which disables the profiling again.
+
+use std::{
+    cell::RefCell,
+    collections::BinaryHeap,
+    fmt::{self, Display, Formatter},
+    ops::AddAssign,
+    rc::Rc,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use rome_rowan::{Language, SyntaxNode, WalkEvent};
+use rustc_hash::FxHashMap;
+
+use crate::{
+    AnalyzerAction, AnalyzerDiagnostic, AnalyzerSignal, MatchQueryParams, QueryMatcher, RuleKey,
+    RuleRegistry, SignalEntry, Visitor, VisitorContext, VisitorFinishContext,
+};
+
+/// Timings recorded by all the runs of the analyzer sharing this profile since
+/// it was enabled
+#[derive(Debug, Default)]
+pub struct Profile {
+    /// The accumulated timings, `None` if the profiling is disabled
+    timings: Mutex<Option<FxHashMap<ProfileKey, Timings>>>,
+}
+
+impl Profile {
+    /// Start recording the time spent in the rules and visitors of the analyzer
+    pub fn enable(&self) {
+        let mut timings = self.timings.lock().unwrap();
+        if timings.is_none() {
+            *timings = Some(FxHashMap::default());
+        }
+    }
+
+    /// Returns `true` if the time spent in the rules is being recorded
+    pub fn is_enabled(&self) -> bool {
+        self.timings.lock().unwrap().is_some()
+    }
+
+    /// Disable the profiling and return the timings recorded since it was
+    /// enabled, sorted by decreasing total time
+    pub fn take(&self) -> Vec<(ProfileKey, Timings)> {
+        let timings = self.timings.lock().unwrap().take();
+        let mut entries: Vec<_> = timings.into_iter().flatten().collect();
+
+        entries.sort_unstable_by(|(left_key, left), (right_key, right)| {
+            right
+                .total()
+                .cmp(&left.total())
+                .then_with(|| left_key.to_string().cmp(&right_key.to_string()))
+        });
+
+        entries
+    }
+}
+
+/// Identifier of an entry of the profile
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ProfileKey {
+    /// A lint rule, including the custom rules and the rules of the plugins
+    Rule(RuleKey),
+    /// A visitor of the analyzer, like the one building the semantic model
+    Visitor(&'static str),
+}
+
+impl Display for ProfileKey {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        match self {
+            ProfileKey::Rule(key) => write!(fmt, "{}/{}", key.group(), key.rule_name()),
+            ProfileKey::Visitor(name) => write!(fmt, "{name}"),
+        }
+    }
+}
+
+/// Time spent in a rule or a visitor
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Timings {
+    /// Number of times the rule was run, or the visitor was called
+    pub calls: u64,
+    /// Time spent running the rule, or in the visitor without the rules it triggered
+    pub run: Duration,
+    /// Time spent creating the diagnostics of the rule
+    pub diagnostic: Duration,
+    /// Time spent creating the code actions of the rule
+    pub action: Duration,
+}
+
+impl Timings {
+    pub fn total(&self) -> Duration {
+        self.run + self.diagnostic + self.action
+    }
+}
+
+impl AddAssign<&Timings> for Timings {
+    fn add_assign(&mut self, other: &Timings) {
+        self.calls += other.calls;
+        self.run += other.run;
+        self.diagnostic += other.diagnostic;
+        self.action += other.action;
+    }
+}
+
+/// Timings of a single run of the analyzer, shared by the [ProfileMatcher],
+/// the [ProfiledVisitor]s and the signals they emit
+#[derive(Clone)]
+pub struct Profiler {
+    state: Rc<RefCell<ProfilerState>>,
+}
+
+struct ProfilerState {
+    /// The profile the timings are merged into
+    profile: Arc<Profile>,
+    timings: FxHashMap<ProfileKey, Timings>,
+    /// Total time spent matching the queries, subtracted from the time of the
+    /// visitors emitting them
+    matching: Duration,
+}
+
+impl Profiler {
+    /// Return a new profiler merging its timings into `profile`, or `None` if
+    /// the profiling of `profile` is disabled
+    pub fn new(profile: Arc<Profile>) -> Option<Self> {
+        if !profile.is_enabled() {
+            return None;
+        }
+
+        Some(Self {
+            state: Rc::new(RefCell::new(ProfilerState {
+                profile,
+                timings: FxHashMap::default(),
+                matching: Duration::ZERO,
+            })),
+        })
+    }
+
+    fn record(&self, key: ProfileKey, update: impl FnOnce(&mut Timings)) {
+        let mut state = self.state.borrow_mut();
+        update(state.timings.entry(key).or_default());
+    }
+
+    fn matching(&self) -> Duration {
+        self.state.borrow().matching
+    }
+}
+
+// The timings are merged in the profile once all the handles to the profiler,
+// including the ones held by the signals, are dropped
+impl Drop for ProfilerState {
+    fn drop(&mut self) {
+        if let Some(profile) = self.profile.timings.lock().unwrap().as_mut() {
+            for (key, timings) in &self.timings {
+                *profile.entry(*key).or_default() += timings;
+            }
+        }
+    }
+}
+
+/// Adapter type wrapping a [RuleRegistry] to measure the time spent running
+/// each rule, and creating the diagnostics and actions of its signals
+pub struct ProfileMatcher<L: Language> {
+    inner: RuleRegistry<L>,
+    profiler: Option<Profiler>,
+}
+
+impl<L: Language> ProfileMatcher<L> {
+    /// Create a new instance of [ProfileMatcher] from an existing [RuleRegistry],
+    /// the rules aren't measured if `profiler` is `None`
+    pub fn new(inner: RuleRegistry<L>, profiler: Option<Profiler>) -> Self {
+        Self { inner, profiler }
+    }
+}
+
+impl<L: Language> QueryMatcher<L> for ProfileMatcher<L> {
+    fn match_query(&mut self, params: MatchQueryParams<L>) {
+        let profiler = match &self.profiler {
+            Some(profiler) => profiler,
+            None => return self.inner.match_query(params),
+        };
+
+        let start = Instant::now();
+
+        // The signals are collected in a separate queue so they can be
+        // wrapped before being pushed to the queue of the analyzer
+        let mut signals = BinaryHeap::new();
+        self.inner.run_rules(
+            MatchQueryParams {
+                phase: params.phase,
+                file_id: params.file_id,
+                root: params.root,
+                query: params.query,
+                services: params.services,
+                signal_queue: &mut signals,
+                options: params.options,
+            },
+            |rule, run| {
+                let start = Instant::now();
+                run();
+                let elapsed = start.elapsed();
+
+                profiler.record(ProfileKey::Rule(rule), |timings| {
+                    timings.calls += 1;
+                    timings.run += elapsed;
+                });
+            },
+        );
+
+        for entry in signals.into_vec() {
+            params.signal_queue.push(SignalEntry {
+                signal: Box::new(ProfiledSignal {
+                    inner: entry.signal,
+                    key: ProfileKey::Rule(entry.rule),
+                    profiler: profiler.clone(),
+                }),
+                rule: entry.rule,
+                text_range: entry.text_range,
+            });
+        }
+
+        profiler.state.borrow_mut().matching += start.elapsed();
+    }
+}
+
+/// Signal measuring the time spent creating the diagnostic and actions of
+/// the signal it wraps
+struct ProfiledSignal<'phase, L: Language> {
+    inner: Box<dyn AnalyzerSignal<L> + 'phase>,
+    key: ProfileKey,
+    profiler: Profiler,
+}
+
+impl<L: Language> AnalyzerSignal<L> for ProfiledSignal<'_, L> {
+    fn diagnostic(&self) -> Option<AnalyzerDiagnostic> {
+        let start = Instant::now();
+        let diagnostic = self.inner.diagnostic();
+        let elapsed = start.elapsed();
+
+        self.profiler.record(self.key, |timings| {
+            timings.diagnostic += elapsed;
+        });
+
+        diagnostic
+    }
+
+    fn actions(&self) -> Vec<AnalyzerAction<L>> {
+        let start = Instant::now();
+        let actions = self.inner.actions();
+        let elapsed = start.elapsed();
+
+        self.profiler.record(self.key, |timings| {
+            timings.action += elapsed;
+        });
+
+        actions
+    }
+}
+
+/// Adapter type wrapping a [Visitor] to measure the time spent in it, without
+/// the time spent running the rules matching the queries it emits
+pub struct ProfiledVisitor<V> {
+    name: &'static str,
+    inner: V,
+    profiler: Option<Profiler>,
+}
+
+impl<V> ProfiledVisitor<V> {
+    /// Create a new instance of [ProfiledVisitor] recording the time spent in
+    /// `inner` under `name`, the visitor isn't measured if `profiler` is `None`
+    pub fn new(name: &'static str, inner: V, profiler: Option<Profiler>) -> Self {
+        Self {
+            name,
+            inner,
+            profiler,
+        }
+    }
+}
+
+impl<V: Visitor> Visitor for ProfiledVisitor<V> {
+    type Language = V::Language;
+
+    fn visit(
+        &mut self,
+        event: &WalkEvent<SyntaxNode<Self::Language>>,
+        ctx: VisitorContext<Self::Language>,
+    ) {
+        let profiler = match &self.profiler {
+            Some(profiler) => profiler,
+            None => return self.inner.visit(event, ctx),
+        };
+
+        let matching = profiler.matching();
+        let start = Instant::now();
+        self.inner.visit(event, ctx);
+        let elapsed = start
+            .elapsed()
+            .saturating_sub(profiler.matching() - matching);
+
+        profiler.record(ProfileKey::Visitor(self.name), |timings| {
+            timings.calls += 1;
+            timings.run += elapsed;
+        });
+    }
+
+    fn finish(self: Box<Self>, ctx: VisitorFinishContext<Self::Language>) {
+        let Self {
+            name,
+            inner,
+            profiler,
+        } = *self;
+
+        let start = Instant::now();
+        Box::new(inner).finish(ctx);
+        let elapsed = start.elapsed();
+
+        if let Some(profiler) = profiler {
+            profiler.record(ProfileKey::Visitor(name), |timings| {
+                timings.run += elapsed;
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use super::{Profile, ProfileKey, Profiler, Timings};
+
+    #[test]
+    fn merges_profilers() {
+        let profile = Arc::new(Profile::default());
+        assert!(Profiler::new(profile.clone()).is_none());
+        profile.enable();
+
+        let record = |name, run| {
+            let profiler = Profiler::new(profile.clone()).unwrap();
+            profiler.record(ProfileKey::Visitor(name), |timings| {
+                timings.calls += 1;
+                timings.run += Duration::from_millis(run);
+            });
+        };
+
+        record("semanticModel", 1);
+        record("controlFlow", 4);
+        record("semanticModel", 2);
+
+        assert_eq!(
+            profile.take(),
+            [
+                (
+                    ProfileKey::Visitor("controlFlow"),
+                    Timings {
+                        calls: 1,
+                        run: Duration::from_millis(4),
+                        ..Timings::default()
+                    }
+                ),
+                (
+                    ProfileKey::Visitor("semanticModel"),
+                    Timings {
+                        calls: 2,
+                        run: Duration::from_millis(3),
+                        ..Timings::default()
+                    }
+                )
+            ]
+        );
+
+        assert!(Profiler::new(profile).is_none());
+    }
+}
@@ -1,4 +1,4 @@
-use std::{borrow, collections::BTreeSet};
+use std::{borrow, collections::BTreeSet, sync::Mutex};
 
 use rome_diagnostics::v2::Error;
 use rome_rowan::{AstNode, Language, RawSyntaxKind, SyntaxKind, SyntaxNode};
@@ -55,7 +55,7 @@ pub trait RegistryVisitor<L: Language> {
 
 /// Stores metadata information for all the rules in the registry, sorted
 /// alphabetically
-#[derive(Default)]
+#[derive(Default, Clone)]
 pub struct MetadataRegistry {
     inner: BTreeSet<MetadataKey>,
 }
@@ -73,11 +73,82 @@ impl MetadataRegistry {
         Some(key.into_rule_key())
     }
 
+    /// Return the name of the known group closest to `group`, if any is similar
+    /// enough to be a likely misspelling of it
+    pub fn suggest_group(&self, group: &str) -> Option<&'static str> {
+        let mut groups: Vec<_> = self.inner.iter().map(|key| key.inner.0).collect();
+        groups.dedup();
+
+        groups
+            .into_iter()
+            .map(|candidate| (edit_distance(group, candidate), candidate))
+            .filter(|(distance, _)| *distance <= max_edit_distance(group))
+            .min_by_key(|(distance, _)| *distance)
+            .map(|(_, candidate)| candidate)
+    }
+
+    /// Return the identifier of the known rule closest to `group/rule`, if any is
+    /// similar enough to be a likely misspelling of it. The rule may belong to
+    /// another group than `group`.
+    pub fn suggest_rule(&self, group: &str, rule: &str) -> Option<RuleKey> {
+        let max_distance = max_edit_distance(rule);
+
+        self.inner
+            .iter()
+            .filter_map(|key| {
+                let (candidate_group, candidate_rule) = key.inner;
+                let distance = edit_distance(rule, candidate_rule);
+                if distance > max_distance {
+                    return None;
+                }
+
+                // Prefer the rules of the requested group, but still suggest a
+                // rule with the exact same name in another group
+                let group_distance = if candidate_group == group {
+                    0
+                } else if distance == 0 {
+                    1
+                } else {
+                    return None;
+                };
+
+                Some(((distance, group_distance), key))
+            })
+            .min_by_key(|(distance, _)| *distance)
+            .map(|(_, key)| key.into_rule_key())
+    }
+
     pub(crate) fn insert_rule(&mut self, group: &'static str, rule: &'static str) {
         self.inner.insert(MetadataKey {
             inner: (group, rule),
         });
     }
+
+    /// Register a rule that isn't statically known to the analyzer, like the
+    /// rules defined in the configuration, and return its identifier
+    pub fn insert_dynamic_rule(&mut self, group: &'static str, rule: &'static str) -> RuleKey {
+        self.insert_rule(group, rule);
+        RuleKey::new(group, rule)
+    }
+}
+
+/// Return a `'static` copy of the name of a rule or group that isn't statically
+/// known to the analyzer, like the rules defined in the configuration
+///
+/// Each distinct name is only allocated once for the lifetime of the process,
+/// so loading the same configuration again doesn't allocate its names again
+pub fn intern_rule_name(name: &str) -> &'static str {
+    static NAMES: Mutex<Option<BTreeSet<&'static str>>> = Mutex::new(None);
+
+    let mut names = NAMES.lock().unwrap();
+    let names = names.get_or_insert_with(BTreeSet::new);
+    if let Some(name) = names.get(name) {
+        return name;
+    }
+
+    let name: &'static str = Box::leak(name.into());
+    names.insert(name);
+    name
 }
 
 impl<L: Language> RegistryVisitor<L> for MetadataRegistry {
@@ -91,6 +162,32 @@ impl<L: Language> RegistryVisitor<L> for MetadataRegistry {
     }
 }
 
+/// Maximum number of edits between a name and a known name for the latter to
+/// be suggested as a replacement
+fn max_edit_distance(name: &str) -> usize {
+    (name.chars().count() / 3).max(1)
+}
+
+/// Levenshtein distance between `a` and `b`, the comparison ignores the case
+/// of the characters
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<_> = b.chars().flat_map(char::to_lowercase).collect();
+    let mut row: Vec<_> = (0..=b.len()).collect();
+
+    for (i, a_char) in a.chars().flat_map(char::to_lowercase).enumerate() {
+        let mut previous = row[0];
+        row[0] = i + 1;
+
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous + usize::from(a_char != *b_char);
+            previous = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(previous + 1);
+        }
+    }
+
+    row[b.len()]
+}
+
 /// The rule registry holds type-erased instances of all active analysis rules
 /// for each phase.
 /// What defines a phase is the set of services that a phase offers. Currently
@@ -124,6 +221,9 @@ struct PhaseRules<L: Language> {
     semantic_model: Vec<RegistryRule<L>>,
     /// Holds a list of states for all the rules in this phase
     rule_states: Vec<RuleState<L>>,
+    /// Holds the identifiers of all the rules in this phase, in the same
+    /// order as `rule_states`
+    rule_keys: Vec<RuleKey>,
 }
 
 pub struct RuleRegistryBuilder<'a, L: Language> {
@@ -190,6 +290,7 @@ impl<L: Language + Default> RegistryVisitor<L> for RuleRegistryBuilder<'_, L> {
         }
 
         phase.rule_states.push(RuleState::default());
+        phase.rule_keys.push(RuleKey::rule::<R>());
     }
 }
 
@@ -199,8 +300,26 @@ impl<L: Language> RuleRegistryBuilder<'_, L> {
     }
 }
 
-impl<L: Language> QueryMatcher<L> for RuleRegistry<L> {
-    fn match_query(&mut self, mut params: MatchQueryParams<L>) {
+impl<L: Language> RuleRegistry<L> {
+    /// Returns `true` if rules querying the control flow graph are registered
+    /// for `phase`, in which case the graphs need to be emitted in this phase
+    pub fn has_control_flow_rules(&self, phase: Phases) -> bool {
+        !self.phase_rules[phase as usize].control_flow.is_empty()
+    }
+
+    /// Returns the identifiers of all the rules registered in this instance
+    pub fn rule_keys(&self) -> impl Iterator<Item = RuleKey> + '_ {
+        self.phase_rules
+            .iter()
+            .flat_map(|phase| phase.rule_keys.iter().copied())
+    }
+
+    /// Run all the rules registered to the query of `params`: `run_rule` is
+    /// called with the key of each rule and a function running it
+    pub(crate) fn run_rules<F>(&mut self, mut params: MatchQueryParams<L>, mut run_rule: F)
+    where
+        F: FnMut(RuleKey, &mut dyn FnMut()),
+    {
         let phase = &mut self.phase_rules[params.phase as usize];
 
         let rules = match &params.query {
@@ -223,12 +342,20 @@ impl<L: Language> QueryMatcher<L> for RuleRegistry<L> {
         // Run all the rules registered to this QueryMatch
         for rule in rules {
             let state = &mut phase.rule_states[rule.state_index];
-            // TODO: #3394 track error in the signal queue
-            let _ = (rule.run)(&mut params, state);
+            run_rule(rule.key, &mut || {
+                // TODO: #3394 track error in the signal queue
+                let _ = (rule.run)(&mut params, state);
+            });
         }
     }
 }
 
+impl<L: Language> QueryMatcher<L> for RuleRegistry<L> {
+    fn match_query(&mut self, params: MatchQueryParams<L>) {
+        self.run_rules(params, |_, run| run());
+    }
+}
+
 /// [SyntaxKindRules] holds a collection of [Rule]s that match a specific [SyntaxKind] value
 struct SyntaxKindRules<L: Language> {
     rules: Vec<RegistryRule<L>>,
@@ -292,6 +419,7 @@ impl RegistryRuleMetadata {
 /// Internal representation of a single rule in the registry
 #[derive(Copy, Clone)]
 pub struct RegistryRule<L: Language> {
+    key: RuleKey,
     run: RuleExecutor<L>,
     state_index: usize,
 }
@@ -378,6 +506,7 @@ impl<L: Language + Default> RegistryRule<L> {
         }
 
         Self {
+            key: RuleKey::rule::<R>(),
             run: run::<R>,
             state_index,
         }
@@ -14,6 +14,9 @@ use rome_diagnostics::v2::{
 use rome_diagnostics::Applicability;
 use rome_rowan::{BatchMutation, Language, TextRange};
 use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::error::Error;
 
 /// Static metadata containing information about a rule
 pub struct RuleMetadata {
@@ -328,6 +331,22 @@ pub trait Rule: RuleMeta {
         let (..) = (ctx, state);
         None
     }
+
+    /// Called by the consumer of the analyzer to generate all the code actions
+    /// available for a signal raised by `run`
+    ///
+    /// Rules that can fix a signal in several different ways should override
+    /// this function instead of `action`, and return one labelled action per
+    /// alternative. The order of the actions is the order in which they are
+    /// presented to the user.
+    ///
+    /// The default implementation returns the action generated by `action`, if any
+    fn actions(
+        ctx: &RuleContext<Self>,
+        state: &Self::State,
+    ) -> Vec<RuleAction<RuleLanguage<Self>>> {
+        Self::action(ctx, state).into_iter().collect()
+    }
 }
 
 /// Diagnostic object returned by a single analysis rule
@@ -361,7 +380,15 @@ impl Advices for RuleAdvice {
                 detail.log_category,
                 &markup! { {detail.message} }.to_owned(),
             )?;
-            if let Some(location) = Location::builder().span(&detail.range).build() {
+            let location = match &detail.file {
+                Some(file) => Location::builder()
+                    .resource(&file.path)
+                    .span(&detail.range)
+                    .source_code(&file.source_code)
+                    .build(),
+                None => Location::builder().span(&detail.range).build(),
+            };
+            if let Some(location) = location {
                 visitor.record_frame(location)?;
             }
         }
@@ -384,6 +411,33 @@ pub struct Detail {
     pub log_category: LogCategory,
     pub message: MarkupBuf,
     pub range: Option<TextRange>,
+    /// The file `range` is in, if it's not the analyzed file
+    pub file: Option<DetailFile>,
+}
+
+/// A file of the project other than the analyzed one, referenced by a [Detail]
+#[derive(Debug)]
+pub struct DetailFile {
+    pub path: String,
+    pub source_code: String,
+}
+
+/// Number of lines printed after a span in the code frames of the diagnostics
+const FRAME_CONTEXT_LINES: usize = 2;
+
+/// Return the start of `source_code` until the end of the code frame of `range`
+fn frame_source_code(source_code: &str, range: TextRange) -> &str {
+    let end = usize::from(range.end());
+    if !source_code.is_char_boundary(end) {
+        return source_code;
+    }
+
+    let frame_end = source_code[end..]
+        .match_indices('\n')
+        .nth(FRAME_CONTEXT_LINES)
+        .map_or(source_code.len(), |(index, _)| end + index);
+
+    &source_code[..frame_end]
 }
 
 impl RuleDiagnostic {
@@ -432,6 +486,7 @@ impl RuleDiagnostic {
             log_category: LogCategory::Info,
             message: markup!({ msg }).to_owned(),
             range: span.as_span(),
+            file: None,
         });
         self
     }
@@ -441,6 +496,36 @@ impl RuleDiagnostic {
         self.label(span, msg)
     }
 
+    /// Attaches a detailed message to this [`RuleDiagnostic`], pointing to a
+    /// span in another file of the project.
+    ///
+    /// Only the source code up to the last line printed in the frame of the
+    /// span is kept in the diagnostic.
+    pub fn detail_in_file(
+        mut self,
+        path: impl Into<String>,
+        source_code: &str,
+        span: impl AsSpan,
+        msg: impl Display,
+    ) -> Self {
+        let range = span.as_span();
+        let source_code = match range {
+            Some(range) => frame_source_code(source_code, range),
+            None => "",
+        };
+
+        self.rule_advice.details.push(Detail {
+            log_category: LogCategory::Info,
+            message: markup!({ msg }).to_owned(),
+            range,
+            file: Some(DetailFile {
+                path: path.into(),
+                source_code: source_code.to_string(),
+            }),
+        });
+        self
+    }
+
     /// Adds a footer to this [`RuleDiagnostic`], which will be displayed under the actual error.
     fn footer(mut self, log_category: LogCategory, msg: impl Display) -> Self {
         self.rule_advice
@@ -482,3 +567,25 @@ pub struct RuleAction<L: Language> {
     pub message: MarkupBuf,
     pub mutation: BatchMutation<L>,
 }
+
+/// Series of errors encountered when running rules on a file
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub enum RuleError {
+    /// The rule with the specified name replaced the root of the file with a node that is not a valid root for that language.
+    ReplacedRootWithNonRootError { rule_name: Cow<'static, str> },
+}
+
+impl std::fmt::Display for RuleError {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            RuleError::ReplacedRootWithNonRootError { rule_name } => {
+                std::write!(
+                    fmt,
+                    "the rule '{rule_name}' replaced the root of the file with a non-root node."
+                )
+            }
+        }
+    }
+}
+
+impl Error for RuleError {}
@@ -3,19 +3,24 @@ use crate::{
     context::RuleContext,
     registry::{RuleLanguage, RuleRoot},
     rule::Rule,
-    AnalyzerDiagnostic, AnalyzerOptions, Queryable, RuleGroup, ServiceBag,
+    AnalyzerDiagnostic, AnalyzerOptions, Queryable, RuleGroup, ServiceBag, SuppressionDiagnostic,
 };
-use rome_console::MarkupBuf;
+use rome_console::{markup, MarkupBuf};
 use rome_diagnostics::file::FileSpan;
 use rome_diagnostics::v2::advice::CodeSuggestionAdvice;
+use rome_diagnostics::v2::category;
 use rome_diagnostics::{file::FileId, Applicability, CodeSuggestion};
-use rome_rowan::{BatchMutation, Language};
+use rome_rowan::{
+    BatchMutation, Language, SyntaxNode, SyntaxTriviaPiece, TextRange, TriviaPieceKind,
+};
 
 /// Event raised by the analyzer when a [Rule](crate::Rule)
 /// emits a diagnostic, a code action, or both
 pub trait AnalyzerSignal<L: Language> {
     fn diagnostic(&self) -> Option<AnalyzerDiagnostic>;
-    fn action(&self) -> Option<AnalyzerAction<L>>;
+    /// Returns all the code actions that can be applied to fix this signal,
+    /// in the order they should be presented to the user
+    fn actions(&self) -> Vec<AnalyzerAction<L>>;
 }
 
 /// Simple implementation of [AnalyzerSignal] generating a [AnalyzerDiagnostic] from a
@@ -41,9 +46,242 @@ where
         Some((self.factory)())
     }
 
-    fn action(&self) -> Option<AnalyzerAction<L>> {
+    fn actions(&self) -> Vec<AnalyzerAction<L>> {
+        Vec::new()
+    }
+}
+
+/// Signal emitted by the analyzer for a suppression comment that didn't suppress
+/// any diagnostic, it offers a safe fix removing the comment
+pub(crate) struct UnusedSuppressionSignal<'a, L: Language> {
+    file_id: FileId,
+    root: &'a SyntaxNode<L>,
+    /// Range of the first comment of the suppression, where the diagnostic is reported
+    comment_span: TextRange,
+    /// Ranges of all the comments of the suppression
+    comments: Vec<TextRange>,
+}
+
+impl<'a, L: Language> UnusedSuppressionSignal<'a, L> {
+    pub(crate) fn new(
+        file_id: FileId,
+        root: &'a SyntaxNode<L>,
+        comment_span: TextRange,
+        comments: Vec<TextRange>,
+    ) -> Self {
+        Self {
+            file_id,
+            root,
+            comment_span,
+            comments,
+        }
+    }
+}
+
+impl<L: Language> AnalyzerSignal<L> for UnusedSuppressionSignal<'_, L> {
+    fn diagnostic(&self) -> Option<AnalyzerDiagnostic> {
+        let diag = SuppressionDiagnostic::new(
+            self.file_id,
+            category!("suppressions/unused"),
+            self.comment_span,
+            "Suppression comment is not being used",
+        );
+
+        Some(AnalyzerDiagnostic::from_error(diag.into()))
+    }
+
+    fn actions(&self) -> Vec<AnalyzerAction<L>> {
+        let mut mutation = BatchMutation::new(self.root.clone());
+        let mut tokens = Vec::new();
+
+        for comment in &self.comments {
+            let token = match self.root.token_at_offset(comment.start()).right_biased() {
+                Some(token) => token,
+                None => return Vec::new(),
+            };
+
+            if !tokens.contains(&token) {
+                tokens.push(token);
+            }
+        }
+
+        for token in tokens {
+            let leading = remove_comments(token.leading_trivia().pieces(), &self.comments);
+            let trailing = remove_comments(token.trailing_trivia().pieces(), &self.comments);
+            let next_token = token
+                .with_leading_trivia_pieces(leading)
+                .with_trailing_trivia_pieces(trailing);
+
+            mutation.replace_token_discard_trivia(token, next_token);
+        }
+
+        vec![AnalyzerAction {
+            group_name: "suppressions",
+            rule_name: "unused",
+            file_id: self.file_id,
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::Always,
+            message: markup! { "Remove the unused suppression comment" }.to_owned(),
+            mutation,
+        }]
+    }
+}
+
+/// Signal emitted by the analyzer for a comment that can be rewritten into a Rome
+/// suppression comment, it offers a suggested fix replacing the comment
+pub(crate) struct SuppressionRewriteSignal<'a, L: Language> {
+    file_id: FileId,
+    root: &'a SyntaxNode<L>,
+    /// Range of the comment to rewrite
+    comment: TextRange,
+    /// Text of the equivalent Rome suppression comment
+    replacement: String,
+    /// Comment appended at the end of the file to close the range opened by
+    /// the replacement, if any
+    end_of_file: Option<String>,
+}
+
+impl<'a, L: Language> SuppressionRewriteSignal<'a, L> {
+    pub(crate) fn new(
+        file_id: FileId,
+        root: &'a SyntaxNode<L>,
+        comment: TextRange,
+        replacement: String,
+        end_of_file: Option<String>,
+    ) -> Self {
+        Self {
+            file_id,
+            root,
+            comment,
+            replacement,
+            end_of_file,
+        }
+    }
+}
+
+impl<L: Language> AnalyzerSignal<L> for SuppressionRewriteSignal<'_, L> {
+    fn diagnostic(&self) -> Option<AnalyzerDiagnostic> {
         None
     }
+
+    fn actions(&self) -> Vec<AnalyzerAction<L>> {
+        let token = match self
+            .root
+            .token_at_offset(self.comment.start())
+            .right_biased()
+        {
+            Some(token) => token,
+            None => return Vec::new(),
+        };
+
+        let rewrite = |pieces: &mut dyn Iterator<Item = SyntaxTriviaPiece<L>>| {
+            pieces
+                .map(|piece| {
+                    if piece.text_range() != self.comment {
+                        return (piece.kind(), piece.text().to_string());
+                    }
+
+                    // The replacement is always written on a single line
+                    let kind = match piece.kind() {
+                        TriviaPieceKind::MultiLineComment => TriviaPieceKind::SingleLineComment,
+                        kind => kind,
+                    };
+
+                    (kind, self.replacement.clone())
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let leading = rewrite(&mut token.leading_trivia().pieces());
+        let trailing = rewrite(&mut token.trailing_trivia().pieces());
+
+        let mut next_token = token
+            .with_leading_trivia(leading.iter().map(|(kind, text)| (*kind, text.as_str())))
+            .with_trailing_trivia(trailing.iter().map(|(kind, text)| (*kind, text.as_str())));
+
+        let mut mutation = BatchMutation::new(self.root.clone());
+
+        // The closing comment is appended to the leading trivia of the last
+        // token of the file, which may be the token holding the rewritten comment
+        let last_token = self.root.last_token();
+        if let (Some(end_of_file), Some(last_token)) = (&self.end_of_file, last_token) {
+            let is_rewritten_token = last_token == token;
+            let base = if is_rewritten_token {
+                next_token.clone()
+            } else {
+                last_token.clone()
+            };
+
+            let mut pieces: Vec<_> = base
+                .leading_trivia()
+                .pieces()
+                .map(|piece| (piece.kind(), piece.text().to_string()))
+                .collect();
+
+            if !matches!(pieces.last(), Some((TriviaPieceKind::Newline, _))) {
+                pieces.push((TriviaPieceKind::Newline, String::from("\n")));
+            }
+            pieces.push((TriviaPieceKind::SingleLineComment, end_of_file.clone()));
+            pieces.push((TriviaPieceKind::Newline, String::from("\n")));
+
+            let closed_token =
+                base.with_leading_trivia(pieces.iter().map(|(kind, text)| (*kind, text.as_str())));
+
+            if is_rewritten_token {
+                next_token = closed_token;
+            } else {
+                mutation.replace_token_discard_trivia(last_token, closed_token);
+            }
+        }
+
+        mutation.replace_token_discard_trivia(token, next_token);
+
+        vec![AnalyzerAction {
+            group_name: "suppressions",
+            rule_name: "rewrite",
+            file_id: self.file_id,
+            category: ActionCategory::Refactor,
+            applicability: Applicability::MaybeIncorrect,
+            message: markup! { "Rewrite into a Rome suppression comment" }.to_owned(),
+            mutation,
+        }]
+    }
+}
+
+/// Returns the trivia `pieces` without the comments in `comments`, along with
+/// their indentation and the line break that follows them if they're on their
+/// own line
+fn remove_comments<L: Language>(
+    pieces: impl Iterator<Item = SyntaxTriviaPiece<L>>,
+    comments: &[TextRange],
+) -> Vec<SyntaxTriviaPiece<L>> {
+    let mut result: Vec<SyntaxTriviaPiece<L>> = Vec::new();
+    let mut is_own_line = false;
+
+    for piece in pieces {
+        if comments.contains(&piece.text_range()) {
+            while matches!(result.last(), Some(last) if last.kind() == TriviaPieceKind::Whitespace)
+            {
+                result.pop();
+            }
+
+            is_own_line = result
+                .last()
+                .map_or(true, |last| last.kind() == TriviaPieceKind::Newline);
+            continue;
+        }
+
+        if is_own_line {
+            is_own_line = false;
+            if piece.kind() == TriviaPieceKind::Newline {
+                continue;
+            }
+        }
+
+        result.push(piece);
+    }
+
+    result
 }
 
 /// Code Action object returned by the analyzer, generated from a [crate::RuleAction]
@@ -141,18 +379,24 @@ where
         R::diagnostic(&ctx, &self.state).map(|diag| diag.into_analyzer_diagnostic(self.file_id))
     }
 
-    fn action(&self) -> Option<AnalyzerAction<RuleLanguage<R>>> {
+    fn actions(&self) -> Vec<AnalyzerAction<RuleLanguage<R>>> {
         let ctx =
-            RuleContext::new(&self.query_result, self.root, self.services, &self.options).ok()?;
+            match RuleContext::new(&self.query_result, self.root, self.services, &self.options) {
+                Ok(ctx) => ctx,
+                Err(_) => return Vec::new(),
+            };
 
-        R::action(&ctx, &self.state).map(|action| AnalyzerAction {
-            group_name: <R::Group as RuleGroup>::NAME,
-            rule_name: R::METADATA.name,
-            file_id: self.file_id,
-            category: action.category,
-            applicability: action.applicability,
-            message: action.message,
-            mutation: action.mutation,
-        })
+        R::actions(&ctx, &self.state)
+            .into_iter()
+            .map(|action| AnalyzerAction {
+                group_name: <R::Group as RuleGroup>::NAME,
+                rule_name: R::METADATA.name,
+                file_id: self.file_id,
+                category: action.category,
+                applicability: action.applicability,
+                message: action.message,
+                mutation: action.mutation,
+            })
+            .collect()
     }
 }
@@ -6,7 +6,8 @@ use rome_rowan::{AstNode, Language, SyntaxNode, TextRange, WalkEvent};
 use crate::{
     matcher::MatchQueryParams,
     registry::{NodeLanguage, Phases},
-    AnalyzerOptions, LanguageRoot, QueryMatch, QueryMatcher, ServiceBag, SignalEntry,
+    AnalyzerOptions, AnalyzerSignal, LanguageRoot, QueryMatch, QueryMatcher, RuleKey, ServiceBag,
+    SignalEntry,
 };
 
 /// Mutable context objects shared by all visitors
@@ -33,6 +34,22 @@ impl<'phase, 'query, L: Language> VisitorContext<'phase, 'query, L> {
             options: self.options,
         })
     }
+
+    /// Emit a signal for a rule that isn't part of the rule registry, like the
+    /// rules defined in the configuration. The signal is suppressed by the
+    /// suppression comments for `rule`
+    pub fn emit_signal(
+        &mut self,
+        rule: RuleKey,
+        text_range: TextRange,
+        signal: Box<dyn AnalyzerSignal<L> + 'phase>,
+    ) {
+        self.signal_queue.push(SignalEntry {
+            signal,
+            rule,
+            text_range,
+        });
+    }
 }
 
 /// Mutable context objects provided to the finish hook of visitors
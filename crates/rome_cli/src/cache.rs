@@ -0,0 +1,235 @@
+//! On-disk cache of the files that are known to be already formatted
+//!
+//! Each entry of the cache maps the path of a file to the hash of its content
+//! at the time it was last found to be correctly formatted. The whole cache is
+//! tagged with the version of Rome and a hash of the configuration it was
+//! computed with: if either of them changes, the cache is discarded. The
+//! configuration files of nested directories are hashed separately, the entries
+//! below a directory are discarded when its configuration changes.
+
+use crate::{CliSession, VERSION};
+use dashmap::DashMap;
+use rome_fs::{FileSystem, OpenOptions};
+use rome_service::Configuration;
+use serde::{Deserialize, Serialize};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::io;
+use std::path::{Component, Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+/// Directory where the cache is stored if none is specified in the configuration
+const DEFAULT_CACHE_DIRECTORY: &str = "node_modules/.cache/rome";
+
+/// Name of the file holding the cache, inside the cache directory
+const CACHE_FILE_NAME: &str = "format-cache.json";
+
+/// Serialized content of the cache file
+#[derive(Deserialize, Serialize)]
+struct CacheFile {
+    /// Version of Rome that wrote the cache
+    version: String,
+    /// Hash of the configuration used to format the files
+    options: u64,
+    /// Path of the file -> hash of its formatted content
+    files: HashMap<PathBuf, u64>,
+    /// Directory of a nested configuration file -> hash of its configuration
+    #[serde(default)]
+    directories: HashMap<PathBuf, u64>,
+}
+
+pub(crate) struct FormatCache {
+    /// Location of the cache file
+    path: PathBuf,
+    /// Hash of the configuration used for this run
+    options: u64,
+    /// Path of the file -> hash of its formatted content
+    entries: DashMap<PathBuf, u64>,
+    /// Directory of a nested configuration file -> hash of its configuration
+    directories: DashMap<PathBuf, u64>,
+    /// Set to `true` when the entries have changed since the cache was loaded
+    dirty: AtomicBool,
+    /// Number of files found to be already formatted during this run
+    hits: AtomicUsize,
+}
+
+impl FormatCache {
+    /// Loads the cache stored in `directory`, discarding its content if it was
+    /// written by another version of Rome or with a different configuration
+    pub(crate) fn load(
+        fs: &dyn FileSystem,
+        directory: &Path,
+        configuration: &Configuration,
+    ) -> Self {
+        let path = directory.join(CACHE_FILE_NAME);
+        let options = hash_configuration(configuration);
+
+        let (entries, directories) = match read_cache_file(fs, &path) {
+            Ok(Some(cache)) if cache.version == VERSION && cache.options == options => (
+                cache.files.into_iter().collect(),
+                cache.directories.into_iter().collect(),
+            ),
+            Ok(Some(_)) => {
+                tracing::info!("discarding the formatter cache at {path:?} as it is outdated");
+                (DashMap::new(), DashMap::new())
+            }
+            Ok(None) => (DashMap::new(), DashMap::new()),
+            Err(err) => {
+                tracing::warn!("failed to read the formatter cache at {path:?}: {err}");
+                (DashMap::new(), DashMap::new())
+            }
+        };
+
+        Self {
+            path,
+            options,
+            entries,
+            directories,
+            dirty: AtomicBool::new(false),
+            hits: AtomicUsize::new(0),
+        }
+    }
+
+    /// Returns `true` if the file at `path` was already formatted the last
+    /// time it was processed, and its content hasn't changed since then
+    pub(crate) fn is_formatted(&self, path: &Path, content: &str) -> bool {
+        let is_formatted = self
+            .entries
+            .get(path)
+            .map_or(false, |entry| *entry == hash_content(content));
+
+        if is_formatted {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+        }
+
+        is_formatted
+    }
+
+    /// Returns the number of files skipped during this run because they were
+    /// already formatted
+    pub(crate) fn hits(&self) -> usize {
+        self.hits.load(Ordering::Relaxed)
+    }
+
+    /// Records that `content` is the formatted content of the file at `path`
+    pub(crate) fn insert(&self, path: &Path, content: &str) {
+        let hash = hash_content(content);
+        let previous = self.entries.insert(path.to_path_buf(), hash);
+        if previous != Some(hash) {
+            self.dirty.store(true, Ordering::Relaxed);
+        }
+    }
+
+    /// Records the configuration used to format the files below `directory`. The
+    /// entries of these files are discarded if the configuration has changed.
+    pub(crate) fn register_directory(&self, directory: &Path, configuration: &Configuration) {
+        let hash = hash_configuration(configuration);
+        let previous = self.directories.insert(directory.to_path_buf(), hash);
+        if previous != Some(hash) {
+            self.entries
+                .retain(|path, _| !without_current_dir(path).starts_with(directory));
+            self.dirty.store(true, Ordering::Relaxed);
+        }
+    }
+
+    /// Writes the cache back to the disk if any entry was modified
+    pub(crate) fn persist(&self, fs: &dyn FileSystem) -> io::Result<()> {
+        if !self.dirty.load(Ordering::Relaxed) {
+            return Ok(());
+        }
+
+        let cache = CacheFile {
+            version: VERSION.to_string(),
+            options: self.options,
+            files: self
+                .entries
+                .iter()
+                .map(|entry| (entry.key().clone(), *entry.value()))
+                .collect(),
+            directories: self
+                .directories
+                .iter()
+                .map(|entry| (entry.key().clone(), *entry.value()))
+                .collect(),
+        };
+
+        let content = serde_json::to_vec(&cache)?;
+
+        if let Some(directory) = self.path.parent() {
+            fs.create_dir_all(directory)?;
+        }
+
+        let options = OpenOptions::default()
+            .write(true)
+            .create(true)
+            .truncate(true);
+        let mut file = fs.open_with_options(&self.path, options)?;
+        file.set_content(&content)
+    }
+}
+
+/// Creates the [FormatCache] for this session of the CLI, if the cache is
+/// enabled in the configuration and `--no-cache` wasn't passed
+pub(crate) fn load_format_cache(
+    session: &mut CliSession,
+    configuration: &Configuration,
+) -> Option<FormatCache> {
+    let no_cache = session.args.contains("--no-cache");
+
+    let formatter = match &configuration.formatter {
+        Some(formatter) if formatter.enabled && formatter.cache && !no_cache => formatter,
+        _ => return None,
+    };
+
+    let directory = formatter
+        .cache_directory
+        .as_deref()
+        .unwrap_or(DEFAULT_CACHE_DIRECTORY);
+
+    Some(FormatCache::load(
+        &*session.app.fs,
+        Path::new(directory),
+        configuration,
+    ))
+}
+
+fn read_cache_file(fs: &dyn FileSystem, path: &Path) -> io::Result<Option<CacheFile>> {
+    let options = OpenOptions::default().read(true);
+    let mut file = match fs.open_with_options(path, options) {
+        Ok(file) => file,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(err) => return Err(err),
+    };
+
+    let mut buffer = String::new();
+    file.read_to_string(&mut buffer)?;
+
+    Ok(Some(serde_json::from_str(&buffer)?))
+}
+
+/// The configuration is hashed through its serialized representation, this
+/// includes both the content of `rome.json` and the options passed on the
+/// command line
+fn hash_configuration(configuration: &Configuration) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    // Serializing the configuration can't fail, it only contains maps with string keys
+    serde_json::to_string(configuration)
+        .unwrap_or_default()
+        .hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Removes the `.` components of `path`, the directories of the configuration
+/// files don't contain them
+fn without_current_dir(path: &Path) -> PathBuf {
+    path.components()
+        .filter(|component| !matches!(component, Component::CurDir))
+        .collect()
+}
+
+fn hash_content(content: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    content.hash(&mut hasher);
+    hasher.finish()
+}
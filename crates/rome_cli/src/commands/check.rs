@@ -1,18 +1,30 @@
 use crate::commands::format::apply_format_settings_from_cli;
-use crate::configuration::load_configuration;
+use crate::configuration::{load_configuration, CliConfiguration, NestedConfigurationLoader};
+use crate::rule_profile::RuleProfileOptions;
 use crate::{execute_mode, CliSession, Execution, Termination, TraversalMode};
 use rome_service::workspace::{FixFileMode, UpdateSettingsParams};
+use std::path::PathBuf;
 
 /// Handler for the "check" command of the Rome CLI
 pub(crate) fn check(mut session: CliSession) -> Result<(), Termination> {
-    let mut configuration = load_configuration(&mut session)?;
+    let CliConfiguration {
+        mut configuration,
+        mut arguments,
+        nested,
+    } = load_configuration(&mut session)?;
 
-    apply_format_settings_from_cli(&mut session, &mut configuration)?;
+    arguments.format = Some(apply_format_settings_from_cli(
+        &mut session,
+        &mut configuration,
+    )?);
 
     session
         .app
         .workspace
-        .update_settings(UpdateSettingsParams { configuration })?;
+        .update_settings(UpdateSettingsParams {
+            configuration,
+            directory: None,
+        })?;
 
     let apply = session.args.contains("--apply");
     let apply_suggested = session.args.contains("--apply-suggested");
@@ -30,8 +42,26 @@ pub(crate) fn check(mut session: CliSession) -> Result<(), Termination> {
         Some(FixFileMode::SafeAndSuggestedFixes)
     };
 
-    execute_mode(
-        Execution::new(TraversalMode::Check { fix_file_mode }),
-        session,
-    )
+    let profile_rules = session.args.contains("--profile-rules");
+    let profile_rules_json: Option<PathBuf> = session
+        .args
+        .opt_value_from_str("--profile-rules-json")
+        .map_err(|source| Termination::ParseError {
+            argument: "--profile-rules-json",
+            source,
+        })?;
+
+    let mut execution = Execution::new(TraversalMode::Check { fix_file_mode })
+        .with_nested_configurations(NestedConfigurationLoader::new(nested, arguments));
+
+    if profile_rules || profile_rules_json.is_some() {
+        if let Some(profile) = session.app.workspace.rule_profile() {
+            profile.enable();
+        }
+        execution = execution.with_rule_profile(RuleProfileOptions {
+            json_path: profile_rules_json,
+        });
+    }
+
+    execute_mode(execution, session)
 }
@@ -1,15 +1,18 @@
-use crate::{
-    configuration::load_configuration, execute_mode, CliSession, Execution, Termination,
-    TraversalMode,
-};
+use crate::configuration::{load_configuration, CliConfiguration, NestedConfigurationLoader};
+use crate::{execute_mode, CliSession, Execution, Termination, TraversalMode};
 use rome_service::configuration::{FormatterConfiguration, LinterConfiguration};
 use rome_service::workspace::UpdateSettingsParams;
 
 use super::format::apply_format_settings_from_cli;
+use crate::cache::load_format_cache;
 
 /// Handler for the "ci" command of the Rome CLI
 pub(crate) fn ci(mut session: CliSession) -> Result<(), Termination> {
-    let mut configuration = load_configuration(&mut session)?;
+    let CliConfiguration {
+        mut configuration,
+        mut arguments,
+        nested,
+    } = load_configuration(&mut session)?;
 
     let formatter_enabled = session
         .args
@@ -27,21 +30,16 @@ pub(crate) fn ci(mut session: CliSession) -> Result<(), Termination> {
             source,
         })?;
 
-    let formatter = configuration
+    configuration
         .formatter
         .get_or_insert_with(FormatterConfiguration::default);
-
-    if let Some(formatter_enabled) = formatter_enabled {
-        formatter.enabled = formatter_enabled;
-    }
-
-    let linter = configuration
+    configuration
         .linter
         .get_or_insert_with(LinterConfiguration::default);
 
-    if let Some(linter_enabled) = linter_enabled {
-        linter.enabled = linter_enabled;
-    }
+    arguments.formatter_enabled = formatter_enabled;
+    arguments.linter_enabled = linter_enabled;
+    arguments.apply(&mut configuration);
 
     // no point in doing the traversal if all the checks have been disabled
     if configuration.is_formatter_disabled() && configuration.is_linter_disabled() {
@@ -49,13 +47,26 @@ pub(crate) fn ci(mut session: CliSession) -> Result<(), Termination> {
     }
 
     if !configuration.is_formatter_disabled() {
-        apply_format_settings_from_cli(&mut session, &mut configuration)?;
+        arguments.format = Some(apply_format_settings_from_cli(
+            &mut session,
+            &mut configuration,
+        )?);
     }
 
+    let format_cache = load_format_cache(&mut session, &configuration);
+
     session
         .app
         .workspace
-        .update_settings(UpdateSettingsParams { configuration })?;
+        .update_settings(UpdateSettingsParams {
+            configuration,
+            directory: None,
+        })?;
 
-    execute_mode(Execution::new(TraversalMode::CI), session)
+    execute_mode(
+        Execution::new(TraversalMode::CI)
+            .with_format_cache(format_cache)
+            .with_nested_configurations(NestedConfigurationLoader::new(nested, arguments)),
+        session,
+    )
 }
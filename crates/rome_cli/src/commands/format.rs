@@ -1,23 +1,36 @@
-use rome_formatter::IndentStyle;
+use rome_formatter::{IndentStyle, LineWidth};
+use rome_js_formatter::context::{ObjectWrap, QuoteProperties, QuoteStyle, TrailingComma};
 use rome_service::configuration::{
     FormatterConfiguration, JavascriptConfiguration, JavascriptFormatter, PlainIndentStyle,
 };
 use rome_service::{workspace::UpdateSettingsParams, Configuration};
 use std::path::PathBuf;
 
-use crate::configuration::load_configuration;
+use crate::cache::load_format_cache;
+use crate::configuration::{load_configuration, CliConfiguration, NestedConfigurationLoader};
 use crate::execute::ReportMode;
 use crate::{execute_mode, CliSession, Execution, Termination, TraversalMode};
 
 /// Handler for the "format" command of the Rome CLI
 pub(crate) fn format(mut session: CliSession) -> Result<(), Termination> {
-    let mut configuration = load_configuration(&mut session)?;
-    apply_format_settings_from_cli(&mut session, &mut configuration)?;
+    let CliConfiguration {
+        mut configuration,
+        mut arguments,
+        nested,
+    } = load_configuration(&mut session)?;
+    arguments.format = Some(apply_format_settings_from_cli(
+        &mut session,
+        &mut configuration,
+    )?);
+    let format_cache = load_format_cache(&mut session, &configuration);
 
     session
         .app
         .workspace
-        .update_settings(UpdateSettingsParams { configuration })?;
+        .update_settings(UpdateSettingsParams {
+            configuration,
+            directory: None,
+        })?;
 
     let is_write = session.args.contains("--write");
     let ignore_errors = session.args.contains("--skip-errors");
@@ -63,20 +76,81 @@ pub(crate) fn format(mut session: CliSession) -> Result<(), Termination> {
         })
     };
 
-    execute_mode(execution, session)
+    execute_mode(
+        execution
+            .with_format_cache(format_cache)
+            .with_nested_configurations(NestedConfigurationLoader::new(nested, arguments)),
+        session,
+    )
+}
+
+/// Formatting options passed as command line arguments
+#[derive(Default)]
+pub(crate) struct FormatArguments {
+    indent_style: Option<IndentStyle>,
+    indent_size: Option<u8>,
+    line_width: Option<LineWidth>,
+    quote_properties: Option<QuoteProperties>,
+    quote_style: Option<QuoteStyle>,
+    trailing_comma: Option<TrailingComma>,
+    object_wrap: Option<ObjectWrap>,
+}
+
+impl FormatArguments {
+    /// Injects the formatting options into `configuration`
+    pub(crate) fn apply(&self, configuration: &mut Configuration) {
+        let formatter = configuration
+            .formatter
+            .get_or_insert_with(FormatterConfiguration::default);
+
+        match self.indent_style {
+            Some(IndentStyle::Tab) => {
+                formatter.indent_style = PlainIndentStyle::Tab;
+            }
+            Some(IndentStyle::Space(default_size)) => {
+                formatter.indent_style = PlainIndentStyle::Space;
+                formatter.indent_size = self.indent_size.unwrap_or(default_size);
+            }
+            None => {}
+        }
+
+        if let Some(line_width) = self.line_width {
+            formatter.line_width = line_width;
+        }
+
+        let javascript = configuration
+            .javascript
+            .get_or_insert_with(JavascriptConfiguration::default);
+        let javascript_formatter = javascript
+            .formatter
+            .get_or_insert_with(JavascriptFormatter::default);
+
+        if let Some(quote_properties) = self.quote_properties {
+            javascript_formatter.quote_properties = quote_properties;
+        }
+
+        if let Some(quote_style) = self.quote_style {
+            javascript_formatter.quote_style = quote_style;
+        }
+
+        if let Some(trailing_comma) = self.trailing_comma {
+            javascript_formatter.trailing_comma = trailing_comma;
+        }
+
+        if let Some(object_wrap) = self.object_wrap {
+            javascript_formatter.object_wrap = object_wrap;
+        }
+    }
 }
 
 /// Read the formatting options for the command line arguments and inject them
-/// into the workspace settings
+/// into the workspace settings. The returned arguments are applied to the
+/// nested configuration files too.
 pub(crate) fn apply_format_settings_from_cli(
     session: &mut CliSession,
     configuration: &mut Configuration,
-) -> Result<(), Termination> {
-    let formatter = configuration
-        .formatter
-        .get_or_insert_with(FormatterConfiguration::default);
-
-    let size = session
+) -> Result<FormatArguments, Termination> {
+    let indent_size = session
         .args
         .opt_value_from_str("--indent-size")
         .map_err(|source| Termination::ParseError {
@@ -100,21 +174,6 @@ pub(crate) fn apply_format_settings_from_cli(
             source,
         })?;
 
-    match indent_style {
-        Some(IndentStyle::Tab) => {
-            formatter.indent_style = PlainIndentStyle::Tab;
-        }
-        Some(IndentStyle::Space(default_size)) => {
-            formatter.indent_style = PlainIndentStyle::Space;
-            formatter.indent_size = size.unwrap_or(default_size);
-        }
-        None => {}
-    }
-
-    if let Some(line_width) = line_width {
-        formatter.line_width = line_width;
-    }
-
     let quote_properties = session
         .args
         .opt_value_from_str("--quote-properties")
@@ -139,24 +198,24 @@ pub(crate) fn apply_format_settings_from_cli(
             source,
         })?;
 
-    let javascript = configuration
-        .javascript
-        .get_or_insert_with(JavascriptConfiguration::default);
-    let javascript_formatter = javascript
-        .formatter
-        .get_or_insert_with(JavascriptFormatter::default);
-
-    if let Some(quote_properties) = quote_properties {
-        javascript_formatter.quote_properties = quote_properties;
-    }
-
-    if let Some(quote_style) = quote_style {
-        javascript_formatter.quote_style = quote_style;
-    }
+    let object_wrap = session
+        .args
+        .opt_value_from_str("--object-wrap")
+        .map_err(|source| Termination::ParseError {
+            argument: "--object-wrap",
+            source,
+        })?;
 
-    if let Some(trailing_comma) = trailing_comma {
-        javascript_formatter.trailing_comma = trailing_comma;
-    }
+    let arguments = FormatArguments {
+        indent_style,
+        indent_size,
+        line_width,
+        quote_properties,
+        quote_style,
+        trailing_comma,
+        object_wrap,
+    };
+    arguments.apply(configuration);
 
-    Ok(())
+    Ok(arguments)
 }
@@ -37,6 +37,8 @@ const CHECK: Markup = markup! {
     "<Dim>"--apply"</Dim>"                       Apply safe fixes
     "<Dim>"--apply-suggested"</Dim>"             Apply safe and suggested fixes
     "<Dim>"--max-diagnostics"</Dim>"             Cap the amount of diagnostics displayed (default: 20)
+    "<Dim>"--profile-rules"</Dim>"               Print the time spent in each lint rule
+    "<Dim>"--profile-rules-json <path>"</Dim>"   Write the time spent in each lint rule to a JSON file
 "
 };
 
@@ -48,6 +50,8 @@ const FORMAT_OPTIONS: Markup = markup! {
     "<Dim>"--quote-style <single|double>"</Dim>"            Changes the quotation character for strings (default: \")
     "<Dim>"--quote-properties <as-needed|preserve>"</Dim>"  Changes when properties in object should be quoted (default: as-needed)
     "<Dim>"--trailing-comma <all|es5|none>"</Dim>"          Changes trailing commas in multi-line comma-separated syntactic structures (default: all)
+    "<Dim>"--object-wrap <preserve|collapse>"</Dim>"        Changes whether objects that span multiple lines are kept expanded (default: preserve)
+    "<Dim>"--no-cache"</Dim>"                               Ignore the formatter cache, and format all the files
     "
 };
 
@@ -4,7 +4,7 @@ use rome_diagnostics::termcolor;
 use rome_diagnostics::termcolor::{ColorChoice, WriteColor};
 use rome_fs::FileSystem;
 use rome_service::workspace::{client, RageEntry, RageParams};
-use rome_service::{load_config, DynRef, Workspace};
+use rome_service::{load_config_with_chain, DynRef, Workspace};
 use std::{env, io, ops::Deref};
 use tokio::runtime::Runtime;
 
@@ -160,21 +160,32 @@ impl Display for RageConfiguration<'_, '_> {
     fn fmt(&self, fmt: &mut Formatter) -> io::Result<()> {
         Section("Rome Configuration").fmt(fmt)?;
 
-        match load_config(self.0, None) {
+        match load_config_with_chain(self.0, None) {
             Ok(None) => KeyValuePair("Status", markup!(<Dim>"unset"</Dim>)).fmt(fmt)?,
-            Ok(Some(configuration)) => {
+            Ok(Some(loaded)) => {
+                let configuration = &loaded.configuration;
                 markup! (
                     {KeyValuePair("Status", markup!(<Dim>"loaded"</Dim>))}
                     {KeyValuePair("Formatter disabled", markup!({DebugDisplay(configuration.is_formatter_disabled())}))}
                     {KeyValuePair("Linter disabled", markup!({DebugDisplay(configuration.is_linter_disabled())}))}
-                ).fmt(fmt)?
-            }
-            Err(err) => {
-                markup! (
-                    {KeyValuePair("Status", markup!(<Error>"Failed to load"</Error>))}
-                    {KeyValuePair("Error", markup!({format!("{err:?}")}))}
-                ).fmt(fmt)?
+                ).fmt(fmt)?;
+
+                // Only printed when the configuration extends other files
+                if loaded.chain.len() > 1 {
+                    let chain = loaded
+                        .chain
+                        .iter()
+                        .map(|path| path.display().to_string())
+                        .collect::<Vec<_>>()
+                        .join(" -> ");
+                    KeyValuePair("Extends", markup!({ chain })).fmt(fmt)?;
+                }
             }
+            Err(err) => markup! (
+                {KeyValuePair("Status", markup!(<Error>"Failed to load"</Error>))}
+                {KeyValuePair("Error", markup!({format!("{err:?}")}))}
+            )
+            .fmt(fmt)?,
         }
 
         Ok(())
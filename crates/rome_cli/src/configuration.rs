@@ -1,11 +1,75 @@
-use rome_service::{load_config, Configuration};
+use rome_fs::FileSystem;
+use rome_service::configuration::{FormatterConfiguration, LinterConfiguration};
+use rome_service::workspace::UpdateSettingsParams;
+use rome_service::{
+    load_config_with_chain, Configuration, NestedConfigurations, RomeError, Workspace,
+};
+use std::num::NonZeroU64;
+use std::path::Path;
 
+use crate::cache::FormatCache;
+use crate::commands::format::FormatArguments;
 use crate::{CliSession, Termination};
 
+/// The configuration of a session of the CLI
+pub(crate) struct CliConfiguration {
+    /// The configuration file of the working directory, with the options of the
+    /// command line applied
+    pub(crate) configuration: Configuration,
+    /// The options of the command line, they're applied to the nested configuration
+    /// files too
+    pub(crate) arguments: ConfigurationArguments,
+    /// The configuration files of the nested directories, they're loaded during the traversal
+    pub(crate) nested: NestedConfigurations,
+}
+
+/// Options of the command line that override the values of the configuration files
+#[derive(Default)]
+pub(crate) struct ConfigurationArguments {
+    files_max_size: Option<NonZeroU64>,
+    pub(crate) formatter_enabled: Option<bool>,
+    pub(crate) linter_enabled: Option<bool>,
+    pub(crate) format: Option<FormatArguments>,
+}
+
+impl ConfigurationArguments {
+    /// Applies the options of the command line to `configuration`
+    pub(crate) fn apply(&self, configuration: &mut Configuration) {
+        if let Some(files_max_size) = self.files_max_size {
+            let files = configuration.files.get_or_insert_with(Default::default);
+            files.max_size = Some(files_max_size);
+        }
+
+        if let Some(formatter_enabled) = self.formatter_enabled {
+            let formatter = configuration
+                .formatter
+                .get_or_insert_with(FormatterConfiguration::default);
+            formatter.enabled = formatter_enabled;
+        }
+
+        if let Some(linter_enabled) = self.linter_enabled {
+            let linter = configuration
+                .linter
+                .get_or_insert_with(LinterConfiguration::default);
+            linter.enabled = linter_enabled;
+        }
+
+        if let Some(format) = &self.format {
+            format.apply(configuration);
+        }
+    }
+}
+
 /// Load the configuration for this session of the CLI, merging the content of
 /// the `rome.json` file if it exists on disk with common command line options
-pub(crate) fn load_configuration(session: &mut CliSession) -> Result<Configuration, Termination> {
-    let mut configuration = load_config(&session.app.fs, None)?.unwrap_or_default();
+pub(crate) fn load_configuration(
+    session: &mut CliSession,
+) -> Result<CliConfiguration, Termination> {
+    let loaded = load_config_with_chain(&session.app.fs, None)?;
+    let nested = NestedConfigurations::new("", loaded.as_ref());
+    let mut configuration = loaded
+        .map(|loaded| loaded.configuration)
+        .unwrap_or_default();
 
     let files_max_size = session
         .args
@@ -15,10 +79,58 @@ pub(crate) fn load_configuration(session: &mut CliSession) -> Result<Configurati
             source,
         })?;
 
-    if let Some(files_max_size) = files_max_size {
-        let files = configuration.files.get_or_insert_with(Default::default);
-        files.max_size = Some(files_max_size);
+    let arguments = ConfigurationArguments {
+        files_max_size,
+        ..ConfigurationArguments::default()
+    };
+    arguments.apply(&mut configuration);
+
+    Ok(CliConfiguration {
+        configuration,
+        arguments,
+        nested,
+    })
+}
+
+/// Registers the configuration files of the nested directories in the workspace,
+/// as they're found during the traversal
+pub(crate) struct NestedConfigurationLoader {
+    configurations: NestedConfigurations,
+    arguments: ConfigurationArguments,
+}
+
+impl NestedConfigurationLoader {
+    pub(crate) fn new(
+        configurations: NestedConfigurations,
+        arguments: ConfigurationArguments,
+    ) -> Self {
+        Self {
+            configurations,
+            arguments,
+        }
     }
 
-    Ok(configuration)
+    /// Loads the configuration files of the directories containing the file at `path`
+    /// that weren't loaded yet
+    pub(crate) fn load(
+        &self,
+        fs: &dyn FileSystem,
+        workspace: &dyn Workspace,
+        format_cache: Option<&FormatCache>,
+        path: &Path,
+    ) -> Result<(), RomeError> {
+        self.configurations
+            .discover(fs, path, |directory, mut configuration| {
+                self.arguments.apply(&mut configuration);
+
+                if let Some(format_cache) = format_cache {
+                    format_cache.register_directory(directory, &configuration);
+                }
+
+                workspace.update_settings(UpdateSettingsParams {
+                    configuration,
+                    directory: Some(directory.to_path_buf()),
+                })
+            })
+    }
 }
@@ -1,3 +1,6 @@
+use crate::cache::FormatCache;
+use crate::configuration::NestedConfigurationLoader;
+use crate::rule_profile::RuleProfileOptions;
 use crate::traversal::traverse;
 use crate::{CliSession, Termination};
 use rome_console::{markup, ConsoleExt};
@@ -19,6 +22,15 @@ pub(crate) struct Execution {
 
     /// The maximum number of diagnostics that can be printed in console
     max_diagnostics: u16,
+
+    /// The cache of already formatted files, if enabled
+    format_cache: Option<FormatCache>,
+
+    /// Loads the configuration files found in the directories of the traversal
+    nested_configurations: Option<NestedConfigurationLoader>,
+
+    /// Reports the time spent in each lint rule, if enabled
+    rule_profile: Option<RuleProfileOptions>,
 }
 
 pub(crate) enum TraversalMode {
@@ -61,6 +73,9 @@ impl Execution {
             report_mode: ReportMode::default(),
             traversal_mode: mode,
             max_diagnostics: MAXIMUM_DISPLAYABLE_DIAGNOSTICS,
+            format_cache: None,
+            nested_configurations: None,
+            rule_profile: None,
         }
     }
 
@@ -70,9 +85,33 @@ impl Execution {
             traversal_mode,
             report_mode,
             max_diagnostics: MAXIMUM_DISPLAYABLE_DIAGNOSTICS,
+            format_cache: None,
+            nested_configurations: None,
+            rule_profile: None,
         }
     }
 
+    /// Sets the cache used to skip the files that are already formatted
+    pub(crate) fn with_format_cache(mut self, format_cache: Option<FormatCache>) -> Self {
+        self.format_cache = format_cache;
+        self
+    }
+
+    /// Sets the loader of the configuration files of the nested directories
+    pub(crate) fn with_nested_configurations(
+        mut self,
+        nested_configurations: NestedConfigurationLoader,
+    ) -> Self {
+        self.nested_configurations = Some(nested_configurations);
+        self
+    }
+
+    /// Reports the time spent in each lint rule at the end of the traversal
+    pub(crate) fn with_rule_profile(mut self, rule_profile: RuleProfileOptions) -> Self {
+        self.rule_profile = Some(rule_profile);
+        self
+    }
+
     /// Tells if the reporting is happening straight to terminal
     pub(crate) fn should_report_to_terminal(&self) -> bool {
         matches!(self.report_mode, ReportMode::Terminal)
@@ -86,6 +125,18 @@ impl Execution {
         self.max_diagnostics
     }
 
+    pub(crate) fn format_cache(&self) -> Option<&FormatCache> {
+        self.format_cache.as_ref()
+    }
+
+    pub(crate) fn nested_configurations(&self) -> Option<&NestedConfigurationLoader> {
+        self.nested_configurations.as_ref()
+    }
+
+    pub(crate) fn rule_profile(&self) -> Option<&RuleProfileOptions> {
+        self.rule_profile.as_ref()
+    }
+
     /// `true` only when running the traversal in [TraversalMode::Check] and `should_fix` is `true`
     pub(crate) fn as_fix_file_mode(&self) -> Option<&FixFileMode> {
         if let TraversalMode::Check { fix_file_mode, .. } = &self.traversal_mode {
@@ -162,6 +213,10 @@ pub(crate) fn execute_mode(
         let console = &mut *session.app.console;
         let rome_path = RomePath::new(path, FileId::zero());
 
+        if let Some(nested_configurations) = mode.nested_configurations() {
+            nested_configurations.load(&*session.app.fs, workspace, None, path)?;
+        }
+
         if mode.is_format() {
             let unsupported_format_reason = workspace
                 .supports_feature(SupportsFeatureParams {
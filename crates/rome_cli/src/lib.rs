@@ -14,12 +14,14 @@ use rome_flags::FeatureFlags;
 use rome_fs::OsFileSystem;
 use rome_service::{App, DynRef, Workspace, WorkspaceRef};
 
+mod cache;
 mod commands;
 mod configuration;
 mod execute;
 mod metrics;
 mod panic;
 mod reports;
+mod rule_profile;
 mod service;
 mod termination;
 mod traversal;
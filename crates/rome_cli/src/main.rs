@@ -6,7 +6,9 @@
 
 use rome_cli::{open_transport, setup_panic_handler, Arguments, CliSession, Termination};
 use rome_diagnostics::v2::set_bottom_frame;
+use rome_fs::OsFileSystem;
 use rome_service::workspace;
+use std::sync::Arc;
 use tokio::runtime::Runtime;
 
 #[cfg(target_os = "windows")]
@@ -32,7 +34,7 @@ fn main() -> Result<(), Termination> {
             None => return Err(Termination::ServerNotRunning),
         }
     } else {
-        workspace::server()
+        workspace::server(Arc::new(OsFileSystem))
     };
 
     let session = CliSession::new(&*workspace, args)?;
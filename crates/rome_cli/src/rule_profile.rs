@@ -0,0 +1,110 @@
+//! Report of the time spent in each lint rule, printed by `rome check --profile-rules`
+//!
+//! The rules are profiled by the workspace of this process: the timings can't
+//! be collected when the files are checked by a daemon with `--use-server`.
+
+use rome_analyze::{Profile, ProfileKey};
+use rome_console::{markup, Console, ConsoleExt};
+use rome_fs::{FileSystem, OpenOptions};
+use serde::Serialize;
+use std::fmt::Write;
+use std::io;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Options of the profiling of the rules, enabled with `--profile-rules`
+pub(crate) struct RuleProfileOptions {
+    /// Path of the file the profile is written to in the JSON format, if any
+    pub(crate) json_path: Option<PathBuf>,
+}
+
+/// Entry of the profile written to the JSON file, the durations are in milliseconds
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RuleProfileEntry {
+    name: String,
+    /// Either `rule` or `visitor`
+    kind: &'static str,
+    calls: u64,
+    total: f64,
+    run: f64,
+    diagnostic: f64,
+    action: f64,
+}
+
+/// Print the time spent in the rules since the profiling was enabled, sorted
+/// by decreasing total time, and write it to the JSON file of the options
+pub(crate) fn report_rule_profile(
+    options: &RuleProfileOptions,
+    profile: Option<&Profile>,
+    fs: &dyn FileSystem,
+    console: &mut dyn Console,
+) -> io::Result<()> {
+    let profile = profile.map(Profile::take).unwrap_or_default();
+
+    if profile.is_empty() {
+        console.log(markup! {
+            <Warn>"No lint rule was run by this process, the rules can't be profiled when the linter is disabled or with "<Emphasis>"--use-server"</Emphasis></Warn>
+        });
+        return Ok(());
+    }
+
+    let mut table = format!(
+        "{:>12} {:>12} {:>12} {:>12} {:>8}  Name",
+        "Total", "Run", "Diagnostic", "Action", "Calls"
+    );
+
+    for (key, timings) in &profile {
+        // Writing to a `String` can't fail
+        let _ = write!(
+            table,
+            "\n{:>12} {:>12} {:>12} {:>12} {:>8}  {key}",
+            format_duration(timings.total()),
+            format_duration(timings.run),
+            format_duration(timings.diagnostic),
+            format_duration(timings.action),
+            timings.calls,
+        );
+    }
+
+    console.log(markup! {
+        <Emphasis>"Time spent in each rule:"</Emphasis>"\n"{table}
+    });
+
+    if let Some(json_path) = &options.json_path {
+        let entries: Vec<_> = profile
+            .iter()
+            .map(|(key, timings)| RuleProfileEntry {
+                name: key.to_string(),
+                kind: match key {
+                    ProfileKey::Rule(_) => "rule",
+                    ProfileKey::Visitor(_) => "visitor",
+                },
+                calls: timings.calls,
+                total: as_millis(timings.total()),
+                run: as_millis(timings.run),
+                diagnostic: as_millis(timings.diagnostic),
+                action: as_millis(timings.action),
+            })
+            .collect();
+
+        let content = serde_json::to_vec_pretty(&entries)?;
+
+        let options = OpenOptions::default()
+            .write(true)
+            .create(true)
+            .truncate(true);
+        let mut file = fs.open_with_options(json_path, options)?;
+        file.set_content(&content)?;
+    }
+
+    Ok(())
+}
+
+fn format_duration(duration: Duration) -> String {
+    format!("{duration:.3?}")
+}
+
+fn as_millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
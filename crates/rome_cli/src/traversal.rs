@@ -1,3 +1,5 @@
+use crate::cache::FormatCache;
+use crate::rule_profile::report_rule_profile;
 use crate::{
     CliSession, Execution, FormatterReportFileDetail, FormatterReportSummary, Report,
     ReportDiagnostic, ReportDiff, ReportErrorKind, ReportKind, Termination, TraversalMode,
@@ -16,12 +18,15 @@ use rome_diagnostics::{
         PrintDiagnostic, Severity, Visit,
     },
 };
+use rome_formatter::comments::UnmatchedSuppression;
 use rome_fs::{AtomicInterner, FileSystem, OpenOptions, PathInterner, RomePath};
 use rome_fs::{TraversalContext, TraversalScope};
+use rome_rowan::TextRange;
 use rome_service::workspace::{SupportsFeatureResult, UnsupportedReason};
 use rome_service::{
     workspace::{
-        FeatureName, FileGuard, Language, OpenFileParams, RuleCategories, SupportsFeatureParams,
+        FeatureName, FileGuard, Language, OpenFileParams, PullProjectDiagnosticsParams,
+        RuleCategories, SupportsFeatureParams,
     },
     RomeError, Workspace,
 };
@@ -34,7 +39,7 @@ use std::{
     path::{Path, PathBuf},
     sync::{
         atomic::{AtomicU16, AtomicUsize, Ordering},
-        Once,
+        Mutex, Once,
     },
     thread,
     time::{Duration, Instant},
@@ -93,6 +98,7 @@ pub(crate) fn traverse(execution: Execution, mut session: CliSession) -> Result<
 
     let processed = AtomicUsize::new(0);
     let skipped = AtomicUsize::new(0);
+    let linted_paths = Mutex::new(Vec::new());
 
     let fs = &*session.app.fs;
     let workspace = &*session.app.workspace;
@@ -124,26 +130,34 @@ pub(crate) fn traverse(execution: Execution, mut session: CliSession) -> Result<
 
         // The traversal context is scoped to ensure all the channels it
         // contains are properly closed once the traversal finishes
-        traverse_inputs(
+        let ctx = TraversalOptions {
             fs,
-            inputs,
-            &TraversalOptions {
-                fs,
-                workspace,
-                execution: &execution,
-                interner,
-                processed: &processed,
-                skipped: &skipped,
-                messages: send_msgs,
-                sender_reports,
-                remaining_diagnostics: &remaining_diagnostics,
-            },
-        )
+            workspace,
+            execution: &execution,
+            interner,
+            processed: &processed,
+            skipped: &skipped,
+            linted_paths: &linted_paths,
+            messages: send_msgs,
+            sender_reports,
+            remaining_diagnostics: &remaining_diagnostics,
+        };
+
+        let start = Instant::now();
+        traverse_inputs(fs, inputs, &ctx);
+        check_project(&ctx);
+        start.elapsed()
     });
 
     let count = processed.load(Ordering::Relaxed);
     let skipped = skipped.load(Ordering::Relaxed);
 
+    if let Some(format_cache) = execution.format_cache() {
+        if let Err(err) = format_cache.persist(fs) {
+            tracing::error!("failed to write the formatter cache: {err}");
+        }
+    }
+
     if execution.should_report_to_terminal() {
         match execution.traversal_mode() {
             TraversalMode::Check { .. } => {
@@ -205,6 +219,20 @@ pub(crate) fn traverse(execution: Execution, mut session: CliSession) -> Result<
         });
     }
 
+    if let Some(hits) = execution
+        .format_cache()
+        .map(FormatCache::hits)
+        .filter(|hits| *hits > 0)
+    {
+        console.log(markup! {
+            <Info>{hits}" file(s) were already formatted and unchanged since the last run"</Info>
+        });
+    }
+
+    if let Some(rule_profile) = execution.rule_profile() {
+        report_rule_profile(rule_profile, workspace.rule_profile(), fs, console)?;
+    }
+
     // Processing emitted error diagnostics, exit with a non-zero code
     if errors > 0 {
         Err(Termination::CheckError)
@@ -227,17 +255,42 @@ fn init_thread_pool() {
 }
 
 /// Initiate the filesystem traversal tasks with the provided input paths and
-/// run it to completion, returning the duration of the process
-fn traverse_inputs(fs: &dyn FileSystem, inputs: Vec<OsString>, ctx: &TraversalOptions) -> Duration {
-    let start = Instant::now();
-
+/// run it to completion
+fn traverse_inputs(fs: &dyn FileSystem, inputs: Vec<OsString>, ctx: &TraversalOptions) {
     fs.traversal(Box::new(move |scope: &dyn TraversalScope| {
         for input in inputs {
             scope.spawn(ctx, PathBuf::from(input));
         }
     }));
+}
 
-    start.elapsed()
+/// Once all the files were processed, emit the diagnostics of the analyses
+/// that need the whole project, like the detection of the dead code
+fn check_project(ctx: &TraversalOptions) {
+    let paths = std::mem::take(&mut *ctx.linted_paths.lock().unwrap());
+    if paths.is_empty() {
+        return;
+    }
+
+    let result = match ctx
+        .workspace
+        .pull_project_diagnostics(PullProjectDiagnosticsParams { paths })
+    {
+        Ok(result) => result,
+        Err(err) => {
+            ctx.push_diagnostic(StdError::from(err).with_category(category!("lint")));
+            return;
+        }
+    };
+
+    for file in result.files {
+        ctx.push_message(Message::Diagnostics {
+            name: file.path.as_path().display().to_string(),
+            content: file.content,
+            diagnostics: file.diagnostics.into_iter().map(Error::from).collect(),
+            skipped_diagnostics: 0,
+        });
+    }
 }
 
 struct ProcessMessagesOptions<'ctx> {
@@ -595,6 +648,9 @@ struct TraversalOptions<'ctx, 'app> {
     processed: &'ctx AtomicUsize,
     /// Shared atomic counter storing the number of skipped files
     skipped: &'ctx AtomicUsize,
+    /// The files that were linted, checked again as a whole project once
+    /// the traversal finishes
+    linted_paths: &'ctx Mutex<Vec<RomePath>>,
     /// Channel sending messages to the display thread
     messages: Sender<Message>,
     /// Channel sending reports to the reports thread
@@ -630,6 +686,30 @@ impl<'ctx, 'app> TraversalOptions<'ctx, 'app> {
         })
     }
 
+    /// Registers the configuration files of the directories containing `rome_path`
+    /// in the workspace, if they weren't loaded yet
+    fn load_nested_configurations(&self, rome_path: &RomePath) {
+        let nested_configurations = match self.execution.nested_configurations() {
+            Some(nested_configurations) => nested_configurations,
+            None => return,
+        };
+
+        let result = nested_configurations.load(
+            self.fs,
+            self.workspace,
+            self.execution.format_cache(),
+            rome_path,
+        );
+
+        if let Err(err) = result {
+            self.push_diagnostic(
+                StdError::from(err)
+                    .with_category(category!("configuration"))
+                    .with_file_path(rome_path.file_id()),
+            );
+        }
+    }
+
     fn miss_handler_err(&self, err: RomeError, rome_path: &RomePath) {
         self.push_diagnostic(
             StdError::from(err)
@@ -649,6 +729,8 @@ impl<'ctx, 'app> TraversalContext for TraversalOptions<'ctx, 'app> {
     }
 
     fn can_handle(&self, rome_path: &RomePath) -> bool {
+        self.load_nested_configurations(rome_path);
+
         let can_lint = self.can_lint(rome_path);
         let can_format = self.can_format(rome_path);
 
@@ -739,6 +821,8 @@ type FileResult = Result<FileStatus, Message>;
 fn process_file(ctx: &TraversalOptions, path: &Path, file_id: FileId) -> FileResult {
     tracing::trace_span!("process_file", path = ?path).in_scope(move || {
         let rome_path = RomePath::new(path, file_id);
+        ctx.load_nested_configurations(&rome_path);
+
         let supported_format = ctx
             .can_format(&rome_path)
             .with_file_id_and_code(file_id, category!("files/missingHandler"))?;
@@ -774,10 +858,20 @@ fn process_file(ctx: &TraversalOptions, path: &Path, file_id: FileId) -> FileRes
         let mut input = String::new();
         file.read_to_string(&mut input).with_file_id(file_id)?;
 
+        // In format mode, files that were already formatted the last time
+        // they were processed don't need to be parsed at all
+        if ctx.execution.is_format() {
+            if let Some(format_cache) = ctx.execution.format_cache() {
+                if format_cache.is_formatted(path, &input) {
+                    return Ok(FileStatus::Success);
+                }
+            }
+        }
+
         let file_guard = FileGuard::open(
             ctx.workspace,
             OpenFileParams {
-                path: rome_path,
+                path: rome_path.clone(),
                 version: 0,
                 content: input.clone(),
                 language_hint: Language::default(),
@@ -816,6 +910,10 @@ fn process_file(ctx: &TraversalOptions, path: &Path, file_id: FileId) -> FileRes
             .pull_diagnostics(categories, max_diagnostics.into())
             .with_file_id_and_code(file_id, category!("lint"))?;
 
+        if categories.contains(RuleCategories::LINT) {
+            ctx.linted_paths.lock().unwrap().push(rome_path);
+        }
+
         // In formatting mode, abort immediately if the file has errors
         let errors = result.errors;
         match ctx.execution.traversal_mode() {
@@ -874,10 +972,38 @@ fn process_file(ctx: &TraversalOptions, path: &Path, file_id: FileId) -> FileRes
                 TraversalMode::Format { write, .. } => *write,
             };
 
+            // The cache is only set for the format and CI commands
+            let format_cache = ctx.execution.format_cache();
+            if let Some(format_cache) = format_cache {
+                if format_cache.is_formatted(path, &input) {
+                    return Ok(result);
+                }
+            }
+
             let printed = file_guard
                 .format_file()
                 .with_file_id_and_code(file_id, category!("format"))?;
 
+            // The suppression range comments that don't match are reported by
+            // the analyzer in the other modes
+            let unmatched_suppressions = printed.unmatched_suppressions();
+            if ctx.execution.is_format() && !unmatched_suppressions.is_empty() {
+                ctx.push_message(Message::Diagnostics {
+                    name: path.display().to_string(),
+                    content: input.clone(),
+                    diagnostics: unmatched_suppressions
+                        .iter()
+                        .map(|suppression| {
+                            Error::from(UnmatchedSuppressionDiagnostic::from(*suppression))
+                        })
+                        .collect(),
+                    skipped_diagnostics: 0,
+                });
+            }
+
+            // Files with unmatched suppression comments are not cached, so
+            // they're reported again on the next run
+            let is_cacheable = unmatched_suppressions.is_empty();
             let output = printed.into_code();
             if output != input {
                 if write {
@@ -905,6 +1031,11 @@ fn process_file(ctx: &TraversalOptions, path: &Path, file_id: FileId) -> FileRes
                     }));
                 }
             }
+
+            // At this point the content of the file on disk is formatted
+            if let Some(format_cache) = format_cache.filter(|_| is_cacheable) {
+                format_cache.insert(path, &output);
+            }
         }
 
         Ok(result)
@@ -956,6 +1087,34 @@ struct UnhandledDiagnostic;
 #[diagnostic(category = "parse", message = "Skipped file with syntax errors")]
 struct SkippedDiagnostic;
 
+#[derive(Debug, v2::Diagnostic)]
+#[diagnostic(category = "suppressions/unmatchedRange", severity = Warning)]
+struct UnmatchedSuppressionDiagnostic {
+    #[location(span)]
+    range: TextRange,
+    #[message]
+    #[description]
+    message: &'static str,
+}
+
+impl From<UnmatchedSuppression> for UnmatchedSuppressionDiagnostic {
+    fn from(suppression: UnmatchedSuppression) -> Self {
+        let message = match suppression {
+            UnmatchedSuppression::Start(_) => {
+                "This suppression comment doesn't have a matching rome-ignore-end format comment"
+            }
+            UnmatchedSuppression::End(_) => {
+                "This suppression comment doesn't have a matching rome-ignore-start format comment"
+            }
+        };
+
+        Self {
+            range: suppression.range(),
+            message,
+        }
+    }
+}
+
 /// Extension trait for turning [Display]-able error types into [TraversalError]
 trait ResultExt {
     type Result;
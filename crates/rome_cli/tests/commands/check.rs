@@ -2,15 +2,20 @@ use pico_args::Arguments;
 use rome_cli::Termination;
 use std::ffi::OsString;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
 use crate::configs::{
-    CONFIG_FILE_SIZE_LIMIT, CONFIG_LINTER_DISABLED, CONFIG_LINTER_DOWNGRADE_DIAGNOSTIC,
-    CONFIG_LINTER_IGNORED_FILES, CONFIG_LINTER_SUPPRESSED_GROUP, CONFIG_LINTER_SUPPRESSED_RULE,
-    CONFIG_LINTER_UPGRADE_DIAGNOSTIC,
+    CONFIG_FILE_SIZE_LIMIT, CONFIG_LINTER_DEAD_CODE, CONFIG_LINTER_DISABLED,
+    CONFIG_LINTER_DISABLED_CUSTOM_RULE, CONFIG_LINTER_DOWNGRADE_DIAGNOSTIC,
+    CONFIG_LINTER_IGNORED_FILES, CONFIG_LINTER_OVERRIDES, CONFIG_LINTER_SORTED_PACKAGE_JSON,
+    CONFIG_LINTER_SUPPRESSED_GROUP, CONFIG_LINTER_SUPPRESSED_RULE,
+    CONFIG_LINTER_UPGRADE_DIAGNOSTIC, CONFIG_MARKDOWN,
 };
-use crate::snap_test::SnapshotPayload;
-use crate::{assert_cli_snapshot, run_cli, FORMATTED, LINT_ERROR, PARSE_ERROR};
-use rome_console::{BufferConsole, LogLevel};
+use crate::snap_test::{markup_to_string, SnapshotPayload};
+use crate::{
+    assert_cli_snapshot, run_cli, run_cli_with_server_workspace, FORMATTED, LINT_ERROR, PARSE_ERROR,
+};
+use rome_console::{markup, BufferConsole, LogLevel};
 use rome_fs::{ErrorEntry, FileSystemExt, MemoryFileSystem};
 use rome_service::DynRef;
 
@@ -215,6 +220,47 @@ fn apply_ok() {
     ));
 }
 
+#[test]
+fn apply_removes_unused_suppression() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("fix.js");
+    fs.insert(
+        file_path.into(),
+        "// rome-ignore lint(correctness/noDebugger): not needed anymore\nstatement();\n"
+            .as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        DynRef::Borrowed(&mut console),
+        Arguments::from_vec(vec![
+            OsString::from("check"),
+            OsString::from("--apply"),
+            file_path.as_os_str().into(),
+        ]),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let mut buffer = String::new();
+    fs.open(file_path)
+        .unwrap()
+        .read_to_string(&mut buffer)
+        .unwrap();
+
+    assert_eq!(buffer, "statement();\n");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "apply_removes_unused_suppression",
+        fs,
+        console,
+        result,
+    ));
+}
+
 #[test]
 fn apply_noop() {
     let mut fs = MemoryFileSystem::default();
@@ -438,6 +484,37 @@ fn should_disable_a_rule() {
     ));
 }
 
+#[test]
+fn should_disable_a_custom_rule() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), "items.forEach(print);\n".as_bytes());
+
+    let config_path = Path::new("rome.json");
+    fs.insert(
+        config_path.into(),
+        CONFIG_LINTER_DISABLED_CUSTOM_RULE.as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        DynRef::Borrowed(&mut console),
+        Arguments::from_vec(vec![OsString::from("check"), file_path.as_os_str().into()]),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "should_disable_a_custom_rule",
+        fs,
+        console,
+        result,
+    ));
+}
+
 #[test]
 fn should_disable_a_rule_group() {
     let mut fs = MemoryFileSystem::default();
@@ -572,6 +649,34 @@ fn upgrade_severity() {
     ));
 }
 
+#[test]
+fn applies_linter_overrides() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("rome.json");
+    fs.insert(file_path.into(), CONFIG_LINTER_OVERRIDES.as_bytes());
+
+    let file_path = Path::new("test/file.js");
+    fs.insert(file_path.into(), NO_DEBUGGER.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        DynRef::Borrowed(&mut console),
+        Arguments::from_vec(vec![OsString::from("check"), file_path.as_os_str().into()]),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "applies_linter_overrides",
+        fs,
+        console,
+        result,
+    ));
+}
+
 #[test]
 fn no_lint_when_file_is_ignored() {
     let mut fs = MemoryFileSystem::default();
@@ -830,3 +935,251 @@ fn max_diagnostics() {
 
     assert_eq!(console.out_buffer.len(), 11);
 }
+
+#[test]
+fn markdown_code_blocks_diagnostics() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(PathBuf::from("rome.json"), CONFIG_MARKDOWN);
+
+    let file_path = Path::new("README.md");
+    fs.insert(
+        file_path.into(),
+        "# Title\n\nSome text.\n\n```js\nlet a = 1;\nfor(;true;);\n```\n".as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        DynRef::Borrowed(&mut console),
+        Arguments::from_vec(vec![OsString::from("check"), file_path.as_os_str().into()]),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    let messages = &console.out_buffer;
+
+    assert!(messages
+        .iter()
+        .filter(|m| m.level == LogLevel::Error)
+        .any(|m| {
+            let content = markup_to_string(markup! {
+                {m.content}
+            });
+            content.contains("README.md:7:1") && content.contains("lint/correctness/useWhile")
+        }));
+}
+
+#[test]
+fn apply_markdown_code_blocks() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(PathBuf::from("rome.json"), CONFIG_MARKDOWN);
+
+    let file_path = Path::new("README.md");
+    fs.insert(
+        file_path.into(),
+        "# Title\r\n\r\n```js\r\nif(a != -0) {}\r\n```\r\n\r\n```js\r\nif(a != -0) {}\r\n```\r\n"
+            .as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        DynRef::Borrowed(&mut console),
+        Arguments::from_vec(vec![
+            OsString::from("check"),
+            OsString::from("--apply"),
+            file_path.as_os_str().into(),
+        ]),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let mut buffer = String::new();
+    fs.open(file_path)
+        .unwrap()
+        .read_to_string(&mut buffer)
+        .unwrap();
+
+    assert_eq!(
+        buffer,
+        "# Title\r\n\r\n```js\r\nif(a != 0) {}\r\n```\r\n\r\n```js\r\nif(a != 0) {}\r\n```\r\n"
+    );
+}
+
+#[test]
+fn markdown_is_opt_in() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("README.md");
+    fs.insert(
+        file_path.into(),
+        "# Title\n\n```js\nfor(;true;);\n```\n".as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        DynRef::Borrowed(&mut console),
+        Arguments::from_vec(vec![OsString::from("check"), file_path.as_os_str().into()]),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert!(console
+        .out_buffer
+        .iter()
+        .all(|message| message.level != LogLevel::Error));
+}
+
+#[test]
+fn apply_sorted_package_json() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let config_path = Path::new("rome.json");
+    fs.insert(
+        config_path.into(),
+        CONFIG_LINTER_SORTED_PACKAGE_JSON.as_bytes(),
+    );
+
+    let file_path = Path::new("package.json");
+    fs.insert(
+        file_path.into(),
+        "{\n  \"version\": \"1.0.0\",\n  \"dependencies\": { \"b\": \"1\", \"a\": \"1\" },\n  \"name\": \"app\"\n}\n"
+            .as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        DynRef::Borrowed(&mut console),
+        Arguments::from_vec(vec![
+            OsString::from("check"),
+            OsString::from("--apply"),
+            file_path.as_os_str().into(),
+        ]),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let mut buffer = String::new();
+    fs.open(file_path)
+        .unwrap()
+        .read_to_string(&mut buffer)
+        .unwrap();
+
+    assert_eq!(
+        buffer,
+        "{\n  \"name\": \"app\",\n  \"version\": \"1.0.0\",\n  \"dependencies\": { \"a\": \"1\", \"b\": \"1\" }\n}\n"
+    );
+}
+
+#[test]
+fn profile_rules() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("check.js");
+    fs.insert(file_path.into(), NO_DEBUGGER.as_bytes());
+
+    let profile_path = Path::new("profile.json");
+
+    // The rules are profiled by the workspace of the process running them
+    let fs = Arc::new(fs);
+    let result = run_cli_with_server_workspace(
+        fs.clone(),
+        DynRef::Borrowed(&mut console),
+        Arguments::from_vec(vec![
+            OsString::from("check"),
+            OsString::from("--profile-rules-json"),
+            profile_path.as_os_str().into(),
+            file_path.as_os_str().into(),
+        ]),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    let messages = &console.out_buffer;
+    assert!(messages
+        .iter()
+        .filter(|m| m.level == LogLevel::Log)
+        .any(|m| {
+            let content = markup_to_string(markup! {
+                {m.content}
+            });
+            content.contains("Time spent in each rule")
+                && content.contains("correctness/noDebugger")
+        }));
+
+    let mut buffer = String::new();
+    fs.open(profile_path)
+        .unwrap()
+        .read_to_string(&mut buffer)
+        .unwrap();
+
+    let profile: serde_json::Value = serde_json::from_str(&buffer).unwrap();
+    let entries = profile.as_array().unwrap();
+    assert!(entries
+        .iter()
+        .any(|entry| entry["name"] == "correctness/noDebugger" && entry["kind"] == "rule"));
+    assert!(entries
+        .iter()
+        .any(|entry| entry["name"] == "semanticModel" && entry["kind"] == "visitor"));
+}
+
+#[test]
+fn reports_dead_code() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        PathBuf::from("rome.json"),
+        CONFIG_LINTER_DEAD_CODE.as_bytes(),
+    );
+
+    let main_path = Path::new("main.js");
+    fs.insert(
+        main_path.into(),
+        "import { used } from \"./lib.js\";\nconsole.log(used);\n".as_bytes(),
+    );
+
+    let lib_path = Path::new("lib.js");
+    fs.insert(
+        lib_path.into(),
+        "export const used = 1;\nexport const unused = 2;\n".as_bytes(),
+    );
+
+    // The modules that aren't open anymore are read from the file system of the app
+    let result = run_cli_with_server_workspace(
+        Arc::new(fs),
+        DynRef::Borrowed(&mut console),
+        Arguments::from_vec(vec![
+            OsString::from("check"),
+            main_path.as_os_str().into(),
+            lib_path.as_os_str().into(),
+        ]),
+    );
+
+    match result {
+        Err(Termination::CheckError) => {}
+        _ => panic!("run_cli returned {result:?} for a project with dead code, expected an error"),
+    }
+
+    let messages: Vec<_> = console
+        .out_buffer
+        .iter()
+        .map(|message| {
+            markup_to_string(markup! {
+                {message.content}
+            })
+        })
+        .collect();
+
+    assert!(messages.iter().any(|message| {
+        message.contains("deadCode/unusedExport")
+            && message.contains("The export unused isn't imported by any module of the project.")
+    }));
+    assert!(!messages
+        .iter()
+        .any(|message| message.contains("The export used isn't imported")));
+}
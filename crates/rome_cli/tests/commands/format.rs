@@ -1,7 +1,9 @@
 use crate::configs::{
-    CONFIG_DISABLED_FORMATTER, CONFIG_FILE_SIZE_LIMIT, CONFIG_FORMAT,
-    CONFIG_FORMATTER_IGNORED_DIRECTORIES, CONFIG_FORMATTER_IGNORED_FILES, CONFIG_ISSUE_3175_1,
-    CONFIG_ISSUE_3175_2,
+    CONFIG_DISABLED_FORMATTER, CONFIG_EXTENDED_BASE, CONFIG_EXTENDS, CONFIG_FILE_SIZE_LIMIT,
+    CONFIG_FORMAT, CONFIG_FORMATTER_CACHE, CONFIG_FORMATTER_IGNORED_DIRECTORIES,
+    CONFIG_FORMATTER_IGNORED_FILES, CONFIG_ISSUE_3175_1, CONFIG_ISSUE_3175_2,
+    CONFIG_JS_FORMATTER_OVERRIDES, CONFIG_MARKDOWN, CONFIG_NESTED_CHILD, CONFIG_NESTED_CHILD_ROOT,
+    CONFIG_NESTED_GRANDCHILD, CONFIG_NESTED_PARENT, CONFIG_NESTED_PARENT_IGNORE, CONFIG_OVERRIDES,
 };
 use crate::snap_test::{markup_to_string, SnapshotPayload};
 use crate::{
@@ -45,6 +47,14 @@ const APPLY_TRAILING_COMMA_AFTER: &str = r#"const a = [
 ];
 "#;
 
+const APPLY_OBJECT_WRAP_BEFORE: &str = r#"const a = {
+	b: 1,
+	c: 2,
+};
+"#;
+
+const APPLY_OBJECT_WRAP_AFTER: &str = "const a = { b: 1, c: 2 };\n";
+
 const CUSTOM_CONFIGURATION_BEFORE: &str = r#"function f() {
   return { a, b }
 }"#;
@@ -237,6 +247,61 @@ fn lint_warning() {
     ));
 }
 
+#[test]
+fn unmatched_suppression_ranges() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("format.js");
+    fs.insert(
+        file_path.into(),
+        r#"// rome-ignore-end format
+statement(  )
+// rome-ignore-start format: aligned table
+const table  =  [1,  0];"#
+            .as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        DynRef::Borrowed(&mut console),
+        Arguments::from_vec(vec![
+            OsString::from("format"),
+            OsString::from("--write"),
+            file_path.as_os_str().into(),
+        ]),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let mut file = fs
+        .open(file_path)
+        .expect("formatting target file was removed by the CLI");
+
+    let mut content = String::new();
+    file.read_to_string(&mut content)
+        .expect("failed to read file from memory FS");
+
+    // The unmatched comments don't suppress the formatting of anything
+    assert_eq!(
+        content,
+        r#"// rome-ignore-end format
+statement();
+// rome-ignore-start format: aligned table
+const table = [1, 0];
+"#
+    );
+
+    drop(file);
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "unmatched_suppression_ranges",
+        fs,
+        console,
+        result,
+    ));
+}
+
 #[test]
 fn applies_custom_configuration() {
     let mut fs = MemoryFileSystem::default();
@@ -512,6 +577,48 @@ fn applies_custom_trailing_comma() {
     ));
 }
 
+#[test]
+fn applies_custom_object_wrap() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), APPLY_OBJECT_WRAP_BEFORE.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        DynRef::Borrowed(&mut console),
+        Arguments::from_vec(vec![
+            OsString::from("format"),
+            OsString::from("--object-wrap"),
+            OsString::from("collapse"),
+            OsString::from("--write"),
+            file_path.as_os_str().into(),
+        ]),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let mut file = fs
+        .open(file_path)
+        .expect("formatting target file was removed by the CLI");
+
+    let mut content = String::new();
+    file.read_to_string(&mut content)
+        .expect("failed to read file from memory FS");
+
+    assert_eq!(content, APPLY_OBJECT_WRAP_AFTER);
+
+    drop(file);
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "applies_custom_object_wrap",
+        fs,
+        console,
+        result,
+    ));
+}
+
 #[test]
 fn trailing_comma_parse_errors() {
     let mut console = BufferConsole::default();
@@ -1033,31 +1140,73 @@ fn does_not_format_ignored_directories() {
 }
 
 #[test]
-fn file_too_large() {
+fn applies_configuration_overrides() {
     let mut fs = MemoryFileSystem::default();
     let mut console = BufferConsole::default();
 
-    let file_path = Path::new("format.js");
-    fs.insert(file_path.into(), "statement();\n".repeat(80660).as_bytes());
+    let file_path = Path::new("rome.json");
+    fs.insert(file_path.into(), CONFIG_OVERRIDES.as_bytes());
+
+    let generated_file = Path::new("generated/file.js");
+    fs.insert(
+        generated_file.into(),
+        CUSTOM_CONFIGURATION_BEFORE.as_bytes(),
+    );
+
+    let legacy_file = Path::new("legacy/old.js");
+    fs.insert(legacy_file.into(), UNFORMATTED.as_bytes());
+
+    let new_file = Path::new("legacy/new.js");
+    fs.insert(new_file.into(), UNFORMATTED.as_bytes());
 
     let result = run_cli(
         DynRef::Borrowed(&mut fs),
         DynRef::Borrowed(&mut console),
         Arguments::from_vec(vec![
             OsString::from("format"),
-            file_path.as_os_str().into(),
+            OsString::from("./"),
             OsString::from("--write"),
         ]),
     );
 
     assert!(result.is_ok(), "run_cli returned {result:?}");
 
-    // Do not store the content of the file in the snapshot
-    fs.remove(file_path);
+    let mut file = fs
+        .open(generated_file)
+        .expect("formatting target file was removed by the CLI");
+
+    let mut content = String::new();
+    file.read_to_string(&mut content)
+        .expect("failed to read file from memory FS");
+
+    assert_eq!(content, CUSTOM_CONFIGURATION_AFTER);
+    drop(file);
+
+    let mut file = fs
+        .open(legacy_file)
+        .expect("formatting target file was removed by the CLI");
+
+    let mut content = String::new();
+    file.read_to_string(&mut content)
+        .expect("failed to read file from memory FS");
+
+    assert_eq!(content, UNFORMATTED, "we test the file is not formatted");
+    drop(file);
+
+    let mut file = fs
+        .open(new_file)
+        .expect("formatting target file was removed by the CLI");
+
+    let mut content = String::new();
+    file.read_to_string(&mut content)
+        .expect("failed to read file from memory FS");
+
+    assert_eq!(content, FORMATTED, "we test the file is formatted");
+    drop(file);
 
     assert_cli_snapshot(SnapshotPayload::new(
         module_path!(),
-        "file_too_large",
+        "applies_configuration_overrides",
         fs,
         console,
         result,
@@ -1065,26 +1214,43 @@ fn file_too_large() {
 }
 
 #[test]
-fn file_too_large_config_limit() {
+fn merges_javascript_formatter_overrides() {
     let mut fs = MemoryFileSystem::default();
     let mut console = BufferConsole::default();
 
-    fs.insert(PathBuf::from("rome.json"), CONFIG_FILE_SIZE_LIMIT);
+    let file_path = Path::new("rome.json");
+    fs.insert(file_path.into(), CONFIG_JS_FORMATTER_OVERRIDES.as_bytes());
 
-    let file_path = Path::new("format.js");
-    fs.insert(file_path.into(), "statement1();\nstatement2();");
+    let special_file = Path::new("special/file.js");
+    fs.insert(special_file.into(), r#"const a = { "b": "c" };"#.as_bytes());
 
     let result = run_cli(
         DynRef::Borrowed(&mut fs),
         DynRef::Borrowed(&mut console),
-        Arguments::from_vec(vec![OsString::from("format"), file_path.as_os_str().into()]),
+        Arguments::from_vec(vec![
+            OsString::from("format"),
+            OsString::from("./"),
+            OsString::from("--write"),
+        ]),
     );
 
     assert!(result.is_ok(), "run_cli returned {result:?}");
 
+    let mut file = fs
+        .open(special_file)
+        .expect("formatting target file was removed by the CLI");
+
+    let mut content = String::new();
+    file.read_to_string(&mut content)
+        .expect("failed to read file from memory FS");
+
+    // The quote style of the top-level configuration is kept by the override
+    assert_eq!(content, "const a = { 'b': 'c' };\n");
+    drop(file);
+
     assert_cli_snapshot(SnapshotPayload::new(
         module_path!(),
-        "file_too_large_config_limit",
+        "merges_javascript_formatter_overrides",
         fs,
         console,
         result,
@@ -1092,29 +1258,45 @@ fn file_too_large_config_limit() {
 }
 
 #[test]
-fn file_too_large_cli_limit() {
+fn applies_extended_configuration() {
     let mut fs = MemoryFileSystem::default();
     let mut console = BufferConsole::default();
 
-    let file_path = Path::new("format.js");
-    fs.insert(file_path.into(), "statement1();\nstatement2();");
+    let file_path = Path::new("rome.json");
+    fs.insert(file_path.into(), CONFIG_EXTENDS.as_bytes());
+
+    let base_path = Path::new("configs/base.json");
+    fs.insert(base_path.into(), CONFIG_EXTENDED_BASE.as_bytes());
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), CUSTOM_CONFIGURATION_BEFORE.as_bytes());
 
     let result = run_cli(
         DynRef::Borrowed(&mut fs),
         DynRef::Borrowed(&mut console),
         Arguments::from_vec(vec![
             OsString::from("format"),
-            OsString::from("--files-max-size"),
-            OsString::from("16"),
+            OsString::from("--write"),
             file_path.as_os_str().into(),
         ]),
     );
 
     assert!(result.is_ok(), "run_cli returned {result:?}");
 
+    let mut file = fs
+        .open(file_path)
+        .expect("formatting target file was removed by the CLI");
+
+    let mut content = String::new();
+    file.read_to_string(&mut content)
+        .expect("failed to read file from memory FS");
+
+    assert_eq!(content, CUSTOM_CONFIGURATION_AFTER);
+
+    drop(file);
     assert_cli_snapshot(SnapshotPayload::new(
         module_path!(),
-        "file_too_large_cli_limit",
+        "applies_extended_configuration",
         fs,
         console,
         result,
@@ -1122,32 +1304,52 @@ fn file_too_large_cli_limit() {
 }
 
 #[test]
-fn files_max_size_parse_error() {
+fn applies_nested_configuration() {
     let mut fs = MemoryFileSystem::default();
     let mut console = BufferConsole::default();
 
-    let file_path = Path::new("format.js");
-    fs.insert(file_path.into(), "statement1();\nstatement2();");
+    let file_path = Path::new("rome.json");
+    fs.insert(file_path.into(), CONFIG_NESTED_PARENT.as_bytes());
+
+    let nested_path = Path::new("packages/a/rome.json");
+    fs.insert(nested_path.into(), CONFIG_NESTED_CHILD.as_bytes());
+
+    let root_file_path = Path::new("file.js");
+    fs.insert(
+        root_file_path.into(),
+        CUSTOM_CONFIGURATION_BEFORE.as_bytes(),
+    );
+
+    let file_path = Path::new("packages/a/file.js");
+    fs.insert(file_path.into(), CUSTOM_CONFIGURATION_BEFORE.as_bytes());
 
     let result = run_cli(
         DynRef::Borrowed(&mut fs),
         DynRef::Borrowed(&mut console),
         Arguments::from_vec(vec![
             OsString::from("format"),
-            OsString::from("--files-max-size"),
-            OsString::from("-1"),
+            OsString::from("--write"),
+            root_file_path.as_os_str().into(),
             file_path.as_os_str().into(),
         ]),
     );
 
-    match result {
-        Err(Termination::ParseError { argument, .. }) => assert_eq!(argument, "--files-max-size"),
-        _ => panic!("run_cli returned {result:?} for an invalid argument value, expected an error"),
-    }
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let mut file = fs
+        .open(file_path)
+        .expect("formatting target file was removed by the CLI");
+
+    let mut content = String::new();
+    file.read_to_string(&mut content)
+        .expect("failed to read file from memory FS");
+
+    assert_eq!(content, CUSTOM_CONFIGURATION_AFTER);
 
+    drop(file);
     assert_cli_snapshot(SnapshotPayload::new(
         module_path!(),
-        "files_max_size_parse_error",
+        "applies_nested_configuration",
         fs,
         console,
         result,
@@ -1155,48 +1357,580 @@ fn files_max_size_parse_error() {
 }
 
 #[test]
-fn max_diagnostics_default() {
+fn nested_root_configuration_ignores_parents() {
     let mut fs = MemoryFileSystem::default();
     let mut console = BufferConsole::default();
 
-    for i in 0..60 {
-        let file_path = PathBuf::from(format!("src/file_{i}.js"));
-        fs.insert(file_path, UNFORMATTED.as_bytes());
-    }
+    let file_path = Path::new("rome.json");
+    fs.insert(file_path.into(), CONFIG_NESTED_PARENT.as_bytes());
+
+    let nested_path = Path::new("packages/a/rome.json");
+    fs.insert(nested_path.into(), CONFIG_NESTED_CHILD_ROOT.as_bytes());
+
+    let file_path = Path::new("packages/a/file.js");
+    fs.insert(file_path.into(), CUSTOM_CONFIGURATION_BEFORE.as_bytes());
 
     let result = run_cli(
         DynRef::Borrowed(&mut fs),
         DynRef::Borrowed(&mut console),
-        Arguments::from_vec(vec![OsString::from("format"), OsString::from("src")]),
+        Arguments::from_vec(vec![
+            OsString::from("format"),
+            OsString::from("--write"),
+            file_path.as_os_str().into(),
+        ]),
     );
 
     assert!(result.is_ok(), "run_cli returned {result:?}");
 
-    assert_eq!(console.out_buffer.len(), 52);
+    let mut file = fs
+        .open(file_path)
+        .expect("formatting target file was removed by the CLI");
+
+    let mut content = String::new();
+    file.read_to_string(&mut content)
+        .expect("failed to read file from memory FS");
+
+    assert_eq!(
+        content,
+        "function f() {\n\treturn {\n\t\ta,\n\t\tb,\n\t};\n}\n"
+    );
+
+    drop(file);
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "nested_root_configuration_ignores_parents",
+        fs,
+        console,
+        result,
+    ));
 }
 
 #[test]
-fn max_diagnostics() {
+fn nested_configuration_keeps_parent_patterns_anchored() {
     let mut fs = MemoryFileSystem::default();
     let mut console = BufferConsole::default();
 
-    for i in 0..60 {
-        let file_path = PathBuf::from(format!("src/file_{i}.js"));
-        fs.insert(file_path, UNFORMATTED.as_bytes());
-    }
+    let file_path = Path::new("rome.json");
+    fs.insert(file_path.into(), CONFIG_NESTED_PARENT_IGNORE.as_bytes());
+
+    let nested_path = Path::new("packages/a/rome.json");
+    fs.insert(nested_path.into(), CONFIG_NESTED_CHILD.as_bytes());
+
+    let ignored_file = Path::new("generated/file.js");
+    fs.insert(ignored_file.into(), UNFORMATTED.as_bytes());
+
+    // The pattern of the parent configuration matches the paths relative to its
+    // directory, not to the nested one
+    let nested_file = Path::new("packages/a/generated/file.js");
+    fs.insert(nested_file.into(), CUSTOM_CONFIGURATION_BEFORE.as_bytes());
 
     let result = run_cli(
         DynRef::Borrowed(&mut fs),
         DynRef::Borrowed(&mut console),
         Arguments::from_vec(vec![
             OsString::from("format"),
-            OsString::from("--max-diagnostics"),
-            OsString::from("10"),
-            OsString::from("src"),
+            OsString::from("./"),
+            OsString::from("--write"),
         ]),
     );
 
     assert!(result.is_ok(), "run_cli returned {result:?}");
 
-    assert_eq!(console.out_buffer.len(), 12);
+    let mut file = fs
+        .open(ignored_file)
+        .expect("formatting target file was removed by the CLI");
+
+    let mut content = String::new();
+    file.read_to_string(&mut content)
+        .expect("failed to read file from memory FS");
+
+    assert_eq!(content, UNFORMATTED, "we test the file is not formatted");
+    drop(file);
+
+    let mut file = fs
+        .open(nested_file)
+        .expect("formatting target file was removed by the CLI");
+
+    let mut content = String::new();
+    file.read_to_string(&mut content)
+        .expect("failed to read file from memory FS");
+
+    assert_eq!(
+        content,
+        "function f() {\n\treturn {\n\t\ta,\n\t\tb,\n\t};\n}\n"
+    );
+    drop(file);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "nested_configuration_keeps_parent_patterns_anchored",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn nested_root_configuration_stops_upward_search() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("rome.json");
+    fs.insert(file_path.into(), CONFIG_NESTED_PARENT.as_bytes());
+
+    let nested_path = Path::new("packages/a/rome.json");
+    fs.insert(nested_path.into(), CONFIG_NESTED_CHILD_ROOT.as_bytes());
+
+    let nested_path = Path::new("packages/a/b/rome.json");
+    fs.insert(nested_path.into(), CONFIG_NESTED_GRANDCHILD.as_bytes());
+
+    let file_path = Path::new("packages/a/b/file.js");
+    fs.insert(file_path.into(), CUSTOM_CONFIGURATION_BEFORE.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        DynRef::Borrowed(&mut console),
+        Arguments::from_vec(vec![
+            OsString::from("format"),
+            OsString::from("--write"),
+            file_path.as_os_str().into(),
+        ]),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let mut file = fs
+        .open(file_path)
+        .expect("formatting target file was removed by the CLI");
+
+    let mut content = String::new();
+    file.read_to_string(&mut content)
+        .expect("failed to read file from memory FS");
+
+    // The line width is inherited from `packages/a`, the indent size isn't
+    // inherited from the root of the workspace
+    assert_eq!(
+        content,
+        "function f() {\n  return {\n    a,\n    b,\n  };\n}\n"
+    );
+
+    drop(file);
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "nested_root_configuration_stops_upward_search",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn file_too_large() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("format.js");
+    fs.insert(file_path.into(), "statement();\n".repeat(80660).as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        DynRef::Borrowed(&mut console),
+        Arguments::from_vec(vec![
+            OsString::from("format"),
+            file_path.as_os_str().into(),
+            OsString::from("--write"),
+        ]),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    // Do not store the content of the file in the snapshot
+    fs.remove(file_path);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "file_too_large",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn file_too_large_config_limit() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(PathBuf::from("rome.json"), CONFIG_FILE_SIZE_LIMIT);
+
+    let file_path = Path::new("format.js");
+    fs.insert(file_path.into(), "statement1();\nstatement2();");
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        DynRef::Borrowed(&mut console),
+        Arguments::from_vec(vec![OsString::from("format"), file_path.as_os_str().into()]),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "file_too_large_config_limit",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn file_too_large_cli_limit() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("format.js");
+    fs.insert(file_path.into(), "statement1();\nstatement2();");
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        DynRef::Borrowed(&mut console),
+        Arguments::from_vec(vec![
+            OsString::from("format"),
+            OsString::from("--files-max-size"),
+            OsString::from("16"),
+            file_path.as_os_str().into(),
+        ]),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "file_too_large_cli_limit",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn files_max_size_parse_error() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("format.js");
+    fs.insert(file_path.into(), "statement1();\nstatement2();");
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        DynRef::Borrowed(&mut console),
+        Arguments::from_vec(vec![
+            OsString::from("format"),
+            OsString::from("--files-max-size"),
+            OsString::from("-1"),
+            file_path.as_os_str().into(),
+        ]),
+    );
+
+    match result {
+        Err(Termination::ParseError { argument, .. }) => assert_eq!(argument, "--files-max-size"),
+        _ => panic!("run_cli returned {result:?} for an invalid argument value, expected an error"),
+    }
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "files_max_size_parse_error",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn max_diagnostics_default() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    for i in 0..60 {
+        let file_path = PathBuf::from(format!("src/file_{i}.js"));
+        fs.insert(file_path, UNFORMATTED.as_bytes());
+    }
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        DynRef::Borrowed(&mut console),
+        Arguments::from_vec(vec![OsString::from("format"), OsString::from("src")]),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_eq!(console.out_buffer.len(), 52);
+}
+
+#[test]
+fn max_diagnostics() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    for i in 0..60 {
+        let file_path = PathBuf::from(format!("src/file_{i}.js"));
+        fs.insert(file_path, UNFORMATTED.as_bytes());
+    }
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        DynRef::Borrowed(&mut console),
+        Arguments::from_vec(vec![
+            OsString::from("format"),
+            OsString::from("--max-diagnostics"),
+            OsString::from("10"),
+            OsString::from("src"),
+        ]),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_eq!(console.out_buffer.len(), 12);
+}
+
+#[test]
+fn write_populates_format_cache() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let config_path = Path::new("rome.json");
+    fs.insert(config_path.into(), CONFIG_FORMATTER_CACHE.as_bytes());
+
+    let file_path = Path::new("format.js");
+    fs.insert(file_path.into(), UNFORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        DynRef::Borrowed(&mut console),
+        Arguments::from_vec(vec![
+            OsString::from("format"),
+            OsString::from("--write"),
+            file_path.as_os_str().into(),
+        ]),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let mut file = fs
+        .open(Path::new("node_modules/.cache/rome/format-cache.json"))
+        .expect("the formatter cache was not written by the CLI");
+
+    let mut content = String::new();
+    file.read_to_string(&mut content)
+        .expect("failed to read file from memory FS");
+
+    assert!(content.contains("format.js"), "{content}");
+}
+
+#[test]
+fn no_cache_does_not_write_format_cache() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let config_path = Path::new("rome.json");
+    fs.insert(config_path.into(), CONFIG_FORMATTER_CACHE.as_bytes());
+
+    let file_path = Path::new("format.js");
+    fs.insert(file_path.into(), UNFORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        DynRef::Borrowed(&mut console),
+        Arguments::from_vec(vec![
+            OsString::from("format"),
+            OsString::from("--write"),
+            OsString::from("--no-cache"),
+            file_path.as_os_str().into(),
+        ]),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert!(fs
+        .open(Path::new("node_modules/.cache/rome/format-cache.json"))
+        .is_err());
+}
+
+#[test]
+fn write_markdown_code_blocks() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(PathBuf::from("rome.json"), CONFIG_MARKDOWN);
+
+    let file_path = Path::new("README.md");
+    fs.insert(
+        file_path.into(),
+        "# Title\n\n```js\nfunction f() {return{}}\n```\n\n- item\n\n  ```ts\n  let a:string\n  ```\n\n```rust\nfn f() {}\n```\n"
+            .as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        DynRef::Borrowed(&mut console),
+        Arguments::from_vec(vec![
+            OsString::from("format"),
+            OsString::from("--write"),
+            file_path.as_os_str().into(),
+        ]),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let mut file = fs
+        .open(file_path)
+        .expect("formatting target file was removed by the CLI");
+
+    let mut content = String::new();
+    file.read_to_string(&mut content)
+        .expect("failed to read file from memory FS");
+
+    assert_eq!(
+        content,
+        "# Title\n\n```js\nfunction f() {\n\treturn {};\n}\n```\n\n- item\n\n  ```ts\n  let a: string;\n  ```\n\n```rust\nfn f() {}\n```\n"
+    );
+}
+
+#[test]
+fn write_markdown_code_blocks_with_crlf() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(PathBuf::from("rome.json"), CONFIG_MARKDOWN);
+
+    let file_path = Path::new("README.md");
+    fs.insert(
+        file_path.into(),
+        "# Title\r\n\r\n```js\r\nfunction f() {return{}}\r\n```\r\n".as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        DynRef::Borrowed(&mut console),
+        Arguments::from_vec(vec![
+            OsString::from("format"),
+            OsString::from("--write"),
+            file_path.as_os_str().into(),
+        ]),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let mut content = String::new();
+    fs.open(file_path)
+        .expect("formatting target file was removed by the CLI")
+        .read_to_string(&mut content)
+        .expect("failed to read file from memory FS");
+
+    assert_eq!(
+        content,
+        "# Title\r\n\r\n```js\r\nfunction f() {\r\n\treturn {};\r\n}\r\n```\r\n"
+    );
+}
+
+/// Runs `rome format` on `format.js`, with `--write` if `write` is set, and
+/// returns whether the run succeeded along with its console
+fn run_format_with_cache(fs: &mut MemoryFileSystem, write: bool) -> (bool, BufferConsole) {
+    let mut console = BufferConsole::default();
+
+    let mut args = vec![OsString::from("format")];
+    if write {
+        args.push(OsString::from("--write"));
+    }
+    args.push(OsString::from("format.js"));
+
+    let result = run_cli(
+        DynRef::Borrowed(fs),
+        DynRef::Borrowed(&mut console),
+        Arguments::from_vec(args),
+    );
+
+    (result.is_ok(), console)
+}
+
+/// Returns `true` if the run reported files skipped thanks to the cache
+fn has_format_cache_hits(console: &BufferConsole) -> bool {
+    console.out_buffer.iter().any(|message| {
+        let content = format!("{:?}", message.content);
+        content.contains("already formatted and unchanged since the last run")
+    })
+}
+
+#[test]
+fn second_run_skips_files_in_format_cache() {
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(
+        PathBuf::from("rome.json"),
+        CONFIG_FORMATTER_CACHE.as_bytes(),
+    );
+    fs.insert(PathBuf::from("format.js"), UNFORMATTED.as_bytes());
+
+    let (success, console) = run_format_with_cache(&mut fs, true);
+    assert!(success);
+    assert!(!has_format_cache_hits(&console));
+
+    let (success, console) = run_format_with_cache(&mut fs, false);
+    assert!(success);
+    assert!(has_format_cache_hits(&console));
+}
+
+#[test]
+fn content_change_invalidates_format_cache() {
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(
+        PathBuf::from("rome.json"),
+        CONFIG_FORMATTER_CACHE.as_bytes(),
+    );
+    fs.insert(PathBuf::from("format.js"), UNFORMATTED.as_bytes());
+
+    let (success, _) = run_format_with_cache(&mut fs, true);
+    assert!(success);
+
+    fs.insert(PathBuf::from("format.js"), UNFORMATTED.as_bytes());
+
+    let (success, console) = run_format_with_cache(&mut fs, true);
+    assert!(success);
+    assert!(!has_format_cache_hits(&console));
+
+    let mut file = fs
+        .open(Path::new("format.js"))
+        .expect("formatting target file was removed by the CLI");
+
+    let mut content = String::new();
+    file.read_to_string(&mut content)
+        .expect("failed to read file from memory FS");
+
+    assert_eq!(
+        content, FORMATTED,
+        "the modified file should be formatted again"
+    );
+}
+
+#[test]
+fn options_change_invalidates_format_cache() {
+    let mut fs = MemoryFileSystem::default();
+    fs.insert(
+        PathBuf::from("rome.json"),
+        CONFIG_FORMATTER_CACHE.as_bytes(),
+    );
+    fs.insert(PathBuf::from("format.js"), UNFORMATTED.as_bytes());
+
+    let (success, _) = run_format_with_cache(&mut fs, true);
+    assert!(success);
+
+    fs.insert(
+        PathBuf::from("rome.json"),
+        CONFIG_FORMATTER_CACHE
+            .replace(r#""cache": true"#, r#""cache": true, "lineWidth": 100"#)
+            .as_bytes(),
+    );
+
+    let (success, console) = run_format_with_cache(&mut fs, false);
+    assert!(success);
+    assert!(!has_format_cache_hits(&console));
 }
@@ -62,6 +62,90 @@ fn with_configuration() {
     ));
 }
 
+#[test]
+fn with_extended_configuration() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+    fs.insert(
+        Path::new("rome.json").to_path_buf(),
+        r#"{
+  "extends": ["./base.json"]
+}"#,
+    );
+    fs.insert(
+        Path::new("base.json").to_path_buf(),
+        r#"{
+  "formatter": {
+    "enabled": false
+  }
+}"#,
+    );
+
+    let result = run_rage(
+        DynRef::Borrowed(&mut fs),
+        DynRef::Borrowed(&mut console),
+        Arguments::from_vec(vec![OsString::from("rage")]),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_rage_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "with_extended_configuration",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn with_diamond_extended_configuration() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+    fs.insert(
+        Path::new("rome.json").to_path_buf(),
+        r#"{
+  "extends": ["./b.json", "./c.json"]
+}"#,
+    );
+    fs.insert(
+        Path::new("b.json").to_path_buf(),
+        r#"{
+  "extends": ["./d.json"]
+}"#,
+    );
+    fs.insert(
+        Path::new("c.json").to_path_buf(),
+        r#"{
+  "extends": ["./d.json"]
+}"#,
+    );
+    fs.insert(
+        Path::new("d.json").to_path_buf(),
+        r#"{
+  "formatter": {
+    "enabled": false
+  }
+}"#,
+    );
+
+    let result = run_rage(
+        DynRef::Borrowed(&mut fs),
+        DynRef::Borrowed(&mut console),
+        Arguments::from_vec(vec![OsString::from("rage")]),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_rage_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "with_diamond_extended_configuration",
+        fs,
+        console,
+        result,
+    ));
+}
+
 #[test]
 fn with_malformed_configuration() {
     let mut fs = MemoryFileSystem::default();
@@ -192,3 +192,185 @@ pub const CONFIG_FILE_SIZE_LIMIT: &str = r#"{
     "maxSize": 16
   }
 }"#;
+
+pub const CONFIG_MARKDOWN: &str = r#"{
+  "files": {
+    "markdown": true
+  }
+}"#;
+
+pub const CONFIG_FORMATTER_CACHE: &str = r#"{
+  "formatter": {
+    "enabled": true,
+    "cache": true
+  }
+}
+"#;
+
+pub const CONFIG_LINTER_SORTED_PACKAGE_JSON: &str = r#"{
+  "linter": {
+    "rules": {
+        "nursery": {
+            "useSortedPackageJson": "error"
+        }
+    }
+  }
+}"#;
+
+pub const CONFIG_OVERRIDES: &str = r#"{
+  "overrides": [
+    {
+      "include": ["generated/*"],
+      "formatter": {
+        "indentStyle": "space",
+        "indentSize": 8,
+        "lineWidth": 10
+      }
+    },
+    {
+      "include": ["legacy/*"],
+      "ignore": ["legacy/new.js"],
+      "formatter": {
+        "enabled": false
+      }
+    }
+  ]
+}"#;
+
+pub const CONFIG_JS_FORMATTER_OVERRIDES: &str = r#"{
+  "javascript": {
+    "formatter": {
+      "quoteStyle": "single"
+    }
+  },
+  "overrides": [
+    {
+      "include": ["special/*"],
+      "javascript": {
+        "formatter": {
+          "quoteProperties": "preserve"
+        }
+      }
+    }
+  ]
+}"#;
+
+pub const CONFIG_LINTER_OVERRIDES: &str = r#"{
+  "linter": {
+    "rules": {
+      "correctness": {
+        "noDebugger": "error"
+      }
+    }
+  },
+  "overrides": [
+    {
+      "include": ["test/*"],
+      "linter": {
+        "rules": {
+          "correctness": {
+            "noDebugger": "off"
+          }
+        }
+      }
+    }
+  ]
+}"#;
+
+pub const CONFIG_EXTENDS: &str = r#"{
+  "extends": ["./configs/base.json"],
+  "formatter": {
+    "lineWidth": 10
+  }
+}"#;
+
+pub const CONFIG_EXTENDED_BASE: &str = r#"{
+  "formatter": {
+    "indentStyle": "space",
+    "indentSize": 8,
+    "lineWidth": 80
+  }
+}"#;
+
+pub const CONFIG_EXTENDS_CIRCULAR: &str = r#"{
+  "extends": ["./base.json"]
+}"#;
+
+pub const CONFIG_EXTENDED_CIRCULAR_BASE: &str = r#"{
+  "extends": ["./rome.json"]
+}"#;
+
+pub const CONFIG_NESTED_PARENT: &str = r#"{
+  "formatter": {
+    "indentStyle": "space",
+    "indentSize": 8
+  }
+}"#;
+
+pub const CONFIG_NESTED_CHILD: &str = r#"{
+  "formatter": {
+    "lineWidth": 10
+  }
+}"#;
+
+pub const CONFIG_NESTED_CHILD_ROOT: &str = r#"{
+  "root": true,
+  "formatter": {
+    "lineWidth": 10
+  }
+}"#;
+
+pub const CONFIG_NESTED_PARENT_IGNORE: &str = r#"{
+  "formatter": {
+    "ignore": ["generated/*"]
+  }
+}"#;
+
+pub const CONFIG_NESTED_GRANDCHILD: &str = r#"{
+  "formatter": {
+    "indentStyle": "space"
+  }
+}"#;
+
+pub const CONFIG_LINTER_INVALID_CUSTOM_RULE: &str = r#"{
+  "linter": {
+    "customRules": {
+      "noForEach": {
+        "pattern": "$obj.forEach(",
+        "message": "Prefer a for...of loop"
+      }
+    }
+  }
+}"#;
+
+pub const CONFIG_LINTER_DISABLED_CUSTOM_RULE: &str = r#"{
+  "linter": {
+    "rules": {
+      "custom": {
+        "noForEach": "off"
+      }
+    },
+    "customRules": {
+      "noForEach": {
+        "pattern": "$obj.forEach($cb)",
+        "message": "Prefer a for...of loop"
+      }
+    }
+  }
+}"#;
+
+pub const CONFIG_LINTER_INVALID_PLUGIN: &str = r#"{
+  "linter": {
+    "plugins": {
+      "correctness": {
+        "path": "./plugin.wasm"
+      }
+    }
+  }
+}"#;
+
+pub const CONFIG_LINTER_DEAD_CODE: &str = r#"{
+  "linter": {
+    "deadCode": {}
+  }
+}"#;
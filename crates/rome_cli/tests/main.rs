@@ -6,7 +6,7 @@ mod snap_test;
 #[cfg(test)]
 use snap_test::assert_cli_snapshot;
 
-use std::{ffi::OsString, path::Path};
+use std::{ffi::OsString, path::Path, sync::Arc};
 
 use pico_args::Arguments;
 use rome_cli::{CliSession, Termination};
@@ -185,7 +185,7 @@ mod main {
 
     #[test]
     fn no_colors() {
-        let workspace = workspace::server();
+        let workspace = workspace::server(Arc::new(MemoryFileSystem::default()));
         let args = Arguments::from_vec(vec![OsString::from("--colors=off")]);
         let result = CliSession::new(&*workspace, args).and_then(|session| session.run());
 
@@ -194,7 +194,7 @@ mod main {
 
     #[test]
     fn force_colors() {
-        let workspace = workspace::server();
+        let workspace = workspace::server(Arc::new(MemoryFileSystem::default()));
         let args = Arguments::from_vec(vec![OsString::from("--colors=force")]);
         let result = CliSession::new(&*workspace, args).and_then(|session| session.run());
 
@@ -203,7 +203,7 @@ mod main {
 
     #[test]
     fn invalid_colors() {
-        let workspace = workspace::server();
+        let workspace = workspace::server(Arc::new(MemoryFileSystem::default()));
         let args = Arguments::from_vec(vec![OsString::from("--colors=other")]);
 
         let result = CliSession::new(&*workspace, args).and_then(|session| session.run());
@@ -266,8 +266,9 @@ mod init {
 mod configuration {
     use super::*;
     use crate::configs::{
-        CONFIG_ALL_FIELDS, CONFIG_BAD_LINE_WIDTH, CONFIG_INCORRECT_GLOBALS,
-        CONFIG_INCORRECT_GLOBALS_V2, CONFIG_LINTER_WRONG_RULE,
+        CONFIG_ALL_FIELDS, CONFIG_BAD_LINE_WIDTH, CONFIG_EXTENDED_CIRCULAR_BASE,
+        CONFIG_EXTENDS_CIRCULAR, CONFIG_INCORRECT_GLOBALS, CONFIG_INCORRECT_GLOBALS_V2,
+        CONFIG_LINTER_INVALID_CUSTOM_RULE, CONFIG_LINTER_INVALID_PLUGIN, CONFIG_LINTER_WRONG_RULE,
     };
     use crate::snap_test::SnapshotPayload;
     use pico_args::Arguments;
@@ -351,6 +352,115 @@ mod configuration {
         ));
     }
 
+    #[test]
+    fn circular_extends() {
+        let mut fs = MemoryFileSystem::default();
+        let mut console = BufferConsole::default();
+
+        let file_path = Path::new("rome.json");
+        fs.insert(file_path.into(), CONFIG_EXTENDS_CIRCULAR.as_bytes());
+
+        let base_path = Path::new("base.json");
+        fs.insert(base_path.into(), CONFIG_EXTENDED_CIRCULAR_BASE.as_bytes());
+
+        let result = run_cli(
+            DynRef::Borrowed(&mut fs),
+            DynRef::Borrowed(&mut console),
+            Arguments::from_vec(vec![OsString::from("format"), OsString::from("file.js")]),
+        );
+
+        assert!(result.is_err(), "run_cli returned {result:?}");
+
+        assert_cli_snapshot(SnapshotPayload::new(
+            module_path!(),
+            "circular_extends",
+            fs,
+            console,
+            result,
+        ));
+    }
+
+    #[test]
+    fn invalid_extended_configuration() {
+        let mut fs = MemoryFileSystem::default();
+        let mut console = BufferConsole::default();
+
+        let file_path = Path::new("rome.json");
+        fs.insert(file_path.into(), CONFIG_EXTENDS_CIRCULAR.as_bytes());
+
+        let base_path = Path::new("base.json");
+        fs.insert(base_path.into(), CONFIG_BAD_LINE_WIDTH.as_bytes());
+
+        let result = run_cli(
+            DynRef::Borrowed(&mut fs),
+            DynRef::Borrowed(&mut console),
+            Arguments::from_vec(vec![OsString::from("format"), OsString::from("file.js")]),
+        );
+
+        assert!(result.is_err(), "run_cli returned {result:?}");
+
+        assert_cli_snapshot(SnapshotPayload::new(
+            module_path!(),
+            "invalid_extended_configuration",
+            fs,
+            console,
+            result,
+        ));
+    }
+
+    #[test]
+    fn invalid_custom_rule() {
+        let mut fs = MemoryFileSystem::default();
+        let mut console = BufferConsole::default();
+
+        let file_path = Path::new("rome.json");
+        fs.insert(
+            file_path.into(),
+            CONFIG_LINTER_INVALID_CUSTOM_RULE.as_bytes(),
+        );
+
+        let result = run_cli(
+            DynRef::Borrowed(&mut fs),
+            DynRef::Borrowed(&mut console),
+            Arguments::from_vec(vec![OsString::from("check"), OsString::from("file.js")]),
+        );
+
+        assert!(result.is_err(), "run_cli returned {result:?}");
+
+        assert_cli_snapshot(SnapshotPayload::new(
+            module_path!(),
+            "invalid_custom_rule",
+            fs,
+            console,
+            result,
+        ));
+    }
+
+    #[test]
+    fn invalid_plugin() {
+        let mut fs = MemoryFileSystem::default();
+        let mut console = BufferConsole::default();
+
+        let file_path = Path::new("rome.json");
+        fs.insert(file_path.into(), CONFIG_LINTER_INVALID_PLUGIN.as_bytes());
+
+        let result = run_cli(
+            DynRef::Borrowed(&mut fs),
+            DynRef::Borrowed(&mut console),
+            Arguments::from_vec(vec![OsString::from("check"), OsString::from("file.js")]),
+        );
+
+        assert!(result.is_err(), "run_cli returned {result:?}");
+
+        assert_cli_snapshot(SnapshotPayload::new(
+            module_path!(),
+            "invalid_plugin",
+            fs,
+            console,
+            result,
+        ));
+    }
+
     #[test]
     fn incorrect_globals() {
         let mut fs = MemoryFileSystem::default();
@@ -523,3 +633,17 @@ pub(crate) fn run_cli<'app>(
     let session = CliSession { app, args };
     session.run()
 }
+
+/// Create an [App] instance using the provided [FileSystem] and [Console]
+/// instance, and using an in-process server workspace reading the files that
+/// aren't open from the same [FileSystem]
+pub(crate) fn run_cli_with_server_workspace<'app>(
+    fs: Arc<dyn FileSystem>,
+    console: DynRef<'app, dyn Console>,
+    args: Arguments,
+) -> Result<(), Termination> {
+    let app = App::with_filesystem_and_console(fs, console);
+
+    let session = CliSession { app, args };
+    session.run()
+}
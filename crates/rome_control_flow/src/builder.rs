@@ -2,7 +2,7 @@ use rome_rowan::{Language, SyntaxElement};
 
 use crate::{
     BasicBlock, ControlFlowGraph, ExceptionHandler, ExceptionHandlerKind, Instruction,
-    InstructionKind,
+    InstructionKind, JumpCondition,
 };
 
 /// Identifier for a block in a [ControlFlowGraph]
@@ -16,6 +16,13 @@ impl BlockId {
     pub fn index(self) -> u32 {
         self.index
     }
+
+    /// Returns the identifier of the block at `index` in the function
+    pub fn new(index: usize) -> Self {
+        Self {
+            index: index.try_into().expect("BlockId overflow"),
+        }
+    }
 }
 
 /// Helper struct for building an instance of [ControlFlowGraph], the builder
@@ -129,6 +136,22 @@ impl<L: Language> FunctionBuilder<L> {
             conditional,
             block,
             finally_fallthrough: false,
+            expression: None,
+        })
+    }
+
+    /// Append the conditional jump of an expression only evaluating some of
+    /// its operands, taken if its node satisfies `condition`
+    pub fn append_expression_jump(
+        &mut self,
+        condition: JumpCondition,
+        block: BlockId,
+    ) -> InstructionBuilder<L> {
+        self.append_instruction(InstructionKind::Jump {
+            conditional: true,
+            block,
+            finally_fallthrough: false,
+            expression: Some(condition),
         })
     }
 
@@ -137,6 +160,7 @@ impl<L: Language> FunctionBuilder<L> {
             conditional: false,
             block,
             finally_fallthrough: true,
+            expression: None,
         })
     }
 }
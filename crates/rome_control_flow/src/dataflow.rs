@@ -0,0 +1,677 @@
+//! Generic solver for dataflow analyses over a [ControlFlowGraph]
+//!
+//! A dataflow analysis computes a set of facts (the [Analysis::Domain]) at
+//! each point of a function, by propagating the facts along the edges of the
+//! graph either in the order of execution ([Direction::Forward], for instance
+//! to compute the definitions reaching an instruction) or in reverse
+//! ([Direction::Backward], for instance to compute the variables live after
+//! an instruction). The facts flowing into a block from several edges are
+//! merged using [Lattice::join], and the effect of each instruction on the
+//! facts is modeled by [Analysis::transfer]. The solver iterates over the
+//! blocks of the graph until the facts reach a fixed point.
+
+use std::collections::{BTreeSet, VecDeque};
+
+use rome_rowan::Language;
+
+use crate::{builder::BlockId, ControlFlowGraph, Instruction, InstructionKind};
+
+/// Values of the domain of an [Analysis]
+pub trait Lattice: Clone {
+    /// Merge the facts of `other` into `self`, returning `true` if this
+    /// changed the value of `self`
+    fn join(&mut self, other: &Self) -> bool;
+}
+
+/// Sets are merged using their union
+impl<T: Ord + Clone> Lattice for BTreeSet<T> {
+    fn join(&mut self, other: &Self) -> bool {
+        let len = self.len();
+        self.extend(other.iter().cloned());
+        self.len() != len
+    }
+}
+
+/// Booleans are merged using a logical or
+impl Lattice for bool {
+    fn join(&mut self, other: &Self) -> bool {
+        let changed = !*self && *other;
+        *self |= *other;
+        changed
+    }
+}
+
+/// Order in which the facts of an [Analysis] are propagated through the graph
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Direction {
+    /// The facts flow from the entry of the function towards its exits
+    Forward,
+    /// The facts flow from the exits of the function towards its entry
+    Backward,
+}
+
+/// A dataflow analysis over the [ControlFlowGraph] of a function
+pub trait Analysis<L: Language> {
+    /// Type of the facts computed by this analysis
+    type Domain: Lattice;
+
+    /// Direction in which the facts are propagated
+    const DIRECTION: Direction;
+
+    /// Return the initial value of the facts of the blocks, usually the least
+    /// element of the lattice
+    fn bottom(&self) -> Self::Domain;
+
+    /// Return the facts at the boundary of the function: at its entry for a
+    /// forward analysis, or at its exits for a backward analysis
+    fn boundary(&self) -> Self::Domain {
+        self.bottom()
+    }
+
+    /// Apply the effect of the instruction at `index` in `block` to `state`,
+    /// the facts before the instruction in the direction of the analysis
+    fn transfer(
+        &self,
+        state: &mut Self::Domain,
+        block: BlockId,
+        index: usize,
+        instruction: &Instruction<L>,
+    );
+}
+
+/// Facts computed by [solve] for each block of a graph
+#[derive(Debug, Clone)]
+pub struct DataflowResults<D> {
+    /// Facts at the start of each block, before any of its instructions is
+    /// evaluated
+    entry_states: Vec<D>,
+}
+
+impl<D> DataflowResults<D> {
+    /// Return the facts at the start of `block`, before any of its
+    /// instructions is evaluated
+    pub fn entry_state(&self, block: BlockId) -> &D {
+        &self.entry_states[block.index() as usize]
+    }
+}
+
+/// Compute the fixed point of `analysis` over the graph `cfg`
+pub fn solve<L, A>(cfg: &ControlFlowGraph<L>, analysis: &A) -> DataflowResults<A::Domain>
+where
+    L: Language,
+    A: Analysis<L>,
+{
+    let mut results = DataflowResults {
+        entry_states: vec![analysis.bottom(); cfg.blocks.len()],
+    };
+
+    if cfg.blocks.is_empty() {
+        return results;
+    }
+
+    match A::DIRECTION {
+        Direction::Forward => {
+            results.entry_states[0] = analysis.boundary();
+
+            let mut queue = Worklist::new(0..cfg.blocks.len());
+            while let Some(block) = queue.pop() {
+                let mut edges = Vec::new();
+                forward_block(
+                    cfg,
+                    analysis,
+                    &results,
+                    block,
+                    |_, _, _| {},
+                    |target, state| {
+                        edges.push((target, state.clone()));
+                    },
+                );
+
+                for (target, state) in edges {
+                    if results.entry_states[target].join(&state) {
+                        queue.push(target);
+                    }
+                }
+            }
+        }
+        Direction::Backward => {
+            let predecessors = predecessors(cfg);
+
+            // Visit the blocks in reverse order so the exits of the function
+            // are usually processed before the blocks jumping to them
+            let mut queue = Worklist::new((0..cfg.blocks.len()).rev());
+            while let Some(block) = queue.pop() {
+                let state = backward_block(cfg, analysis, &results, block, |_, _, _| {});
+
+                if results.entry_states[block].join(&state) {
+                    for predecessor in &predecessors[block] {
+                        queue.push(*predecessor);
+                    }
+                }
+            }
+        }
+    }
+
+    results
+}
+
+/// Call `visitor` for each instruction of `cfg` not following a terminator
+/// instruction in its block, with the facts computed by `analysis` before the
+/// instruction in the direction of the analysis: before the instruction is
+/// evaluated for a forward analysis, or after it was evaluated for a backward
+/// analysis
+pub fn visit_instructions<L, A, F>(
+    cfg: &ControlFlowGraph<L>,
+    analysis: &A,
+    results: &DataflowResults<A::Domain>,
+    mut visitor: F,
+) where
+    L: Language,
+    A: Analysis<L>,
+    F: FnMut(BlockId, usize, &A::Domain),
+{
+    for block in 0..cfg.blocks.len() {
+        match A::DIRECTION {
+            Direction::Forward => {
+                forward_block(cfg, analysis, results, block, &mut visitor, |_, _| {});
+            }
+            Direction::Backward => {
+                backward_block(cfg, analysis, results, block, &mut visitor);
+            }
+        }
+    }
+}
+
+/// Propagate the facts at the entry of `block` through its instructions,
+/// calling `edge` with the facts flowing out of the block along each of its
+/// outgoing edges
+fn forward_block<L, A>(
+    cfg: &ControlFlowGraph<L>,
+    analysis: &A,
+    results: &DataflowResults<A::Domain>,
+    block: usize,
+    mut visitor: impl FnMut(BlockId, usize, &A::Domain),
+    mut edge: impl FnMut(usize, &A::Domain),
+) where
+    L: Language,
+    A: Analysis<L>,
+{
+    let block_id = BlockId::new(block);
+    let basic_block = &cfg.blocks[block];
+    let mut state = results.entry_states[block].clone();
+
+    // Any instruction of the block may throw and jump to the exception handler
+    let exception_handler = basic_block
+        .exception_handlers
+        .first()
+        .map(|handler| handler.target as usize);
+
+    if let Some(handler) = exception_handler {
+        edge(handler, &state);
+    }
+
+    for (index, instruction) in basic_block.instructions.iter().enumerate() {
+        visitor(block_id, index, &state);
+        analysis.transfer(&mut state, block_id, index, instruction);
+
+        if let Some(handler) = exception_handler {
+            edge(handler, &state);
+        }
+
+        match instruction.kind {
+            InstructionKind::Statement => {}
+            InstructionKind::Jump {
+                conditional, block, ..
+            } => {
+                edge(block.index() as usize, &state);
+
+                // The instructions following an unconditional jump are unreachable
+                if !conditional {
+                    return;
+                }
+            }
+            InstructionKind::Return => {
+                if let Some(handler) = basic_block.cleanup_handlers.first() {
+                    edge(handler.target as usize, &state);
+                }
+
+                return;
+            }
+        }
+    }
+}
+
+/// Propagate the facts at the start of the successors of `block` through its
+/// instructions in reverse order, returning the facts at the start of the block
+fn backward_block<L, A>(
+    cfg: &ControlFlowGraph<L>,
+    analysis: &A,
+    results: &DataflowResults<A::Domain>,
+    block: usize,
+    mut visitor: impl FnMut(BlockId, usize, &A::Domain),
+) -> A::Domain
+where
+    L: Language,
+    A: Analysis<L>,
+{
+    let block_id = BlockId::new(block);
+    let basic_block = &cfg.blocks[block];
+
+    let exception_handler = basic_block
+        .exception_handlers
+        .first()
+        .map(|handler| &results.entry_states[handler.target as usize]);
+
+    // Find the terminator of the block, the instructions following it are unreachable
+    let terminator =
+        basic_block
+            .instructions
+            .iter()
+            .position(|instruction| match instruction.kind {
+                InstructionKind::Statement => false,
+                InstructionKind::Jump { conditional, .. } => !conditional,
+                InstructionKind::Return => true,
+            });
+
+    let end = terminator.map_or(basic_block.instructions.len(), |index| index + 1);
+
+    // A block without a terminator falls through to the exit of the function
+    let mut state = match terminator {
+        Some(_) => analysis.bottom(),
+        None => analysis.boundary(),
+    };
+
+    for (index, instruction) in basic_block.instructions[..end].iter().enumerate().rev() {
+        match instruction.kind {
+            InstructionKind::Statement => {}
+            InstructionKind::Jump { block, .. } => {
+                state.join(&results.entry_states[block.index() as usize]);
+            }
+            InstructionKind::Return => {
+                match basic_block.cleanup_handlers.first() {
+                    Some(handler) => state.join(&results.entry_states[handler.target as usize]),
+                    None => state.join(&analysis.boundary()),
+                };
+            }
+        }
+
+        if let Some(handler) = exception_handler {
+            state.join(handler);
+        }
+
+        visitor(block_id, index, &state);
+        analysis.transfer(&mut state, block_id, index, instruction);
+    }
+
+    if let Some(handler) = exception_handler {
+        state.join(handler);
+    }
+
+    state
+}
+
+/// Return the list of the blocks jumping to each block of the graph
+fn predecessors<L: Language>(cfg: &ControlFlowGraph<L>) -> Vec<Vec<usize>> {
+    let mut predecessors = vec![Vec::new(); cfg.blocks.len()];
+
+    for (index, block) in cfg.blocks.iter().enumerate() {
+        let handlers = block
+            .exception_handlers
+            .first()
+            .into_iter()
+            .chain(block.cleanup_handlers.first())
+            .map(|handler| handler.target as usize);
+
+        let jumps = block
+            .instructions
+            .iter()
+            .filter_map(|instruction| match instruction.kind {
+                InstructionKind::Jump { block, .. } => Some(block.index() as usize),
+                _ => None,
+            });
+
+        for target in handlers.chain(jumps) {
+            if !predecessors[target].contains(&index) {
+                predecessors[target].push(index);
+            }
+        }
+    }
+
+    predecessors
+}
+
+/// Queue of the blocks whose facts need to be recomputed, each block being
+/// queued at most once
+struct Worklist {
+    queue: VecDeque<usize>,
+    queued: Vec<bool>,
+}
+
+impl Worklist {
+    fn new(blocks: impl ExactSizeIterator<Item = usize>) -> Self {
+        let queued = vec![true; blocks.len()];
+        Self {
+            queue: blocks.collect(),
+            queued,
+        }
+    }
+
+    fn push(&mut self, block: usize) {
+        if !self.queued[block] {
+            self.queued[block] = true;
+            self.queue.push_back(block);
+        }
+    }
+
+    fn pop(&mut self) -> Option<usize> {
+        let block = self.queue.pop_front()?;
+        self.queued[block] = false;
+        Some(block)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use rome_rowan::raw_language::RawLanguage;
+
+    use super::{solve, visit_instructions, Analysis, Direction};
+    use crate::{
+        builder::{BlockId, FunctionBuilder},
+        ControlFlowGraph, ExceptionHandlerKind, Instruction, InstructionKind,
+    };
+
+    type Statements = BTreeSet<(u32, usize)>;
+
+    /// Collects the statements evaluated before each point of the function
+    struct Evaluated;
+
+    impl Analysis<RawLanguage> for Evaluated {
+        type Domain = Statements;
+
+        const DIRECTION: Direction = Direction::Forward;
+
+        fn bottom(&self) -> Self::Domain {
+            Statements::new()
+        }
+
+        fn transfer(
+            &self,
+            state: &mut Self::Domain,
+            block: BlockId,
+            index: usize,
+            instruction: &Instruction<RawLanguage>,
+        ) {
+            if let InstructionKind::Statement = instruction.kind {
+                state.insert((block.index(), index));
+            }
+        }
+    }
+
+    /// Collects the statements that may be evaluated after each point of the
+    /// function
+    struct Pending;
+
+    impl Analysis<RawLanguage> for Pending {
+        type Domain = Statements;
+
+        const DIRECTION: Direction = Direction::Backward;
+
+        fn bottom(&self) -> Self::Domain {
+            Statements::new()
+        }
+
+        fn transfer(
+            &self,
+            state: &mut Self::Domain,
+            block: BlockId,
+            index: usize,
+            instruction: &Instruction<RawLanguage>,
+        ) {
+            Evaluated.transfer(state, block, index, instruction);
+        }
+    }
+
+    fn statements(statements: &[(u32, usize)]) -> Statements {
+        statements.iter().copied().collect()
+    }
+
+    /// `if (test) { a } else { b } c; return;`, with a statement following
+    /// the terminator of the consequent
+    fn branches() -> ControlFlowGraph<RawLanguage> {
+        let mut builder = FunctionBuilder::default();
+        let consequent = builder.append_block();
+        let alternate = builder.append_block();
+        let next = builder.append_block();
+
+        builder.append_statement();
+        builder.append_jump(true, consequent);
+        builder.append_jump(false, alternate);
+
+        builder.set_cursor(consequent);
+        builder.append_statement();
+        builder.append_jump(false, next);
+        builder.append_statement();
+
+        builder.set_cursor(alternate);
+        builder.append_statement();
+        builder.append_jump(false, next);
+
+        builder.set_cursor(next);
+        builder.append_statement();
+        builder.append_return();
+
+        builder.finish()
+    }
+
+    #[test]
+    fn forward_joins_the_branches() {
+        let cfg = branches();
+        let results = solve(&cfg, &Evaluated);
+
+        assert_eq!(results.entry_state(BlockId::new(0)), &statements(&[]));
+        assert_eq!(results.entry_state(BlockId::new(1)), &statements(&[(0, 0)]));
+        assert_eq!(results.entry_state(BlockId::new(2)), &statements(&[(0, 0)]));
+        assert_eq!(
+            results.entry_state(BlockId::new(3)),
+            &statements(&[(0, 0), (1, 0), (2, 0)])
+        );
+    }
+
+    #[test]
+    fn backward_joins_the_successors() {
+        let cfg = branches();
+        let results = solve(&cfg, &Pending);
+
+        // The statement following the jump of the consequent is unreachable
+        assert_eq!(
+            results.entry_state(BlockId::new(0)),
+            &statements(&[(0, 0), (1, 0), (2, 0), (3, 0)])
+        );
+        assert_eq!(
+            results.entry_state(BlockId::new(1)),
+            &statements(&[(1, 0), (3, 0)])
+        );
+        assert_eq!(
+            results.entry_state(BlockId::new(2)),
+            &statements(&[(2, 0), (3, 0)])
+        );
+        assert_eq!(results.entry_state(BlockId::new(3)), &statements(&[(3, 0)]));
+    }
+
+    #[test]
+    fn visits_the_reachable_instructions() {
+        let cfg = branches();
+        let results = solve(&cfg, &Evaluated);
+
+        let mut visited = Vec::new();
+        visit_instructions(&cfg, &Evaluated, &results, |block, index, state| {
+            visited.push((block.index(), index, state.len()));
+        });
+
+        assert_eq!(
+            visited,
+            [
+                (0, 0, 0),
+                (0, 1, 1),
+                (0, 2, 1),
+                (1, 0, 1),
+                (1, 1, 2),
+                (2, 0, 1),
+                (2, 1, 2),
+                (3, 0, 3),
+                (3, 1, 4),
+            ]
+        );
+    }
+
+    /// `while (test) { a } return;`
+    fn loop_statement() -> ControlFlowGraph<RawLanguage> {
+        let mut builder = FunctionBuilder::default();
+        let header = builder.append_block();
+        let body = builder.append_block();
+        let next = builder.append_block();
+
+        builder.append_statement();
+        builder.append_jump(false, header);
+
+        builder.set_cursor(header);
+        builder.append_jump(true, body);
+        builder.append_jump(false, next);
+
+        builder.set_cursor(body);
+        builder.append_statement();
+        builder.append_jump(false, header);
+
+        builder.set_cursor(next);
+        builder.append_return();
+
+        builder.finish()
+    }
+
+    #[test]
+    fn forward_reaches_a_fixed_point_in_loops() {
+        let cfg = loop_statement();
+        let results = solve(&cfg, &Evaluated);
+
+        // The facts of the body flow back to the header of the loop
+        assert_eq!(
+            results.entry_state(BlockId::new(1)),
+            &statements(&[(0, 0), (2, 0)])
+        );
+        assert_eq!(
+            results.entry_state(BlockId::new(2)),
+            &statements(&[(0, 0), (2, 0)])
+        );
+        assert_eq!(
+            results.entry_state(BlockId::new(3)),
+            &statements(&[(0, 0), (2, 0)])
+        );
+    }
+
+    #[test]
+    fn backward_reaches_a_fixed_point_in_loops() {
+        let cfg = loop_statement();
+        let results = solve(&cfg, &Pending);
+
+        assert_eq!(
+            results.entry_state(BlockId::new(0)),
+            &statements(&[(0, 0), (2, 0)])
+        );
+        assert_eq!(results.entry_state(BlockId::new(1)), &statements(&[(2, 0)]));
+        assert_eq!(results.entry_state(BlockId::new(2)), &statements(&[(2, 0)]));
+        assert_eq!(results.entry_state(BlockId::new(3)), &statements(&[]));
+    }
+
+    /// `try { a; b; } catch { c } return;`
+    fn try_catch() -> ControlFlowGraph<RawLanguage> {
+        let mut builder = FunctionBuilder::default();
+        let catch = builder.append_block();
+        let next = builder.append_block();
+
+        builder.push_exception_target(ExceptionHandlerKind::Catch, catch);
+        let body = builder.append_block();
+        builder.pop_exception_target();
+
+        builder.append_jump(false, body);
+
+        builder.set_cursor(body);
+        builder.append_statement();
+        builder.append_statement();
+        builder.append_jump(false, next);
+
+        builder.set_cursor(catch);
+        builder.append_statement();
+        builder.append_jump(false, next);
+
+        builder.set_cursor(next);
+        builder.append_return();
+
+        builder.finish()
+    }
+
+    #[test]
+    fn forward_follows_the_exception_edges() {
+        let cfg = try_catch();
+        let results = solve(&cfg, &Evaluated);
+
+        // Any statement of the `try` block may throw, the facts following
+        // each of them flow to the `catch` clause
+        assert_eq!(
+            results.entry_state(BlockId::new(1)),
+            &statements(&[(3, 0), (3, 1)])
+        );
+        assert_eq!(
+            results.entry_state(BlockId::new(2)),
+            &statements(&[(1, 0), (3, 0), (3, 1)])
+        );
+    }
+
+    #[test]
+    fn backward_follows_the_exception_edges() {
+        let cfg = try_catch();
+        let results = solve(&cfg, &Pending);
+
+        assert_eq!(
+            results.entry_state(BlockId::new(3)),
+            &statements(&[(1, 0), (3, 0), (3, 1)])
+        );
+    }
+
+    /// `try { a; return; } finally { b }`
+    fn try_finally() -> ControlFlowGraph<RawLanguage> {
+        let mut builder = FunctionBuilder::default();
+        let finally = builder.append_block();
+
+        builder.push_exception_target(ExceptionHandlerKind::Finally, finally);
+        let body = builder.append_block();
+        builder.pop_exception_target();
+
+        builder.append_jump(false, body);
+
+        builder.set_cursor(body);
+        builder.append_statement();
+        builder.append_return();
+
+        builder.set_cursor(finally);
+        builder.append_statement();
+        builder.append_return();
+
+        builder.finish()
+    }
+
+    #[test]
+    fn returns_follow_the_cleanup_edges() {
+        let cfg = try_finally();
+
+        let results = solve(&cfg, &Evaluated);
+        assert!(results.entry_state(BlockId::new(1)).contains(&(2, 0)));
+
+        let results = solve(&cfg, &Pending);
+        assert_eq!(
+            results.entry_state(BlockId::new(2)),
+            &statements(&[(1, 0), (2, 0)])
+        );
+    }
+}
@@ -1,13 +1,14 @@
 #![deny(rustdoc::broken_intra_doc_links)]
 
 use std::{
-    collections::HashMap,
+    collections::BTreeMap,
     fmt::{self, Display, Formatter},
 };
 
 use rome_rowan::{Language, SyntaxElement};
 
 pub mod builder;
+pub mod dataflow;
 
 use crate::builder::BlockId;
 
@@ -87,6 +88,11 @@ pub enum InstructionKind {
         /// `finally` clause, the target block can be reinterpreted to the next
         /// exception handler instead if the control flow is currently unwinding
         finally_fallthrough: bool,
+        /// The condition of the conditional jumps of the expressions only
+        /// evaluating some of their operands, like `a && b` or `a?.b`. It's
+        /// [None] for the jumps of the statements, taken if their node
+        /// evaluates to a truthy value
+        expression: Option<JumpCondition>,
     },
     /// This instruction causes the control flow to unconditionally abort the
     /// execution of the function, for example is JavaScript this can be
@@ -94,6 +100,18 @@ pub enum InstructionKind {
     Return,
 }
 
+/// The condition under which the conditional jump of an expression is taken,
+/// depending on the value its node evaluates to
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum JumpCondition {
+    /// The value is truthy, for `&&`, `||` and `?:`
+    Truthy,
+    /// The value is neither `null` nor `undefined`, for `??` and `?.`
+    NotNullish,
+    /// The value isn't `undefined`, for the default values of the bindings
+    NotUndefined,
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct ExceptionHandler {
     pub kind: ExceptionHandlerKind,
@@ -157,7 +175,7 @@ impl<L: Language> Display for ControlFlowGraph<L> {
     fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
         writeln!(fmt, "flowchart TB")?;
 
-        let mut links = HashMap::new();
+        let mut links = BTreeMap::new();
         for (id, block) in self.blocks.iter().enumerate() {
             if fmt.alternate() {
                 writeln!(fmt, "    subgraph block_{id}")?;
@@ -2,7 +2,7 @@ use std::io;
 
 use rome_console::{fmt, markup, MarkupBuf};
 use rome_text_edit::TextEdit;
-use rome_text_size::TextRange;
+use rome_text_size::{TextRange, TextSize};
 use serde::{
     de::{self, SeqAccess},
     Deserialize, Deserializer, Serialize, Serializer,
@@ -72,6 +72,23 @@ impl Diagnostic {
             source,
         }
     }
+
+    /// Shifts the text ranges of the diagnostic and of its advices by `offset`,
+    /// this is used for the diagnostics emitted for a fragment of a larger
+    /// document like the code blocks of a markdown file
+    pub fn with_offset(mut self, offset: TextSize) -> Self {
+        if let Some(location) = &mut self.location {
+            location.shift(offset);
+        }
+
+        self.advices.shift(offset);
+        self.verbose_advices.shift(offset);
+        self.source = self
+            .source
+            .map(|source| Box::new(source.with_offset(offset)));
+
+        self
+    }
 }
 
 impl super::Diagnostic for Diagnostic {
@@ -139,6 +156,12 @@ struct Location {
     source_code: Option<String>,
 }
 
+impl Location {
+    fn shift(&mut self, offset: TextSize) {
+        self.span = self.span.map(|span| span + offset);
+    }
+}
+
 impl From<super::Location<'_>> for Location {
     fn from(loc: super::Location<'_>) -> Self {
         Self {
@@ -165,6 +188,16 @@ impl Advices {
             advices: Vec::new(),
         }
     }
+
+    fn shift(&mut self, offset: TextSize) {
+        for advice in &mut self.advices {
+            match advice {
+                Advice::Frame(location) => location.shift(offset),
+                Advice::Group(_, advices) => advices.shift(offset),
+                _ => {}
+            }
+        }
+    }
 }
 
 impl Visit for Advices {
@@ -478,6 +511,15 @@ mod tests {
         assert_eq!(json, expected, "actual:\n{json:#}\nexpected:\n{expected:#}");
     }
 
+    #[test]
+    fn test_with_offset() {
+        let diag = TestDiagnostic::default();
+        let diag = super::Diagnostic::new(diag).with_offset(TextSize::from(10));
+        let json = to_value(&diag).unwrap();
+
+        assert_eq!(json["location"]["span"], json!([10, 16]));
+    }
+
     #[test]
     fn test_deserialize() {
         let json = serialized();
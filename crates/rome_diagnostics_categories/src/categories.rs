@@ -75,32 +75,50 @@ define_dategories! {
 
 
     // nursery
+    "lint/nursery/noAsyncArrayCallback": "https://docs.rome.tools/lint/rules/noAsyncArrayCallback",
+    "lint/nursery/noAwaitInLoop": "https://docs.rome.tools/lint/rules/noAwaitInLoop",
     "lint/nursery/noBannedTypes":"https://docs.rome.tools/lint/rules/noBannedTypes",
     "lint/nursery/noConstAssign": "https://docs.rome.tools/lint/rules/noConstAssign",
     "lint/nursery/noDupeKeys":"https://docs.rome.tools/lint/rules/noDupeKeys",
+    "lint/nursery/noExcessiveComplexity": "https://docs.rome.tools/lint/rules/noExcessiveComplexity",
     "lint/nursery/noExplicitAny": "https://docs.rome.tools/lint/rules/noExplicitAny",
+    "lint/nursery/noFloatingPromises": "https://docs.rome.tools/lint/rules/noFloatingPromises",
+    "lint/nursery/noImportCycles": "https://docs.rome.tools/lint/rules/noImportCycles",
     "lint/nursery/noInvalidConstructorSuper": "https://docs.rome.tools/lint/rules/noInvalidConstructorSuper",
+    "lint/nursery/noPromiseInCondition": "https://docs.rome.tools/lint/rules/noPromiseInCondition",
+    "lint/nursery/noSelfAssign": "https://docs.rome.tools/lint/rules/noSelfAssign",
+    "lint/nursery/noUseBeforeAssign": "https://docs.rome.tools/lint/rules/noUseBeforeAssign",
+    "lint/nursery/noUselessAssignment": "https://docs.rome.tools/lint/rules/noUselessAssignment",
     "lint/nursery/useCamelCase": "https://docs.rome.tools/lint/rules/useCamelCase",
     "lint/nursery/useExhaustiveDependencies": "https://docs.rome.tools/lint/rules/useExhaustiveDependencies",
     "lint/nursery/useFlatMap": "https://docs.rome.tools/lint/rules/useFlatMap",
+    "lint/nursery/useHookAtTopLevel": "https://docs.rome.tools/lint/rules/useHookAtTopLevel",
     "lint/nursery/useNumericLiterals": "https://docs.rome.tools/lint/rules/useNumericLiterals",
+    "lint/nursery/useSortedPackageJson": "https://docs.rome.tools/lint/rules/useSortedPackageJson",
     "lint/nursery/useValidForDirection": "https://docs.rome.tools/lint/rules/useValidForDirection",
 
     ;
 
     // General categories
+    "configuration",
+    "deadCode/unreachableFile",
+    "deadCode/unusedExport",
     "files/missingHandler",
     "format",
     "internalError/io",
     "internalError/fs",
     "internalError/panic",
     "lint",
+    "lint/custom",
+    "lint/plugin",
     // parse categories
     "parse",
     "parse/noSuperWithoutExtends",
 
+    "suppressions/misplaced",
     "suppressions/unknownGroup",
     "suppressions/unknownRule",
+    "suppressions/unmatchedRange",
     "suppressions/unused",
     // Used in tests and examples
     "args/fileNotFound",
@@ -79,7 +79,7 @@ mod builder;
 mod map;
 
 use self::{builder::CommentsBuilderVisitor, map::CommentsMap};
-use crate::{TextSize, TransformSourceMap};
+use crate::{TextRange, TextSize, TransformSourceMap};
 use rome_rowan::syntax::SyntaxElementKey;
 use rome_rowan::{Language, SyntaxNode, SyntaxToken, SyntaxTriviaPieceComments};
 use rustc_hash::FxHashSet;
@@ -765,6 +765,18 @@ pub trait CommentStyle: Default {
         false
     }
 
+    /// Returns `true` if a comment with the given `text` is a `rome-ignore-start format:` comment
+    /// that opens a suppressed range.
+    fn is_suppression_range_start(_text: &str) -> bool {
+        false
+    }
+
+    /// Returns `true` if a comment with the given `text` is a `rome-ignore-end format` comment
+    /// that closes a suppressed range.
+    fn is_suppression_range_end(_text: &str) -> bool {
+        false
+    }
+
     /// Returns the (kind)[CommentKind] of the comment
     fn get_comment_kind(comment: &SyntaxTriviaPieceComments<Self::Language>) -> CommentKind;
 
@@ -814,12 +826,14 @@ impl<L: Language> Comments<L> {
     {
         let builder = CommentsBuilderVisitor::new(style, source_map);
 
-        let (comments, skipped) = builder.visit(root);
+        let (comments, skipped, suppressed_ranges, unmatched_suppressions) = builder.visit(root);
 
         Self {
             data: Rc::new(CommentsData {
                 root: Some(root.clone()),
                 is_suppression: Style::is_suppression,
+                suppressed_ranges,
+                unmatched_suppressions,
 
                 comments,
                 with_skipped: skipped,
@@ -909,7 +923,8 @@ impl<L: Language> Comments<L> {
         self.data.with_skipped.contains(&token.key())
     }
 
-    /// Returns `true` if `node` has a [leading](self#leading-comments), [dangling](self#dangling-comments), or [trailing](self#trailing-comments) suppression comment.
+    /// Returns `true` if `node` has a [leading](self#leading-comments), [dangling](self#dangling-comments), or [trailing](self#trailing-comments) suppression comment,
+    /// or if it is fully contained in a range delimited by a pair of suppression range comments.
     ///
     /// # Examples
     ///
@@ -920,14 +935,45 @@ impl<L: Language> Comments<L> {
     ///
     /// Returns `true` for the expression statement but `false` for the call expression because the
     /// call expression is nested inside of the expression statement.
+    ///
+    /// ```javascript
+    /// // rome-ignore-start format: Reason
+    /// const a  =  1;
+    /// const b  =  2;
+    /// // rome-ignore-end format
+    /// ```
+    ///
+    /// Returns `true` for both variable statements.
     pub fn is_suppressed(&self, node: &SyntaxNode<L>) -> bool {
         self.mark_suppression_checked(node);
         let is_suppression = self.data.is_suppression;
 
+        if self.is_in_suppressed_range(node) {
+            return true;
+        }
+
         self.leading_dangling_trailing_comments(node)
             .any(|comment| is_suppression(comment.piece().text()))
     }
 
+    /// Returns the suppression range comments that don't have a matching opening or closing
+    /// comment, sorted by their position in the source.
+    pub fn unmatched_suppressions(&self) -> &[UnmatchedSuppression] {
+        &self.data.unmatched_suppressions
+    }
+
+    fn is_in_suppressed_range(&self, node: &SyntaxNode<L>) -> bool {
+        if self.data.suppressed_ranges.is_empty() {
+            return false;
+        }
+
+        let range = node.text_trimmed_range();
+        self.data
+            .suppressed_ranges
+            .iter()
+            .any(|suppressed| suppressed.contains_range(range))
+    }
+
     #[cfg(not(debug_assertions))]
     #[inline(always)]
     pub fn mark_suppression_checked(&self, _: &SyntaxNode<L>) {}
@@ -1026,11 +1072,41 @@ Node:
     }
 }
 
+/// A suppression range comment without a matching opening or closing comment. The range is the
+/// range of the comment in the source text.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema)
+)]
+pub enum UnmatchedSuppression {
+    /// A `rome-ignore-start format` comment that isn't closed, it doesn't suppress anything
+    Start(TextRange),
+    /// A `rome-ignore-end format` comment that doesn't close any range
+    End(TextRange),
+}
+
+impl UnmatchedSuppression {
+    /// Returns the range of the comment in the source text
+    pub const fn range(&self) -> TextRange {
+        match self {
+            UnmatchedSuppression::Start(range) | UnmatchedSuppression::End(range) => *range,
+        }
+    }
+}
+
 struct CommentsData<L: Language> {
     root: Option<SyntaxNode<L>>,
 
     is_suppression: fn(&str) -> bool,
 
+    /// Ranges of the source delimited by a pair of suppression range comments.
+    /// All nodes fully contained in one of these ranges are formatted verbatim.
+    suppressed_ranges: Vec<TextRange>,
+
+    /// Suppression range comments without a matching opening or closing comment.
+    unmatched_suppressions: Vec<UnmatchedSuppression>,
+
     /// Stores all leading node comments by node
     comments: CommentsMap<SyntaxElementKey, SourceComment<L>>,
     with_skipped: FxHashSet<SyntaxElementKey>,
@@ -1052,6 +1128,8 @@ impl<L: Language> Default for CommentsData<L> {
         Self {
             root: None,
             is_suppression: |_| false,
+            suppressed_ranges: Vec::new(),
+            unmatched_suppressions: Vec::new(),
             comments: Default::default(),
             with_skipped: Default::default(),
             #[cfg(debug_assertions)]
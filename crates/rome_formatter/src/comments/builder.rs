@@ -1,6 +1,6 @@
 use super::{
     map::CommentsMap, CommentPlacement, CommentStyle, CommentTextPosition, DecoratedComment,
-    SourceComment, TransformSourceMap,
+    SourceComment, TransformSourceMap, UnmatchedSuppression,
 };
 use crate::source_map::{DeletedRangeEntry, DeletedRanges};
 use crate::{TextRange, TextSize};
@@ -15,6 +15,7 @@ pub(super) struct CommentsBuilderVisitor<'a, Style: CommentStyle> {
     builder: CommentsBuilder<Style::Language>,
     style: &'a Style,
     parentheses: SourceParentheses<'a>,
+    source_map: Option<&'a TransformSourceMap>,
 
     // State
     pending_comments: Vec<DecoratedComment<Style::Language>>,
@@ -22,6 +23,8 @@ pub(super) struct CommentsBuilderVisitor<'a, Style: CommentStyle> {
     following_node_index: Option<usize>,
     parents: Vec<SyntaxNode<Style::Language>>,
     last_token: Option<SyntaxToken<Style::Language>>,
+    /// Ranges of the suppression range comments that haven't been closed yet
+    open_suppression_ranges: Vec<TextRange>,
 }
 
 impl<'a, Style> CommentsBuilderVisitor<'a, Style>
@@ -33,12 +36,14 @@ where
             style,
             builder: Default::default(),
             parentheses: SourceParentheses::from_source_map(source_map),
+            source_map,
 
             pending_comments: Default::default(),
             preceding_node: Default::default(),
             following_node_index: Default::default(),
             parents: Default::default(),
             last_token: Default::default(),
+            open_suppression_ranges: Default::default(),
         }
     }
 
@@ -48,6 +53,8 @@ where
     ) -> (
         CommentsMap<SyntaxElementKey, SourceComment<Style::Language>>,
         FxHashSet<SyntaxElementKey>,
+        Vec<TextRange>,
+        Vec<UnmatchedSuppression>,
     ) {
         for event in root.preorder_with_tokens(Direction::Next) {
             match event {
@@ -89,6 +96,13 @@ where
 
         self.flush_comments(None);
 
+        // The ranges still open at the end of the file are never closed
+        for range in std::mem::take(&mut self.open_suppression_ranges) {
+            let range = self.source_range(range);
+            self.builder
+                .push_unmatched_suppression(UnmatchedSuppression::Start(range));
+        }
+
         self.builder.finish()
     }
 
@@ -219,9 +233,44 @@ where
     }
 
     fn queue_comment(&mut self, comment: DecoratedComment<Style::Language>) {
+        self.track_suppression_range(&comment);
         self.pending_comments.push(comment);
     }
 
+    /// Pairs the suppression range comments as they're visited in source order.
+    ///
+    /// The suppressed range spans from the end of the opening comment to the start of the closing
+    /// comment. An opening comment without a matching closing comment (or the inverse) doesn't
+    /// suppress anything and is recorded as [UnmatchedSuppression].
+    fn track_suppression_range(&mut self, comment: &DecoratedComment<Style::Language>) {
+        let text = comment.comment.text();
+        let range = comment.comment.text_range();
+
+        if Style::is_suppression_range_start(text) {
+            self.open_suppression_ranges.push(range);
+        } else if Style::is_suppression_range_end(text) {
+            match self.open_suppression_ranges.pop() {
+                Some(start) => {
+                    self.builder
+                        .push_suppressed_range(TextRange::new(start.end(), range.start()));
+                }
+                None => {
+                    let range = self.source_range(range);
+                    self.builder
+                        .push_unmatched_suppression(UnmatchedSuppression::End(range));
+                }
+            }
+        }
+    }
+
+    /// Maps a range of the transformed tree to the range of the source text
+    fn source_range(&self, range: TextRange) -> TextRange {
+        match self.source_map {
+            Some(source_map) => source_map.source_range(range),
+            None => range,
+        }
+    }
+
     fn update_comments(
         comments: &mut [DecoratedComment<Style::Language>],
         position: CommentTextPosition,
@@ -369,6 +418,8 @@ where
 struct CommentsBuilder<L: Language> {
     comments: CommentsMap<SyntaxElementKey, SourceComment<L>>,
     skipped: FxHashSet<SyntaxElementKey>,
+    suppressed_ranges: Vec<TextRange>,
+    unmatched_suppressions: Vec<UnmatchedSuppression>,
 }
 
 impl<L: Language> CommentsBuilder<L> {
@@ -469,6 +520,14 @@ impl<L: Language> CommentsBuilder<L> {
         }
     }
 
+    fn push_suppressed_range(&mut self, range: TextRange) {
+        self.suppressed_ranges.push(range);
+    }
+
+    fn push_unmatched_suppression(&mut self, suppression: UnmatchedSuppression) {
+        self.unmatched_suppressions.push(suppression);
+    }
+
     fn mark_has_skipped(&mut self, token: &SyntaxToken<L>) {
         self.skipped.insert(token.key());
     }
@@ -494,12 +553,23 @@ impl<L: Language> CommentsBuilder<L> {
     }
 
     fn finish(
-        self,
+        mut self,
     ) -> (
         CommentsMap<SyntaxElementKey, SourceComment<L>>,
         FxHashSet<SyntaxElementKey>,
+        Vec<TextRange>,
+        Vec<UnmatchedSuppression>,
     ) {
-        (self.comments, self.skipped)
+        // The closing comments are recorded when they're visited, the opening comments at the end
+        self.unmatched_suppressions
+            .sort_by_key(|suppression| suppression.range().start());
+
+        (
+            self.comments,
+            self.skipped,
+            self.suppressed_ranges,
+            self.unmatched_suppressions,
+        )
     }
 }
 
@@ -508,6 +578,8 @@ impl<L: Language> Default for CommentsBuilder<L> {
         Self {
             comments: CommentsMap::new(),
             skipped: FxHashSet::default(),
+            suppressed_ranges: Vec::new(),
+            unmatched_suppressions: Vec::new(),
         }
     }
 }
@@ -905,7 +977,7 @@ b;"#;
 
         let style = TestCommentStyle::default();
         let comments_builder = CommentsBuilderVisitor::new(&style, Some(&source_map));
-        let (comments, _) = comments_builder.visit(&transformed);
+        let (comments, _, _, _) = comments_builder.visit(&transformed);
 
         let decorated_comments = style.finish();
 
@@ -1016,7 +1088,7 @@ b;"#;
 
         let style = TestCommentStyle::default();
         let builder = CommentsBuilderVisitor::new(&style, source_map);
-        let (comments, _) = builder.visit(&tree.syntax());
+        let (comments, _, _, _) = builder.visit(&tree.syntax());
 
         (tree.syntax(), style.finish(), comments)
     }
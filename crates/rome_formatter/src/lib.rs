@@ -54,7 +54,7 @@ pub use buffer::{
 pub use builders::BestFitting;
 
 use crate::builders::syntax_token_cow_slice;
-use crate::comments::{CommentStyle, Comments, SourceComment};
+use crate::comments::{CommentStyle, Comments, SourceComment, UnmatchedSuppression};
 pub use format_element::{normalize_newlines, FormatElement, LINE_TERMINATORS};
 pub use group_id::GroupId;
 use rome_rowan::{
@@ -472,6 +472,7 @@ pub struct Printed {
     range: Option<TextRange>,
     sourcemap: Vec<SourceMarker>,
     verbatim_ranges: Vec<TextRange>,
+    unmatched_suppressions: Vec<UnmatchedSuppression>,
 }
 
 impl Printed {
@@ -486,6 +487,7 @@ impl Printed {
             range,
             sourcemap,
             verbatim_ranges: verbatim_source,
+            unmatched_suppressions: Vec::new(),
         }
     }
 
@@ -496,6 +498,7 @@ impl Printed {
             range: None,
             sourcemap: Vec::new(),
             verbatim_ranges: Vec::new(),
+            unmatched_suppressions: Vec::new(),
         }
     }
 
@@ -550,6 +553,19 @@ impl Printed {
     pub fn take_verbatim_ranges(&mut self) -> Vec<TextRange> {
         std::mem::take(&mut self.verbatim_ranges)
     }
+
+    /// Sets the suppression range comments of the source that don't have a matching opening or
+    /// closing comment.
+    pub fn with_unmatched_suppressions(mut self, suppressions: Vec<UnmatchedSuppression>) -> Self {
+        self.unmatched_suppressions = suppressions;
+        self
+    }
+
+    /// Suppression range comments of the source that don't have a matching opening or closing
+    /// comment, and didn't suppress the formatting of anything.
+    pub fn unmatched_suppressions(&self) -> &[UnmatchedSuppression] {
+        &self.unmatched_suppressions
+    }
 }
 
 /// Public return type of the formatter
@@ -29,6 +29,15 @@ pub trait FileSystem: Send + Sync + RefUnwindSafe {
     /// efficiently batch many filesystem read operations
     fn traversal<'scope>(&'scope self, func: BoxedTraversal<'_, 'scope>);
 
+    /// Recursively create a directory and all of its parent components if
+    /// they are missing
+    ///
+    /// File systems that don't have a concept of directories can rely on the
+    /// default implementation, which doesn't do anything
+    fn create_dir_all(&self, _path: &Path) -> io::Result<()> {
+        Ok(())
+    }
+
     /// Returns the name of the main configuration file
     fn config_name(&self) -> &str {
         CONFIG_NAME
@@ -39,6 +48,10 @@ pub trait File {
     /// Read the content of the file into `buffer`
     fn read_to_string(&mut self, buffer: &mut String) -> io::Result<()>;
 
+    /// Read the content of the file into `buffer`, without checking that it's
+    /// valid UTF-8
+    fn read_to_end(&mut self, buffer: &mut Vec<u8>) -> io::Result<()>;
+
     /// Overwrite the content of the file with the provided bytes
     ///
     /// This will write to the associated memory buffer, as well as flush the
@@ -145,7 +158,7 @@ pub trait TraversalContext: Sync {
 
 impl<T> FileSystem for Arc<T>
 where
-    T: FileSystem + Send,
+    T: FileSystem + Send + ?Sized,
 {
     fn open_with_options(&self, path: &Path, options: OpenOptions) -> io::Result<Box<dyn File>> {
         T::open_with_options(self, path, options)
@@ -154,6 +167,10 @@ where
     fn traversal<'scope>(&'scope self, func: BoxedTraversal<'_, 'scope>) {
         T::traversal(self, func)
     }
+
+    fn create_dir_all(&self, path: &Path) -> io::Result<()> {
+        T::create_dir_all(self, path)
+    }
 }
 
 #[derive(Debug, Diagnostic)]
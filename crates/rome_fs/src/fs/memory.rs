@@ -75,6 +75,8 @@ impl FileSystem for MemoryFileSystem {
     fn open_with_options(&self, path: &Path, options: OpenOptions) -> io::Result<Box<dyn File>> {
         if options.read && options.write {
             self.open(path)
+        } else if options.read {
+            self.read(path)
         } else if options.create_new || options.write {
             self.create(path)
         } else {
@@ -141,6 +143,11 @@ impl File for MemoryFile {
         Ok(())
     }
 
+    fn read_to_end(&mut self, buffer: &mut Vec<u8>) -> io::Result<()> {
+        buffer.extend_from_slice(&self.inner);
+        Ok(())
+    }
+
     fn set_content(&mut self, content: &[u8]) -> io::Result<()> {
         // Resize the memory buffer to fit the new content
         self.inner.resize(content.len(), 0);
@@ -208,7 +215,7 @@ mod tests {
     use parking_lot::Mutex;
     use rome_diagnostics::v2::Error;
 
-    use crate::fs::FileSystemExt;
+    use crate::fs::{FileSystemExt, OpenOptions};
     use crate::{
         AtomicInterner, FileSystem, MemoryFileSystem, PathInterner, RomePath, TraversalContext,
     };
@@ -244,6 +251,34 @@ mod tests {
         assert_eq!(buffer, content_2);
     }
 
+    #[test]
+    fn file_read_only() {
+        let mut fs = MemoryFileSystem::default();
+
+        let path = Path::new("file.js");
+        fs.insert(path.into(), "content".as_bytes());
+
+        let mut file = fs
+            .open_with_options(path, OpenOptions::default().read(true))
+            .expect("the file should be opened read-only");
+
+        let mut buffer = String::new();
+        file.read_to_string(&mut buffer)
+            .expect("the file should be read without error");
+
+        assert_eq!(buffer, "content");
+
+        let result =
+            fs.open_with_options(Path::new("missing.js"), OpenOptions::default().read(true));
+
+        match result {
+            Ok(_) => panic!("opening a non-existing file read-only should return an error"),
+            Err(error) => {
+                assert_eq!(error.kind(), io::ErrorKind::NotFound);
+            }
+        }
+    }
+
     #[test]
     fn missing_file() {
         let fs = MemoryFileSystem::default();
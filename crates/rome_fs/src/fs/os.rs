@@ -31,6 +31,11 @@ impl FileSystem for OsFileSystem {
             func(scope);
         })
     }
+
+    fn create_dir_all(&self, path: &Path) -> io::Result<()> {
+        tracing::debug_span!("OsFileSystem::create_dir_all", path = ?path)
+            .in_scope(move || fs::create_dir_all(path))
+    }
 }
 
 impl FileSystemExt for OsFileSystem {
@@ -66,6 +71,16 @@ impl File for OsFile {
         })
     }
 
+    fn read_to_end(&mut self, buffer: &mut Vec<u8>) -> io::Result<()> {
+        tracing::debug_span!("OsFile::read_to_end").in_scope(move || {
+            // Reset the cursor to the starting position
+            self.inner.seek(SeekFrom::Start(0))?;
+            // Read the file content
+            self.inner.read_to_end(buffer)?;
+            Ok(())
+        })
+    }
+
     fn set_content(&mut self, content: &[u8]) -> io::Result<()> {
         tracing::debug_span!("OsFile::set_content").in_scope(move || {
             // Truncate the file
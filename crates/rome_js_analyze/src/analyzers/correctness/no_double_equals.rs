@@ -96,13 +96,14 @@ impl Rule for NoDoubleEquals {
         .description(description))
     }
 
-    fn action(ctx: &RuleContext<Self>, op: &Self::State) -> Option<JsRuleAction> {
+    fn actions(ctx: &RuleContext<Self>, op: &Self::State) -> Vec<JsRuleAction> {
+        let mut actions = Vec::with_capacity(2);
         let mut mutation = ctx.root().begin();
 
         let suggestion = if op.kind() == EQ2 { T![===] } else { T![!==] };
         mutation.replace_token(op.clone(), make::token(suggestion));
 
-        Some(JsRuleAction {
+        actions.push(JsRuleAction {
             category: ActionCategory::QuickFix,
             applicability: Applicability::MaybeIncorrect,
             // SAFETY: `suggestion` can only be JsSyntaxKind::EQ3 or JsSyntaxKind::NEQ2,
@@ -110,7 +111,37 @@ impl Rule for NoDoubleEquals {
             message: markup! { "Use "<Emphasis>{suggestion.to_string().unwrap()}</Emphasis> }
                 .to_owned(),
             mutation,
-        })
+        });
+
+        // `a == undefined` behaves like `a == null`, which is allowed by this rule
+        let node = ctx.query();
+        let undefined = [node.left(), node.right()]
+            .into_iter()
+            .flatten()
+            .find(is_undefined_identifier);
+
+        if let Some(undefined) = undefined {
+            let mut mutation = ctx.root().begin();
+
+            mutation.replace_node(
+                undefined,
+                JsAnyExpression::JsAnyLiteralExpression(
+                    JsAnyLiteralExpression::JsNullLiteralExpression(
+                        make::js_null_literal_expression(make::token(T![null])),
+                    ),
+                ),
+            );
+
+            actions.push(JsRuleAction {
+                category: ActionCategory::QuickFix,
+                applicability: Applicability::MaybeIncorrect,
+                message: markup! { "Compare with "<Emphasis>"null"</Emphasis>" instead" }
+                    .to_owned(),
+                mutation,
+            });
+        }
+
+        actions
     }
 }
 
@@ -122,3 +153,13 @@ fn is_null_literal(res: SyntaxResult<JsAnyExpression>) -> bool {
         ))
     )
 }
+
+fn is_undefined_identifier(expression: &JsAnyExpression) -> bool {
+    match expression {
+        JsAnyExpression::JsIdentifierExpression(identifier) => identifier
+            .name()
+            .and_then(|name| name.value_token())
+            .map_or(false, |token| token.text_trimmed() == "undefined"),
+        _ => false,
+    }
+}
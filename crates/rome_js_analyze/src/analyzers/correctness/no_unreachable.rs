@@ -226,6 +226,13 @@ fn exceeds_complexity_threshold(cfg: &ControlFlowGraph) -> bool {
 
             match inst.kind {
                 InstructionKind::Statement => {}
+                // The conditional jumps of the expressions aren't counted, the
+                // blocks they branch to are balanced by their unconditional
+                // jumps: the complexity is the one of the statements
+                InstructionKind::Jump {
+                    expression: Some(_),
+                    ..
+                } => {}
                 InstructionKind::Jump { conditional, .. } => {
                     edges += 1;
 
@@ -305,6 +312,7 @@ fn analyze_simple(cfg: &ControlFlowGraph, signals: &mut UnreachableRanges) {
                     conditional,
                     block,
                     finally_fallthrough,
+                    ..
                 } => {
                     if finally_fallthrough && handlers.is_some() {
                         // Jump towards the corresponding block if there are pending exception
@@ -492,6 +500,7 @@ fn traverse_cfg(
                     conditional,
                     block,
                     finally_fallthrough,
+                    ..
                 } => {
                     handle_jump(&mut queue, &path, block, finally_fallthrough);
 
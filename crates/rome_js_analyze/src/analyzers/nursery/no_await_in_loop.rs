@@ -0,0 +1,157 @@
+use crate::control_flow::is_nested_function;
+use rome_analyze::context::RuleContext;
+use rome_analyze::{declare_rule, Ast, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_js_syntax::{
+    JsAwaitExpression, JsForInStatement, JsForOfStatement, JsForStatement, JsSyntaxKind,
+    JsSyntaxNode, TextRange,
+};
+use rome_rowan::AstNode;
+
+declare_rule! {
+    /// Disallow `await` inside loops
+    ///
+    /// Awaiting in the body of a loop waits for each iteration to complete
+    /// before starting the next one. When the iterations don't depend on each
+    /// other, the promises can be created in the loop and awaited together
+    /// with `Promise.all`, running the asynchronous operations concurrently.
+    ///
+    /// The `await` expressions of the body of a `for await` loop, and of the
+    /// initializer or iterated expression of a `for` loop, aren't reported
+    /// unless the loop is itself nested in another loop.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// async function uploadAll(files) {
+    ///     for (const file of files) {
+    ///         await upload(file);
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// async function poll() {
+    ///     while (!(await isReady())) {}
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// async function uploadAll(files) {
+    ///     await Promise.all(files.map((file) => upload(file)));
+    /// }
+    /// ```
+    ///
+    /// ```js
+    /// async function readAll(stream) {
+    ///     for await (const chunk of stream) {
+    ///         await write(chunk);
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub(crate) NoAwaitInLoop {
+        version: "11.0.0",
+        name: "noAwaitInLoop",
+        recommended: false,
+    }
+}
+
+impl Rule for NoAwaitInLoop {
+    type Query = Ast<JsAwaitExpression>;
+    /// The range of the keyword of the loop containing the `await` expression
+    type State = TextRange;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+
+        let mut child = node.syntax().clone();
+        for ancestor in node.syntax().ancestors().skip(1) {
+            if is_nested_function(ancestor.kind()) {
+                return None;
+            }
+
+            match loop_iteration(&ancestor, &child) {
+                Iteration::Repeated => {
+                    let keyword = ancestor.first_token()?;
+                    return Some(keyword.text_trimmed_range());
+                }
+                Iteration::Once => {}
+            }
+
+            child = ancestor;
+        }
+
+        None
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, keyword: &Self::State) -> Option<RuleDiagnostic> {
+        let node = ctx.query();
+
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                node.range(),
+                markup! {
+                    "Avoid using "<Emphasis>"await"</Emphasis>" inside loops."
+                },
+            )
+            .detail(
+                keyword,
+                markup! {
+                    "This loop waits for each iteration to complete before starting the next one."
+                },
+            )
+            .note(markup! {
+                "If the iterations don't depend on each other, create the promises in the loop and await them together with "<Emphasis>"Promise.all"</Emphasis>"."
+            }),
+        )
+    }
+}
+
+/// How many times the `child` of a node is evaluated
+enum Iteration {
+    /// The child is evaluated at most once per evaluation of the node
+    Once,
+    /// The child is evaluated on each iteration of a loop
+    Repeated,
+}
+
+fn loop_iteration(node: &JsSyntaxNode, child: &JsSyntaxNode) -> Iteration {
+    match node.kind() {
+        JsSyntaxKind::JS_WHILE_STATEMENT | JsSyntaxKind::JS_DO_WHILE_STATEMENT => {
+            Iteration::Repeated
+        }
+        JsSyntaxKind::JS_FOR_STATEMENT => {
+            let statement = JsForStatement::unwrap_cast(node.clone());
+            match statement.initializer() {
+                Some(initializer) if initializer.syntax() == child => Iteration::Once,
+                _ => Iteration::Repeated,
+            }
+        }
+        JsSyntaxKind::JS_FOR_IN_STATEMENT => {
+            let statement = JsForInStatement::unwrap_cast(node.clone());
+            match statement.body() {
+                Ok(body) if body.syntax() == child => Iteration::Repeated,
+                _ => Iteration::Once,
+            }
+        }
+        JsSyntaxKind::JS_FOR_OF_STATEMENT => {
+            let statement = JsForOfStatement::unwrap_cast(node.clone());
+            match statement.body() {
+                // The body of a `for await` loop awaits each iteration by design
+                Ok(body) if body.syntax() == child && statement.await_token().is_none() => {
+                    Iteration::Repeated
+                }
+                _ => Iteration::Once,
+            }
+        }
+        _ => Iteration::Once,
+    }
+}
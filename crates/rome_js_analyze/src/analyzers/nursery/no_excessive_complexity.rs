@@ -0,0 +1,470 @@
+use crate::control_flow::{function_node, function_nodes, is_nested_function, FunctionControlFlow};
+use rome_analyze::context::RuleContext;
+use rome_analyze::{declare_rule, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_control_flow::InstructionKind;
+use rome_js_syntax::{
+    JsBreakStatement, JsCatchClause, JsConditionalExpression, JsContinueStatement,
+    JsDoWhileStatement, JsForInStatement, JsForOfStatement, JsForStatement, JsIfStatement,
+    JsLogicalExpression, JsSwitchStatement, JsSyntaxKind, JsSyntaxNode, JsSyntaxToken,
+    JsWhileStatement, TextRange,
+};
+use rome_rowan::{AstNode, SyntaxResult};
+use serde::{Deserialize, Serialize};
+
+declare_rule! {
+    /// Disallow functions that are too complex to understand or to test
+    ///
+    /// This rule computes two metrics for each function, and reports the
+    /// functions for which one of them exceeds its maximum:
+    ///
+    /// - The **cognitive complexity** measures how hard the function is to
+    ///   understand. Each control structure (`if`, `else`, ternary, `switch`,
+    ///   loop, `catch`, labeled `break` and `continue`) and each sequence of
+    ///   logical operators adds 1. The control structures other than `else`
+    ///   and the jumps to labels add 1 more for each control structure they
+    ///   are nested in. The maximum is 15 by default.
+    /// - The **cyclomatic complexity** counts the independent paths through
+    ///   the function: it starts at 1 and each branch of the control flow
+    ///   adds 1, including the short-circuiting operators, the optional chains,
+    ///   the default values and the `catch` clauses. The maximum is 20 by
+    ///   default.
+    ///
+    /// The nested functions are measured separately from the functions
+    /// containing them. The maximums can be configured with the
+    /// `maxCognitiveComplexity` and `maxCyclomaticComplexity` options.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// function f(items, limit) {
+    ///     for (const item of items) {
+    ///         if (item.isEnabled) {
+    ///             for (const child of item.children) {
+    ///                 if (child.value > limit) {
+    ///                     if (child.isVisible || child.isPinned) {
+    ///                         return child;
+    ///                     }
+    ///                 }
+    ///             }
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// function f(items, limit) {
+    ///     for (const item of items) {
+    ///         if (item.isEnabled) {
+    ///             return item.children.find(isVisibleAbove(limit));
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub(crate) NoExcessiveComplexity {
+        version: "11.0.0",
+        name: "noExcessiveComplexity",
+        recommended: false,
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct NoExcessiveComplexityOptions {
+    /// Maximum cognitive complexity of a function
+    #[serde(default = "default_max_cognitive_complexity")]
+    max_cognitive_complexity: u16,
+    /// Maximum cyclomatic complexity of a function
+    #[serde(default = "default_max_cyclomatic_complexity")]
+    max_cyclomatic_complexity: u16,
+}
+
+fn default_max_cognitive_complexity() -> u16 {
+    15
+}
+
+fn default_max_cyclomatic_complexity() -> u16 {
+    20
+}
+
+impl Default for NoExcessiveComplexityOptions {
+    fn default() -> Self {
+        Self {
+            max_cognitive_complexity: default_max_cognitive_complexity(),
+            max_cyclomatic_complexity: default_max_cyclomatic_complexity(),
+        }
+    }
+}
+
+/// The metric exceeding its maximum
+#[derive(Clone, Copy)]
+pub(crate) enum ComplexityKind {
+    Cognitive,
+    Cyclomatic,
+}
+
+/// A construct contributing to the complexity of a function
+pub(crate) struct Increment {
+    range: TextRange,
+    /// Part of the increment caused by the nesting of the construct
+    nesting: u32,
+}
+
+impl Increment {
+    fn value(&self) -> u32 {
+        1 + self.nesting
+    }
+}
+
+pub(crate) struct ExcessiveComplexity {
+    kind: ComplexityKind,
+    /// Range of the function up to the start of its body
+    range: TextRange,
+    complexity: u32,
+    max: u16,
+    increments: Vec<Increment>,
+}
+
+impl Rule for NoExcessiveComplexity {
+    type Query = FunctionControlFlow;
+    type State = ExcessiveComplexity;
+    type Signals = Vec<Self::State>;
+    type Options = NoExcessiveComplexityOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let (cfg, range) = ctx.query();
+        let function = match function_node(&ctx.root(), *range) {
+            Some(function) => function,
+            None => return Vec::new(),
+        };
+
+        // The top level of modules and scripts isn't a function
+        if matches!(
+            function.kind(),
+            JsSyntaxKind::JS_MODULE | JsSyntaxKind::JS_SCRIPT
+        ) {
+            return Vec::new();
+        }
+
+        let default_options = NoExcessiveComplexityOptions::default();
+        let options = ctx.options().unwrap_or(&default_options);
+        let range = function_head(&function);
+
+        let mut signals = Vec::new();
+
+        let mut cognitive = Vec::new();
+        for child in function.children() {
+            cognitive_increments(&child, 0, &mut cognitive);
+        }
+
+        // The operators of the logical sequences are collected out of order
+        cognitive.sort_by_key(|increment| increment.range.start());
+
+        let complexity = cognitive.iter().map(Increment::value).sum();
+        if complexity > u32::from(options.max_cognitive_complexity) {
+            signals.push(ExcessiveComplexity {
+                kind: ComplexityKind::Cognitive,
+                range,
+                complexity,
+                max: options.max_cognitive_complexity,
+                increments: cognitive,
+            });
+        }
+
+        // Each conditional jump of the graph is a branch of the control flow,
+        // the exception edges towards the `catch` clauses aren't jumps
+        let mut cyclomatic: Vec<_> = cfg
+            .blocks
+            .iter()
+            .flat_map(|block| &block.instructions)
+            .filter(|instruction| {
+                matches!(
+                    instruction.kind,
+                    InstructionKind::Jump {
+                        conditional: true,
+                        ..
+                    }
+                )
+            })
+            .filter_map(|instruction| {
+                let range = instruction.node.as_ref()?.text_trimmed_range();
+                Some(Increment { range, nesting: 0 })
+            })
+            .chain(
+                function_nodes::<JsCatchClause>(&function)
+                    .into_iter()
+                    .filter_map(|clause| {
+                        let range = clause.catch_token().ok()?.text_trimmed_range();
+                        Some(Increment { range, nesting: 0 })
+                    }),
+            )
+            .collect();
+
+        cyclomatic.sort_by_key(|increment| increment.range.start());
+
+        let complexity = 1 + cyclomatic.len() as u32;
+        if complexity > u32::from(options.max_cyclomatic_complexity) {
+            signals.push(ExcessiveComplexity {
+                kind: ComplexityKind::Cyclomatic,
+                range,
+                complexity,
+                max: options.max_cyclomatic_complexity,
+                increments: cyclomatic,
+            });
+        }
+
+        signals
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let complexity = state.complexity;
+        let max = state.max;
+
+        let mut diagnostic = match state.kind {
+            ComplexityKind::Cognitive => RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "This function has a cognitive complexity of "{complexity}", which exceeds the maximum of "{max}"."
+                },
+            ),
+            ComplexityKind::Cyclomatic => RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "This function has a cyclomatic complexity of "{complexity}", which exceeds the maximum of "{max}"."
+                },
+            ),
+        };
+
+        for increment in &state.increments {
+            let value = increment.value();
+            let nesting = increment.nesting;
+            diagnostic = if nesting > 0 {
+                diagnostic.detail(
+                    increment.range,
+                    markup! {"+"{value}" (incl. "{nesting}" for nesting)"},
+                )
+            } else {
+                diagnostic.detail(increment.range, markup! {"+"{value}})
+            };
+        }
+
+        let note = match state.kind {
+            ComplexityKind::Cognitive => markup! {
+                "Extract parts of the function into smaller functions, or reduce the nesting of its control structures using early returns."
+            },
+            ComplexityKind::Cyclomatic => markup! {
+                "Extract parts of the function into smaller functions to reduce the number of paths through each function."
+            },
+        };
+
+        Some(diagnostic.note(note))
+    }
+}
+
+/// Collect the increments of the cognitive complexity of the constructs of
+/// `node`, nested in `nesting` control structures of the function
+fn cognitive_increments(node: &JsSyntaxNode, nesting: u32, increments: &mut Vec<Increment>) {
+    if is_nested_function(node.kind()) {
+        return;
+    }
+
+    // The constructs breaking the linear flow of the function, along with the
+    // children nested in them
+    let (keyword, nested) = match node.kind() {
+        JsSyntaxKind::JS_IF_STATEMENT => {
+            let node = JsIfStatement::unwrap_cast(node.clone());
+            // The `else if` chains are flat, they aren't nested in the first `if`
+            let is_else_if = node.syntax().parent().map_or(false, |parent| {
+                parent.kind() == JsSyntaxKind::JS_ELSE_CLAUSE
+            });
+
+            push_increment(
+                node.if_token(),
+                if is_else_if { 0 } else { nesting },
+                increments,
+            );
+
+            for child in node.syntax().children() {
+                if child.kind() == JsSyntaxKind::JS_ELSE_CLAUSE {
+                    else_increments(&child, nesting, increments);
+                } else if node
+                    .consequent()
+                    .map_or(false, |body| body.syntax() == &child)
+                {
+                    cognitive_increments(&child, nesting + 1, increments);
+                } else {
+                    cognitive_increments(&child, nesting, increments);
+                }
+            }
+
+            return;
+        }
+        JsSyntaxKind::JS_CONDITIONAL_EXPRESSION => {
+            let node = JsConditionalExpression::unwrap_cast(node.clone());
+            let nested = vec![
+                node.consequent().ok().map(AstNode::into_syntax),
+                node.alternate().ok().map(AstNode::into_syntax),
+            ];
+            (node.question_mark_token(), nested)
+        }
+        JsSyntaxKind::JS_SWITCH_STATEMENT => {
+            let node = JsSwitchStatement::unwrap_cast(node.clone());
+            let cases = node
+                .syntax()
+                .children()
+                .find(|child| child.kind() == JsSyntaxKind::JS_SWITCH_CASE_LIST);
+            (node.switch_token(), vec![cases])
+        }
+        JsSyntaxKind::JS_FOR_STATEMENT => {
+            let node = JsForStatement::unwrap_cast(node.clone());
+            (
+                node.for_token(),
+                vec![node.body().ok().map(AstNode::into_syntax)],
+            )
+        }
+        JsSyntaxKind::JS_FOR_IN_STATEMENT => {
+            let node = JsForInStatement::unwrap_cast(node.clone());
+            (
+                node.for_token(),
+                vec![node.body().ok().map(AstNode::into_syntax)],
+            )
+        }
+        JsSyntaxKind::JS_FOR_OF_STATEMENT => {
+            let node = JsForOfStatement::unwrap_cast(node.clone());
+            (
+                node.for_token(),
+                vec![node.body().ok().map(AstNode::into_syntax)],
+            )
+        }
+        JsSyntaxKind::JS_WHILE_STATEMENT => {
+            let node = JsWhileStatement::unwrap_cast(node.clone());
+            (
+                node.while_token(),
+                vec![node.body().ok().map(AstNode::into_syntax)],
+            )
+        }
+        JsSyntaxKind::JS_DO_WHILE_STATEMENT => {
+            let node = JsDoWhileStatement::unwrap_cast(node.clone());
+            (
+                node.do_token(),
+                vec![node.body().ok().map(AstNode::into_syntax)],
+            )
+        }
+        JsSyntaxKind::JS_CATCH_CLAUSE => {
+            let node = JsCatchClause::unwrap_cast(node.clone());
+            (
+                node.catch_token(),
+                vec![node.body().ok().map(AstNode::into_syntax)],
+            )
+        }
+        JsSyntaxKind::JS_BREAK_STATEMENT | JsSyntaxKind::JS_CONTINUE_STATEMENT => {
+            // Jumping to a label is a flat increment
+            let label = JsBreakStatement::cast_ref(node)
+                .and_then(|node| node.label_token())
+                .or_else(|| JsContinueStatement::cast_ref(node)?.label_token());
+
+            if let Some(label) = label {
+                push_increment(Ok(label), 0, increments);
+            }
+
+            return;
+        }
+        JsSyntaxKind::JS_LOGICAL_EXPRESSION => {
+            let node = JsLogicalExpression::unwrap_cast(node.clone());
+            // A sequence of the same operator is a single increment
+            let is_sequence = node
+                .syntax()
+                .parent()
+                .and_then(JsLogicalExpression::cast)
+                .map_or(false, |parent| {
+                    parent.operator().ok() == node.operator().ok()
+                });
+
+            if !is_sequence {
+                push_increment(node.operator_token(), 0, increments);
+            }
+
+            for child in node.syntax().children() {
+                cognitive_increments(&child, nesting, increments);
+            }
+
+            return;
+        }
+        _ => {
+            for child in node.children() {
+                cognitive_increments(&child, nesting, increments);
+            }
+
+            return;
+        }
+    };
+
+    push_increment(keyword, nesting, increments);
+
+    for child in node.children() {
+        let child_nesting = if nested.contains(&Some(child.clone())) {
+            nesting + 1
+        } else {
+            nesting
+        };
+
+        cognitive_increments(&child, child_nesting, increments);
+    }
+}
+
+/// Collect the increments of an `else` clause, the `else if` clauses being
+/// counted by their `if` statement
+fn else_increments(clause: &JsSyntaxNode, nesting: u32, increments: &mut Vec<Increment>) {
+    for child in clause.children() {
+        if child.kind() == JsSyntaxKind::JS_IF_STATEMENT {
+            cognitive_increments(&child, nesting, increments);
+        } else {
+            if let Some(else_token) = clause.first_token() {
+                push_increment(Ok(else_token), 0, increments);
+            }
+
+            cognitive_increments(&child, nesting + 1, increments);
+        }
+    }
+}
+
+fn push_increment(
+    keyword: SyntaxResult<JsSyntaxToken>,
+    nesting: u32,
+    increments: &mut Vec<Increment>,
+) {
+    if let Ok(keyword) = keyword {
+        increments.push(Increment {
+            range: keyword.text_trimmed_range(),
+            nesting,
+        });
+    }
+}
+
+/// Returns the range of `function` up to the start of its body, or the range
+/// of the whole function if it doesn't have a body
+fn function_head(function: &JsSyntaxNode) -> TextRange {
+    let range = function.text_trimmed_range();
+
+    let body = function
+        .children()
+        .find(|child| child.kind() == JsSyntaxKind::JS_FUNCTION_BODY)
+        // The arrow functions may have an expression as body
+        .or_else(|| function.last_child());
+
+    let head_end = body
+        .and_then(|body| body.first_token())
+        .and_then(|token| token.prev_token())
+        .map(|token| token.text_trimmed_range().end());
+
+    match head_end {
+        Some(end) if end > range.start() => TextRange::new(range.start(), end),
+        _ => range,
+    }
+}
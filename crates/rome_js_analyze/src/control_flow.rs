@@ -1,9 +1,59 @@
-use rome_js_syntax::JsLanguage;
+use rome_analyze::{QueryKey, QueryMatch, Queryable, ServiceBag};
+use rome_js_syntax::{JsLanguage, TextRange};
+
+use crate::semantic_services::SemanticServices;
 
 pub(crate) type ControlFlowGraph = rome_control_flow::ControlFlowGraph<JsLanguage>;
 pub(crate) type FunctionBuilder = rome_control_flow::builder::FunctionBuilder<JsLanguage>;
 
+mod dataflow;
 mod nodes;
 mod visitor;
 
+pub(crate) use self::dataflow::{
+    function_node, function_nodes, is_nested_function, InstructionRanges, LocalVariables, Location,
+};
 pub(crate) use self::visitor::make_visitor;
+
+/// Query type usable by lint rules combining the control flow graph of a
+/// function with the semantic model, matching on the graph along with the
+/// range of the function
+pub(crate) struct SemanticControlFlow;
+
+impl Queryable for SemanticControlFlow {
+    type Output = (ControlFlowGraph, TextRange);
+    type Language = JsLanguage;
+    type Services = SemanticServices;
+
+    const KEY: QueryKey<Self::Language> = QueryKey::ControlFlowGraph;
+
+    fn unwrap_match(_: &ServiceBag, query: &QueryMatch<Self::Language>) -> Self::Output {
+        unwrap_control_flow_match(query)
+    }
+}
+
+/// Query type usable by lint rules matching on the control flow graph of a
+/// function along with the range of the function, without requiring the
+/// semantic model
+pub(crate) struct FunctionControlFlow;
+
+impl Queryable for FunctionControlFlow {
+    type Output = (ControlFlowGraph, TextRange);
+    type Language = JsLanguage;
+    type Services = ();
+
+    const KEY: QueryKey<Self::Language> = QueryKey::ControlFlowGraph;
+
+    fn unwrap_match(_: &ServiceBag, query: &QueryMatch<Self::Language>) -> Self::Output {
+        unwrap_control_flow_match(query)
+    }
+}
+
+/// Return the graph and the range of the function matched by a
+/// [QueryKey::ControlFlowGraph] query
+fn unwrap_control_flow_match(query: &QueryMatch<JsLanguage>) -> (ControlFlowGraph, TextRange) {
+    match query {
+        QueryMatch::ControlFlowGraph(cfg, range) => (cfg.clone(), *range),
+        _ => panic!("tried to unwrap unsupported QueryMatch kind, expected ControlFlowGraph"),
+    }
+}
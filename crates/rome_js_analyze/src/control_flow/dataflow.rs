@@ -0,0 +1,768 @@
+//! Dataflow analyses of the local variables of a function
+//!
+//! The semantic model resolves the reads and writes of each variable, while
+//! the control flow graph orders the instructions evaluating them. The
+//! [LocalVariables] of a function attribute each access to a variable to the
+//! innermost instruction of the graph evaluating it, and run the reaching
+//! definitions and liveness analyses of [rome_control_flow::dataflow] using
+//! these accesses.
+//!
+//! Only the variables declared by the function (with `var`, `let` or `const`,
+//! or as parameters) and referenced exclusively by the function itself are
+//! analyzed: the variables captured by closures may be read or written
+//! whenever the closure is called, and the exported variables by any module.
+
+use std::{
+    cmp::Reverse,
+    collections::{BTreeSet, HashMap},
+};
+
+use rome_control_flow::{
+    builder::BlockId,
+    dataflow::{solve, visit_instructions, Analysis, Direction},
+    Instruction,
+};
+use rome_js_semantic::{CanBeImportedExported, SemanticModel};
+use rome_js_syntax::{
+    JsAnyExpression, JsAnyRoot, JsAssignmentExpression, JsAssignmentOperator, JsIdentifierBinding,
+    JsLanguage, JsSyntaxKind, JsSyntaxNode, JsVariableDeclaration, JsVariableDeclarator,
+    JsVariableDeclaratorList, TextRange, TextSize, WalkEvent,
+};
+use rome_rowan::AstNode;
+
+use super::{visitor::JsAnyControlFlowRoot, ControlFlowGraph};
+
+/// Return the function whose control flow graph spans `range`
+pub(crate) fn function_node(root: &JsAnyRoot, range: TextRange) -> Option<JsSyntaxNode> {
+    root.syntax()
+        .covering_element(range)
+        .ancestors()
+        .find(|node| {
+            node.text_trimmed_range() == range && JsAnyControlFlowRoot::can_cast(node.kind())
+        })
+}
+
+/// A local variable of a function
+pub(crate) struct Variable {
+    pub(crate) binding: JsIdentifierBinding,
+    /// Set to `true` for the variables declared with `let` without an initializer
+    is_uninitialized_let: bool,
+    /// Set to `true` if the value of the variable is read at least once
+    has_reads: bool,
+}
+
+/// How a [Definition] assigns a value to a variable
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum DefinitionKind {
+    /// The variable is declared without an initializer and holds `undefined`
+    Uninitialized,
+    /// The variable is a parameter of the function
+    Parameter,
+    /// The variable is assigned by the initializer of its declaration
+    Initializer,
+    /// The variable is assigned by an assignment or an update expression
+    Assignment,
+}
+
+/// A point of the function where a value is assigned to a variable
+pub(crate) struct Definition {
+    variable: usize,
+    pub(crate) kind: DefinitionKind,
+    /// Binding or assignment target of the definition
+    pub(crate) node: JsSyntaxNode,
+    /// Variable whose current value is copied by the definition, for the
+    /// definitions assigning another variable (`a = b` or `let a = b`)
+    copy_of: Option<usize>,
+}
+
+/// An access to a variable evaluated by an instruction
+#[derive(Clone, Copy, Debug)]
+enum Access {
+    Read { variable: usize, reference: usize },
+    Write { definition: usize },
+}
+
+/// Position of an instruction in the graph, as the index of its block and
+/// its index in the block
+pub(crate) type Location = (u32, usize);
+
+/// The local variables of a function, along with the accesses to these
+/// variables evaluated by each instruction of its control flow graph
+pub(crate) struct LocalVariables<'cfg> {
+    cfg: &'cfg ControlFlowGraph,
+    variables: Vec<Variable>,
+    definitions: Vec<Definition>,
+    /// Definitions of each variable
+    variable_definitions: Vec<Vec<usize>>,
+    /// Nodes of the references reading the variables
+    references: Vec<JsSyntaxNode>,
+    /// Accesses evaluated by each instruction, in evaluation order
+    accesses: HashMap<Location, Vec<Access>>,
+}
+
+impl<'cfg> LocalVariables<'cfg> {
+    /// Collect the local variables of `function` and their accesses in `cfg`,
+    /// the control flow graph of the function
+    pub(crate) fn new(
+        cfg: &'cfg ControlFlowGraph,
+        function: &JsSyntaxNode,
+        model: &SemanticModel,
+    ) -> Self {
+        let instructions = InstructionRanges::new(cfg);
+
+        let collected: Vec<_> = function_nodes::<JsIdentifierBinding>(function)
+            .into_iter()
+            .filter(|binding| !binding.is_exported(model))
+            .filter_map(|binding| collect_variable(binding, function, model, &instructions))
+            .collect();
+
+        let indices: HashMap<_, _> = collected
+            .iter()
+            .enumerate()
+            .map(|(index, variable)| (variable.binding.syntax().text_trimmed_range(), index))
+            .collect();
+
+        let mut result = Self {
+            cfg,
+            variables: Vec::new(),
+            definitions: Vec::new(),
+            variable_definitions: Vec::new(),
+            references: Vec::new(),
+            accesses: HashMap::new(),
+        };
+
+        let mut accesses: HashMap<Location, Vec<(TextSize, Access)>> = HashMap::new();
+
+        for (variable, collected) in collected.into_iter().enumerate() {
+            let mut definitions = Vec::new();
+            for definition in collected.definitions {
+                let id = result.definitions.len();
+                definitions.push(id);
+
+                if let Some((location, offset)) = definition.location {
+                    accesses
+                        .entry(location)
+                        .or_default()
+                        .push((offset, Access::Write { definition: id }));
+                }
+
+                result.definitions.push(Definition {
+                    variable,
+                    kind: definition.kind,
+                    node: definition.node,
+                    copy_of: definition
+                        .copy_of
+                        .and_then(|range| indices.get(&range).copied()),
+                });
+            }
+
+            for (node, location, offset) in collected.reads {
+                let reference = result.references.len();
+                result.references.push(node);
+
+                accesses.entry(location).or_default().push((
+                    offset,
+                    Access::Read {
+                        variable,
+                        reference,
+                    },
+                ));
+            }
+
+            result.variable_definitions.push(definitions);
+            result.variables.push(Variable {
+                binding: collected.binding,
+                is_uninitialized_let: collected.is_uninitialized_let,
+                has_reads: collected.has_reads,
+            });
+        }
+
+        result.accesses = accesses
+            .into_iter()
+            .map(|(location, mut accesses)| {
+                accesses.sort_by_key(|(offset, _)| *offset);
+                let accesses = accesses.into_iter().map(|(_, access)| access).collect();
+                (location, accesses)
+            })
+            .collect();
+
+        result
+    }
+
+    /// Return the variable assigned by `definition`
+    pub(crate) fn variable(&self, definition: &Definition) -> &Variable {
+        &self.variables[definition.variable]
+    }
+
+    /// Return the definitions assigning a value to their variable that is
+    /// never read afterwards, before the variable is assigned again or the
+    /// function returns
+    ///
+    /// The variables that are never read are ignored, since all their
+    /// definitions would be reported
+    pub(crate) fn useless_definitions(&self) -> Vec<&Definition> {
+        let reachable = solve(self.cfg, &Reachability);
+
+        let liveness = Liveness { variables: self };
+        let results = solve(self.cfg, &liveness);
+
+        let mut useless = Vec::new();
+        visit_instructions(self.cfg, &liveness, &results, |block, index, live| {
+            if !*reachable.entry_state(block) {
+                return;
+            }
+
+            let mut live = live.clone();
+            for access in self.accesses_of(block, index).iter().rev() {
+                match *access {
+                    Access::Read { variable, .. } => {
+                        live.insert(variable);
+                    }
+                    Access::Write { definition } => {
+                        let variable = self.definitions[definition].variable;
+                        if !live.remove(&variable) && self.variables[variable].has_reads {
+                            useless.push(&self.definitions[definition]);
+                        }
+                    }
+                }
+            }
+        });
+
+        useless.sort_by_key(|definition| definition.node.text_trimmed_range().start());
+        useless
+    }
+
+    /// Return the reads of the variables declared with `let` without an
+    /// initializer that may happen before a value is assigned to the variable,
+    /// along with the variable being read
+    pub(crate) fn unassigned_reads(&self) -> Vec<(&Variable, &JsSyntaxNode)> {
+        let reaching = ReachingDefinitions { variables: self };
+        let results = solve(self.cfg, &reaching);
+
+        let mut reads = Vec::new();
+        visit_instructions(self.cfg, &reaching, &results, |block, index, state| {
+            let mut state = state.clone();
+            for access in self.accesses_of(block, index) {
+                match *access {
+                    Access::Read {
+                        variable,
+                        reference,
+                    } => {
+                        let is_unassigned = self.variables[variable].is_uninitialized_let
+                            && self.variable_definitions[variable]
+                                .iter()
+                                .any(|definition| {
+                                    self.definitions[*definition].kind
+                                        == DefinitionKind::Uninitialized
+                                        && state.contains(definition)
+                                });
+
+                        if is_unassigned {
+                            reads.push((&self.variables[variable], &self.references[reference]));
+                        }
+                    }
+                    Access::Write { definition } => self.apply_write(&mut state, definition),
+                }
+            }
+        });
+
+        reads.sort_by_key(|(_, node)| node.text_trimmed_range().start());
+        reads
+    }
+
+    /// Return the definitions assigning a variable the value it already holds:
+    /// either the value of the variable itself (`a = a`), or the value of a
+    /// variable that was copied from it while it held the same value
+    /// (`b = a; a = b;`)
+    pub(crate) fn self_assignments(&self) -> Vec<&Definition> {
+        let reaching = ReachingDefinitions { variables: self };
+        let results = solve(self.cfg, &reaching);
+
+        // Definitions of the copied and the assigned variables reaching each copy
+        let mut copies = HashMap::new();
+        visit_instructions(self.cfg, &reaching, &results, |block, index, state| {
+            let mut state = state.clone();
+            for access in self.accesses_of(block, index) {
+                if let Access::Write { definition } = *access {
+                    let target = &self.definitions[definition];
+                    if let Some(source) = target.copy_of {
+                        copies.insert(
+                            definition,
+                            (
+                                self.reaching_definitions(&state, source),
+                                self.reaching_definitions(&state, target.variable),
+                            ),
+                        );
+                    }
+
+                    self.apply_write(&mut state, definition);
+                }
+            }
+        });
+
+        let mut assignments: Vec<_> = copies
+            .iter()
+            .filter(|(definition, (source_definitions, target_definitions))| {
+                let definition = &self.definitions[**definition];
+                if definition.copy_of == Some(definition.variable) {
+                    return true;
+                }
+
+                // Each definition of the copied variable copied the assigned
+                // variable, which wasn't assigned since
+                !source_definitions.is_empty()
+                    && source_definitions.iter().all(|source_definition| {
+                        self.definitions[*source_definition].copy_of == Some(definition.variable)
+                            && copies
+                                .get(source_definition)
+                                .map_or(false, |(copied, _)| copied == target_definitions)
+                    })
+            })
+            .map(|(definition, _)| &self.definitions[*definition])
+            .collect();
+
+        assignments.sort_by_key(|definition| definition.node.text_trimmed_range().start());
+        assignments
+    }
+
+    fn accesses_of(&self, block: BlockId, index: usize) -> &[Access] {
+        self.accesses
+            .get(&(block.index(), index))
+            .map_or(&[], Vec::as_slice)
+    }
+
+    /// Return the definitions of `variable` in the set of definitions `state`
+    fn reaching_definitions(&self, state: &BTreeSet<usize>, variable: usize) -> BTreeSet<usize> {
+        self.variable_definitions[variable]
+            .iter()
+            .filter(|definition| state.contains(definition))
+            .copied()
+            .collect()
+    }
+
+    /// Replace the definitions of its variable in `state` with `definition`
+    fn apply_write(&self, state: &mut BTreeSet<usize>, definition: usize) {
+        let variable = self.definitions[definition].variable;
+        for definition in &self.variable_definitions[variable] {
+            state.remove(definition);
+        }
+
+        state.insert(definition);
+    }
+}
+
+/// Computes the definitions whose value may be held by their variable before
+/// each instruction
+struct ReachingDefinitions<'a, 'cfg> {
+    variables: &'a LocalVariables<'cfg>,
+}
+
+impl Analysis<JsLanguage> for ReachingDefinitions<'_, '_> {
+    type Domain = BTreeSet<usize>;
+
+    const DIRECTION: Direction = Direction::Forward;
+
+    fn bottom(&self) -> Self::Domain {
+        BTreeSet::new()
+    }
+
+    fn boundary(&self) -> Self::Domain {
+        // The parameters and the variables without initializer are defined
+        // when entering the function
+        let definitions = &self.variables.definitions;
+        (0..definitions.len())
+            .filter(|definition| {
+                matches!(
+                    definitions[*definition].kind,
+                    DefinitionKind::Uninitialized | DefinitionKind::Parameter
+                )
+            })
+            .collect()
+    }
+
+    fn transfer(
+        &self,
+        state: &mut Self::Domain,
+        block: BlockId,
+        index: usize,
+        _: &Instruction<JsLanguage>,
+    ) {
+        for access in self.variables.accesses_of(block, index) {
+            if let Access::Write { definition } = *access {
+                self.variables.apply_write(state, definition);
+            }
+        }
+    }
+}
+
+/// Computes the variables whose current value may be read after each instruction
+struct Liveness<'a, 'cfg> {
+    variables: &'a LocalVariables<'cfg>,
+}
+
+impl Analysis<JsLanguage> for Liveness<'_, '_> {
+    type Domain = BTreeSet<usize>;
+
+    const DIRECTION: Direction = Direction::Backward;
+
+    fn bottom(&self) -> Self::Domain {
+        BTreeSet::new()
+    }
+
+    fn transfer(
+        &self,
+        state: &mut Self::Domain,
+        block: BlockId,
+        index: usize,
+        _: &Instruction<JsLanguage>,
+    ) {
+        for access in self.variables.accesses_of(block, index).iter().rev() {
+            match *access {
+                Access::Read { variable, .. } => {
+                    state.insert(variable);
+                }
+                Access::Write { definition } => {
+                    state.remove(&self.variables.definitions[definition].variable);
+                }
+            }
+        }
+    }
+}
+
+/// Computes whether each block can be reached from the entry of the function
+struct Reachability;
+
+impl Analysis<JsLanguage> for Reachability {
+    type Domain = bool;
+
+    const DIRECTION: Direction = Direction::Forward;
+
+    fn bottom(&self) -> Self::Domain {
+        false
+    }
+
+    fn boundary(&self) -> Self::Domain {
+        true
+    }
+
+    fn transfer(&self, _: &mut Self::Domain, _: BlockId, _: usize, _: &Instruction<JsLanguage>) {}
+}
+
+/// The ranges of the nodes of the instructions of a graph, sorted by start
+/// offset with the enclosing nodes first
+pub(crate) struct InstructionRanges {
+    ranges: Vec<(TextRange, Location)>,
+}
+
+impl InstructionRanges {
+    pub(crate) fn new(cfg: &ControlFlowGraph) -> Self {
+        let mut ranges = Vec::new();
+        for (block, basic_block) in cfg.blocks.iter().enumerate() {
+            for (index, instruction) in basic_block.instructions.iter().enumerate() {
+                if let Some(node) = &instruction.node {
+                    ranges.push((node.text_trimmed_range(), (block as u32, index)));
+                }
+            }
+        }
+
+        ranges.sort_by_key(|(range, _)| (range.start(), Reverse(range.len())));
+        Self { ranges }
+    }
+
+    /// Return the location of the innermost instruction whose node contains `range`
+    pub(crate) fn innermost(&self, range: TextRange) -> Option<Location> {
+        // The nodes of the instructions are either nested or disjoint: the
+        // nodes containing the range are the ones starting before it and
+        // ending after it, the innermost one being the last to start
+        let end = self
+            .ranges
+            .partition_point(|(node, _)| node.start() <= range.start());
+
+        self.ranges[..end]
+            .iter()
+            .rev()
+            .find(|(node, _)| node.end() >= range.end())
+            .map(|(_, location)| *location)
+    }
+}
+
+/// A variable collected by [collect_variable], before the variables are indexed
+struct CollectedVariable {
+    binding: JsIdentifierBinding,
+    is_uninitialized_let: bool,
+    has_reads: bool,
+    definitions: Vec<CollectedDefinition>,
+    reads: Vec<(JsSyntaxNode, Location, TextSize)>,
+}
+
+struct CollectedDefinition {
+    kind: DefinitionKind,
+    node: JsSyntaxNode,
+    /// Location of the instruction evaluating the definition and offset of
+    /// the definition in the instruction
+    location: Option<(Location, TextSize)>,
+    /// Range of the binding of the copied variable
+    copy_of: Option<TextRange>,
+}
+
+/// Collect the definitions and reads of the variable declared by `binding`,
+/// returning `None` if the variable isn't a local variable of `function` or
+/// if one of its accesses isn't evaluated by an instruction of the graph
+fn collect_variable(
+    binding: JsIdentifierBinding,
+    function: &JsSyntaxNode,
+    model: &SemanticModel,
+    instructions: &InstructionRanges,
+) -> Option<CollectedVariable> {
+    let mut definitions = Vec::new();
+    let mut reads = Vec::new();
+    let mut is_uninitialized_let = false;
+
+    let declaration = binding
+        .syntax()
+        .ancestors()
+        .skip(1)
+        .find(|node| !is_binding_pattern(node.kind()))?;
+
+    match declaration.kind() {
+        JsSyntaxKind::JS_FORMAL_PARAMETER
+        | JsSyntaxKind::JS_REST_PARAMETER
+        | JsSyntaxKind::JS_ARROW_FUNCTION_EXPRESSION => {
+            definitions.push(CollectedDefinition {
+                kind: DefinitionKind::Parameter,
+                node: binding.syntax().clone(),
+                location: None,
+                copy_of: None,
+            });
+        }
+        JsSyntaxKind::JS_VARIABLE_DECLARATOR => {
+            let declarator = JsVariableDeclarator::unwrap_cast(declaration);
+            let declaration = declarator
+                .parent::<JsVariableDeclaratorList>()?
+                .parent::<JsVariableDeclaration>()?;
+
+            // The variables declared in the head of `for...in` and `for...of`
+            // loops are assigned by the loop
+            if !matches!(
+                declaration.syntax().parent()?.kind(),
+                JsSyntaxKind::JS_VARIABLE_STATEMENT | JsSyntaxKind::JS_FOR_STATEMENT
+            ) {
+                return None;
+            }
+
+            match declarator.initializer() {
+                Some(initializer) => {
+                    let expression = initializer.expression().ok()?;
+                    let location =
+                        instructions.innermost(expression.syntax().text_trimmed_range())?;
+                    let is_target = declarator.id().ok()?.syntax() == binding.syntax();
+
+                    definitions.push(CollectedDefinition {
+                        kind: DefinitionKind::Initializer,
+                        node: binding.syntax().clone(),
+                        location: Some((location, declarator.syntax().text_trimmed_range().end())),
+                        copy_of: is_target
+                            .then(|| copied_binding(model, expression))
+                            .flatten(),
+                    });
+                }
+                None => {
+                    is_uninitialized_let = declaration.is_let();
+                    definitions.push(CollectedDefinition {
+                        kind: DefinitionKind::Uninitialized,
+                        node: binding.syntax().clone(),
+                        location: None,
+                        copy_of: None,
+                    });
+                }
+            }
+        }
+        _ => return None,
+    }
+
+    let mut has_reads = false;
+    for reference in model.all_references(&binding) {
+        let node = reference.node().clone();
+        if !is_evaluated_by(&node, function) {
+            return None;
+        }
+
+        let range = node.text_trimmed_range();
+        let location = instructions.innermost(range)?;
+
+        if reference.is_read() {
+            has_reads = true;
+            reads.push((node, location, range.start()));
+            continue;
+        }
+
+        let target = node
+            .ancestors()
+            .skip(1)
+            .find(|node| !is_assignment_pattern(node.kind()))?;
+
+        let end = target.text_trimmed_range().end();
+        match target.kind() {
+            JsSyntaxKind::JS_ASSIGNMENT_EXPRESSION => {
+                let assignment = JsAssignmentExpression::unwrap_cast(target);
+                match assignment.operator().ok()? {
+                    JsAssignmentOperator::Assign => {
+                        let is_target = assignment.left().ok()?.syntax() == &node;
+                        let right = assignment.right().ok()?;
+
+                        definitions.push(CollectedDefinition {
+                            kind: DefinitionKind::Assignment,
+                            node,
+                            location: Some((location, end)),
+                            copy_of: is_target.then(|| copied_binding(model, right)).flatten(),
+                        });
+                    }
+                    JsAssignmentOperator::LogicalAndAssign
+                    | JsAssignmentOperator::LogicalOrAssign
+                    | JsAssignmentOperator::NullishCoalescingAssign => {
+                        // The variable is read by the condition of the
+                        // assignment, and only assigned in the branch
+                        // evaluating the right operand
+                        let right = assignment.right().ok()?;
+                        let right_location =
+                            instructions.innermost(right.syntax().text_trimmed_range())?;
+
+                        reads.push((node.clone(), location, range.start()));
+                        definitions.push(CollectedDefinition {
+                            kind: DefinitionKind::Assignment,
+                            node,
+                            location: Some((right_location, end)),
+                            copy_of: None,
+                        });
+                    }
+                    _ => {
+                        // Compound assignments read the current value of the variable
+                        reads.push((node.clone(), location, range.start()));
+                        definitions.push(CollectedDefinition {
+                            kind: DefinitionKind::Assignment,
+                            node,
+                            location: Some((location, end)),
+                            copy_of: None,
+                        });
+                    }
+                }
+            }
+            JsSyntaxKind::JS_PRE_UPDATE_EXPRESSION | JsSyntaxKind::JS_POST_UPDATE_EXPRESSION => {
+                reads.push((node.clone(), location, range.start()));
+                definitions.push(CollectedDefinition {
+                    kind: DefinitionKind::Assignment,
+                    node,
+                    location: Some((location, end)),
+                    copy_of: None,
+                });
+            }
+            // The other writes, like the ones in the head of `for...in` and
+            // `for...of` loops, aren't modeled
+            _ => return None,
+        }
+    }
+
+    Some(CollectedVariable {
+        binding,
+        is_uninitialized_let,
+        has_reads,
+        definitions,
+        reads,
+    })
+}
+
+/// Return the range of the binding of the variable whose value is `expression`
+fn copied_binding(model: &SemanticModel, expression: JsAnyExpression) -> Option<TextRange> {
+    let reference = expression
+        .omit_parentheses()
+        .as_js_identifier_expression()?
+        .name()
+        .ok()?;
+
+    let binding = model.declaration(&reference)?;
+    Some(binding.syntax().text_trimmed_range())
+}
+
+/// Return the nodes of type `T` evaluated by `function`, excluding the ones
+/// in its nested functions
+pub(crate) fn function_nodes<T>(function: &JsSyntaxNode) -> Vec<T>
+where
+    T: AstNode<Language = JsLanguage>,
+{
+    let mut nodes = Vec::new();
+
+    let mut iter = function.preorder();
+    while let Some(event) = iter.next() {
+        if let WalkEvent::Enter(node) = event {
+            if &node != function && is_nested_function(node.kind()) {
+                iter.skip_subtree();
+                continue;
+            }
+
+            if let Some(node) = T::cast(node) {
+                nodes.push(node);
+            }
+        }
+    }
+
+    nodes
+}
+
+/// Returns `true` if `node` is evaluated by `function` itself and not by one
+/// of its nested functions
+fn is_evaluated_by(node: &JsSyntaxNode, function: &JsSyntaxNode) -> bool {
+    for ancestor in node.ancestors().skip(1) {
+        if &ancestor == function {
+            return true;
+        }
+
+        if is_nested_function(ancestor.kind()) {
+            return false;
+        }
+    }
+
+    false
+}
+
+/// Returns `true` for the nodes evaluated separately from the function
+/// containing them: the nested functions and the class members evaluated on
+/// construction
+pub(crate) fn is_nested_function(kind: JsSyntaxKind) -> bool {
+    JsAnyControlFlowRoot::can_cast(kind)
+        || matches!(
+            kind,
+            JsSyntaxKind::JS_PROPERTY_CLASS_MEMBER
+                | JsSyntaxKind::JS_STATIC_INITIALIZATION_BLOCK_CLASS_MEMBER
+        )
+}
+
+fn is_binding_pattern(kind: JsSyntaxKind) -> bool {
+    matches!(
+        kind,
+        JsSyntaxKind::JS_OBJECT_BINDING_PATTERN
+            | JsSyntaxKind::JS_ARRAY_BINDING_PATTERN
+            | JsSyntaxKind::JS_ARRAY_BINDING_PATTERN_ELEMENT_LIST
+            | JsSyntaxKind::JS_BINDING_PATTERN_WITH_DEFAULT
+            | JsSyntaxKind::JS_ARRAY_BINDING_PATTERN_REST_ELEMENT
+            | JsSyntaxKind::JS_OBJECT_BINDING_PATTERN_PROPERTY_LIST
+            | JsSyntaxKind::JS_OBJECT_BINDING_PATTERN_REST
+            | JsSyntaxKind::JS_OBJECT_BINDING_PATTERN_PROPERTY
+            | JsSyntaxKind::JS_OBJECT_BINDING_PATTERN_SHORTHAND_PROPERTY
+    )
+}
+
+fn is_assignment_pattern(kind: JsSyntaxKind) -> bool {
+    matches!(
+        kind,
+        JsSyntaxKind::JS_ASSIGNMENT_WITH_DEFAULT
+            | JsSyntaxKind::JS_PARENTHESIZED_ASSIGNMENT
+            | JsSyntaxKind::JS_ARRAY_ASSIGNMENT_PATTERN
+            | JsSyntaxKind::JS_ARRAY_ASSIGNMENT_PATTERN_ELEMENT_LIST
+            | JsSyntaxKind::JS_ARRAY_ASSIGNMENT_PATTERN_REST_ELEMENT
+            | JsSyntaxKind::JS_OBJECT_ASSIGNMENT_PATTERN
+            | JsSyntaxKind::JS_OBJECT_ASSIGNMENT_PATTERN_PROPERTY_LIST
+            | JsSyntaxKind::JS_OBJECT_ASSIGNMENT_PATTERN_SHORTHAND_PROPERTY
+            | JsSyntaxKind::JS_OBJECT_ASSIGNMENT_PATTERN_PROPERTY
+            | JsSyntaxKind::JS_OBJECT_ASSIGNMENT_PATTERN_REST
+    )
+}
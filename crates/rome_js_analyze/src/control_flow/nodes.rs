@@ -2,6 +2,7 @@ mod block;
 mod break_stmt;
 mod continue_stmt;
 mod do_while;
+mod expression;
 mod for_in;
 mod for_of;
 mod for_stmt;
@@ -18,6 +19,7 @@ pub(super) use block::*;
 pub(super) use break_stmt::*;
 pub(super) use continue_stmt::*;
 pub(super) use do_while::*;
+pub(super) use expression::*;
 pub(super) use for_in::*;
 pub(super) use for_of::*;
 pub(super) use for_stmt::*;
@@ -3,6 +3,7 @@ use rome_js_syntax::{JsDoWhileStatement, JsLabeledStatement, JsSyntaxToken};
 use rome_rowan::{AstNode, SyntaxResult};
 
 use crate::control_flow::{
+    nodes::append_expression,
     visitor::{NodeVisitor, StatementStack},
     FunctionBuilder,
 };
@@ -65,9 +66,13 @@ impl NodeVisitor for DoWhileVisitor {
 
         // Fill the continue block
         builder.set_cursor(continue_block);
+
+        let test = node.test()?;
+        append_expression(builder, test.clone())?;
+
         builder
             .append_jump(true, body_block)
-            .with_node(node.test()?.into_syntax());
+            .with_node(test.into_syntax());
 
         builder.append_jump(false, break_block);
 
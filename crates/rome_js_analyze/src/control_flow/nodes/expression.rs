@@ -0,0 +1,332 @@
+//! Lowering of the expressions diverging the control flow
+//!
+//! Most expressions are evaluated linearly and are represented by the
+//! instruction of the statement containing them, but short-circuiting logical
+//! operators (`&&`, `||`, `??` and their assignment forms), conditional
+//! expressions, optional chains and default values of bindings only evaluate
+//! some of their operands. The functions in this module append the blocks and
+//! jumps modeling these branches to the graph before the instruction of the
+//! enclosing statement.
+//!
+//! The conditional jumps emitted for `&&`, `||` and `?:` are taken if their
+//! condition node evaluates to a truthy value, while the ones emitted for `??`
+//! and `?.` are taken if the node evaluates to a value other than `null` or
+//! `undefined`, and the ones emitted for default values are taken if the value
+//! of the binding is not `undefined`. The [JumpCondition] of these jumps is
+//! recorded in their instruction, telling them apart from the jumps of the
+//! statements.
+
+use rome_control_flow::{builder::BlockId, JumpCondition};
+use rome_js_syntax::{
+    JsAnyExpression, JsAssignmentExpression, JsAssignmentOperator, JsAssignmentWithDefault,
+    JsBindingPatternWithDefault, JsConditionalExpression, JsInitializerClause, JsLogicalExpression,
+    JsLogicalOperator, JsSyntaxKind, JsSyntaxNode,
+};
+use rome_rowan::{AstNode, SyntaxResult};
+
+use crate::control_flow::{visitor::JsAnyControlFlowRoot, FunctionBuilder};
+
+/// Append the branches of `expression` and of its subexpressions at the
+/// current position of the builder, leaving the cursor on the block where the
+/// control flow joins after the expression has been evaluated
+pub(in crate::control_flow) fn append_expression(
+    builder: &mut FunctionBuilder,
+    expression: JsAnyExpression,
+) -> SyntaxResult<()> {
+    match expression {
+        JsAnyExpression::JsLogicalExpression(expression) => append_logical(builder, expression),
+        JsAnyExpression::JsConditionalExpression(expression) => {
+            append_conditional(builder, expression)
+        }
+        JsAnyExpression::JsAssignmentExpression(expression) => {
+            append_assignment(builder, expression)
+        }
+        JsAnyExpression::JsCallExpression(_)
+        | JsAnyExpression::JsStaticMemberExpression(_)
+        | JsAnyExpression::JsComputedMemberExpression(_) => {
+            let mut chain_end = None;
+            append_chain(builder, &expression, &mut chain_end)?;
+
+            // If the chain contains an optional link, the rest of the chain is
+            // only evaluated if the object is not nullish
+            if let Some(chain_end) = chain_end {
+                builder
+                    .append_statement()
+                    .with_node(expression.into_syntax());
+                builder.append_jump(false, chain_end);
+                builder.set_cursor(chain_end);
+            }
+
+            Ok(())
+        }
+        // The body of functions and classes isn't evaluated with the
+        // expression, functions get their own control flow graph
+        JsAnyExpression::JsArrowFunctionExpression(_)
+        | JsAnyExpression::JsFunctionExpression(_)
+        | JsAnyExpression::JsClassExpression(_) => Ok(()),
+        expression => append_children(builder, expression.syntax()),
+    }
+}
+
+/// Append the branches of the default values of the bindings declared by the
+/// pattern `node`
+pub(in crate::control_flow) fn append_pattern_defaults(
+    builder: &mut FunctionBuilder,
+    node: &JsSyntaxNode,
+) -> SyntaxResult<()> {
+    let default = pattern_default(node)?;
+
+    if let Some(default) = &default {
+        // The default value is only evaluated if the value is `undefined`
+        let default_block = builder.append_block();
+        let next_block = builder.append_block();
+
+        builder
+            .append_expression_jump(JumpCondition::NotUndefined, next_block)
+            .with_node(node.clone());
+        builder.append_jump(false, default_block);
+
+        builder.set_cursor(default_block);
+        append_operand(builder, default.clone())?;
+        builder.append_jump(false, next_block);
+
+        builder.set_cursor(next_block);
+    }
+
+    for child in node.children() {
+        if JsInitializerClause::can_cast(child.kind())
+            || JsAnyControlFlowRoot::can_cast(child.kind())
+            || default
+                .as_ref()
+                .map_or(false, |default| default.syntax() == &child)
+        {
+            continue;
+        }
+
+        match JsAnyExpression::cast_ref(&child) {
+            // Computed property names and assignment targets
+            Some(expression) => append_expression(builder, expression)?,
+            None => append_pattern_defaults(builder, &child)?,
+        }
+    }
+
+    Ok(())
+}
+
+/// Append the branches of the default values of the parameters of the
+/// function `node`, evaluated when entering the function
+pub(in crate::control_flow) fn append_parameter_defaults(
+    builder: &mut FunctionBuilder,
+    node: &JsSyntaxNode,
+) -> SyntaxResult<()> {
+    for child in node.children() {
+        if matches!(
+            child.kind(),
+            JsSyntaxKind::JS_PARAMETERS
+                | JsSyntaxKind::JS_CONSTRUCTOR_PARAMETERS
+                | JsSyntaxKind::JS_FORMAL_PARAMETER
+        ) {
+            append_pattern_defaults(builder, &child)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn append_logical(
+    builder: &mut FunctionBuilder,
+    expression: JsLogicalExpression,
+) -> SyntaxResult<()> {
+    let left = expression.left()?;
+    append_expression(builder, left.clone())?;
+
+    let right_block = builder.append_block();
+    let next_block = builder.append_block();
+
+    match expression.operator()? {
+        JsLogicalOperator::LogicalAnd => {
+            builder
+                .append_expression_jump(JumpCondition::Truthy, right_block)
+                .with_node(left.into_syntax());
+            builder.append_jump(false, next_block);
+        }
+        JsLogicalOperator::LogicalOr => {
+            builder
+                .append_expression_jump(JumpCondition::Truthy, next_block)
+                .with_node(left.into_syntax());
+            builder.append_jump(false, right_block);
+        }
+        JsLogicalOperator::NullishCoalescing => {
+            builder
+                .append_expression_jump(JumpCondition::NotNullish, next_block)
+                .with_node(left.into_syntax());
+            builder.append_jump(false, right_block);
+        }
+    }
+
+    builder.set_cursor(right_block);
+    append_operand(builder, expression.right()?)?;
+    builder.append_jump(false, next_block);
+
+    builder.set_cursor(next_block);
+
+    Ok(())
+}
+
+fn append_conditional(
+    builder: &mut FunctionBuilder,
+    expression: JsConditionalExpression,
+) -> SyntaxResult<()> {
+    let test = expression.test()?;
+    append_expression(builder, test.clone())?;
+
+    let consequent_block = builder.append_block();
+    let alternate_block = builder.append_block();
+    let next_block = builder.append_block();
+
+    builder
+        .append_expression_jump(JumpCondition::Truthy, consequent_block)
+        .with_node(test.into_syntax());
+    builder.append_jump(false, alternate_block);
+
+    builder.set_cursor(consequent_block);
+    append_operand(builder, expression.consequent()?)?;
+    builder.append_jump(false, next_block);
+
+    builder.set_cursor(alternate_block);
+    append_operand(builder, expression.alternate()?)?;
+    builder.append_jump(false, next_block);
+
+    builder.set_cursor(next_block);
+
+    Ok(())
+}
+
+fn append_assignment(
+    builder: &mut FunctionBuilder,
+    expression: JsAssignmentExpression,
+) -> SyntaxResult<()> {
+    let left = expression.left()?;
+
+    let operator = expression.operator()?;
+    let (is_and, condition) = match operator {
+        JsAssignmentOperator::LogicalAndAssign => (true, JumpCondition::Truthy),
+        JsAssignmentOperator::LogicalOrAssign => (false, JumpCondition::Truthy),
+        JsAssignmentOperator::NullishCoalescingAssign => (false, JumpCondition::NotNullish),
+        _ => {
+            append_expression(builder, expression.right()?)?;
+            return append_pattern_defaults(builder, left.syntax());
+        }
+    };
+
+    // Logical assignments only evaluate and assign their right operand
+    // depending on the current value of the assignment target
+    append_children(builder, left.syntax())?;
+
+    let right_block = builder.append_block();
+    let next_block = builder.append_block();
+
+    if is_and {
+        builder
+            .append_expression_jump(condition, right_block)
+            .with_node(left.into_syntax());
+        builder.append_jump(false, next_block);
+    } else {
+        builder
+            .append_expression_jump(condition, next_block)
+            .with_node(left.into_syntax());
+        builder.append_jump(false, right_block);
+    }
+
+    builder.set_cursor(right_block);
+    append_operand(builder, expression.right()?)?;
+    builder.append_jump(false, next_block);
+
+    builder.set_cursor(next_block);
+
+    Ok(())
+}
+
+/// Append the branches of a link in a chain of member accesses and calls,
+/// the optional links jumping to `chain_end` if their object is nullish
+fn append_chain(
+    builder: &mut FunctionBuilder,
+    expression: &JsAnyExpression,
+    chain_end: &mut Option<BlockId>,
+) -> SyntaxResult<()> {
+    let (object, is_optional) = match expression {
+        JsAnyExpression::JsCallExpression(expression) => {
+            (expression.callee()?, expression.is_optional())
+        }
+        JsAnyExpression::JsStaticMemberExpression(expression) => {
+            (expression.object()?, expression.is_optional())
+        }
+        JsAnyExpression::JsComputedMemberExpression(expression) => {
+            (expression.object()?, expression.is_optional())
+        }
+        _ => return append_expression(builder, expression.clone()),
+    };
+
+    append_chain(builder, &object, chain_end)?;
+
+    if is_optional {
+        let end_block = *chain_end.get_or_insert_with(|| builder.append_block());
+        let rest_block = builder.append_block();
+
+        builder
+            .append_expression_jump(JumpCondition::NotNullish, rest_block)
+            .with_node(object.into_syntax());
+        builder.append_jump(false, end_block);
+
+        builder.set_cursor(rest_block);
+    }
+
+    match expression {
+        JsAnyExpression::JsCallExpression(expression) => {
+            append_children(builder, expression.arguments()?.syntax())
+        }
+        JsAnyExpression::JsComputedMemberExpression(expression) => {
+            append_expression(builder, expression.member()?)
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Append an operand only evaluated in one of the branches of an expression
+fn append_operand(builder: &mut FunctionBuilder, operand: JsAnyExpression) -> SyntaxResult<()> {
+    append_expression(builder, operand.clone())?;
+    builder.append_statement().with_node(operand.into_syntax());
+    Ok(())
+}
+
+/// Append the branches of the expressions nested in `node`
+fn append_children(builder: &mut FunctionBuilder, node: &JsSyntaxNode) -> SyntaxResult<()> {
+    for child in node.children() {
+        if JsAnyControlFlowRoot::can_cast(child.kind()) {
+            continue;
+        }
+
+        match JsAnyExpression::cast_ref(&child) {
+            Some(expression) => append_expression(builder, expression)?,
+            None => append_children(builder, &child)?,
+        }
+    }
+
+    Ok(())
+}
+
+/// Return the default value of the binding or assignment pattern `node`
+fn pattern_default(node: &JsSyntaxNode) -> SyntaxResult<Option<JsAnyExpression>> {
+    if let Some(pattern) = JsBindingPatternWithDefault::cast_ref(node) {
+        return pattern.default().map(Some);
+    }
+
+    if let Some(pattern) = JsAssignmentWithDefault::cast_ref(node) {
+        return pattern.default().map(Some);
+    }
+
+    node.children()
+        .find_map(JsInitializerClause::cast)
+        .map(|initializer| initializer.expression())
+        .transpose()
+}
@@ -3,6 +3,7 @@ use rome_js_syntax::{JsForInStatement, JsLabeledStatement, JsSyntaxToken};
 use rome_rowan::{AstNode, SyntaxResult};
 
 use crate::control_flow::{
+    nodes::append_expression,
     visitor::{NodeVisitor, StatementStack},
     FunctionBuilder,
 };
@@ -23,6 +24,9 @@ impl NodeVisitor for ForInVisitor {
         builder: &mut FunctionBuilder,
         _: StatementStack,
     ) -> SyntaxResult<Self> {
+        // The iterated expression is evaluated once before entering the loop
+        append_expression(builder, node.expression()?)?;
+
         let continue_block = builder.append_block();
         let loop_block = builder.append_block();
         let break_block = builder.append_block();
@@ -1,8 +1,9 @@
 use rome_control_flow::builder::BlockId;
-use rome_js_syntax::{JsForStatement, JsLabeledStatement, JsSyntaxToken};
+use rome_js_syntax::{JsAnyForInitializer, JsForStatement, JsLabeledStatement, JsSyntaxToken};
 use rome_rowan::{AstNode, SyntaxResult};
 
 use crate::control_flow::{
+    nodes::append_expression,
     visitor::{NodeVisitor, StatementStack},
     FunctionBuilder,
 };
@@ -27,6 +28,10 @@ impl NodeVisitor for ForVisitor {
     ) -> SyntaxResult<Self> {
         // Immediately evaluate the initializer statement
         if let Some(initializer) = node.initializer() {
+            if let JsAnyForInitializer::JsAnyExpression(expression) = &initializer {
+                append_expression(builder, expression.clone())?;
+            }
+
             builder
                 .append_statement()
                 .with_node(initializer.into_syntax());
@@ -44,6 +49,7 @@ impl NodeVisitor for ForVisitor {
         builder.set_cursor(continue_block);
 
         if let Some(update) = node.update() {
+            append_expression(builder, update.clone())?;
             builder.append_statement().with_node(update.into_syntax());
         }
 
@@ -87,6 +93,8 @@ impl NodeVisitor for ForVisitor {
         builder.set_cursor(cond_block);
 
         if let Some(test) = node.test() {
+            append_expression(builder, test.clone())?;
+
             builder
                 .append_jump(true, loop_block)
                 .with_node(test.syntax().clone());
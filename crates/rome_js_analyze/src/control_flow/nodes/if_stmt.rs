@@ -3,6 +3,7 @@ use rome_js_syntax::{JsElseClause, JsIfStatement};
 use rome_rowan::{AstNode, SyntaxResult};
 
 use crate::control_flow::{
+    nodes::append_expression,
     visitor::{NodeVisitor, StatementStack},
     FunctionBuilder,
 };
@@ -26,10 +27,13 @@ impl NodeVisitor for IfVisitor {
     type Node = JsIfStatement;
 
     fn enter(
-        _: Self::Node,
+        node: Self::Node,
         builder: &mut FunctionBuilder,
         _: StatementStack,
     ) -> SyntaxResult<Self> {
+        // Evaluate the branches of the test expression before the statement
+        append_expression(builder, node.test()?)?;
+
         let entry_block = builder.cursor();
 
         let consequent_start = builder.append_block();
@@ -2,6 +2,7 @@ use rome_js_syntax::JsReturnStatement;
 use rome_rowan::{AstNode, SyntaxResult};
 
 use crate::control_flow::{
+    nodes::append_expression,
     visitor::{NodeVisitor, StatementStack},
     FunctionBuilder,
 };
@@ -16,6 +17,10 @@ impl NodeVisitor for ReturnVisitor {
         builder: &mut FunctionBuilder,
         _: StatementStack,
     ) -> SyntaxResult<Self> {
+        if let Some(argument) = node.argument() {
+            append_expression(builder, argument)?;
+        }
+
         builder.append_return().with_node(node.into_syntax());
 
         Ok(Self)
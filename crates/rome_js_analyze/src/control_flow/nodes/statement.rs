@@ -2,6 +2,7 @@ use rome_js_syntax::{JsDebuggerStatement, JsEmptyStatement, JsExpressionStatemen
 use rome_rowan::{declare_node_union, AstNode, SyntaxResult};
 
 use crate::control_flow::{
+    nodes::append_expression,
     visitor::{NodeVisitor, StatementStack},
     FunctionBuilder,
 };
@@ -20,6 +21,11 @@ impl NodeVisitor for StatementVisitor {
         builder: &mut FunctionBuilder,
         _: StatementStack,
     ) -> SyntaxResult<Self> {
+        // Expressions are evaluated before the statement itself
+        if let JsSimpleStatement::JsExpressionStatement(statement) = &node {
+            append_expression(builder, statement.expression()?)?;
+        }
+
         builder.append_statement().with_node(node.into_syntax());
 
         Ok(Self)
@@ -3,6 +3,7 @@ use rome_js_syntax::{JsAnySwitchClause, JsLabeledStatement, JsSwitchStatement, J
 use rome_rowan::{AstNode, SyntaxResult};
 
 use crate::control_flow::{
+    nodes::append_expression,
     visitor::{NodeVisitor, StatementStack},
     FunctionBuilder,
 };
@@ -27,7 +28,11 @@ impl NodeVisitor for SwitchVisitor {
         _: StatementStack,
     ) -> SyntaxResult<Self> {
         // Execute the discriminant expression as a side-effect
-        builder.append_statement().with_node(node.discriminant()?);
+        let discriminant = node.discriminant()?;
+        append_expression(builder, discriminant.clone())?;
+        builder
+            .append_statement()
+            .with_node(discriminant.into_syntax());
 
         let entry_block = builder.cursor();
         let break_block = builder.append_block();
@@ -2,6 +2,7 @@ use rome_js_syntax::JsThrowStatement;
 use rome_rowan::{AstNode, SyntaxResult};
 
 use crate::control_flow::{
+    nodes::append_expression,
     visitor::{NodeVisitor, StatementStack},
     FunctionBuilder,
 };
@@ -16,6 +17,7 @@ impl NodeVisitor for ThrowVisitor {
         builder: &mut FunctionBuilder,
         _: StatementStack,
     ) -> SyntaxResult<Self> {
+        append_expression(builder, node.argument()?)?;
         builder.append_return().with_node(node.into_syntax());
 
         Ok(Self)
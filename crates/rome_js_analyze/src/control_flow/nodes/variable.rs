@@ -2,6 +2,7 @@ use rome_js_syntax::JsVariableStatement;
 use rome_rowan::{AstNode, SyntaxResult};
 
 use crate::control_flow::{
+    nodes::{append_expression, append_pattern_defaults},
     visitor::{NodeVisitor, StatementStack},
     FunctionBuilder,
 };
@@ -18,10 +19,14 @@ impl NodeVisitor for VariableVisitor {
     ) -> SyntaxResult<Self> {
         let declaration = node.declaration()?;
         for declarator in declaration.declarators() {
-            if let Some(initializer) = declarator?.initializer() {
+            let declarator = declarator?;
+            if let Some(initializer) = declarator.initializer() {
                 let expr = initializer.expression()?;
+                append_expression(builder, expr.clone())?;
                 builder.append_statement().with_node(expr.into_syntax());
             }
+
+            append_pattern_defaults(builder, declarator.id()?.syntax())?;
         }
 
         Ok(Self)
@@ -156,7 +156,7 @@ pub(super) struct FunctionVisitor {
 }
 
 declare_node_union! {
-    JsAnyControlFlowRoot = JsModule
+    pub(in crate::control_flow) JsAnyControlFlowRoot = JsModule
         | JsScript
         | JsAnyFunction
         | JsGetterObjectMember
@@ -172,12 +172,21 @@ impl rome_analyze::NodeVisitor<ControlFlowVisitor> for FunctionVisitor {
     type Node = JsAnyControlFlowRoot;
 
     fn enter(
-        _: Self::Node,
+        node: Self::Node,
         _: &mut VisitorContext<JsLanguage>,
         _: &mut ControlFlowVisitor,
     ) -> Self {
+        let mut builder = FunctionBuilder::default();
+
+        // The default values of the parameters are evaluated when entering
+        // the function. If one of them has a syntax error the graph is built
+        // without their branches, rather than not being built at all
+        if append_parameter_defaults(&mut builder, node.syntax()).is_err() {
+            builder = FunctionBuilder::default();
+        }
+
         Self {
-            builder: Some(FunctionBuilder::default()),
+            builder: Some(builder),
         }
     }
 
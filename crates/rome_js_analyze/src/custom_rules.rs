@@ -0,0 +1,342 @@
+//! Lint rules defined in the configuration as code patterns
+//!
+//! Each [CustomRule] is compiled into a structural [Pattern] by
+//! [compile_custom_rule] when the configuration is loaded, and matched by the
+//! [CustomRulesVisitor] against all the nodes of the syntax tree. The signals of
+//! the custom rules are emitted under the `custom` group, so they can be
+//! suppressed with `// rome-ignore lint(custom/ruleName)`.
+
+mod pattern;
+
+use crate::custom_rules::pattern::{parse_snippet, Bindings, Pattern};
+use regex::Regex;
+use rome_analyze::{
+    intern_rule_name, ActionCategory, AnalysisFilter, AnalyzerAction, AnalyzerDiagnostic,
+    AnalyzerSignal, CustomRule, MetadataRegistry, RuleDiagnostic, RuleKey, Visitor, VisitorContext,
+};
+use rome_console::markup;
+use rome_diagnostics::{
+    file::FileId,
+    v2::{category, Severity},
+    Applicability,
+};
+use rome_js_syntax::{JsLanguage, JsSyntaxKind, JsSyntaxNode};
+use rome_rowan::{BatchMutation, SyntaxElement, TextRange, WalkEvent};
+use std::sync::Arc;
+
+/// Group of the custom rules in the suppression comments
+const GROUP: &str = "custom";
+
+/// Compile a lint rule defined in the configuration, returning a description
+/// of the error if it isn't valid. The rule is disabled if `severity` is `None`
+pub fn compile_custom_rule<I>(
+    name: &str,
+    pattern: &str,
+    message: &str,
+    constraints: I,
+    rewrite: Option<String>,
+    severity: Option<Severity>,
+) -> Result<CustomRule, String>
+where
+    I: IntoIterator<Item = (String, String)>,
+{
+    if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return Err(format!(
+            "the name of the rule `{name}` must only contain letters and digits"
+        ));
+    }
+
+    let pattern = Pattern::parse(pattern)?;
+
+    let mut compiled_constraints = Vec::new();
+    for (name, regex) in constraints {
+        if !name.starts_with('$') {
+            return Err(format!(
+                "the constraint `{name}` doesn't refer to a metavariable, it must start with `$`"
+            ));
+        }
+
+        let regex = Regex::new(&regex)
+            .map_err(|error| format!("the constraint of `{name}` is invalid: {error}"))?;
+        compiled_constraints.push((name, regex));
+    }
+
+    if let Some(rewrite) = &rewrite {
+        parse_snippet(rewrite)?;
+    }
+
+    Ok(CustomRule {
+        name: intern_rule_name(name),
+        pattern: pattern.as_send(),
+        message: message.into(),
+        constraints: compiled_constraints,
+        rewrite,
+        severity,
+    })
+}
+
+/// A [CustomRule] enabled in the current analysis, with its [Pattern]
+struct CompiledRule {
+    rule: Arc<CustomRule>,
+    pattern: Pattern,
+    kind: Option<JsSyntaxKind>,
+    severity: Severity,
+}
+
+impl CompiledRule {
+    /// Return the bindings of the metavariables if `node` matches the pattern
+    /// and the constraints of this rule
+    fn matches(&self, node: &JsSyntaxNode) -> Option<Bindings> {
+        if self.kind.map_or(false, |kind| kind != node.kind()) {
+            return None;
+        }
+
+        let bindings = self.pattern.matches(node)?;
+        let constraints_hold = self.rule.constraints.iter().all(|(name, regex)| {
+            bindings.get(name).map_or(false, |nodes| {
+                nodes
+                    .iter()
+                    .all(|node| regex.is_match(&node.text_trimmed().to_string()))
+            })
+        });
+
+        if constraints_hold {
+            Some(bindings)
+        } else {
+            None
+        }
+    }
+}
+
+/// Visitor matching the custom rules against each node of the syntax tree
+pub(crate) struct CustomRulesVisitor {
+    rules: Vec<(RuleKey, CompiledRule)>,
+}
+
+impl CustomRulesVisitor {
+    /// Register the custom `rules` in `metadata` so the suppression comments can
+    /// refer to them, and create a visitor running the rules enabled by `filter`
+    pub(crate) fn new(
+        rules: &[Arc<CustomRule>],
+        filter: &AnalysisFilter,
+        metadata: &mut MetadataRegistry,
+    ) -> Self {
+        let mut compiled = Vec::with_capacity(rules.len());
+
+        for rule in rules {
+            // The disabled rules are registered too, their suppression comments are valid
+            let key = metadata.insert_dynamic_rule(GROUP, rule.name);
+
+            let severity = match rule.severity {
+                Some(severity) if filter.match_dynamic_rule(key) => severity,
+                _ => continue,
+            };
+
+            let pattern = match Pattern::from_send(rule.pattern.clone()) {
+                Some(pattern) => pattern,
+                None => continue,
+            };
+
+            compiled.push((
+                key,
+                CompiledRule {
+                    rule: rule.clone(),
+                    kind: pattern.kind(),
+                    pattern,
+                    severity,
+                },
+            ));
+        }
+
+        Self { rules: compiled }
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Returns the identifiers of the enabled custom rules
+    pub(crate) fn rule_keys(&self) -> impl Iterator<Item = RuleKey> + '_ {
+        self.rules.iter().map(|(key, _)| *key)
+    }
+}
+
+impl Visitor for CustomRulesVisitor {
+    type Language = JsLanguage;
+
+    fn visit(&mut self, event: &WalkEvent<JsSyntaxNode>, mut ctx: VisitorContext<JsLanguage>) {
+        let node = match event {
+            WalkEvent::Enter(node) => node,
+            WalkEvent::Leave(_) => return,
+        };
+
+        if let Some(range) = ctx.range {
+            if node.text_range().ordering(range).is_ne() {
+                return;
+            }
+        }
+
+        for (key, rule) in &self.rules {
+            let bindings = match rule.matches(node) {
+                Some(bindings) => bindings,
+                None => continue,
+            };
+
+            let signal = CustomRuleSignal {
+                file_id: ctx.file_id,
+                key: *key,
+                node: node.clone(),
+                message: rule.rule.message.clone(),
+                severity: rule.severity,
+                rewrite: rule
+                    .rule
+                    .rewrite
+                    .as_deref()
+                    .map(|template| substitute(template, node, &bindings)),
+            };
+
+            ctx.emit_signal(*key, node.text_trimmed_range(), Box::new(signal));
+        }
+    }
+}
+
+/// Replace the metavariables in `template` with the code of the nodes bound to
+/// them in the match of `node`
+fn substitute(template: &str, node: &JsSyntaxNode, bindings: &Bindings) -> String {
+    let mut result = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find('$') {
+        result.push_str(&rest[..start]);
+
+        let name_len = rest[start..]
+            .char_indices()
+            .find(|(_, c)| *c != '$' && *c != '_' && !c.is_alphanumeric())
+            .map_or(rest.len() - start, |(index, _)| index);
+        let name = &rest[start..start + name_len];
+
+        match bindings.get(name) {
+            Some(nodes) => {
+                // The code between the nodes bound to a `$$$name` metavariable is kept
+                if let (Some(first), Some(last)) = (nodes.first(), nodes.last()) {
+                    let offset = node.text_range().start();
+                    let range = TextRange::new(
+                        first.text_trimmed_range().start() - offset,
+                        last.text_trimmed_range().end() - offset,
+                    );
+                    result.push_str(&node.text().slice(range).to_string());
+                }
+            }
+            None => result.push_str(name),
+        }
+
+        rest = &rest[start + name_len..];
+    }
+
+    result.push_str(rest);
+    result
+}
+
+/// Signal emitted for each node matching a custom rule
+struct CustomRuleSignal {
+    file_id: FileId,
+    key: RuleKey,
+    node: JsSyntaxNode,
+    message: Arc<str>,
+    severity: Severity,
+    /// Code replacing the node in the fix of the rule
+    rewrite: Option<String>,
+}
+
+impl AnalyzerSignal<JsLanguage> for CustomRuleSignal {
+    fn diagnostic(&self) -> Option<AnalyzerDiagnostic> {
+        let rule_name = self.key.rule_name();
+        let diagnostic = RuleDiagnostic::new(
+            category!("lint/custom"),
+            self.node.text_trimmed_range(),
+            &*self.message,
+        )
+        .note(markup! {
+            "This diagnostic is emitted by the custom rule "<Emphasis>{rule_name}</Emphasis>" of the configuration."
+        });
+
+        let mut diagnostic = AnalyzerDiagnostic::from_rule_diagnostic(self.file_id, diagnostic);
+        diagnostic.set_severity(self.severity);
+        Some(diagnostic)
+    }
+
+    fn actions(&self) -> Vec<AnalyzerAction<JsLanguage>> {
+        let replacement = match self.rewrite.as_deref().map(parse_snippet) {
+            Some(Ok(replacement)) => replacement,
+            _ => return Vec::new(),
+        };
+
+        let root = match self.node.ancestors().last() {
+            Some(root) => root,
+            None => return Vec::new(),
+        };
+
+        let mut mutation = BatchMutation::new(root);
+        mutation.replace_element(
+            SyntaxElement::Node(self.node.clone()),
+            SyntaxElement::Node(replacement),
+        );
+
+        vec![AnalyzerAction {
+            group_name: GROUP,
+            rule_name: self.key.rule_name(),
+            file_id: self.file_id,
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::MaybeIncorrect,
+            message: markup! { "Apply the rewrite of the custom rule." }.to_owned(),
+            mutation,
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::compile_custom_rule;
+    use rome_analyze::CustomRule;
+    use rome_diagnostics::v2::Severity;
+
+    fn compile(
+        name: &str,
+        pattern: &str,
+        constraints: &[(&str, &str)],
+        rewrite: Option<&str>,
+    ) -> Result<CustomRule, String> {
+        compile_custom_rule(
+            name,
+            pattern,
+            "Prefer a for...of loop",
+            constraints
+                .iter()
+                .map(|(name, regex)| (name.to_string(), regex.to_string())),
+            rewrite.map(String::from),
+            Some(Severity::Error),
+        )
+    }
+
+    #[test]
+    fn validates_rules() {
+        assert!(compile("noForEach", "$obj.forEach($cb)", &[], None).is_ok());
+        assert!(compile("noForEach", "$obj.forEach(", &[], None).is_err());
+        assert!(compile("no/forEach", "$obj.forEach($cb)", &[], None).is_err());
+        assert!(compile("noForEach", "$obj.forEach($cb)", &[("$obj", "(")], None).is_err());
+        assert!(compile(
+            "noForEach",
+            "$obj.forEach($cb)",
+            &[],
+            Some("for (const $x of $obj")
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn interns_rule_names() {
+        let first = compile("noForEach", "$obj.forEach($cb)", &[], None).unwrap();
+        let second = compile("noForEach", "$obj.forEach($cb)", &[], None).unwrap();
+        assert!(std::ptr::eq(first.name, second.name));
+    }
+}
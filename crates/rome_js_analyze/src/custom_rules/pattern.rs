@@ -0,0 +1,342 @@
+//! Structural patterns matched by the custom lint rules
+//!
+//! A pattern is a snippet of code, parsed into a syntax tree and matched against
+//! the nodes with the same structure: the trivia and the quotes of the string
+//! literals are ignored, and the parts of the syntax omitted by the pattern (like
+//! the semicolon of a statement) match anything.
+//!
+//! An identifier starting with `$` is a metavariable matching any node, all the
+//! nodes matched by the same metavariable must contain the same code. In a list,
+//! like the arguments of a call, a metavariable starting with `$$$` matches any
+//! number of elements. The `$_` and `$$$_` metavariables match without binding
+//! the nodes.
+
+use rome_diagnostics::file::FileId;
+use rome_js_parser::parse;
+use rome_js_syntax::{
+    JsAnyModuleItem, JsAnyRoot, JsAnyStatement, JsSyntaxKind, JsSyntaxNode, JsSyntaxToken,
+    SourceType,
+};
+use rome_rowan::{AstNode, AstNodeList, Direction, SendNode, SyntaxKind, SyntaxSlot};
+
+/// A snippet of code matched structurally against the syntax nodes
+#[derive(Debug, Clone)]
+pub(crate) struct Pattern {
+    root: JsSyntaxNode,
+}
+
+impl Pattern {
+    /// Parse a pattern, it must contain a single statement or expression
+    pub(crate) fn parse(source: &str) -> Result<Self, String> {
+        parse_snippet(source).map(|root| Self { root })
+    }
+
+    /// Return a handle to the syntax tree of this pattern that can be shared
+    /// between threads
+    pub(crate) fn as_send(&self) -> SendNode {
+        self.root
+            .as_send()
+            .expect("the root of a pattern is a detached node")
+    }
+
+    /// Restore a pattern from the handle returned by [Pattern::as_send], returns
+    /// `None` if `node` isn't a JavaScript syntax tree
+    pub(crate) fn from_send(node: SendNode) -> Option<Self> {
+        node.into_node().map(|root| Self { root })
+    }
+
+    /// Return the kind of the nodes this pattern can match, or `None` if the
+    /// pattern is a single metavariable matching any node
+    pub(crate) fn kind(&self) -> Option<JsSyntaxKind> {
+        if metavariable(&self.root).is_some() {
+            None
+        } else {
+            Some(self.root.kind())
+        }
+    }
+
+    /// Match `node` against this pattern, returning the nodes bound to the
+    /// metavariables if it matches
+    pub(crate) fn matches(&self, node: &JsSyntaxNode) -> Option<Bindings> {
+        let mut bindings = Bindings::default();
+
+        if match_node(&self.root, node, &mut bindings) {
+            Some(bindings)
+        } else {
+            None
+        }
+    }
+}
+
+/// The nodes matched by each metavariable of a [Pattern]
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Bindings {
+    entries: Vec<(String, Vec<JsSyntaxNode>)>,
+}
+
+impl Bindings {
+    /// Return the nodes bound to the metavariable `name`
+    pub(crate) fn get(&self, name: &str) -> Option<&[JsSyntaxNode]> {
+        self.entries
+            .iter()
+            .find(|(bound, _)| bound == name)
+            .map(|(_, nodes)| nodes.as_slice())
+    }
+
+    /// Bind `nodes` to the metavariable `name`, or check they contain the same
+    /// code as the nodes already bound to it
+    fn bind(&mut self, name: &str, nodes: &[JsSyntaxNode]) -> bool {
+        if name == "$_" || name == "$$$_" {
+            return true;
+        }
+
+        match self.get(name) {
+            Some(bound) => {
+                bound.len() == nodes.len()
+                    && bound
+                        .iter()
+                        .zip(nodes)
+                        .all(|(left, right)| same_code(left, right))
+            }
+            None => {
+                self.entries.push((name.to_string(), nodes.to_vec()));
+                true
+            }
+        }
+    }
+}
+
+/// Parse a snippet of code into the syntax node of its single statement, or of
+/// the expression if it's an expression statement
+pub(crate) fn parse_snippet(source: &str) -> Result<JsSyntaxNode, String> {
+    let parsed = parse(source, FileId::zero(), SourceType::tsx());
+    if parsed.has_errors() {
+        return Err(format!("`{source}` is not valid code"));
+    }
+
+    let module = match parsed.tree() {
+        JsAnyRoot::JsModule(module) => module,
+        _ => return Err(format!("`{source}` is not valid code")),
+    };
+
+    let mut items = module.items().iter();
+    let item = match (items.next(), items.next()) {
+        (Some(item), None) => item,
+        _ => {
+            return Err(format!(
+                "`{source}` must contain a single statement or expression"
+            ))
+        }
+    };
+
+    let node = match item {
+        JsAnyModuleItem::JsAnyStatement(JsAnyStatement::JsExpressionStatement(statement)) => {
+            match statement.expression() {
+                Ok(expression) => expression.into_syntax(),
+                Err(_) => return Err(format!("`{source}` is not valid code")),
+            }
+        }
+        item => item.into_syntax(),
+    };
+
+    Ok(node.detach())
+}
+
+/// Return the identifier of the metavariable if `node` only contains a
+/// metavariable, optionally followed by a semicolon
+fn metavariable(node: &JsSyntaxNode) -> Option<JsSyntaxToken> {
+    if node.kind().is_list() {
+        return None;
+    }
+
+    let mut tokens = node
+        .descendants_tokens(Direction::Next)
+        .filter(|token| token.kind() != JsSyntaxKind::SEMICOLON);
+
+    let token = tokens.next()?;
+    if tokens.next().is_some() {
+        return None;
+    }
+
+    if token.kind() == JsSyntaxKind::IDENT && token.text_trimmed().starts_with('$') {
+        Some(token)
+    } else {
+        None
+    }
+}
+
+fn match_node(pattern: &JsSyntaxNode, node: &JsSyntaxNode, bindings: &mut Bindings) -> bool {
+    if let Some(token) = metavariable(pattern) {
+        return bindings.bind(token.text_trimmed(), std::slice::from_ref(node));
+    }
+
+    if pattern.kind() != node.kind() {
+        return false;
+    }
+
+    if pattern.kind().is_list() {
+        let patterns: Vec<_> = pattern.children().collect();
+        let nodes: Vec<_> = node.children().collect();
+        return match_list(&patterns, &nodes, bindings);
+    }
+
+    pattern
+        .slots()
+        .zip(node.slots())
+        .all(|(pattern, node)| match (pattern, node) {
+            // The parts omitted by the pattern match anything
+            (SyntaxSlot::Empty, _) => true,
+            (SyntaxSlot::Node(pattern), SyntaxSlot::Node(node)) => {
+                match_node(&pattern, &node, bindings)
+            }
+            (SyntaxSlot::Token(pattern), SyntaxSlot::Token(token)) => same_token(&pattern, &token),
+            _ => false,
+        })
+}
+
+/// Match the elements of a list, the `$$$name` metavariables match any number
+/// of elements
+fn match_list(patterns: &[JsSyntaxNode], nodes: &[JsSyntaxNode], bindings: &mut Bindings) -> bool {
+    let (pattern, rest) = match patterns.split_first() {
+        Some(split) => split,
+        None => return nodes.is_empty(),
+    };
+
+    let spread = metavariable(pattern).filter(|token| token.text_trimmed().starts_with("$$$"));
+    if let Some(token) = spread {
+        for len in 0..=nodes.len() {
+            let mut candidate = bindings.clone();
+            if candidate.bind(token.text_trimmed(), &nodes[..len])
+                && match_list(rest, &nodes[len..], &mut candidate)
+            {
+                *bindings = candidate;
+                return true;
+            }
+        }
+
+        return false;
+    }
+
+    match nodes.split_first() {
+        Some((node, nodes)) => {
+            match_node(pattern, node, bindings) && match_list(rest, nodes, bindings)
+        }
+        None => false,
+    }
+}
+
+/// Return `true` if `left` and `right` contain the same tokens
+fn same_code(left: &JsSyntaxNode, right: &JsSyntaxNode) -> bool {
+    let mut left = left.descendants_tokens(Direction::Next);
+    let mut right = right.descendants_tokens(Direction::Next);
+
+    loop {
+        match (left.next(), right.next()) {
+            (None, None) => return true,
+            (Some(left), Some(right)) if same_token(&left, &right) => {}
+            _ => return false,
+        }
+    }
+}
+
+fn same_token(left: &JsSyntaxToken, right: &JsSyntaxToken) -> bool {
+    left.kind() == right.kind() && token_value(left) == token_value(right)
+}
+
+/// Return the text of `token`, without the quotes if it's a string literal
+fn token_value(token: &JsSyntaxToken) -> &str {
+    let text = token.text_trimmed();
+    if token.kind() == JsSyntaxKind::JS_STRING_LITERAL && text.len() >= 2 {
+        &text[1..text.len() - 1]
+    } else {
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_snippet, Pattern};
+    use rome_diagnostics::file::FileId;
+    use rome_js_parser::parse;
+    use rome_js_syntax::{JsSyntaxNode, SourceType};
+
+    /// Return the text of the nodes of `source` matched by `pattern`
+    fn find_matches(pattern: &str, source: &str) -> Vec<String> {
+        let pattern = Pattern::parse(pattern).unwrap();
+        let parsed = parse(source, FileId::zero(), SourceType::tsx());
+
+        parsed
+            .syntax()
+            .descendants()
+            .filter(|node: &JsSyntaxNode| pattern.matches(node).is_some())
+            .map(|node| node.text_trimmed().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn matches_metavariables() {
+        assert_eq!(
+            find_matches("$obj.forEach($cb)", "items.forEach(log); items.map(log);"),
+            ["items.forEach(log)"]
+        );
+        assert_eq!(find_matches("$a == $a", "a == a; a == b;"), ["a == a"]);
+        assert_eq!(
+            find_matches("$_ == $_", "a == a; a == b;"),
+            ["a == a", "a == b"]
+        );
+    }
+
+    #[test]
+    fn matches_lists() {
+        assert_eq!(
+            find_matches("f($$$args, last)", "f(last); f(a, b, last); f(a, b);"),
+            ["f(last)", "f(a, b, last)"]
+        );
+        assert_eq!(find_matches("f($x)", "f(); f(a); f(a, b);"), ["f(a)"]);
+    }
+
+    #[test]
+    fn ignores_quotes_and_trivia() {
+        assert_eq!(
+            find_matches(
+                "import $x from 'lodash'",
+                "import a from \"lodash\";\nimport b from 'underscore';"
+            ),
+            ["import a from \"lodash\";"]
+        );
+        assert_eq!(
+            find_matches("foo(  bar  )", "foo(/* comment */ bar)"),
+            ["foo(/* comment */ bar)"]
+        );
+    }
+
+    #[test]
+    fn binds_nodes() {
+        let pattern = Pattern::parse("$obj.forEach($$$args)").unwrap();
+        let parsed = parse("list.forEach(a, b)", FileId::zero(), SourceType::tsx());
+        let bindings = parsed
+            .syntax()
+            .descendants()
+            .find_map(|node| pattern.matches(&node))
+            .unwrap();
+
+        let text = |name| {
+            bindings
+                .get(name)
+                .unwrap()
+                .iter()
+                .map(|node| node.text_trimmed().to_string())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(text("$obj"), ["list"]);
+        assert_eq!(text("$$$args"), ["a", "b"]);
+    }
+
+    #[test]
+    fn rejects_invalid_snippets() {
+        assert!(parse_snippet("foo(").is_err());
+        assert!(parse_snippet("a; b").is_err());
+        assert!(parse_snippet("").is_err());
+    }
+}
@@ -0,0 +1,523 @@
+//! Compatibility with the suppression comments of ESLint
+//!
+//! When `linter.eslintSuppressionCompat` is enabled, the `eslint-disable-next-line`,
+//! `eslint-disable` and `eslint-enable` comments suppress the Rome rules equivalent
+//! to the ESLint rules they name, and a code action rewrites them into Rome
+//! suppression comments. The ESLint rules without an equivalent Rome rule are
+//! ignored.
+
+use rome_analyze::{AnalyzerSuppression, SuppressionComment, SuppressionRewrite};
+
+/// ESLint rules and the group and name of their equivalent Rome rule, sorted by
+/// the name of the ESLint rule
+const ESLINT_RULES: &[(&str, &str)] = &[
+    (
+        "@typescript-eslint/array-type",
+        "style/useShorthandArrayType",
+    ),
+    ("@typescript-eslint/ban-types", "nursery/noBannedTypes"),
+    (
+        "@typescript-eslint/no-explicit-any",
+        "nursery/noExplicitAny",
+    ),
+    (
+        "@typescript-eslint/no-unused-vars",
+        "correctness/noUnusedVariables",
+    ),
+    (
+        "@typescript-eslint/prefer-optional-chain",
+        "style/useOptionalChain",
+    ),
+    ("camelcase", "nursery/useCamelCase"),
+    ("constructor-super", "nursery/noInvalidConstructorSuper"),
+    ("curly", "style/useBlockStatements"),
+    ("eqeqeq", "correctness/noDoubleEquals"),
+    ("for-direction", "nursery/useValidForDirection"),
+    ("jsx-a11y/alt-text", "a11y/useAltText"),
+    ("jsx-a11y/anchor-has-content", "a11y/useAnchorContent"),
+    ("jsx-a11y/anchor-is-valid", "a11y/useValidAnchor"),
+    (
+        "jsx-a11y/click-events-have-key-events",
+        "a11y/useKeyWithClickEvents",
+    ),
+    (
+        "jsx-a11y/mouse-events-have-key-events",
+        "a11y/useKeyWithMouseEvents",
+    ),
+    ("jsx-a11y/no-autofocus", "a11y/noAutofocus"),
+    ("jsx-a11y/tabindex-no-positive", "a11y/noPositiveTabindex"),
+    (
+        "no-async-promise-executor",
+        "correctness/noAsyncPromiseExecutor",
+    ),
+    ("no-compare-neg-zero", "correctness/noCompareNegZero"),
+    ("no-const-assign", "nursery/noConstAssign"),
+    ("no-debugger", "correctness/noDebugger"),
+    ("no-dupe-args", "correctness/noDupeArgs"),
+    ("no-dupe-keys", "nursery/noDupeKeys"),
+    ("no-empty-pattern", "correctness/noEmptyPattern"),
+    ("no-ex-assign", "correctness/noCatchAssign"),
+    ("no-extra-boolean-cast", "complexity/noExtraBooleanCast"),
+    ("no-func-assign", "correctness/noFunctionAssign"),
+    ("no-import-assign", "correctness/noImportAssign"),
+    ("no-label-var", "correctness/noLabelVar"),
+    ("no-negated-condition", "style/noNegationElse"),
+    ("no-new-symbol", "correctness/noNewSymbol"),
+    (
+        "no-regex-spaces",
+        "correctness/noMultipleSpacesInRegularExpressionLiterals",
+    ),
+    ("no-restricted-globals", "correctness/noRestrictedGlobals"),
+    (
+        "no-shadow-restricted-names",
+        "correctness/noShadowRestrictedNames",
+    ),
+    ("no-sparse-arrays", "correctness/noSparseArray"),
+    ("no-undef", "correctness/noUndeclaredVariables"),
+    ("no-unreachable", "correctness/noUnreachable"),
+    ("no-unsafe-negation", "correctness/noUnsafeNegation"),
+    ("no-unused-vars", "correctness/noUnusedVariables"),
+    ("one-var", "style/useSingleVarDeclarator"),
+    ("prefer-numeric-literals", "nursery/useNumericLiterals"),
+    ("prefer-rest-params", "correctness/noArguments"),
+    ("prefer-template", "style/useTemplate"),
+    (
+        "react-hooks/exhaustive-deps",
+        "nursery/useExhaustiveDependencies",
+    ),
+    ("react/button-has-type", "a11y/useButtonType"),
+    ("react/jsx-fragments", "style/useFragmentSyntax"),
+    ("react/jsx-no-target-blank", "a11y/useBlankTarget"),
+    (
+        "react/jsx-no-useless-fragment",
+        "correctness/noUselessFragments",
+    ),
+    ("react/no-array-index-key", "correctness/noArrayIndexKey"),
+    ("react/no-children-prop", "correctness/noChildrenProp"),
+    ("react/no-danger", "security/noDangerouslySetInnerHtml"),
+    (
+        "react/no-danger-with-children",
+        "security/noDangerouslySetInnerHtmlWithChildren",
+    ),
+    (
+        "react/no-render-return-value",
+        "correctness/noRenderReturnValue",
+    ),
+    ("react/self-closing-comp", "style/useSelfClosingElements"),
+    (
+        "react/void-dom-elements-no-children",
+        "correctness/noVoidElementsWithChildren",
+    ),
+    ("unicorn/prefer-array-flat-map", "nursery/useFlatMap"),
+    ("valid-typeof", "correctness/useValidTypeof"),
+];
+
+/// Returns the group and name of the Rome rule equivalent to the ESLint rule `name`
+fn find_rule(name: &str) -> Option<&'static str> {
+    ESLINT_RULES
+        .binary_search_by(|(eslint_name, _)| eslint_name.cmp(&name))
+        .ok()
+        .map(|index| ESLINT_RULES[index].1)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DirectiveKind {
+    /// `eslint-disable-next-line`
+    DisableNextLine,
+    /// `eslint-disable`
+    Disable,
+    /// `eslint-enable`
+    Enable,
+}
+
+/// ESLint directive comment parsed by [parse_directive]
+#[derive(Debug, PartialEq, Eq)]
+struct Directive<'a> {
+    kind: DirectiveKind,
+    /// Group and name of the Rome rules equivalent to the ESLint rules named in
+    /// the comment, `None` if the comment applies to all the rules
+    rules: Option<Vec<&'static str>>,
+    /// Description following the `--` separator, if any
+    description: Option<&'a str>,
+}
+
+impl Directive<'_> {
+    /// Returns the categories of the Rome suppression comment equivalent to
+    /// this directive
+    fn categories(&self) -> Vec<String> {
+        match &self.rules {
+            Some(rules) => rules.iter().map(|rule| format!("lint({rule})")).collect(),
+            None => vec![String::from("lint")],
+        }
+    }
+}
+
+/// Parses the text of a comment as an ESLint directive
+fn parse_directive(text: &str) -> Option<Directive> {
+    let text = match text.strip_prefix("//") {
+        Some(text) => text,
+        None => text.strip_prefix("/*")?.strip_suffix("*/")?,
+    };
+
+    let text = text.trim();
+
+    let (kind, rest) = if let Some(rest) = text.strip_prefix("eslint-disable-next-line") {
+        (DirectiveKind::DisableNextLine, rest)
+    } else if let Some(rest) = text.strip_prefix("eslint-disable") {
+        (DirectiveKind::Disable, rest)
+    } else if let Some(rest) = text.strip_prefix("eslint-enable") {
+        (DirectiveKind::Enable, rest)
+    } else {
+        return None;
+    };
+
+    // Skip over the other directives starting with the same name, like
+    // `eslint-disable-line`
+    if !rest.is_empty() && !rest.starts_with(char::is_whitespace) {
+        return None;
+    }
+
+    let (names, description) = match rest.split_once("--") {
+        Some((names, description)) => (names, Some(description.trim())),
+        None => (rest, None),
+    };
+
+    let names: Vec<_> = names
+        .split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .collect();
+
+    let rules = if names.is_empty() {
+        None
+    } else {
+        let mut rules = Vec::new();
+        for rule in names.into_iter().filter_map(find_rule) {
+            if !rules.contains(&rule) {
+                rules.push(rule);
+            }
+        }
+
+        // None of the rules have an equivalent Rome rule, the comment is ignored
+        if rules.is_empty() {
+            return None;
+        }
+
+        Some(rules)
+    };
+
+    Some(Directive {
+        kind,
+        rules,
+        description,
+    })
+}
+
+/// Parses the ESLint directive comments into the equivalent suppressions
+pub(crate) fn parse_eslint_suppression_comment(text: &str) -> Vec<AnalyzerSuppression> {
+    let directive = match parse_directive(text) {
+        Some(directive) => directive,
+        None => return Vec::new(),
+    };
+
+    let suppression = match directive.kind {
+        DirectiveKind::DisableNextLine => AnalyzerSuppression::Line,
+        DirectiveKind::Disable => AnalyzerSuppression::LintDisable,
+        DirectiveKind::Enable => AnalyzerSuppression::LintEnable,
+    };
+
+    match directive.rules {
+        Some(rules) => rules
+            .into_iter()
+            .map(|rule| suppression(Some(rule)))
+            .collect(),
+        None => vec![suppression(None)],
+    }
+}
+
+/// Rewrites the ESLint directive comments of a file into the equivalent Rome
+/// suppression comments
+///
+/// Each `eslint-enable` comment is paired with the `eslint-disable` comments it
+/// closes, and is rewritten into a `rome-ignore-end` comment for their
+/// categories. An `eslint-disable` comment that is never closed suppresses
+/// the rules until the end of the file: it's rewritten into a file suppression
+/// comment if it's placed at the top of the file, otherwise a
+/// `rome-ignore-end` comment is inserted at the end of the file
+pub(crate) fn rewrite_eslint_suppression_comments(
+    comments: &[SuppressionComment],
+) -> Vec<SuppressionRewrite> {
+    let directives: Vec<_> = comments
+        .iter()
+        .map(|comment| parse_directive(comment.text))
+        .collect();
+
+    // The categories of the `eslint-disable` comments that haven't been closed
+    // yet, along with the index of their comment, and the categories closed by
+    // each `eslint-enable` comment
+    let mut open: Vec<(usize, String)> = Vec::new();
+    let mut closed = vec![Vec::new(); comments.len()];
+
+    for (index, directive) in directives.iter().enumerate() {
+        let directive = match directive {
+            Some(directive) => directive,
+            None => continue,
+        };
+
+        match directive.kind {
+            DirectiveKind::DisableNextLine => {}
+            DirectiveKind::Disable => {
+                for category in directive.categories() {
+                    open.push((index, category));
+                }
+            }
+            DirectiveKind::Enable => {
+                let categories = directive.rules.as_ref().map(|_| directive.categories());
+                open.retain(|(_, category)| {
+                    let is_closed = categories
+                        .as_ref()
+                        .map_or(true, |categories| categories.contains(category));
+                    if is_closed {
+                        closed[index].push(category.clone());
+                    }
+
+                    !is_closed
+                });
+            }
+        }
+    }
+
+    let mut rewrites = Vec::new();
+
+    for (index, (comment, directive)) in comments.iter().zip(directives).enumerate() {
+        let directive = match directive {
+            Some(directive) => directive,
+            None => continue,
+        };
+
+        let categories = directive.categories();
+        let reason = directive.description.unwrap_or("migrated from ESLint");
+
+        let (content, end_of_file) = match directive.kind {
+            DirectiveKind::DisableNextLine => (
+                format!("rome-ignore {}: {reason}", categories.join(" ")),
+                None,
+            ),
+            DirectiveKind::Disable => {
+                let unclosed: Vec<_> = open
+                    .iter()
+                    .filter(|(disable, _)| *disable == index)
+                    .map(|(_, category)| category.as_str())
+                    .collect();
+
+                if comment.is_top_of_file && unclosed.len() == categories.len() {
+                    (
+                        format!("rome-ignore-all {}: {reason}", categories.join(" ")),
+                        None,
+                    )
+                } else {
+                    let end_of_file = if unclosed.is_empty() {
+                        None
+                    } else {
+                        Some(format!("// rome-ignore-end {}", unclosed.join(" ")))
+                    };
+
+                    (
+                        format!("rome-ignore-start {}: {reason}", categories.join(" ")),
+                        end_of_file,
+                    )
+                }
+            }
+            // An `eslint-enable` comment that doesn't close anything is left
+            // as is, it's reported as an unmatched range
+            DirectiveKind::Enable if closed[index].is_empty() => continue,
+            DirectiveKind::Enable => (format!("rome-ignore-end {}", closed[index].join(" ")), None),
+        };
+
+        let replacement = if comment.text.starts_with("//") {
+            format!("// {content}")
+        } else {
+            format!("/* {content} */")
+        };
+
+        rewrites.push(SuppressionRewrite {
+            index,
+            replacement,
+            end_of_file,
+        });
+    }
+
+    rewrites
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        parse_eslint_suppression_comment, rewrite_eslint_suppression_comments, ESLINT_RULES,
+    };
+    use rome_analyze::{AnalyzerSuppression, SuppressionComment};
+
+    #[test]
+    fn eslint_rules_are_sorted() {
+        assert!(ESLINT_RULES.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    }
+
+    #[test]
+    fn parse_eslint_suppressions() {
+        assert_eq!(
+            parse_eslint_suppression_comment(
+                "// eslint-disable-next-line no-debugger, react/no-danger -- legacy code"
+            ),
+            [
+                AnalyzerSuppression::Line(Some("correctness/noDebugger")),
+                AnalyzerSuppression::Line(Some("security/noDangerouslySetInnerHtml")),
+            ]
+        );
+
+        assert_eq!(
+            parse_eslint_suppression_comment("/* eslint-disable */"),
+            [AnalyzerSuppression::LintDisable(None)]
+        );
+
+        assert_eq!(
+            parse_eslint_suppression_comment("/* eslint-enable eqeqeq */"),
+            [AnalyzerSuppression::LintEnable(Some(
+                "correctness/noDoubleEquals"
+            ))]
+        );
+
+        assert!(
+            parse_eslint_suppression_comment("// eslint-disable-next-line import/no-cycle")
+                .is_empty()
+        );
+        assert!(parse_eslint_suppression_comment("// eslint-disable-line no-debugger").is_empty());
+    }
+
+    /// Rewrites the comments of a file, each comment is at the top of the
+    /// file if it's preceded by `true`
+    fn rewrite(comments: &[(bool, &str)]) -> Vec<(usize, String, Option<String>)> {
+        let comments: Vec<_> = comments
+            .iter()
+            .map(|(is_top_of_file, text)| SuppressionComment {
+                text,
+                is_top_of_file: *is_top_of_file,
+            })
+            .collect();
+
+        rewrite_eslint_suppression_comments(&comments)
+            .into_iter()
+            .map(|rewrite| (rewrite.index, rewrite.replacement, rewrite.end_of_file))
+            .collect()
+    }
+
+    #[test]
+    fn rewrite_eslint_suppressions() {
+        assert_eq!(
+            rewrite(&[(
+                false,
+                "// eslint-disable-next-line no-debugger, import/no-cycle -- legacy code"
+            )]),
+            [(
+                0,
+                String::from("// rome-ignore lint(correctness/noDebugger): legacy code"),
+                None
+            )]
+        );
+
+        assert_eq!(
+            rewrite(&[(true, "/* eslint-disable */")]),
+            [(
+                0,
+                String::from("/* rome-ignore-all lint: migrated from ESLint */"),
+                None
+            )]
+        );
+
+        assert_eq!(
+            rewrite(&[
+                (false, "/* eslint-disable eqeqeq */"),
+                (false, "/* eslint-enable eqeqeq */")
+            ]),
+            [
+                (
+                    0,
+                    String::from(
+                        "/* rome-ignore-start lint(correctness/noDoubleEquals): migrated from ESLint */"
+                    ),
+                    None
+                ),
+                (
+                    1,
+                    String::from("/* rome-ignore-end lint(correctness/noDoubleEquals) */"),
+                    None
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn rewrite_unclosed_eslint_disable() {
+        assert_eq!(
+            rewrite(&[(false, "// eslint-disable no-debugger")]),
+            [(
+                0,
+                String::from(
+                    "// rome-ignore-start lint(correctness/noDebugger): migrated from ESLint"
+                ),
+                Some(String::from(
+                    "// rome-ignore-end lint(correctness/noDebugger)"
+                ))
+            )]
+        );
+
+        // The top disable is closed, so it can't suppress the whole file
+        assert_eq!(
+            rewrite(&[(true, "// eslint-disable"), (false, "// eslint-enable")]),
+            [
+                (
+                    0,
+                    String::from("// rome-ignore-start lint: migrated from ESLint"),
+                    None
+                ),
+                (1, String::from("// rome-ignore-end lint"), None),
+            ]
+        );
+    }
+
+    #[test]
+    fn rewrite_eslint_enable_with_categories_of_disable() {
+        assert_eq!(
+            rewrite(&[
+                (false, "// eslint-disable no-debugger, eqeqeq"),
+                (false, "// eslint-disable"),
+                (false, "// eslint-enable eqeqeq"),
+                (false, "// eslint-enable"),
+                (false, "// eslint-enable"),
+            ]),
+            [
+                (
+                    0,
+                    String::from(
+                        "// rome-ignore-start lint(correctness/noDebugger) lint(correctness/noDoubleEquals): migrated from ESLint"
+                    ),
+                    None
+                ),
+                (
+                    1,
+                    String::from("// rome-ignore-start lint: migrated from ESLint"),
+                    None
+                ),
+                (
+                    2,
+                    String::from("// rome-ignore-end lint(correctness/noDoubleEquals)"),
+                    None
+                ),
+                (
+                    3,
+                    String::from("// rome-ignore-end lint(correctness/noDebugger) lint"),
+                    None
+                ),
+            ]
+        );
+    }
+}
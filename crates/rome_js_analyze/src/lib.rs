@@ -1,31 +1,42 @@
 use control_flow::make_visitor;
 use rome_analyze::{
-    AnalysisFilter, Analyzer, AnalyzerContext, AnalyzerOptions, AnalyzerSignal, ControlFlow,
-    InspectMatcher, LanguageRoot, MatchQueryParams, MetadataRegistry, Phases, RuleAction,
+    AnalysisFilter, Analyzer, AnalyzerContext, AnalyzerOptions, AnalyzerSignal,
+    AnalyzerSuppression, ControlFlow, InspectMatcher, LanguageRoot, MatchQueryParams,
+    MetadataRegistry, Phases, PluginVisitor, ProfileMatcher, ProfiledVisitor, Profiler, RuleAction,
     RuleRegistry, ServiceBag, SyntaxVisitor,
 };
 use rome_diagnostics::file::FileId;
 use rome_js_syntax::{
-    suppression::{parse_suppression_comment, SuppressionCategory},
+    suppression::{parse_suppression_comment, SuppressionCategory, SuppressionKind},
     JsLanguage,
 };
-use serde::{Deserialize, Serialize};
-use std::{borrow::Cow, error::Error};
+use std::collections::BTreeSet;
 
 mod analyzers;
 mod assists;
 mod ast_utils;
 mod control_flow;
+mod custom_rules;
+mod eslint;
 pub mod globals;
+mod module_graph_services;
+mod project_analyzers;
+mod promises;
 mod react;
 mod registry;
 mod semantic_analyzers;
 mod semantic_services;
 mod syntax;
+mod type_services;
 pub mod utils;
 
+pub use crate::custom_rules::compile_custom_rule;
+use crate::custom_rules::CustomRulesVisitor;
+pub use crate::module_graph_services::{ModuleGraphServices, Project};
 pub use crate::registry::visit_registry;
 use crate::semantic_services::{SemanticModelBuilderVisitor, SemanticModelVisitor};
+use crate::type_services::TypeModelBuilderVisitor;
+pub use crate::type_services::{TypeServices, Typed};
 
 pub(crate) type JsRuleAction = RuleAction<JsLanguage>;
 
@@ -61,40 +72,163 @@ where
     F: FnMut(&dyn AnalyzerSignal<JsLanguage>) -> ControlFlow<B> + 'a,
     B: 'a,
 {
-    fn parse_linter_suppression_comment(text: &str) -> Vec<Option<&str>> {
-        parse_suppression_comment(text)
-            .flat_map(|comment| comment.categories)
-            .filter_map(|(key, value)| {
-                if key == SuppressionCategory::Lint {
-                    Some(value)
-                } else {
-                    None
+    fn parse_linter_suppression_comment(text: &str) -> Vec<AnalyzerSuppression> {
+        let mut result = Vec::new();
+
+        for comment in parse_suppression_comment(text) {
+            for (key, value) in comment.categories {
+                // `lint/group/rule` is a shorthand for `lint(group/rule)`
+                let (key, value) = match key.strip_prefix("lint/") {
+                    Some(rule) if value.is_none() => ("lint", Some(rule)),
+                    _ => (key, value),
+                };
+
+                match comment.kind {
+                    SuppressionKind::Next if key == SuppressionCategory::Lint => {
+                        result.push(AnalyzerSuppression::Line(value));
+                    }
+                    SuppressionKind::All if key == SuppressionCategory::Lint => {
+                        result.push(AnalyzerSuppression::All(value));
+                    }
+                    SuppressionKind::RangeStart if key == SuppressionCategory::Lint => {
+                        result.push(AnalyzerSuppression::LintRangeStart(value));
+                    }
+                    SuppressionKind::RangeEnd if key == SuppressionCategory::Lint => {
+                        result.push(AnalyzerSuppression::LintRangeEnd(value));
+                    }
+                    SuppressionKind::RangeStart if key == SuppressionCategory::Format => {
+                        result.push(AnalyzerSuppression::RangeStart(key));
+                    }
+                    SuppressionKind::RangeEnd if key == SuppressionCategory::Format => {
+                        result.push(AnalyzerSuppression::RangeEnd(key));
+                    }
+                    _ => {}
                 }
-            })
-            .collect()
+            }
+        }
+
+        result
     }
 
+    fn parse_linter_and_eslint_suppression_comment(text: &str) -> Vec<AnalyzerSuppression> {
+        let mut result = parse_linter_suppression_comment(text);
+        result.extend(eslint::parse_eslint_suppression_comment(text));
+        result
+    }
+
+    let eslint_suppression_compat = options.configuration.eslint_suppression_compat;
+    let parse_suppression_comment: fn(&str) -> Vec<AnalyzerSuppression> =
+        if eslint_suppression_compat {
+            parse_linter_and_eslint_suppression_comment
+        } else {
+            parse_linter_suppression_comment
+        };
+
     let mut registry = RuleRegistry::builder(&filter);
     visit_registry(&mut registry);
 
+    // The custom rules and the rules of the plugins are registered in the
+    // metadata of the dynamic rules, so the suppression comments can refer to them
+    let custom_rules = &options.configuration.custom_rules;
+    let plugins = &options.configuration.plugins;
+    let mut dynamic_metadata = None;
+    let mut custom_visitor = None;
+    let mut plugin_visitor = None;
+    if !custom_rules.is_empty() || !plugins.is_empty() {
+        let mut metadata = MetadataRegistry::default();
+        custom_visitor = Some(CustomRulesVisitor::new(
+            custom_rules,
+            &filter,
+            &mut metadata,
+        ));
+        plugin_visitor = Some(PluginVisitor::<JsLanguage>::new(
+            plugins,
+            &filter,
+            &mut metadata,
+        ));
+        dynamic_metadata = Some(metadata);
+    }
+
+    // Measure the time spent in the rules and visitors if the profiling is enabled
+    let profiler = options.profile.clone().and_then(Profiler::new);
+
+    // The control flow graphs are emitted again in the semantic phase for the
+    // rules combining them with the semantic model
+    let registry = registry.build();
+    let has_semantic_control_flow = registry.has_control_flow_rules(Phases::Semantic);
+
+    let mut enabled_rules: BTreeSet<_> = registry.rule_keys().collect();
+    if let Some(visitor) = &custom_visitor {
+        enabled_rules.extend(visitor.rule_keys());
+    }
+    if let Some(visitor) = &plugin_visitor {
+        enabled_rules.extend(visitor.rule_keys());
+    }
+
     let mut analyzer = Analyzer::new(
         metadata(),
-        InspectMatcher::new(registry.build(), inspect_matcher),
-        parse_linter_suppression_comment,
+        InspectMatcher::new(
+            ProfileMatcher::new(registry, profiler.clone()),
+            inspect_matcher,
+        ),
+        parse_suppression_comment,
         &mut emit_signal,
     );
-    analyzer.add_visitor(Phases::Syntax, make_visitor());
+    analyzer.set_enabled_rules(enabled_rules);
+    if let Some(metadata) = &dynamic_metadata {
+        analyzer.set_dynamic_metadata(metadata);
+    }
+    if eslint_suppression_compat {
+        analyzer.set_suppression_rewriter(eslint::rewrite_eslint_suppression_comments);
+    }
+    analyzer.add_visitor(
+        Phases::Syntax,
+        ProfiledVisitor::new("controlFlow", make_visitor(), profiler.clone()),
+    );
     analyzer.add_visitor(Phases::Syntax, SyntaxVisitor::default());
-    analyzer.add_visitor(Phases::Syntax, SemanticModelBuilderVisitor::new(root));
+    analyzer.add_visitor(
+        Phases::Syntax,
+        ProfiledVisitor::new(
+            "semanticModel",
+            SemanticModelBuilderVisitor::new(root),
+            profiler.clone(),
+        ),
+    );
+    // Finished after the semantic model builder, whose model it wraps
+    analyzer.add_visitor(Phases::Syntax, TypeModelBuilderVisitor);
+    if let Some(visitor) = custom_visitor.filter(|visitor| !visitor.is_empty()) {
+        analyzer.add_visitor(
+            Phases::Syntax,
+            ProfiledVisitor::new("customRules", visitor, profiler.clone()),
+        );
+    }
+    if let Some(visitor) = plugin_visitor.filter(|visitor| !visitor.is_empty()) {
+        analyzer.add_visitor(
+            Phases::Syntax,
+            ProfiledVisitor::new("plugins", visitor, profiler.clone()),
+        );
+    }
 
     analyzer.add_visitor(Phases::Semantic, SemanticModelVisitor);
     analyzer.add_visitor(Phases::Semantic, SyntaxVisitor::default());
+    if has_semantic_control_flow {
+        analyzer.add_visitor(
+            Phases::Semantic,
+            ProfiledVisitor::new("controlFlow", make_visitor(), profiler),
+        );
+    }
+
+    let mut services = ServiceBag::default();
+    services.insert_service(ModuleGraphServices::new(
+        options.module_graph.clone(),
+        options.file_path.clone(),
+    ));
 
     analyzer.run(AnalyzerContext {
         file_id,
         root: root.clone(),
         range: filter.range,
-        services: ServiceBag::default(),
+        services,
         options,
     })
 }
@@ -119,29 +253,37 @@ where
 #[cfg(test)]
 mod tests {
 
-    use rome_analyze::{AnalyzerOptions, Never, RuleCategories};
+    use rome_analyze::{
+        AnalyzerOptions, AnalyzerPlugin, ImportKind, ModuleGraph, ModuleImport, ModuleResolution,
+        Never, PluginRule, RuleCategories, RuleFilter,
+    };
     use rome_console::fmt::{Formatter, Termcolor};
     use rome_console::{markup, Markup};
     use rome_diagnostics::termcolor::NoColor;
-    use rome_diagnostics::v2::{Diagnostic, DiagnosticExt, PrintDiagnostic, Severity};
+    use rome_diagnostics::v2::{Category, Diagnostic, DiagnosticExt, PrintDiagnostic, Severity};
     use rome_diagnostics::{file::FileId, v2::category};
     use rome_js_parser::parse;
     use rome_js_syntax::{SourceType, TextRange, TextSize};
+    use serde_json::{json, Value};
+    use std::collections::HashMap;
+    use std::path::{Path, PathBuf};
+    use std::slice;
+    use std::sync::Arc;
+
+    use crate::{analyze, compile_custom_rule, AnalysisFilter, ControlFlow};
 
-    use crate::{analyze, AnalysisFilter, ControlFlow};
+    fn markup_to_string(markup: Markup) -> String {
+        let mut buffer = Vec::new();
+        let mut write = Termcolor(NoColor::new(&mut buffer));
+        let mut fmt = Formatter::new(&mut write);
+        fmt.write_markup(markup).unwrap();
+
+        String::from_utf8(buffer).unwrap()
+    }
 
     #[ignore]
     #[test]
     fn quick_test() {
-        fn markup_to_string(markup: Markup) -> String {
-            let mut buffer = Vec::new();
-            let mut write = Termcolor(NoColor::new(&mut buffer));
-            let mut fmt = Formatter::new(&mut write);
-            fmt.write_markup(markup).unwrap();
-
-            String::from_utf8(buffer).unwrap()
-        }
-
         const SOURCE: &str = r#"<img src="image.png" aria-label="alt text" />
         "#;
 
@@ -158,7 +300,7 @@ mod tests {
                 if let Some(mut diag) = signal.diagnostic() {
                     diag.set_severity(Severity::Warning);
                     error_ranges.push(diag.location().unwrap().span.unwrap());
-                    if let Some(action) = signal.action() {
+                    for action in signal.actions() {
                         let new_code = action.mutation.commit();
                         eprintln!("{new_code}");
                     }
@@ -262,26 +404,727 @@ mod tests {
             ControlFlow::<Never>::Continue(())
         });
     }
-}
 
-/// Series of errors encountered when running rules on a file
-#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
-pub enum RuleError {
-    /// The rule with the specified name replaced the root of the file with a node that is not a valid root for that language.
-    ReplacedRootWithNonRootError { rule_name: Cow<'static, str> },
+    #[test]
+    fn multiple_actions() {
+        const SOURCE: &str = "a == undefined;";
+
+        let parsed = parse(SOURCE, FileId::zero(), SourceType::js_module());
+
+        let rule_filter = RuleFilter::Rule("correctness", "noDoubleEquals");
+        let filter = AnalysisFilter {
+            enabled_rules: Some(slice::from_ref(&rule_filter)),
+            ..AnalysisFilter::default()
+        };
+
+        let mut fixes = Vec::new();
+        let options = AnalyzerOptions::default();
+        analyze(FileId::zero(), &parsed.tree(), filter, &options, |signal| {
+            for action in signal.actions() {
+                fixes.push(action.mutation.commit().to_string());
+            }
+
+            ControlFlow::<Never>::Continue(())
+        });
+
+        assert_eq!(fixes, ["a === undefined;", "a == null;"]);
+    }
+
+    #[test]
+    fn unused_suppression_fix() {
+        const SOURCE: &str = "function f() {
+    // rome-ignore lint(correctness/noDoubleEquals): unused
+    a === b;
 }
+";
+
+        let parsed = parse(SOURCE, FileId::zero(), SourceType::js_module());
+
+        let rule_filter = RuleFilter::Rule("correctness", "noDoubleEquals");
+        let filter = AnalysisFilter {
+            enabled_rules: Some(slice::from_ref(&rule_filter)),
+            ..AnalysisFilter::default()
+        };
+
+        let mut fixes = Vec::new();
+        let options = AnalyzerOptions::default();
+        analyze(FileId::zero(), &parsed.tree(), filter, &options, |signal| {
+            let diag = signal.diagnostic().unwrap();
+            assert_eq!(diag.category(), Some(category!("suppressions/unused")));
+
+            for action in signal.actions() {
+                fixes.push(action.mutation.commit().to_string());
+            }
+
+            ControlFlow::<Never>::Continue(())
+        });
+
+        assert_eq!(fixes, ["function f() {\n    a === b;\n}\n"]);
+    }
+
+    #[test]
+    fn suppression_of_disabled_rule_is_not_unused() {
+        const SOURCE: &str = "// rome-ignore lint(correctness/noDebugger): not run
+debugger;
+// rome-ignore-start lint(correctness/noDebugger): not run
+debugger;
+// rome-ignore-end lint(correctness/noDebugger)
+";
+
+        let parsed = parse(SOURCE, FileId::zero(), SourceType::js_module());
+
+        let rule_filter = RuleFilter::Rule("correctness", "noDoubleEquals");
+        let filter = AnalysisFilter {
+            enabled_rules: Some(slice::from_ref(&rule_filter)),
+            ..AnalysisFilter::default()
+        };
+
+        let options = AnalyzerOptions::default();
+        analyze(FileId::zero(), &parsed.tree(), filter, &options, |signal| {
+            if let Some(diag) = signal.diagnostic() {
+                panic!("unexpected diagnostic {:?}", diag.category());
+            }
+
+            ControlFlow::<Never>::Continue(())
+        });
+    }
+
+    #[test]
+    fn unknown_suppression_suggestion() {
+        const SOURCE: &str = "
+            // rome-ignore lint(correctness/noDoubleEqual): misspelled rule
+            a == b;
+            // rome-ignore lint(corectness): misspelled group
+            a == b;
+        ";
+
+        let parsed = parse(SOURCE, FileId::zero(), SourceType::js_module());
+
+        let mut messages = Vec::new();
+        let options = AnalyzerOptions::default();
+        analyze(
+            FileId::zero(),
+            &parsed.tree(),
+            AnalysisFilter::default(),
+            &options,
+            |signal| {
+                if let Some(diag) = signal.diagnostic() {
+                    let code = diag.category().unwrap();
+                    if code == category!("suppressions/unknownRule")
+                        || code == category!("suppressions/unknownGroup")
+                    {
+                        let error = diag
+                            .with_file_path(FileId::zero())
+                            .with_file_source_code(SOURCE);
+                        messages.push(markup_to_string(markup! {
+                            {PrintDiagnostic(&error)}
+                        }));
+                    }
+                }
+
+                ControlFlow::<Never>::Continue(())
+            },
+        );
+
+        assert_eq!(messages.len(), 2);
+        assert!(messages[0].contains("Did you mean correctness/noDoubleEquals?"));
+        assert!(messages[1].contains("Did you mean correctness?"));
+    }
+
+    /// Returns the categories of the diagnostics emitted for `source` by the
+    /// rules used by the suppression tests
+    fn diagnostic_categories(source: &str) -> Vec<&'static Category> {
+        source_type_diagnostic_categories(source, SourceType::js_module())
+    }
+
+    fn source_type_diagnostic_categories(
+        source: &str,
+        source_type: SourceType,
+    ) -> Vec<&'static Category> {
+        let parsed = parse(source, FileId::zero(), source_type);
+
+        let rules = [
+            RuleFilter::Rule("correctness", "noDoubleEquals"),
+            RuleFilter::Rule("correctness", "noDebugger"),
+        ];
+        let filter = AnalysisFilter {
+            enabled_rules: Some(rules.as_slice()),
+            ..AnalysisFilter::default()
+        };
+
+        let mut categories = Vec::new();
+        let options = AnalyzerOptions::default();
+        analyze(FileId::zero(), &parsed.tree(), filter, &options, |signal| {
+            if let Some(diag) = signal.diagnostic() {
+                categories.extend(diag.category());
+            }
+
+            ControlFlow::<Never>::Continue(())
+        });
+
+        categories
+    }
+
+    /// Returns the categories of the diagnostics emitted for `source` with
+    /// `options`, ignoring the rules that aren't used by the tests
+    fn options_diagnostic_categories(
+        options: &AnalyzerOptions,
+        source: &str,
+    ) -> Vec<&'static Category> {
+        let parsed = parse(source, FileId::zero(), SourceType::js_module());
+
+        let mut categories = Vec::new();
+        analyze(
+            FileId::zero(),
+            &parsed.tree(),
+            AnalysisFilter::default(),
+            options,
+            |signal| {
+                if let Some(category) = signal.diagnostic().and_then(|diag| diag.category()) {
+                    if category.name().starts_with("suppressions/")
+                        || category == category!("lint/correctness/noDoubleEquals")
+                        || category == category!("lint/correctness/noDebugger")
+                        || category == category!("lint/custom")
+                    {
+                        categories.push(category);
+                    }
+                }
+
+                ControlFlow::<Never>::Continue(())
+            },
+        );
+
+        categories
+    }
+
+    #[test]
+    fn range_suppression() {
+        let categories = diagnostic_categories(
+            "
+            a == b;
+            // rome-ignore-start lint(correctness/noDoubleEquals): legacy code
+            a == b;
+            a == b;
+            // rome-ignore-end lint(correctness/noDoubleEquals)
+            a == b;
+        ",
+        );
+
+        assert_eq!(
+            categories,
+            [
+                category!("lint/correctness/noDoubleEquals"),
+                category!("lint/correctness/noDoubleEquals"),
+            ]
+        );
+    }
+
+    #[test]
+    fn overlapping_range_suppressions() {
+        let categories = diagnostic_categories(
+            "
+            // rome-ignore-start lint(correctness): legacy code
+            // rome-ignore-start lint(correctness/noDoubleEquals): legacy code
+            a == b;
+            // rome-ignore-end lint(correctness/noDoubleEquals)
+            // rome-ignore-end lint(correctness)
+        ",
+        );
+
+        assert!(categories.is_empty());
+    }
+
+    #[test]
+    fn file_suppression() {
+        let categories = diagnostic_categories(
+            "// rome-ignore-all lint/correctness/noDoubleEquals: generated code
+            a == b;
+            a == b;
+        ",
+        );
+
+        assert_eq!(categories, []);
+    }
+
+    #[test]
+    fn invalid_range_and_file_suppressions() {
+        let categories = diagnostic_categories(
+            "
+            a === b;
+            // rome-ignore-all lint(correctness/noDoubleEquals): not at the top
+            // rome-ignore-end lint(correctness/noDoubleEquals)
+            // rome-ignore-start lint(correctness): unused
+            a === b;
+            // rome-ignore-end lint(correctness)
+        ",
+        );
+
+        assert_eq!(
+            categories,
+            [
+                category!("suppressions/misplaced"),
+                category!("suppressions/unmatchedRange"),
+                category!("suppressions/unused"),
+            ]
+        );
+    }
+
+    #[test]
+    fn unmatched_range_start() {
+        let categories = diagnostic_categories(
+            "
+            // rome-ignore-start lint(correctness/noDoubleEquals): never closed
+            a == b;
+        ",
+        );
+
+        assert_eq!(categories, [category!("suppressions/unmatchedRange")]);
+    }
+
+    #[test]
+    fn class_member_range_suppression() {
+        let categories = diagnostic_categories(
+            "
+            class A {
+                // rome-ignore-start lint(correctness/noDoubleEquals): legacy code
+                a = b == c;
+                // rome-ignore-end lint(correctness/noDoubleEquals)
+                d = b == c;
+            }
+        ",
+        );
+
+        assert_eq!(categories, [category!("lint/correctness/noDoubleEquals")]);
+    }
+
+    #[test]
+    fn jsx_range_suppression() {
+        let categories = source_type_diagnostic_categories(
+            "
+            <div>
+                {/* rome-ignore-start lint(correctness/noDoubleEquals): legacy code */}
+                {a == b}
+                {/* rome-ignore-end lint(correctness/noDoubleEquals) */}
+                {a == b}
+            </div>;
+        ",
+            SourceType::jsx(),
+        );
+
+        assert_eq!(categories, [category!("lint/correctness/noDoubleEquals")]);
+    }
+
+    #[test]
+    fn eslint_suppression_compat() {
+        const SOURCE: &str = "/* eslint-disable no-debugger */
+a == b;
+// eslint-disable-next-line eqeqeq -- legacy code
+a == b;
+debugger;
+";
+
+        let mut options = AnalyzerOptions::default();
+        options.configuration.eslint_suppression_compat = true;
+
+        assert_eq!(
+            options_diagnostic_categories(&options, SOURCE),
+            [category!("lint/correctness/noDoubleEquals")]
+        );
+        assert_eq!(
+            options_diagnostic_categories(&AnalyzerOptions::default(), SOURCE),
+            [
+                category!("lint/correctness/noDoubleEquals"),
+                category!("lint/correctness/noDoubleEquals"),
+                category!("lint/correctness/noDebugger"),
+            ]
+        );
+
+        let parsed = parse(SOURCE, FileId::zero(), SourceType::js_module());
+
+        let mut fixes = Vec::new();
+        analyze(
+            FileId::zero(),
+            &parsed.tree(),
+            AnalysisFilter::default(),
+            &options,
+            |signal| {
+                for action in signal.actions() {
+                    if action.rule_name == "rewrite" {
+                        fixes.push(action.mutation.commit().to_string());
+                    }
+                }
+
+                ControlFlow::<Never>::Continue(())
+            },
+        );
+
+        assert_eq!(
+            fixes,
+            [
+                SOURCE.replace(
+                    "/* eslint-disable no-debugger */",
+                    "/* rome-ignore-all lint(correctness/noDebugger): migrated from ESLint */"
+                ),
+                SOURCE.replace(
+                    "// eslint-disable-next-line eqeqeq -- legacy code",
+                    "// rome-ignore lint(correctness/noDoubleEquals): legacy code"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn eslint_disable_until_end_of_file() {
+        const SOURCE: &str = "a == b;
+/* eslint-disable no-debugger */
+debugger;
+";
+
+        let parsed = parse(SOURCE, FileId::zero(), SourceType::js_module());
+
+        let mut options = AnalyzerOptions::default();
+        options.configuration.eslint_suppression_compat = true;
+
+        let mut fixes = Vec::new();
+        analyze(
+            FileId::zero(),
+            &parsed.tree(),
+            AnalysisFilter::default(),
+            &options,
+            |signal| {
+                for action in signal.actions() {
+                    if action.rule_name == "rewrite" {
+                        fixes.push(action.mutation.commit().to_string());
+                    }
+                }
+
+                ControlFlow::<Never>::Continue(())
+            },
+        );
+
+        assert_eq!(
+            fixes,
+            ["a == b;
+/* rome-ignore-start lint(correctness/noDebugger): migrated from ESLint */
+debugger;
+// rome-ignore-end lint(correctness/noDebugger)
+"]
+        );
+    }
+
+    #[test]
+    fn custom_rules() {
+        const SOURCE: &str = "items.indexOf(item) !== -1;
+// rome-ignore lint(custom/preferIncludes): legacy code
+items.indexOf(item) !== -1;
+other.indexOf(item) !== -1;
+";
+
+        let mut options = AnalyzerOptions::default();
+        let rule = |severity| {
+            compile_custom_rule(
+                "preferIncludes",
+                "$list.indexOf($item) !== -1",
+                "Use includes() to check if an item is in the list",
+                [("$list".into(), "^items$".into())],
+                Some("$list.includes($item)".into()),
+                severity,
+            )
+            .map(Arc::new)
+            .unwrap()
+        };
+        options.configuration.custom_rules = vec![rule(Some(Severity::Warning))];
+
+        assert_eq!(
+            options_diagnostic_categories(&options, SOURCE),
+            [category!("lint/custom")]
+        );
+
+        let parsed = parse(SOURCE, FileId::zero(), SourceType::js_module());
+
+        let mut fixes = Vec::new();
+        analyze(
+            FileId::zero(),
+            &parsed.tree(),
+            AnalysisFilter::default(),
+            &options,
+            |signal| {
+                if let Some(diag) = signal.diagnostic() {
+                    if diag.category() == Some(category!("lint/custom")) {
+                        assert_eq!(diag.severity(), Severity::Warning);
+                    }
+                }
+
+                for action in signal.actions() {
+                    if action.group_name == "custom" {
+                        fixes.push(action.mutation.commit().to_string());
+                    }
+                }
+
+                ControlFlow::<Never>::Continue(())
+            },
+        );
 
-impl std::fmt::Display for RuleError {
-    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
-        match self {
-            RuleError::ReplacedRootWithNonRootError { rule_name } => {
-                std::write!(
-                    fmt,
-                    "the rule '{rule_name}' replaced the root of the file with a non-root node."
-                )
+        assert_eq!(
+            fixes,
+            [SOURCE.replacen("items.indexOf(item) !== -1", "items.includes(item)", 1)]
+        );
+
+        let disabled = RuleFilter::Rule("custom", "preferIncludes");
+        let filter = AnalysisFilter {
+            disabled_rules: Some(slice::from_ref(&disabled)),
+            ..AnalysisFilter::default()
+        };
+
+        analyze(FileId::zero(), &parsed.tree(), filter, &options, |signal| {
+            if let Some(diag) = signal.diagnostic() {
+                assert_ne!(diag.category(), Some(category!("lint/custom")));
             }
+
+            ControlFlow::<Never>::Continue(())
+        });
+
+        // A rule disabled in the configuration is still known to the suppression comments
+        options.configuration.custom_rules = vec![rule(None)];
+        assert_eq!(options_diagnostic_categories(&options, SOURCE), []);
+    }
+
+    /// A plugin reporting the `foo` and `baz` identifiers with its rule `noFoo`: the
+    /// fix of `foo` replaces it with `bar`, the fix of `baz` has overlapping edits
+    #[derive(Debug)]
+    struct TestPlugin(Vec<PluginRule>);
+
+    impl AnalyzerPlugin for TestPlugin {
+        fn name(&self) -> &'static str {
+            "example"
+        }
+
+        fn rules(&self) -> &[PluginRule] {
+            &self.0
+        }
+
+        fn evaluate(&self, input: &str) -> Result<String, String> {
+            let input: Value = serde_json::from_str(input).map_err(|error| error.to_string())?;
+            assert_eq!(input["rules"], json!(["noFoo"]));
+
+            let mut diagnostics = Vec::new();
+            let mut elements = vec![&input["root"]];
+            while let Some(element) = elements.pop() {
+                let (start, end) = (&element["start"], &element["end"]);
+                let edits = match element["text"].as_str() {
+                    Some("foo") => json!([{ "start": start, "end": end, "text": "bar" }]),
+                    Some("baz") => json!([
+                        { "start": start, "end": end, "text": "bar" },
+                        { "start": start, "end": end, "text": "qux" }
+                    ]),
+                    _ => {
+                        if let Some(children) = element["children"].as_array() {
+                            elements.extend(children);
+                        }
+                        continue;
+                    }
+                };
+
+                diagnostics.push(json!({
+                    "rule": "noFoo",
+                    "start": start,
+                    "end": end,
+                    "message": "Do not use this identifier",
+                    "fix": { "message": "Use bar instead", "edits": edits }
+                }));
+            }
+
+            Ok(Value::Array(diagnostics).to_string())
         }
     }
-}
 
-impl Error for RuleError {}
+    #[test]
+    fn plugin_rules() {
+        const SOURCE: &str = "foo;
+// rome-ignore lint(example/noFoo): legacy code
+foo;
+baz;
+";
+
+        let mut options = AnalyzerOptions::default();
+        options.configuration.plugins = vec![Arc::new(TestPlugin(vec![PluginRule {
+            name: "noFoo",
+            severity: Some(Severity::Warning),
+        }]))];
+
+        let parsed = parse(SOURCE, FileId::zero(), SourceType::js_module());
+
+        let mut diagnostics = Vec::new();
+        let mut fixes = Vec::new();
+        analyze(
+            FileId::zero(),
+            &parsed.tree(),
+            AnalysisFilter::default(),
+            &options,
+            |signal| {
+                if let Some(diag) = signal.diagnostic() {
+                    if diag.category() == Some(category!("lint/plugin")) {
+                        let span = diag.location().and_then(|location| location.span).unwrap();
+                        diagnostics.push((&SOURCE[span], diag.severity()));
+                    }
+                }
+
+                for action in signal.actions() {
+                    if action.group_name == "example" {
+                        fixes.push(action.mutation.commit().to_string());
+                    }
+                }
+
+                ControlFlow::<Never>::Continue(())
+            },
+        );
+
+        // The diagnostic with an invalid fix is emitted without it, along with
+        // an error describing the fix
+        diagnostics.sort();
+        assert_eq!(
+            diagnostics,
+            [
+                ("baz", Severity::Error),
+                ("baz", Severity::Warning),
+                ("foo", Severity::Warning)
+            ]
+        );
+        assert_eq!(fixes, [SOURCE.replacen("foo", "bar", 1)]);
+
+        // The rules disabled in the configuration don't run
+        options.configuration.plugins = vec![Arc::new(TestPlugin(vec![PluginRule {
+            name: "noFoo",
+            severity: None,
+        }]))];
+        assert_eq!(options_diagnostic_categories(&options, SOURCE), []);
+    }
+
+    /// A module graph of in-memory modules, each import being a `from "..."`
+    /// clause resolved relative to the root
+    #[derive(Debug)]
+    struct TestModuleGraph(HashMap<PathBuf, &'static str>);
+
+    impl ModuleGraph for TestModuleGraph {
+        fn imports(&self, path: &Path) -> Option<Vec<ModuleImport>> {
+            let source = self.0.get(path)?;
+            let imports = source
+                .match_indices("from \"")
+                .map(|(index, prefix)| {
+                    let start = index + prefix.len();
+                    let end = start + source[start..].find('"').unwrap();
+                    let specifier = &source[start..end];
+
+                    ModuleImport {
+                        specifier: specifier.into(),
+                        range: TextRange::new(
+                            TextSize::from(start as u32 - 1),
+                            TextSize::from(end as u32 + 1),
+                        ),
+                        kind: if source[..index].ends_with("import type { a } ") {
+                            ImportKind::TypeOnly
+                        } else {
+                            ImportKind::Static
+                        },
+                        resolution: ModuleResolution::File(Path::new("/").join(specifier)),
+                    }
+                })
+                .collect();
+
+            Some(imports)
+        }
+
+        fn importers(&self, _: &Path) -> Vec<PathBuf> {
+            Vec::new()
+        }
+
+        fn source_text(&self, path: &Path) -> Option<String> {
+            self.0.get(path).map(|source| source.to_string())
+        }
+    }
+
+    /// Returns the paths of the modules reporting a cycle, with the text of
+    /// the reported import
+    fn import_cycles(graph: &Arc<TestModuleGraph>, options: &AnalyzerOptions) -> Vec<String> {
+        let mut paths: Vec<_> = graph.0.keys().cloned().collect();
+        paths.sort();
+
+        let mut cycles = Vec::new();
+        for path in paths {
+            let source = graph.0[&path];
+            let parsed = parse(source, FileId::zero(), SourceType::js_module());
+            let options = AnalyzerOptions {
+                file_path: path.clone(),
+                module_graph: Some(graph.clone()),
+                ..options.clone()
+            };
+
+            analyze(
+                FileId::zero(),
+                &parsed.tree(),
+                AnalysisFilter::default(),
+                &options,
+                |signal| {
+                    if let Some(diag) = signal.diagnostic() {
+                        if diag.category() == Some(category!("lint/nursery/noImportCycles")) {
+                            let span = diag.location().and_then(|location| location.span).unwrap();
+                            cycles.push(format!("{}: {}", path.display(), &source[span]));
+                        }
+                    }
+
+                    ControlFlow::<Never>::Continue(())
+                },
+            );
+        }
+
+        cycles
+    }
+
+    #[test]
+    fn import_cycles_are_reported_by_each_module() {
+        let graph = Arc::new(TestModuleGraph(HashMap::from([
+            (
+                PathBuf::from("/a.js"),
+                r#"import { b } from "b.js"; import { d } from "d.js"; export const a = 1;"#,
+            ),
+            (
+                PathBuf::from("/b.js"),
+                r#"import { c } from "c.js"; export const b = 1;"#,
+            ),
+            (
+                PathBuf::from("/c.js"),
+                r#"import { a } from "a.js"; export const c = 1;"#,
+            ),
+            (
+                PathBuf::from("/d.js"),
+                r#"import type { a } from "a.js"; export const d = 1;"#,
+            ),
+        ])));
+
+        // The type import of `d.js` isn't part of the cycles by default
+        assert_eq!(
+            import_cycles(&graph, &AnalyzerOptions::default()),
+            [r#"/a.js: "b.js""#, r#"/b.js: "c.js""#, r#"/c.js: "a.js""#]
+        );
+
+        let mut options = AnalyzerOptions::default();
+        let rule_key = crate::metadata()
+            .find_rule("nursery", "noImportCycles")
+            .unwrap();
+        options
+            .configuration
+            .rules
+            .push_rule(rule_key, json!({ "includeTypeImports": true }));
+
+        // The shortest cycle of `a.js` goes through the type import
+        assert_eq!(
+            import_cycles(&graph, &options),
+            [
+                r#"/a.js: "d.js""#,
+                r#"/b.js: "c.js""#,
+                r#"/c.js: "a.js""#,
+                r#"/d.js: "a.js""#
+            ]
+        );
+    }
+}
@@ -0,0 +1,88 @@
+use rome_analyze::{
+    FromServices, MissingServicesDiagnostic, ModuleGraph, ModuleImport, Phase, Phases, QueryKey,
+    QueryMatch, Queryable, RuleKey, ServiceBag,
+};
+use rome_js_syntax::JsLanguage;
+use rome_rowan::AstNode;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// The module graph of the project, along with the path of the analyzed module
+///
+/// The graph is only available when the file is analyzed as part of a
+/// project, the rules using it don't report anything otherwise.
+#[derive(Debug, Clone)]
+pub struct ModuleGraphServices {
+    graph: Option<Arc<dyn ModuleGraph>>,
+    path: PathBuf,
+}
+
+impl ModuleGraphServices {
+    pub(crate) fn new(graph: Option<Arc<dyn ModuleGraph>>, path: PathBuf) -> Self {
+        Self { graph, path }
+    }
+
+    pub fn graph(&self) -> Option<&dyn ModuleGraph> {
+        self.graph.as_deref()
+    }
+
+    /// Return a shared reference to the graph, for the services outliving the
+    /// analysis of a single rule
+    pub(crate) fn shared_graph(&self) -> Option<Arc<dyn ModuleGraph>> {
+        self.graph.clone()
+    }
+
+    /// The path of the analyzed module
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Return the imports of the analyzed module
+    pub fn imports(&self) -> Vec<ModuleImport> {
+        self.graph()
+            .and_then(|graph| graph.imports(&self.path))
+            .unwrap_or_default()
+    }
+}
+
+impl FromServices for ModuleGraphServices {
+    fn from_services(
+        rule_key: &RuleKey,
+        services: &ServiceBag,
+    ) -> Result<Self, MissingServicesDiagnostic> {
+        services
+            .get_service()
+            .ok_or_else(|| MissingServicesDiagnostic::new(rule_key.rule_name(), &["ModuleGraph"]))
+    }
+}
+
+impl Phase for ModuleGraphServices {
+    fn phase() -> Phases {
+        Phases::Syntax
+    }
+}
+
+/// Query type usable by lint rules **that use the module graph** to match on
+/// specific [AstNode] types
+#[derive(Clone)]
+pub struct Project<N>(pub N);
+
+impl<N> Queryable for Project<N>
+where
+    N: AstNode<Language = JsLanguage> + 'static,
+{
+    type Output = N;
+    type Language = JsLanguage;
+    type Services = ModuleGraphServices;
+
+    /// Match on [QueryMatch::Syntax] if the kind of the syntax node matches
+    /// the kind set of `N`
+    const KEY: QueryKey<Self::Language> = QueryKey::Syntax(N::KIND_SET);
+
+    fn unwrap_match(_: &ServiceBag, query: &QueryMatch<Self::Language>) -> Self::Output {
+        match query {
+            QueryMatch::Syntax(node) => N::unwrap_cast(node.clone()),
+            _ => panic!("tried to unwrap unsupported QueryMatch kind, expected Syntax"),
+        }
+    }
+}
@@ -0,0 +1,4 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+mod nursery;
+::rome_analyze::declare_category! { pub (crate) ProjectAnalyzers { kind : Lint , groups : [self :: nursery :: Nursery ,] } }
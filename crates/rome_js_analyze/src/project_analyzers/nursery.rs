@@ -0,0 +1,5 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+use rome_analyze::declare_group;
+mod no_import_cycles;
+declare_group! { pub (crate) Nursery { name : "nursery" , rules : [self :: no_import_cycles :: NoImportCycles ,] } }
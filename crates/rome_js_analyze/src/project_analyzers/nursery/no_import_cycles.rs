@@ -0,0 +1,172 @@
+use crate::module_graph_services::Project;
+use rome_analyze::context::RuleContext;
+use rome_analyze::{declare_rule, ModuleGraph, ModuleImport, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_js_syntax::JsAnyRoot;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap, VecDeque};
+use std::path::{Path, PathBuf};
+
+declare_rule! {
+    /// Disallow the modules of the project from importing each other in a cycle.
+    ///
+    /// When the modules import each other in a cycle, one of them is always
+    /// evaluated before the modules it imports, and may use their exports
+    /// before they are initialized. The order in which the modules are
+    /// evaluated also depends on the module imported first, so adding an
+    /// import elsewhere in the project can break the code of the cycle.
+    ///
+    /// The rule finds the modules that belong to a cycle with the module
+    /// graph of the project, and reports on each of them the shortest chain of
+    /// imports going back to the module. The modules of the `node_modules`
+    /// directories and the dynamic `import()` calls, that are evaluated later,
+    /// are ignored.
+    ///
+    /// The `import type` statements are erased at runtime and are ignored by
+    /// default, the `includeTypeImports` option can be set to `true` to report
+    /// the cycles they are part of.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js
+    /// // a.js
+    /// import { b } from "./b.js";
+    /// export const a = 1;
+    ///
+    /// // b.js
+    /// import { a } from "./a.js";
+    /// export const b = a + 1;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// // a.js
+    /// import { b } from "./b.js";
+    ///
+    /// // b.js
+    /// import { c } from "./c.js";
+    /// ```
+    ///
+    pub(crate) NoImportCycles {
+        version: "11.0.0",
+        name: "noImportCycles",
+        recommended: false,
+    }
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct NoImportCyclesOptions {
+    /// Whether the `import type` statements are part of the cycles
+    #[serde(default)]
+    include_type_imports: bool,
+}
+
+/// The imports of a cycle, each one with the path of the module containing
+/// it. The first import is in the analyzed module.
+pub(crate) struct ImportCycle(Vec<(PathBuf, ModuleImport)>);
+
+impl Rule for NoImportCycles {
+    type Query = Project<JsAnyRoot>;
+    type State = ImportCycle;
+    type Signals = Option<Self::State>;
+    type Options = NoImportCyclesOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let graph = ctx.graph()?;
+        let path = ctx.path();
+        let include_type_imports = ctx
+            .options()
+            .map_or(false, |options| options.include_type_imports);
+
+        // The components are computed once for the whole project by the graph
+        let component = graph.import_component(path, include_type_imports);
+
+        shortest_cycle(graph, path, &component, include_type_imports).map(ImportCycle)
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let ImportCycle(chain) = state;
+        let ((_, first), rest) = chain.split_first()?;
+
+        let mut diagnostic = RuleDiagnostic::new(
+            rule_category!(),
+            first.range,
+            markup! {
+                "This import is part of a cycle of "{chain.len()}" modules importing each other."
+            },
+        );
+
+        for (importer, import) in rest {
+            let importer_name = importer.display().to_string();
+            let message = markup! {
+                <Emphasis>{importer_name}</Emphasis>" imports "<Emphasis>{import.specifier}</Emphasis>
+            };
+
+            diagnostic = match ctx.graph()?.source_text(importer) {
+                Some(source_text) => diagnostic.detail_in_file(
+                    importer_name.as_str(),
+                    &source_text,
+                    import.range,
+                    message,
+                ),
+                None => diagnostic.note(message),
+            };
+        }
+
+        Some(diagnostic.note(markup! {
+            "A module of the cycle may use the exports of the others before they are initialized, move the code they share to a separate module to break the cycle."
+        }))
+    }
+}
+
+/// Return the shortest chain of imports starting and ending at `path`, going
+/// through the modules of its `component`
+fn shortest_cycle(
+    graph: &dyn ModuleGraph,
+    path: &Path,
+    component: &BTreeSet<PathBuf>,
+    include_type_imports: bool,
+) -> Option<Vec<(PathBuf, ModuleImport)>> {
+    // The import each module was first reached with, and the module containing it
+    let mut parents: HashMap<PathBuf, (PathBuf, ModuleImport)> = HashMap::new();
+    let mut queue = VecDeque::from([path.to_path_buf()]);
+
+    while let Some(module) = queue.pop_front() {
+        for (target, import) in graph.cycle_imports(&module, include_type_imports) {
+            if !component.contains(&target) || parents.contains_key(&target) {
+                continue;
+            }
+
+            let is_cycle = target == path;
+            parents.insert(target.clone(), (module.clone(), import));
+
+            if is_cycle {
+                let mut chain = Vec::new();
+                let mut current = target;
+
+                loop {
+                    let (importer, import) = parents.remove(&current)?;
+                    let is_start = importer == path;
+                    chain.push((importer.clone(), import));
+
+                    if is_start {
+                        break;
+                    }
+
+                    current = importer;
+                }
+
+                chain.reverse();
+                return Some(chain);
+            }
+
+            queue.push_back(target);
+        }
+    }
+
+    None
+}
@@ -0,0 +1,78 @@
+//! A series of utilities to detect the promises, shared by the rules
+//! checking how the promises are handled
+
+use rome_js_syntax::{
+    JsAnyClassMember, JsAnyExpression, JsCallExpression, JsClassMemberList, JsSyntaxKind,
+};
+use rome_js_type_info::{Type, TypeModel};
+use rome_rowan::AstNode;
+
+/// Returns `true` if `expression` evaluates to a promise
+///
+/// The type of the expression is inferred by the [TypeModel]. When it's
+/// unknown, the calls of the `async` methods of the enclosing class through
+/// `this` are still detected as promises.
+pub(crate) fn is_promise(types: &TypeModel, expression: &JsAnyExpression) -> bool {
+    match types.expression_type(expression).is_promise() {
+        Some(is_promise) => is_promise,
+        None => match expression.clone().omit_parentheses() {
+            JsAnyExpression::JsCallExpression(call) => calls_async_method(&call),
+            _ => false,
+        },
+    }
+}
+
+/// Returns `true` if `function` is a function returning promises, like the
+/// `async` functions
+pub(crate) fn returns_promise(types: &TypeModel, function: &JsAnyExpression) -> bool {
+    match types.expression_type(function) {
+        Type::Function(function) => {
+            function.is_async || function.returns.is_promise() == Some(true)
+        }
+        _ => false,
+    }
+}
+
+/// Returns `true` if `call` calls an `async` method of the enclosing class,
+/// like `this.load()`
+fn calls_async_method(call: &JsCallExpression) -> bool {
+    let member = match call.callee().map(JsAnyExpression::omit_parentheses) {
+        Ok(JsAnyExpression::JsStaticMemberExpression(member)) => member,
+        _ => return false,
+    };
+
+    let is_this = matches!(member.object(), Ok(JsAnyExpression::JsThisExpression(_)));
+    let name = member
+        .member()
+        .ok()
+        .and_then(|name| name.as_js_name()?.value_token().ok());
+    let name = match name {
+        Some(name) if is_this => name,
+        _ => return false,
+    };
+
+    // `this` is bound to the class instance up to the first enclosing
+    // function that isn't an arrow function
+    let members = call
+        .syntax()
+        .ancestors()
+        .take_while(|node| {
+            !matches!(
+                node.kind(),
+                JsSyntaxKind::JS_FUNCTION_DECLARATION | JsSyntaxKind::JS_FUNCTION_EXPRESSION
+            )
+        })
+        .find_map(JsClassMemberList::cast);
+
+    members.map_or(false, |members| {
+        members.into_iter().any(|member| match member {
+            JsAnyClassMember::JsMethodClassMember(method) => {
+                method.async_token().is_some()
+                    && method.name().map_or(false, |method_name| {
+                        method_name.syntax().text_trimmed() == name.text_trimmed()
+                    })
+            }
+            _ => false,
+        })
+    })
+}
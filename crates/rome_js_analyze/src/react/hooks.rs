@@ -3,7 +3,7 @@ use std::collections::{HashMap, HashSet};
 use rome_js_semantic::{Capture, ClosureExtensions, SemanticModel};
 use rome_js_syntax::{
     JsAnyExpression, JsArrayBindingPattern, JsArrayBindingPatternElementList, JsCallExpression,
-    JsIdentifierBinding, JsVariableDeclarator, TextRange,
+    JsIdentifierBinding, JsSyntaxToken, JsVariableDeclarator, TextRange,
 };
 use rome_rowan::AstNode;
 use serde::{Deserialize, Serialize};
@@ -103,6 +103,47 @@ pub(crate) fn react_hook_with_dependency(
     })
 }
 
+/// Returns `true` if `name` follows the naming convention of React hooks:
+/// `use` followed by an uppercase letter or a digit, like `useState`
+pub(crate) fn is_react_hook_name(name: &str) -> bool {
+    name.strip_prefix("use")
+        .and_then(|rest| rest.chars().next())
+        .map_or(false, |c| c.is_ascii_uppercase() || c.is_ascii_digit())
+}
+
+/// Returns `true` if `name` follows the naming convention of React
+/// components: starting with an uppercase letter, like `Button`
+pub(crate) fn is_react_component_name(name: &str) -> bool {
+    name.chars()
+        .next()
+        .map_or(false, |c| c.is_ascii_uppercase())
+}
+
+/// Returns the name of the hook called by `call`, if the callee is named
+/// like a React hook either directly or as a member of a namespace.
+///
+/// Example:
+/// ```js
+/// useState(0);
+/// ^^^^^^^^ <- name
+/// React.useState(0);
+///       ^^^^^^^^ <- name
+/// ```
+pub(crate) fn react_hook_call_name(call: &JsCallExpression) -> Option<JsSyntaxToken> {
+    let name = match call.callee().ok()?.omit_parentheses() {
+        JsAnyExpression::JsIdentifierExpression(identifier) => {
+            identifier.name().ok()?.value_token().ok()?
+        }
+        JsAnyExpression::JsStaticMemberExpression(member) => {
+            member.object().ok()?.as_js_identifier_expression()?;
+            member.member().ok()?.as_js_name()?.value_token().ok()?
+        }
+        _ => return None,
+    };
+
+    is_react_hook_name(name.text_trimmed()).then_some(name)
+}
+
 /// Specifies which, if any, of the returns of a React hook are stable.    
 /// See [is_binding_react_stable].
 #[derive(Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -1,7 +1,14 @@
 //! Generated file, do not edit by hand, see `xtask/codegen`
 
 use rome_analyze::declare_group;
+mod no_async_array_callback;
 mod no_const_assign;
+mod no_floating_promises;
+mod no_promise_in_condition;
+mod no_self_assign;
+mod no_use_before_assign;
+mod no_useless_assignment;
 mod use_camel_case;
 mod use_exhaustive_dependencies;
-declare_group! { pub (crate) Nursery { name : "nursery" , rules : [self :: no_const_assign :: NoConstAssign , self :: use_camel_case :: UseCamelCase , self :: use_exhaustive_dependencies :: UseExhaustiveDependencies ,] } }
+mod use_hook_at_top_level;
+declare_group! { pub (crate) Nursery { name : "nursery" , rules : [self :: no_async_array_callback :: NoAsyncArrayCallback , self :: no_const_assign :: NoConstAssign , self :: no_floating_promises :: NoFloatingPromises , self :: no_promise_in_condition :: NoPromiseInCondition , self :: no_self_assign :: NoSelfAssign , self :: no_use_before_assign :: NoUseBeforeAssign , self :: no_useless_assignment :: NoUselessAssignment , self :: use_camel_case :: UseCamelCase , self :: use_exhaustive_dependencies :: UseExhaustiveDependencies , self :: use_hook_at_top_level :: UseHookAtTopLevel ,] } }
@@ -0,0 +1,124 @@
+use crate::promises::returns_promise;
+use crate::type_services::Typed;
+use rome_analyze::context::RuleContext;
+use rome_analyze::{declare_rule, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_js_syntax::{JsAnyCallArgument, JsAnyExpression, JsCallExpression};
+use rome_rowan::{AstNode, AstSeparatedList, SyntaxTokenText};
+
+declare_rule! {
+    /// Disallow passing callbacks returning promises to the array methods
+    /// that ignore them
+    ///
+    /// `forEach` doesn't wait for the promises returned by its callback, so
+    /// the code following the loop runs before the callbacks complete and
+    /// their rejections are unhandled. The predicates of `filter`, `find`,
+    /// `some` and the similar methods are always truthy when they return a
+    /// promise, and the comparators of `sort` never compare anything.
+    ///
+    /// The callbacks are detected as returning promises from the inferred
+    /// types, like the `async` functions. Passing an `async` callback to `map`
+    /// is allowed, the returned promises can be awaited with `Promise.all`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// files.forEach(async (file) => {
+    ///     await upload(file);
+    /// });
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const missing = files.filter(async (file) => !(await exists(file)));
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// async function uploadAll(files) {
+    ///     await Promise.all(files.map(async (file) => {
+    ///         await upload(file);
+    ///     }));
+    ///     for (const file of files) {
+    ///         await upload(file);
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub(crate) NoAsyncArrayCallback {
+        version: "11.0.0",
+        name: "noAsyncArrayCallback",
+        recommended: false,
+    }
+}
+
+/// The array methods ignoring the promises returned by their callback
+const METHODS: [&str; 9] = [
+    "every",
+    "filter",
+    "find",
+    "findIndex",
+    "findLast",
+    "findLastIndex",
+    "forEach",
+    "some",
+    "sort",
+];
+
+pub(crate) struct AsyncCallback {
+    method: SyntaxTokenText,
+    callback: JsAnyExpression,
+}
+
+impl Rule for NoAsyncArrayCallback {
+    type Query = Typed<JsCallExpression>;
+    type State = AsyncCallback;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let call = ctx.query();
+        let member = match call.callee().ok()?.omit_parentheses() {
+            JsAnyExpression::JsStaticMemberExpression(member) => member,
+            _ => return None,
+        };
+
+        let method = member.member().ok()?.as_js_name()?.value_token().ok()?;
+        let method = method.token_text_trimmed();
+        if !METHODS.contains(&method.text()) {
+            return None;
+        }
+
+        let callback = match call.arguments().ok()?.args().first()?.ok()? {
+            JsAnyCallArgument::JsAnyExpression(callback) => callback,
+            JsAnyCallArgument::JsSpread(_) => return None,
+        };
+
+        returns_promise(ctx.types(), &callback).then_some(AsyncCallback { method, callback })
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let method = state.method.text();
+        let diagnostic = RuleDiagnostic::new(
+            rule_category!(),
+            state.callback.range(),
+            markup! {
+                "This callback returns a promise, which "<Emphasis>{method}</Emphasis>" ignores."
+            },
+        );
+
+        Some(match method {
+            "forEach" => diagnostic.note(markup! {
+                "Use a "<Emphasis>"for...of"</Emphasis>" loop to run the callbacks one after another, or "<Emphasis>"Promise.all"</Emphasis>" with "<Emphasis>"map"</Emphasis>" to run them concurrently."
+            }),
+            "sort" => diagnostic.note(markup! {
+                "The order of the elements doesn't depend on the resolved values: compute them before sorting the array."
+            }),
+            _ => diagnostic.note(markup! {
+                "A promise is always truthy: compute the resolved values before calling "<Emphasis>{method}</Emphasis>"."
+            }),
+        })
+    }
+}
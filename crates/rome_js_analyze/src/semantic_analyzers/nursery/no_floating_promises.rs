@@ -0,0 +1,116 @@
+use crate::promises::is_promise;
+use crate::type_services::Typed;
+use rome_analyze::context::RuleContext;
+use rome_analyze::{declare_rule, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_js_syntax::{JsAnyExpression, JsCallExpression, JsExpressionStatement};
+use rome_rowan::{AstNode, AstSeparatedList};
+
+declare_rule! {
+    /// Require the promises returned by calls in statement position to be
+    /// handled
+    ///
+    /// A promise that is neither awaited, returned nor given a rejection
+    /// handler "floats": its rejection is unhandled, which crashes Node.js
+    /// processes, and the code following the call doesn't wait for it to be
+    /// settled.
+    ///
+    /// The calls are detected as returning a promise from the inferred types,
+    /// like the calls of the `async` functions. The calls of a `catch` method,
+    /// or of a `then` method with a rejection handler, handle the rejection.
+    /// The promises can be explicitly ignored with the `void` operator.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// async function save() {}
+    /// save();
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// fetch("/api").then((response) => console.log(response));
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// async function save() {}
+    /// async function run() {
+    ///     await save();
+    ///     save().catch((error) => console.error(error));
+    ///     void save();
+    /// }
+    /// ```
+    ///
+    pub(crate) NoFloatingPromises {
+        version: "11.0.0",
+        name: "noFloatingPromises",
+        recommended: false,
+    }
+}
+
+impl Rule for NoFloatingPromises {
+    type Query = Typed<JsExpressionStatement>;
+    type State = JsCallExpression;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let statement = ctx.query();
+        let call = match statement.expression().ok()?.omit_parentheses() {
+            JsAnyExpression::JsCallExpression(call) => call,
+            _ => return None,
+        };
+
+        if handles_rejection(&call) {
+            return None;
+        }
+
+        is_promise(ctx.types(), &call.clone().into()).then_some(call)
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, call: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                call.range(),
+                markup! {
+                    "This promise is neither awaited nor handled."
+                },
+            )
+            .note(markup! {
+                "Await the promise, return it, handle its rejection with "<Emphasis>"catch"</Emphasis>", or ignore it explicitly with the "<Emphasis>"void"</Emphasis>" operator."
+            }),
+        )
+    }
+}
+
+/// Returns `true` if `call` is a call of `catch`, or of `then` with a
+/// rejection handler
+fn handles_rejection(call: &JsCallExpression) -> bool {
+    let member = match call.callee().map(JsAnyExpression::omit_parentheses) {
+        Ok(JsAnyExpression::JsStaticMemberExpression(member)) => member,
+        _ => return false,
+    };
+
+    let name = match member
+        .member()
+        .ok()
+        .and_then(|name| name.as_js_name()?.value_token().ok())
+    {
+        Some(name) => name,
+        None => return false,
+    };
+
+    let arguments = call
+        .arguments()
+        .map_or(0, |arguments| arguments.args().len());
+
+    match name.text_trimmed() {
+        "catch" => arguments >= 1,
+        "then" => arguments >= 2,
+        _ => false,
+    }
+}
@@ -0,0 +1,153 @@
+use crate::promises::is_promise;
+use crate::type_services::Typed;
+use rome_analyze::context::RuleContext;
+use rome_analyze::{declare_rule, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_js_syntax::{
+    JsAnyExpression, JsConditionalExpression, JsDoWhileStatement, JsForStatement, JsIfStatement,
+    JsLogicalExpression, JsLogicalOperator, JsUnaryExpression, JsUnaryOperator, JsWhileStatement,
+};
+use rome_rowan::{declare_node_union, AstNode};
+
+declare_rule! {
+    /// Disallow promises in boolean conditions
+    ///
+    /// A promise is always truthy, so a condition testing a promise instead
+    /// of the value it resolves to is always fulfilled. This is usually an
+    /// `await` missing in an `async` function.
+    ///
+    /// The tests of the `if` statements, the loops and the conditional
+    /// expressions are checked, as well as the operands of the `!`, `&&` and
+    /// `||` operators. The expressions are detected as promises from the
+    /// inferred types, like the calls of the `async` functions. The values
+    /// that are either a promise or a falsy value, like `null`, aren't
+    /// reported.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// async function isAllowed() {}
+    /// if (isAllowed()) {
+    ///     proceed();
+    /// }
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// async function isAllowed() {}
+    /// const denied = !isAllowed();
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// async function isAllowed() {}
+    /// async function run() {
+    ///     if (await isAllowed()) {
+    ///         proceed();
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub(crate) NoPromiseInCondition {
+        version: "11.0.0",
+        name: "noPromiseInCondition",
+        recommended: false,
+    }
+}
+
+declare_node_union! {
+    pub(crate) JsAnyConditional = JsIfStatement
+        | JsWhileStatement
+        | JsDoWhileStatement
+        | JsForStatement
+        | JsConditionalExpression
+        | JsUnaryExpression
+        | JsLogicalExpression
+}
+
+impl JsAnyConditional {
+    /// Returns the expression whose truthiness is tested
+    fn test(&self) -> Option<JsAnyExpression> {
+        match self {
+            JsAnyConditional::JsIfStatement(statement) => statement.test().ok(),
+            JsAnyConditional::JsWhileStatement(statement) => statement.test().ok(),
+            JsAnyConditional::JsDoWhileStatement(statement) => statement.test().ok(),
+            JsAnyConditional::JsForStatement(statement) => statement.test(),
+            JsAnyConditional::JsConditionalExpression(expression) => expression.test().ok(),
+            JsAnyConditional::JsUnaryExpression(expression) => match expression.operator() {
+                Ok(JsUnaryOperator::LogicalNot) => expression.argument().ok(),
+                _ => None,
+            },
+            JsAnyConditional::JsLogicalExpression(expression) => match expression.operator() {
+                Ok(JsLogicalOperator::LogicalAnd | JsLogicalOperator::LogicalOr) => {
+                    expression.left().ok()
+                }
+                _ => None,
+            },
+        }
+    }
+}
+
+impl Rule for NoPromiseInCondition {
+    type Query = Typed<JsAnyConditional>;
+    type State = JsAnyExpression;
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let mut tested = Vec::new();
+        if let Some(test) = ctx.query().test() {
+            collect_tested(test, &mut tested);
+        }
+
+        // The values that may also be falsy, like `null` or `undefined`, are
+        // legitimately tested
+        tested.retain(|expression| {
+            is_promise(ctx.types(), expression)
+                && ctx.types().expression_type(expression).truthiness() == Some(true)
+        });
+        tested
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, expression: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                expression.range(),
+                markup! {
+                    "This condition tests a promise, which is always truthy."
+                },
+            )
+            .note(markup! {
+                "Did you forget to "<Emphasis>"await"</Emphasis>" the promise?"
+            }),
+        )
+    }
+}
+
+/// Collects the expressions whose truthiness is tested when `test` is
+/// evaluated as a condition
+///
+/// The left operand of the `&&` and `||` expressions and the argument of the
+/// `!` expressions are tested by these expressions, which are matched by the
+/// rule on their own.
+fn collect_tested(test: JsAnyExpression, tested: &mut Vec<JsAnyExpression>) {
+    match test.omit_parentheses() {
+        JsAnyExpression::JsLogicalExpression(expression) => {
+            let is_tested = matches!(
+                expression.operator(),
+                Ok(JsLogicalOperator::LogicalAnd | JsLogicalOperator::LogicalOr)
+            );
+
+            match expression.right() {
+                Ok(right) if is_tested => collect_tested(right, tested),
+                _ => tested.push(expression.into()),
+            }
+        }
+        JsAnyExpression::JsUnaryExpression(expression)
+            if matches!(expression.operator(), Ok(JsUnaryOperator::LogicalNot)) => {}
+        test => tested.push(test),
+    }
+}
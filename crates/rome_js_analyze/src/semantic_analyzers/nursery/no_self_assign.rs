@@ -0,0 +1,171 @@
+use crate::control_flow::{function_node, function_nodes, LocalVariables, SemanticControlFlow};
+use rome_analyze::context::RuleContext;
+use rome_analyze::{declare_rule, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_js_syntax::{
+    JsAnyAssignment, JsAnyAssignmentPattern, JsAnyExpression, JsAssignmentExpression,
+    JsAssignmentOperator,
+};
+use rome_rowan::{AstNode, TextRange};
+
+declare_rule! {
+    /// Disallow assigning a variable the value it already holds
+    ///
+    /// Such an assignment has no effect. The assignments of a variable or a
+    /// property to itself, like `a = a` or `this.a = this.a`, are reported
+    /// wherever they are. This rule also reports assigning a local variable of
+    /// a function the value of another variable that was copied from it, if
+    /// neither variable was assigned in between.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// function f(a) {
+    ///     a = a;
+    ///     return a;
+    /// }
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// class A {
+    ///     reset() {
+    ///         this.a = this.a;
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// function f(a) {
+    ///     const b = a;
+    ///     a = b;
+    ///     return a;
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// function swap(a, b) {
+    ///     let tmp = a;
+    ///     a = b;
+    ///     b = tmp;
+    ///     return [a, b];
+    /// }
+    /// ```
+    pub(crate) NoSelfAssign {
+        version: "11.0.0",
+        name: "noSelfAssign",
+        recommended: false,
+    }
+}
+
+impl Rule for NoSelfAssign {
+    type Query = SemanticControlFlow;
+    type State = (String, TextRange);
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let (cfg, range) = ctx.query();
+        let function = match function_node(&ctx.root(), *range) {
+            Some(function) => function,
+            None => return Vec::new(),
+        };
+
+        // The assignments of a target to itself don't depend on the control
+        // flow, they're reported for any variable and for the properties
+        let mut signals: Vec<_> = function_nodes::<JsAssignmentExpression>(&function)
+            .into_iter()
+            .filter_map(|assignment| self_assigned_target(&assignment))
+            .collect();
+
+        let variables = LocalVariables::new(cfg, &function, ctx.model());
+        for definition in variables.self_assignments() {
+            let range = definition.node.text_trimmed_range();
+            if signals.iter().any(|(_, signal)| *signal == range) {
+                continue;
+            }
+
+            let variable = variables.variable(definition);
+            if let Ok(name) = variable.binding.name_token() {
+                signals.push((name.text_trimmed().to_string(), range));
+            }
+        }
+
+        signals.sort_by_key(|(_, range)| range.start());
+        signals
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, (name, range): &Self::State) -> Option<RuleDiagnostic> {
+        Some(RuleDiagnostic::new(
+            rule_category!(),
+            range,
+            markup! {
+                <Emphasis>{name}</Emphasis>" is assigned the value it already holds."
+            },
+        ))
+    }
+}
+
+/// Return the text and the range of the target of `assignment` if it's
+/// assigned to itself, like `a = a` or `this.a.b = this.a.b`
+fn self_assigned_target(assignment: &JsAssignmentExpression) -> Option<(String, TextRange)> {
+    if !matches!(assignment.operator().ok()?, JsAssignmentOperator::Assign) {
+        return None;
+    }
+
+    let left = match assignment.left().ok()? {
+        JsAnyAssignmentPattern::JsAnyAssignment(left) => left,
+        _ => return None,
+    };
+
+    let target = assignment_path(&left)?;
+    if expression_path(&assignment.right().ok()?)? == target {
+        Some((target, left.range()))
+    } else {
+        None
+    }
+}
+
+/// Return the text of an assignment to a variable or to a static member of
+/// an object, without parentheses
+fn assignment_path(assignment: &JsAnyAssignment) -> Option<String> {
+    match assignment {
+        JsAnyAssignment::JsIdentifierAssignment(assignment) => {
+            Some(assignment.name_token().ok()?.text_trimmed().to_string())
+        }
+        JsAnyAssignment::JsStaticMemberAssignment(assignment) => {
+            let object = expression_path(&assignment.object().ok()?)?;
+            let member = assignment.member().ok()?;
+            Some(format!("{object}.{}", member.syntax().text_trimmed()))
+        }
+        JsAnyAssignment::JsParenthesizedAssignment(assignment) => {
+            assignment_path(&assignment.assignment().ok()?)
+        }
+        _ => None,
+    }
+}
+
+/// Return the text of a reference to a variable, to `this` or to a static
+/// member of an object, without parentheses
+fn expression_path(expression: &JsAnyExpression) -> Option<String> {
+    match expression.clone().omit_parentheses() {
+        JsAnyExpression::JsIdentifierExpression(expression) => {
+            let name = expression.name().ok()?.value_token().ok()?;
+            Some(name.text_trimmed().to_string())
+        }
+        JsAnyExpression::JsThisExpression(_) => Some(String::from("this")),
+        JsAnyExpression::JsStaticMemberExpression(expression) => {
+            if expression.is_optional() {
+                return None;
+            }
+
+            let object = expression_path(&expression.object().ok()?)?;
+            let member = expression.member().ok()?;
+            Some(format!("{object}.{}", member.syntax().text_trimmed()))
+        }
+        _ => None,
+    }
+}
@@ -0,0 +1,102 @@
+use crate::control_flow::{function_node, LocalVariables, SemanticControlFlow};
+use rome_analyze::context::RuleContext;
+use rome_analyze::{declare_rule, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_rowan::{AstNode, TextRange};
+
+declare_rule! {
+    /// Disallow reading a variable declared with `let` before a value is assigned to it
+    ///
+    /// A variable declared with `let` without an initializer holds `undefined`
+    /// until it's assigned. Reading it on a path where it wasn't assigned yet
+    /// usually means one of the branches forgot to assign it.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// function f(condition) {
+    ///     let value;
+    ///     if (condition) {
+    ///         value = 1;
+    ///     }
+    ///     return value;
+    /// }
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// function f() {
+    ///     let value;
+    ///     console.log(value);
+    ///     value = 1;
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// function f(condition) {
+    ///     let value;
+    ///     if (condition) {
+    ///         value = 1;
+    ///     } else {
+    ///         value = 2;
+    ///     }
+    ///     return value;
+    /// }
+    /// ```
+    pub(crate) NoUseBeforeAssign {
+        version: "11.0.0",
+        name: "noUseBeforeAssign",
+        recommended: false,
+    }
+}
+
+impl Rule for NoUseBeforeAssign {
+    type Query = SemanticControlFlow;
+    type State = (String, TextRange, TextRange);
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let (cfg, range) = ctx.query();
+        let function = match function_node(&ctx.root(), *range) {
+            Some(function) => function,
+            None => return Vec::new(),
+        };
+
+        let variables = LocalVariables::new(cfg, &function, ctx.model());
+        variables
+            .unassigned_reads()
+            .into_iter()
+            .filter_map(|(variable, reference)| {
+                let name = variable.binding.name_token().ok()?;
+                Some((
+                    name.text_trimmed().to_string(),
+                    reference.text_trimmed_range(),
+                    variable.binding.syntax().text_trimmed_range(),
+                ))
+            })
+            .collect()
+    }
+
+    fn diagnostic(
+        _: &RuleContext<Self>,
+        (name, range, declaration): &Self::State,
+    ) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "The variable "<Emphasis>{name}</Emphasis>" may be read before a value is assigned to it."
+                },
+            )
+            .detail(
+                declaration,
+                markup! {"The variable is declared here without an initializer"},
+            ),
+        )
+    }
+}
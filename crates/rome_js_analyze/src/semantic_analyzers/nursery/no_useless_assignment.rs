@@ -0,0 +1,100 @@
+use crate::control_flow::{function_node, LocalVariables, SemanticControlFlow};
+use rome_analyze::context::RuleContext;
+use rome_analyze::{declare_rule, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_rowan::TextRange;
+
+declare_rule! {
+    /// Disallow assigning a value to a variable that is never read afterwards
+    ///
+    /// Such an assignment is useless: the value is either overwritten by a
+    /// later assignment or discarded when the function returns, which usually
+    /// indicates a mistake in the logic of the function.
+    ///
+    /// The variables that are never read at all are reported by
+    /// `noUnusedVariables` instead.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// function f() {
+    ///     let value = compute();
+    ///     value = computeAgain();
+    ///     return value;
+    /// }
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// function f(items) {
+    ///     let count;
+    ///     for (const item of items) {
+    ///         count = item.count;
+    ///     }
+    ///     count = 0;
+    ///     return count;
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// function f(condition) {
+    ///     let value = 0;
+    ///     if (condition) {
+    ///         value = 1;
+    ///     }
+    ///     return value;
+    /// }
+    /// ```
+    pub(crate) NoUselessAssignment {
+        version: "11.0.0",
+        name: "noUselessAssignment",
+        recommended: false,
+    }
+}
+
+impl Rule for NoUselessAssignment {
+    type Query = SemanticControlFlow;
+    type State = (String, TextRange);
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let (cfg, range) = ctx.query();
+        let function = match function_node(&ctx.root(), *range) {
+            Some(function) => function,
+            None => return Vec::new(),
+        };
+
+        let variables = LocalVariables::new(cfg, &function, ctx.model());
+        variables
+            .useless_definitions()
+            .into_iter()
+            .filter_map(|definition| {
+                let variable = variables.variable(definition);
+                let name = variable.binding.name_token().ok()?;
+                Some((
+                    name.text_trimmed().to_string(),
+                    definition.node.text_trimmed_range(),
+                ))
+            })
+            .collect()
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, (name, range): &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "The value assigned to "<Emphasis>{name}</Emphasis>" here is never read."
+                },
+            )
+            .note(markup! {
+                "The variable is either assigned again or goes out of scope before this value is read."
+            }),
+        )
+    }
+}
@@ -0,0 +1,428 @@
+use crate::control_flow::{
+    function_node, function_nodes, ControlFlowGraph, InstructionRanges, Location,
+    SemanticControlFlow,
+};
+use crate::react::hooks::{is_react_component_name, is_react_hook_name, react_hook_call_name};
+use crate::react::{is_react_call_api, ReactLibrary};
+use rome_analyze::context::RuleContext;
+use rome_analyze::{declare_rule, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_control_flow::{
+    builder::BlockId,
+    dataflow::{solve, Analysis, Direction},
+    Instruction, InstructionKind,
+};
+use rome_js_semantic::SemanticModel;
+use rome_js_syntax::{
+    JsAnyFunction, JsCallExpression, JsLanguage, JsSyntaxKind, JsSyntaxNode, JsVariableDeclarator,
+    TextRange,
+};
+use rome_rowan::AstNode;
+use std::collections::BTreeSet;
+
+declare_rule! {
+    /// Enforce that React hooks are called unconditionally at the top level of
+    /// components and custom hooks
+    ///
+    /// React relies on the order in which hooks are called to associate each
+    /// hook with its state, so every render of a component must call the same
+    /// hooks in the same order. Hooks must not be called conditionally, in a
+    /// loop or after an early `return`, and can only be called by React
+    /// components (functions whose name starts with an uppercase letter) and
+    /// custom hooks (functions whose name starts with `use`), so the callbacks
+    /// passed to other functions can't call hooks either. The anonymous
+    /// functions exported by default aren't checked.
+    ///
+    /// The functions named like `useState` or `React.useState` are considered
+    /// hooks.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// function Component({ isOpen }) {
+    ///     if (isOpen) {
+    ///         useEffect(() => {});
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// function Component({ items }) {
+    ///     for (const item of items) {
+    ///         useEffect(() => {});
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// function Component({ isHidden }) {
+    ///     if (isHidden) {
+    ///         return null;
+    ///     }
+    ///     const [value, setValue] = useState(0);
+    ///     return value;
+    /// }
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// function helper() {
+    ///     return useContext(Context);
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// function Component({ isOpen }) {
+    ///     const [value, setValue] = useState(0);
+    ///     useEffect(() => {
+    ///         if (isOpen) {
+    ///             setValue(1);
+    ///         }
+    ///     });
+    ///     return value;
+    /// }
+    /// ```
+    ///
+    /// ```js
+    /// function useCounter() {
+    ///     return useState(0);
+    /// }
+    /// ```
+    pub(crate) UseHookAtTopLevel {
+        version: "11.0.0",
+        name: "useHookAtTopLevel",
+        recommended: false,
+    }
+}
+
+/// Why a hook call breaks the rules of hooks
+pub(crate) enum Violation {
+    /// The hook is called by a function that is neither a component nor a hook
+    NotInComponent,
+    /// The hook may be called several times in a loop
+    InLoop,
+    /// The hook isn't called if the function returns early
+    AfterEarlyReturn(TextRange),
+    /// The hook is called in a branch that may not be evaluated
+    Conditional,
+}
+
+pub(crate) struct HookCall {
+    name: String,
+    range: TextRange,
+    violation: Violation,
+}
+
+impl Rule for UseHookAtTopLevel {
+    type Query = SemanticControlFlow;
+    type State = HookCall;
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let (cfg, range) = ctx.query();
+        let function = match function_node(&ctx.root(), *range) {
+            Some(function) => function,
+            None => return Vec::new(),
+        };
+
+        let hooks: Vec<_> = function_nodes::<JsCallExpression>(&function)
+            .into_iter()
+            .filter_map(|call| {
+                let name = react_hook_call_name(&call)?;
+                Some((name.text_trimmed().to_string(), call))
+            })
+            .collect();
+
+        if hooks.is_empty() {
+            return Vec::new();
+        }
+
+        match is_component_or_hook(&function, ctx.model()) {
+            Some(true) => {}
+            Some(false) => {
+                return hooks
+                    .into_iter()
+                    .map(|(name, call)| HookCall {
+                        name,
+                        range: call.syntax().text_trimmed_range(),
+                        violation: Violation::NotInComponent,
+                    })
+                    .collect();
+            }
+            None => return Vec::new(),
+        }
+
+        let instructions = InstructionRanges::new(cfg);
+        let locations: Vec<_> = hooks
+            .iter()
+            .map(|(_, call)| instructions.innermost(call.syntax().text_trimmed_range()))
+            .collect();
+
+        let skipped = skipped_hooks(cfg, &locations);
+
+        hooks
+            .into_iter()
+            .zip(locations)
+            .enumerate()
+            .filter_map(|(index, ((name, call), location))| {
+                let range = call.syntax().text_trimmed_range();
+                let violation = if is_in_loop(cfg, location?) {
+                    Violation::InLoop
+                } else {
+                    let returns = skipped.get(index)?.as_ref()?;
+                    match returns.iter().find(|exit| exit.start() < range.start()) {
+                        Some(early_return) => Violation::AfterEarlyReturn(*early_return),
+                        None => Violation::Conditional,
+                    }
+                };
+
+                Some(HookCall {
+                    name,
+                    range,
+                    violation,
+                })
+            })
+            .collect()
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let name = &state.name;
+        let diagnostic = match &state.violation {
+            Violation::NotInComponent => RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "The hook "<Emphasis>{name}</Emphasis>" is called by a function that is neither a React component nor a custom hook."
+                },
+            ),
+            Violation::InLoop => RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "The hook "<Emphasis>{name}</Emphasis>" is called in a loop."
+                },
+            ),
+            Violation::AfterEarlyReturn(early_return) => RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "The hook "<Emphasis>{name}</Emphasis>" is called after an early return."
+                },
+            )
+            .detail(
+                early_return,
+                markup! {"The hook isn't called if the function returns here"},
+            ),
+            Violation::Conditional => RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "The hook "<Emphasis>{name}</Emphasis>" is called conditionally."
+                },
+            ),
+        };
+
+        Some(diagnostic.note(markup! {
+            "Hooks must be called in the same order on every render, at the top level of a React component or a custom hook."
+        }))
+    }
+}
+
+/// Returns whether `function` is a React component or a custom hook,
+/// according to its name or to the name of the variable it's assigned to, or
+/// `None` if the function is an anonymous default export
+fn is_component_or_hook(function: &JsSyntaxNode, model: &SemanticModel) -> Option<bool> {
+    let function = match JsAnyFunction::cast_ref(function) {
+        Some(function) => function,
+        None => return Some(false),
+    };
+
+    let is_component_or_hook_name =
+        |name: &str| is_react_component_name(name) || is_react_hook_name(name);
+
+    if let Ok(Some(id)) = function.id() {
+        let name = id
+            .as_js_identifier_binding()
+            .and_then(|binding| binding.name_token().ok());
+        return Some(name.map_or(false, |name| is_component_or_hook_name(name.text_trimmed())));
+    }
+
+    let mut node = function.into_syntax();
+    while let Some(parent) = node.parent() {
+        match parent.kind() {
+            JsSyntaxKind::JS_PARENTHESIZED_EXPRESSION => {}
+            // The render functions wrapped in `memo` or `forwardRef` are
+            // components, the other callbacks are not
+            JsSyntaxKind::JS_CALL_ARGUMENT_LIST => {
+                let callee = parent
+                    .grand_parent()
+                    .and_then(JsCallExpression::cast)
+                    .and_then(|call| call.callee().ok());
+
+                return Some(callee.map_or(false, |callee| {
+                    is_react_call_api(callee.clone(), model, ReactLibrary::React, "memo")
+                        || is_react_call_api(callee, model, ReactLibrary::React, "forwardRef")
+                }));
+            }
+            JsSyntaxKind::JS_INITIALIZER_CLAUSE => {
+                let name = parent
+                    .parent()
+                    .and_then(JsVariableDeclarator::cast)
+                    .and_then(|declarator| declarator.id().ok())
+                    .and_then(|id| id.as_js_any_binding()?.as_js_identifier_binding().cloned())
+                    .and_then(|binding| binding.name_token().ok());
+
+                return Some(
+                    name.map_or(false, |name| is_component_or_hook_name(name.text_trimmed())),
+                );
+            }
+            // The name of an anonymous default export is chosen by the
+            // modules importing it
+            JsSyntaxKind::JS_EXPORT_DEFAULT_DECLARATION_CLAUSE
+            | JsSyntaxKind::JS_EXPORT_DEFAULT_EXPRESSION_CLAUSE => return None,
+            _ => return Some(false),
+        }
+
+        node = parent;
+    }
+
+    Some(false)
+}
+
+/// Computes the hooks that may not have been called yet before each instruction
+struct PendingHooks<'a> {
+    locations: &'a [Option<Location>],
+}
+
+impl Analysis<JsLanguage> for PendingHooks<'_> {
+    type Domain = BTreeSet<usize>;
+
+    const DIRECTION: Direction = Direction::Forward;
+
+    fn bottom(&self) -> Self::Domain {
+        BTreeSet::new()
+    }
+
+    fn boundary(&self) -> Self::Domain {
+        (0..self.locations.len()).collect()
+    }
+
+    fn transfer(
+        &self,
+        state: &mut Self::Domain,
+        block: BlockId,
+        index: usize,
+        _: &Instruction<JsLanguage>,
+    ) {
+        for (hook, location) in self.locations.iter().enumerate() {
+            if *location == Some((block.index(), index)) {
+                state.remove(&hook);
+            }
+        }
+    }
+}
+
+/// Return, for each hook, the ranges of the `return` statements reached
+/// without calling the hook, or `None` if the hook is called on every path
+/// completing the function
+fn skipped_hooks(
+    cfg: &ControlFlowGraph,
+    locations: &[Option<Location>],
+) -> Vec<Option<Vec<TextRange>>> {
+    let analysis = PendingHooks { locations };
+    let results = solve(cfg, &analysis);
+
+    let mut skipped = vec![None; locations.len()];
+    let mut skip = |state: &BTreeSet<usize>, exit: Option<TextRange>| {
+        for hook in state {
+            let returns: &mut Vec<_> = skipped[*hook].get_or_insert_with(Vec::new);
+            returns.extend(exit);
+        }
+    };
+
+    for (block, basic_block) in cfg.blocks.iter().enumerate() {
+        let block = BlockId::new(block);
+        let mut state = results.entry_state(block).clone();
+        let mut is_terminated = false;
+
+        for (index, instruction) in basic_block.instructions.iter().enumerate() {
+            analysis.transfer(&mut state, block, index, instruction);
+
+            match instruction.kind {
+                InstructionKind::Statement => {}
+                InstructionKind::Jump { conditional, .. } => {
+                    if !conditional {
+                        is_terminated = true;
+                        break;
+                    }
+                }
+                InstructionKind::Return => {
+                    // The paths throwing an exception don't complete the render
+                    let node = instruction.node.as_ref();
+                    if let Some(node) = node.and_then(|node| node.as_node()) {
+                        if node.kind() == JsSyntaxKind::JS_RETURN_STATEMENT {
+                            skip(&state, Some(node.text_trimmed_range()));
+                        }
+                    }
+
+                    is_terminated = true;
+                    break;
+                }
+            }
+        }
+
+        // The blocks without a terminator fall through to the end of the function
+        if !is_terminated {
+            skip(&state, None);
+        }
+    }
+
+    skipped
+}
+
+/// Returns `true` if the instruction at `location` may be evaluated again
+/// after it was evaluated once
+fn is_in_loop(cfg: &ControlFlowGraph, (block, _): Location) -> bool {
+    let mut visited = vec![false; cfg.blocks.len()];
+    let mut queue = successors(cfg, block);
+
+    while let Some(current) = queue.pop() {
+        if current == block {
+            return true;
+        }
+
+        let index = current as usize;
+        if !visited[index] {
+            visited[index] = true;
+            queue.extend(successors(cfg, current));
+        }
+    }
+
+    false
+}
+
+/// Return the blocks the control flow may reach when leaving `block`
+fn successors(cfg: &ControlFlowGraph, block: u32) -> Vec<u32> {
+    let basic_block = &cfg.blocks[block as usize];
+
+    let handlers = basic_block
+        .exception_handlers
+        .first()
+        .into_iter()
+        .chain(basic_block.cleanup_handlers.first())
+        .map(|handler| handler.target);
+
+    let jumps = basic_block
+        .instructions
+        .iter()
+        .filter_map(|instruction| match instruction.kind {
+            InstructionKind::Jump { block, .. } => Some(block.index()),
+            _ => None,
+        });
+
+    handlers.chain(jumps).collect()
+}
@@ -0,0 +1,203 @@
+use crate::module_graph_services::ModuleGraphServices;
+use rome_analyze::{
+    FromServices, MissingServicesDiagnostic, ModuleGraph, Phase, Phases, QueryKey, QueryMatch,
+    Queryable, RuleKey, ServiceBag, Visitor, VisitorContext, VisitorFinishContext,
+};
+use rome_diagnostics::file::FileId;
+use rome_js_semantic::{semantic_model, SemanticModel, SemanticModelOptions};
+use rome_js_syntax::{JsLanguage, SourceType, WalkEvent};
+use rome_js_type_info::{ImportResolver, Type, TypeModel};
+use rome_rowan::{AstNode, SyntaxNode};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+/// Maximum number of modules followed to resolve the type of an imported
+/// declaration, breaking the cycles between modules re-exporting each other
+const MAX_IMPORT_DEPTH: usize = 4;
+
+pub struct TypeServices {
+    types: TypeModel,
+}
+
+impl TypeServices {
+    pub fn types(&self) -> &TypeModel {
+        &self.types
+    }
+
+    pub fn model(&self) -> &SemanticModel {
+        self.types.semantic_model()
+    }
+}
+
+impl FromServices for TypeServices {
+    fn from_services(
+        rule_key: &RuleKey,
+        services: &ServiceBag,
+    ) -> Result<Self, MissingServicesDiagnostic> {
+        let types = services
+            .get_service()
+            .ok_or_else(|| MissingServicesDiagnostic::new(rule_key.rule_name(), &["TypeModel"]))?;
+
+        Ok(Self { types })
+    }
+}
+
+impl Phase for TypeServices {
+    fn phase() -> Phases {
+        Phases::Semantic
+    }
+}
+
+/// The [TypeServices] types can be used as a queryable to get an instance
+/// of the whole [TypeModel] without matching on a specific AST node
+impl Queryable for TypeServices {
+    type Output = TypeModel;
+    type Language = JsLanguage;
+    type Services = Self;
+
+    const KEY: QueryKey<Self::Language> = QueryKey::SemanticModel;
+
+    fn unwrap_match(services: &ServiceBag, query: &QueryMatch<Self::Language>) -> Self::Output {
+        match query {
+            QueryMatch::SemanticModel(..) => services
+                .get_service::<TypeModel>()
+                .expect("TypeModel service is not registered"),
+            _ => panic!("tried to unwrap unsupported QueryMatch kind, expected SemanticModel"),
+        }
+    }
+}
+
+/// Query type usable by lint rules **that use the inferred types** to match on
+/// specific [AstNode] types
+#[derive(Clone)]
+pub struct Typed<N>(pub N);
+
+impl<N> Queryable for Typed<N>
+where
+    N: AstNode<Language = JsLanguage> + 'static,
+{
+    type Output = N;
+    type Language = JsLanguage;
+    type Services = TypeServices;
+
+    /// Match on [QueryMatch::Syntax] if the kind of the syntax node matches
+    /// the kind set of `N`
+    const KEY: QueryKey<Self::Language> = QueryKey::Syntax(N::KIND_SET);
+
+    fn unwrap_match(_: &ServiceBag, query: &QueryMatch<Self::Language>) -> Self::Output {
+        match query {
+            QueryMatch::Syntax(node) => N::unwrap_cast(node.clone()),
+            _ => panic!("tried to unwrap unsupported QueryMatch kind, expected Syntax"),
+        }
+    }
+}
+
+/// Builds the type model of the analyzed module once the semantic model has
+/// been built, and inserts it in the services of the rules
+pub(crate) struct TypeModelBuilderVisitor;
+
+impl Visitor for TypeModelBuilderVisitor {
+    type Language = JsLanguage;
+
+    fn visit(&mut self, _: &WalkEvent<SyntaxNode<JsLanguage>>, _: VisitorContext<JsLanguage>) {}
+
+    fn finish(self: Box<Self>, ctx: VisitorFinishContext<JsLanguage>) {
+        if let Some(model) = ctx.services.get_service::<SemanticModel>() {
+            let types = type_model(model, ctx.services);
+            ctx.services.insert_service(types);
+        }
+    }
+}
+
+/// Build the type model of the analyzed module, resolving its imports through
+/// the module graph when the module is analyzed as part of a project
+fn type_model(model: SemanticModel, services: &ServiceBag) -> TypeModel {
+    let types = TypeModel::new(model);
+
+    let project = match services.get_service::<ModuleGraphServices>() {
+        Some(project) => project,
+        None => return types,
+    };
+
+    match project.shared_graph() {
+        Some(graph) => types.with_import_resolver(Arc::new(ModuleGraphImports {
+            graph,
+            path: project.path().to_path_buf(),
+            depth: 0,
+            exports: Arc::default(),
+        })),
+        None => types,
+    }
+}
+
+/// The types of the exports of each module, by name
+type ModuleExports = HashMap<PathBuf, Arc<HashMap<String, Type>>>;
+
+/// Resolves the imported declarations by inferring their types in the
+/// modules of the graph they're imported from
+struct ModuleGraphImports {
+    graph: Arc<dyn ModuleGraph>,
+    /// The path of the importing module
+    path: PathBuf,
+    /// The number of modules followed so far
+    depth: usize,
+    /// The exports of the modules inferred so far, shared by the resolvers of
+    /// all the modules followed from the analyzed module
+    exports: Arc<Mutex<ModuleExports>>,
+}
+
+impl ModuleGraphImports {
+    /// Return the types of the exports of the module at `path`, parsing and
+    /// inferring them on the first lookup of the module
+    fn module_exports(&self, path: &Path) -> Option<Arc<HashMap<String, Type>>> {
+        if let Some(exports) = self.exports.lock().ok()?.get(path) {
+            return Some(exports.clone());
+        }
+
+        let source_text = self.graph.source_text(path)?;
+        let source_type = SourceType::try_from(path).ok()?;
+
+        let parsed = rome_js_parser::parse(&source_text, FileId::zero(), source_type);
+        let model = semantic_model(&parsed.tree(), SemanticModelOptions::default());
+
+        // The lock isn't held while inferring the types, the exports may
+        // depend on the exports of other modules
+        let exports = TypeModel::new(model)
+            .with_import_resolver(Arc::new(ModuleGraphImports {
+                graph: self.graph.clone(),
+                path: path.to_path_buf(),
+                depth: self.depth + 1,
+                exports: self.exports.clone(),
+            }))
+            .export_types();
+
+        let exports = Arc::new(exports.into_iter().collect::<HashMap<_, _>>());
+        self.exports
+            .lock()
+            .ok()?
+            .insert(path.to_path_buf(), exports.clone());
+
+        Some(exports)
+    }
+}
+
+impl ImportResolver for ModuleGraphImports {
+    fn export_type(&self, specifier: &str, name: &str) -> Type {
+        if self.depth >= MAX_IMPORT_DEPTH {
+            return Type::Unknown;
+        }
+
+        let path = self
+            .graph
+            .imports(&self.path)
+            .unwrap_or_default()
+            .into_iter()
+            .find(|import| import.specifier == specifier)
+            .and_then(|import| import.resolved_path().map(|path| path.to_path_buf()));
+
+        path.and_then(|path| self.module_exports(&path))
+            .and_then(|exports| exports.get(name).cloned())
+            .unwrap_or(Type::Unknown)
+    }
+}
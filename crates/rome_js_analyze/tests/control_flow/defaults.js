@@ -0,0 +1,2 @@
+function f(a = 1) {}
+const { b = a } = c;
@@ -0,0 +1,67 @@
+use rome_analyze::{AnalysisFilter, AnalyzerOptions, ControlFlow, Never, QueryMatch, RuleFilter};
+use rome_diagnostics::file::FileId;
+use rome_js_parser::parse;
+use std::{cmp::Reverse, ffi::OsStr, fmt::Write, fs::read_to_string, path::Path, slice};
+
+tests_macros::gen_tests! {"tests/control_flow/**/*.{js,ts}", crate::run_test, "module"}
+
+/// Snapshot the control flow graphs of the functions (and of the module
+/// itself) in the input file, sorted in source order
+fn run_test(input: &'static str, _: &str, _: &str, _: &str) {
+    let input_file = Path::new(input);
+    let file_name = input_file.file_name().and_then(OsStr::to_str).unwrap();
+    let input_code = read_to_string(input_file)
+        .unwrap_or_else(|err| panic!("failed to read {:?}: {:?}", input_file, err));
+
+    let source_type = input_file.try_into().unwrap();
+    let parsed = parse(&input_code, FileId::zero(), source_type);
+    let root = parsed.tree();
+
+    let rule_filter = RuleFilter::Rule("correctness", "noUnreachable");
+    let filter = AnalysisFilter {
+        enabled_rules: Some(slice::from_ref(&rule_filter)),
+        ..AnalysisFilter::default()
+    };
+
+    let mut graphs = Vec::new();
+    let options = AnalyzerOptions::default();
+    rome_js_analyze::analyze_with_inspect_matcher(
+        FileId::zero(),
+        &root,
+        filter,
+        |params| {
+            if let QueryMatch::ControlFlowGraph(cfg, range) = &params.query {
+                graphs.push((*range, cfg.to_string()));
+            }
+        },
+        &options,
+        |_| ControlFlow::<Never>::Continue(()),
+    );
+
+    // Graphs are emitted when exiting their function, order them by position
+    // with the enclosing functions first
+    graphs.sort_by_key(|(range, _)| (range.start(), Reverse(range.len())));
+
+    let mut snapshot = String::new();
+
+    writeln!(snapshot, "# Input").unwrap();
+    writeln!(snapshot, "```js").unwrap();
+    writeln!(snapshot, "{}", input_code).unwrap();
+    writeln!(snapshot, "```").unwrap();
+    writeln!(snapshot).unwrap();
+
+    writeln!(snapshot, "# Control flow graphs").unwrap();
+    for (_, graph) in graphs {
+        writeln!(snapshot, "```mermaid").unwrap();
+        write!(snapshot, "{}", graph).unwrap();
+        writeln!(snapshot, "```").unwrap();
+        writeln!(snapshot).unwrap();
+    }
+
+    insta::with_settings!({
+        prepend_module_to_snapshot => false,
+        snapshot_path => input_file.parent().unwrap(),
+    }, {
+        insta::assert_snapshot!(file_name, snapshot, file_name);
+    });
+}
@@ -43,11 +43,11 @@ fn run_test(input: &'static str, _: &str, _: &str, _: &str) {
 
     let mut diagnostics = Vec::new();
     let mut code_fixes = Vec::new();
-    let options = AnalyzerOptions::default();
+    let options = rule_options(input_file, group, rule);
     rome_js_analyze::analyze(FileId::zero(), &root, filter, &options, |event| {
         if let Some(mut diag) = event.diagnostic() {
             diag.set_severity(Severity::Warning);
-            if let Some(action) = event.action() {
+            for action in event.actions() {
                 check_code_action(input_file, &input_code, source_type, &action);
                 diag.add_code_suggestion(action.into());
             }
@@ -56,7 +56,7 @@ fn run_test(input: &'static str, _: &str, _: &str, _: &str) {
             return ControlFlow::Continue(());
         }
 
-        if let Some(action) = event.action() {
+        for action in event.actions() {
             check_code_action(input_file, &input_code, source_type, &action);
             code_fixes.push(code_fix_to_string(&input_code, action));
         }
@@ -100,6 +100,25 @@ fn run_test(input: &'static str, _: &str, _: &str, _: &str) {
     });
 }
 
+/// Load the options of the rule from the `<name>.options.json` file next to
+/// the test file, if it exists
+fn rule_options(input_file: &Path, group: &str, rule: &str) -> AnalyzerOptions {
+    let mut options = AnalyzerOptions::default();
+
+    let options_file = input_file.with_extension("options.json");
+    if let Ok(json) = read_to_string(&options_file) {
+        let value = serde_json::from_str(&json)
+            .unwrap_or_else(|err| panic!("failed to parse {:?}: {:?}", options_file, err));
+        let rule_key = rome_js_analyze::metadata()
+            .find_rule(group, rule)
+            .unwrap_or_else(|| panic!("unknown rule {group}/{rule}"));
+
+        options.configuration.rules.push_rule(rule_key, value);
+    }
+
+    options
+}
+
 /// The test runner for the analyzer is currently designed to have a
 /// one-to-one mapping between test case and analyzer rules, so each testing
 /// file will be run through the analyzer with only the rule corresponding
@@ -0,0 +1,55 @@
+function ShortCircuits() {
+    a && b;
+    a || b;
+    a ?? b;
+    a && b || c;
+    (a || b) && c;
+    a ? b : c;
+    a && (b ?? c);
+    x &&= a;
+    x ||= b;
+    x ??= c;
+    a && b && c && d;
+    a && b;
+    a || b;
+    a ?? b;
+    a && b || c;
+    (a || b) && c;
+    a ? b : c;
+    a && (b ?? c);
+    x &&= a;
+    x ||= b;
+    x ??= c;
+    a && b && c && d;
+
+    return;
+    afterReturn();
+}
+
+function OptionalChains() {
+    a?.b;
+    a?.b?.c;
+    a?.();
+    a?.[b];
+    a?.b.c?.d;
+    a?.b ?? c;
+    a?.b?.();
+    a?.[b]?.[c];
+    a?.b && a.b?.c;
+    (a?.b)?.c;
+    a?.b?.c?.d?.e;
+    a?.b;
+    a?.b?.c;
+    a?.();
+    a?.[b];
+    a?.b.c?.d;
+    a?.b ?? c;
+    a?.b?.();
+    a?.[b]?.[c];
+    a?.b && a.b?.c;
+    (a?.b)?.c;
+    a?.b?.c?.d?.e;
+
+    return;
+    afterReturn();
+}
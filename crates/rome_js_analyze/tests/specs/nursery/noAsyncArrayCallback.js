@@ -0,0 +1,8 @@
+async function upload(file) {}
+files.forEach(async (file) => upload(file));
+files.filter(async function (file) {});
+files.forEach(upload);
+files.forEach((file) => upload(file));
+files.map(async (file) => upload(file));
+files.forEach((file) => {});
+files.sort(async (a, b) => a - b);
\ No newline at end of file
@@ -0,0 +1,19 @@
+async function f(items, stream) {
+    for (const item of items) await item;
+    for (let i = await start(); i < 10; i++) {}
+    for (const item of await items) {}
+    while (await next()) {}
+    do {} while (await next());
+    for await (const chunk of stream) await chunk;
+    for (const item of items) {
+        items.map(async (item) => await item);
+    }
+    for (const key in items) {
+        await key;
+    }
+    for (const item of items) {
+        for await (const chunk of item) {
+            await chunk;
+        }
+    }
+}
\ No newline at end of file
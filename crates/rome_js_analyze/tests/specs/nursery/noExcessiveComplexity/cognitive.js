@@ -0,0 +1,31 @@
+function elseIf(a) {
+    if (a === 1) {
+        return "one";
+    } else if (a === 2) {
+        return "two";
+    } else if (a === 3) {
+        return "three";
+    } else {
+        return "many";
+    }
+}
+
+function sequences(a, b, c, d) {
+    return (a && b && c) || d || (c && d) || (a && !b);
+}
+
+function labels(rows) {
+    outer: for (const row of rows) {
+        for (const cell of row) {
+            if (cell) continue outer;
+        }
+    }
+}
+
+function atMaximum(a, b) {
+    if (a) {
+        return b || a;
+    } else {
+        return b;
+    }
+}
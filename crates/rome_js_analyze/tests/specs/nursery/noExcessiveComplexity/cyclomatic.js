@@ -0,0 +1,13 @@
+function f(a, b) {
+    if (a) {
+        return b ? 1 : 2;
+    }
+    return a && b;
+}
+
+function atMaximum(a, b) {
+    if (a) {
+        return b;
+    }
+    return a ?? b;
+}
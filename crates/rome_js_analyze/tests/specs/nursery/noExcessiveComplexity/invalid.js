@@ -0,0 +1,9 @@
+function f(a) {
+    for (const b of a) {
+        for (const c of b) {
+            for (const d of c) {
+                if (d.x) while (d.y) return d.z ? b : c;
+            }
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,25 @@
+function describe(value) {
+    if (typeof value === "string") {
+        return `"${value}"`;
+    } else if (Array.isArray(value)) {
+        return value.map(describe).join(", ");
+    } else if (value && typeof value === "object") {
+        return Object.keys(value).join(", ");
+    } else {
+        return String(value);
+    }
+}
+
+function find(rows, predicate) {
+    outer: for (const row of rows) {
+        for (const cell of row) {
+            if (!cell) continue outer;
+            if (predicate(cell)) return cell;
+        }
+    }
+    return null;
+}
+
+function isVisible(node) {
+    return (node.isEnabled && !node.isHidden) || node.isPinned || node.parent?.isExpanded;
+}
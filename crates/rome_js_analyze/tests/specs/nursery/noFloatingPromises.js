@@ -0,0 +1,21 @@
+async function save() {}
+function load() { return fetch("/api"); }
+save();
+load().then(render);
+save().then(render, report);
+save().catch(report);
+void save();
+unknown();
+async function run() {
+    await save();
+    this.save();
+}
+class Store {
+    async save() {}
+    sync() { this.save(); }
+}
+function pending() { if (ready) { return save(); } }
+pending()?.catch(report);
+(ready ? save() : null)?.then(render, report);
+function either() { return Math.random() > 0.5 && save(); }
+void either();
\ No newline at end of file
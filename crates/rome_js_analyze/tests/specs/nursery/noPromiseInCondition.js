@@ -0,0 +1,16 @@
+async function check() {}
+if (check()) {}
+while (ready && check()) {}
+const denied = !check();
+const value = check() || fallback;
+const result = check() ? 1 : 2;
+for (; (check());) {}
+if (await check()) {}
+const cached = value ?? check();
+do {} while (ready || !check());
+const conditional = ready ? check() : null;
+if (conditional) {}
+function pending() { if (ready) { return check(); } }
+while (pending()) {}
+const union = Math.random() > 0.5 && check();
+if (union || fallback) {}
\ No newline at end of file
@@ -0,0 +1,25 @@
+function f(a, b) {
+    a = a;
+    const c = b;
+    b = c;
+    return a + b;
+}
+
+function outer() {
+    let captured = 1;
+    captured = captured;
+    return () => captured;
+}
+
+class A {
+    reset() {
+        this.a = this.a;
+        this.b.c = (this.b.c);
+    }
+}
+
+let shared = 1;
+shared = shared;
+
+export let exported = 1;
+exported = exported;
@@ -0,0 +1,53 @@
+function swap(a, b) {
+    let tmp = a;
+    a = b;
+    b = tmp;
+    return [a, b];
+}
+
+function rotate(items) {
+    let first = items[0];
+    let current = first;
+    for (const item of items) {
+        current = first;
+        first = item;
+    }
+    return current;
+}
+
+function restore(value) {
+    let last = value;
+    try {
+        value = compute(value);
+    } catch (error) {
+        value = last;
+    }
+    return value;
+}
+
+function letInLoop(items) {
+    const result = [];
+    for (const item of items) {
+        let value = item;
+        value = transform(value);
+        result.push(value);
+    }
+    return result;
+}
+
+function closure(value) {
+    const get = () => value;
+    const set = (next) => {
+        value = next;
+    };
+    return [get, set];
+}
+
+class B {
+    copy(other) {
+        this.a = other.a;
+        this.b = this.c;
+        this.d = this.d.e;
+        this.f = this.f + 1;
+    }
+}
@@ -0,0 +1,7 @@
+function f(condition) {
+    let a;
+    if (condition) {
+        a = 1;
+    }
+    return a;
+}
\ No newline at end of file
@@ -0,0 +1,50 @@
+function loop(items) {
+    let total;
+    total = 0;
+    for (const item of items) {
+        total += item;
+    }
+    return total;
+}
+
+function whileLoop() {
+    let line;
+    while ((line = next()) !== null) {
+        use(line);
+    }
+}
+
+function branches(condition) {
+    let value;
+    if (condition) {
+        value = 1;
+    } else {
+        value = 2;
+    }
+    return value;
+}
+
+function tryCatch(text) {
+    let result;
+    try {
+        result = JSON.parse(text);
+    } catch (error) {
+        result = null;
+    }
+    return result;
+}
+
+function letInLoop(items) {
+    for (const item of items) {
+        let doubled;
+        doubled = item * 2;
+        use(doubled);
+    }
+}
+
+function closure() {
+    let value;
+    const read = () => value;
+    value = 1;
+    return read;
+}
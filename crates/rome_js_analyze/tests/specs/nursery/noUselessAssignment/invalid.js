@@ -0,0 +1,9 @@
+function f(condition) {
+    let a = 1;
+    a = 2;
+    let b = 0;
+    if (condition) {
+        b = 1;
+    }
+    return a + b;
+}
\ No newline at end of file
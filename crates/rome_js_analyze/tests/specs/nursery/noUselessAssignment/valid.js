@@ -0,0 +1,49 @@
+function loop(items) {
+    let total = 0;
+    for (const item of items) {
+        total = total + item;
+    }
+    return total;
+}
+
+function retry(task) {
+    let attempts = 0;
+    while (true) {
+        try {
+            return task();
+        } catch (error) {
+            attempts = attempts + 1;
+            if (attempts > 3) {
+                throw error;
+            }
+        }
+    }
+}
+
+function parse(text) {
+    let result = null;
+    try {
+        result = JSON.parse(text);
+    } catch (error) {
+        report(error);
+    }
+    return result;
+}
+
+function letInLoop(items) {
+    let last;
+    for (let i = 0; i < items.length; i++) {
+        let item = items[i];
+        if (item > 0) {
+            last = item;
+        }
+    }
+    return last;
+}
+
+function closure() {
+    let value = 0;
+    const read = () => value;
+    value = 1;
+    return read;
+}
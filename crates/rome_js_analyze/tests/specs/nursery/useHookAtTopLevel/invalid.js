@@ -0,0 +1,45 @@
+function helper() {
+    useEffect(() => {});
+}
+
+function Component({ a }) {
+    if (a) {
+        return null;
+    }
+    return useMemo(() => a);
+}
+
+function List({ items }) {
+    for (const item of items) {
+        useEffect(() => {});
+    }
+    return null;
+}
+
+function Toggle({ isOpen }) {
+    if (isOpen) {
+        useState(0);
+    }
+    return null;
+}
+
+const useValue = (condition) => {
+    const value = condition && useContext(Context);
+    return value;
+};
+
+items.map((item) => {
+    if (item) {
+        useState(0);
+    }
+});
+
+promise.then(function () {
+    useEffect(() => {});
+});
+
+function Effect() {
+    useEffect(() => {
+        useContext(Context);
+    });
+}
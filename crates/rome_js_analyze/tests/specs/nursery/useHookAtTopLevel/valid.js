@@ -0,0 +1,28 @@
+function Component({ isOpen }) {
+    const [value, setValue] = useState(0);
+    useEffect(() => {
+        if (isOpen) {
+            setValue(1);
+        }
+    });
+    return value;
+}
+
+function useCounter() {
+    return useState(0);
+}
+
+const Memoized = React.memo(function ({ value }) {
+    const [state] = useState(value);
+    return state;
+});
+
+const Forwarded = React.forwardRef((props, ref) => {
+    useImperativeHandle(ref, () => ({}));
+    return null;
+});
+
+export default function () {
+    const [state] = useState(0);
+    return state;
+}
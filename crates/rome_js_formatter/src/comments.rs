@@ -7,7 +7,9 @@ use rome_formatter::{
     },
     write,
 };
-use rome_js_syntax::suppression::{parse_suppression_comment, SuppressionCategory};
+use rome_js_syntax::suppression::{
+    parse_suppression_comment, SuppressionCategory, SuppressionKind,
+};
 use rome_js_syntax::{
     JsAnyClass, JsAnyName, JsAnyRoot, JsAnyStatement, JsArrayHole, JsArrowFunctionExpression,
     JsBlockStatement, JsCallArguments, JsCatchClause, JsEmptyStatement, JsFinallyClause,
@@ -127,6 +129,13 @@ pub fn is_doc_comment(comment: &SyntaxTriviaPieceComments<JsLanguage>) -> bool {
     })
 }
 
+fn is_format_suppression_of_kind(text: &str, kind: SuppressionKind) -> bool {
+    parse_suppression_comment(text)
+        .filter(|suppression| suppression.kind == kind)
+        .flat_map(|suppression| suppression.categories)
+        .any(|(category, _)| category == SuppressionCategory::Format)
+}
+
 #[derive(Eq, PartialEq, Copy, Clone, Debug, Default)]
 pub struct JsCommentStyle;
 
@@ -134,9 +143,15 @@ impl CommentStyle for JsCommentStyle {
     type Language = JsLanguage;
 
     fn is_suppression(text: &str) -> bool {
-        parse_suppression_comment(text)
-            .flat_map(|suppression| suppression.categories)
-            .any(|(category, _)| category == SuppressionCategory::Format)
+        is_format_suppression_of_kind(text, SuppressionKind::Next)
+    }
+
+    fn is_suppression_range_start(text: &str) -> bool {
+        is_format_suppression_of_kind(text, SuppressionKind::RangeStart)
+    }
+
+    fn is_suppression_range_end(text: &str) -> bool {
+        is_format_suppression_of_kind(text, SuppressionKind::RangeEnd)
     }
 
     fn get_comment_kind(comment: &SyntaxTriviaPieceComments<JsLanguage>) -> CommentKind {
@@ -145,6 +145,9 @@ pub struct JsFormatOptions {
     /// Print trailing commas wherever possible in multi-line comma-separated syntactic structures. Defaults to "all".
     trailing_comma: TrailingComma,
 
+    /// Whether objects written on multiple lines are kept expanded. Defaults to preserve.
+    object_wrap: ObjectWrap,
+
     /// Information related to the current file
     source_type: SourceType,
 }
@@ -158,6 +161,7 @@ impl JsFormatOptions {
             quote_style: QuoteStyle::default(),
             quote_properties: QuoteProperties::default(),
             trailing_comma: TrailingComma::default(),
+            object_wrap: ObjectWrap::default(),
         }
     }
 
@@ -186,6 +190,11 @@ impl JsFormatOptions {
         self
     }
 
+    pub fn with_object_wrap(mut self, object_wrap: ObjectWrap) -> Self {
+        self.object_wrap = object_wrap;
+        self
+    }
+
     pub fn quote_style(&self) -> QuoteStyle {
         self.quote_style
     }
@@ -202,6 +211,10 @@ impl JsFormatOptions {
         self.trailing_comma
     }
 
+    pub fn object_wrap(&self) -> ObjectWrap {
+        self.object_wrap
+    }
+
     pub fn tab_width(&self) -> TabWidth {
         match self.indent_style {
             IndentStyle::Tab => 2.into(),
@@ -232,7 +245,8 @@ impl fmt::Display for JsFormatOptions {
         writeln!(f, "Line width: {}", self.line_width.value())?;
         writeln!(f, "Quote style: {}", self.quote_style)?;
         writeln!(f, "Quote properties: {}", self.quote_properties)?;
-        writeln!(f, "Trailing comma: {}", self.trailing_comma)
+        writeln!(f, "Trailing comma: {}", self.trailing_comma)?;
+        writeln!(f, "Object wrap: {}", self.object_wrap)
     }
 }
 
@@ -351,3 +365,46 @@ impl fmt::Display for QuoteProperties {
         }
     }
 }
+
+/// Controls how objects that the user wrote on multiple lines are formatted
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema)
+)]
+pub enum ObjectWrap {
+    /// Keeps an object expanded if there's a line break between its `{` and its first member,
+    /// even if the object would fit on a single line.
+    #[default]
+    Preserve,
+    /// Prints an object on a single line whenever it fits.
+    Collapse,
+}
+
+impl ObjectWrap {
+    pub const fn is_preserve(&self) -> bool {
+        matches!(self, ObjectWrap::Preserve)
+    }
+}
+
+impl FromStr for ObjectWrap {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "preserve" | "Preserve" => Ok(Self::Preserve),
+            "collapse" | "Collapse" => Ok(Self::Collapse),
+            // TODO: replace this error with a diagnostic
+            _ => Err("Value not supported for ObjectWrap"),
+        }
+    }
+}
+
+impl fmt::Display for ObjectWrap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ObjectWrap::Preserve => write!(f, "Preserve"),
+            ObjectWrap::Collapse => write!(f, "Collapse"),
+        }
+    }
+}
@@ -29,7 +29,9 @@ impl FormatNodeRule<JsExportNamedFromClause> for FormatJsExportNamedFromClause {
 
             write!(f, [l_curly_token.format(),])?;
 
-            if node_has_leading_newline(specifiers.syntax()) {
+            if f.options().object_wrap().is_preserve()
+                && node_has_leading_newline(specifiers.syntax())
+            {
                 write!(f, [block_indent(&specifiers.format()),])?;
             } else {
                 write!(
@@ -27,16 +27,17 @@ impl FormatNodeRule<TsMappedType> for FormatTsMappedType {
 
         let property_name = property_name?;
 
-        let should_expand = node
-            .syntax()
-            .tokens()
-            .flat_map(|token| {
-                token
-                    .leading_trivia()
-                    .pieces()
-                    .chain(token.trailing_trivia().pieces())
-            })
-            .any(|piece| piece.is_newline());
+        let should_expand = f.options().object_wrap().is_preserve()
+            && node
+                .syntax()
+                .tokens()
+                .flat_map(|token| {
+                    token
+                        .leading_trivia()
+                        .pieces()
+                        .chain(token.trailing_trivia().pieces())
+                })
+                .any(|piece| piece.is_newline());
 
         let format_semi = format_with(|f| {
             if let Some(semi) = &semicolon_token {
@@ -61,7 +61,8 @@ impl Format<JsFormatContext> for JsObjectLike {
                 [format_dangling_comments(self.syntax()).with_block_indent(),]
             )?;
         } else {
-            let should_expand = self.members_have_leading_newline();
+            let should_expand =
+                f.options().object_wrap().is_preserve() && self.members_have_leading_newline();
             write!(
                 f,
                 [group(&soft_space_or_block_indent(&members)).should_expand(should_expand)]
@@ -3,7 +3,7 @@ use rome_formatter::{FormatOptions, LineWidth};
 use rome_formatter::{IndentStyle, Printed};
 use rome_fs::RomePath;
 use rome_js_formatter::context::trailing_comma::TrailingComma;
-use rome_js_formatter::context::{JsFormatOptions, QuoteProperties, QuoteStyle};
+use rome_js_formatter::context::{JsFormatOptions, ObjectWrap, QuoteProperties, QuoteStyle};
 use rome_js_formatter::format_node;
 use rome_js_parser::parse;
 use rome_js_syntax::{ModuleKind, SourceType};
@@ -83,6 +83,21 @@ impl From<SerializableTrailingComma> for TrailingComma {
     }
 }
 
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Deserialize, Serialize)]
+pub enum SerializableObjectWrap {
+    Preserve,
+    Collapse,
+}
+
+impl From<SerializableObjectWrap> for ObjectWrap {
+    fn from(test: SerializableObjectWrap) -> Self {
+        match test {
+            SerializableObjectWrap::Preserve => ObjectWrap::Preserve,
+            SerializableObjectWrap::Collapse => ObjectWrap::Collapse,
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize, Clone, Copy)]
 pub struct SerializableFormatOptions {
     /// The indent style.
@@ -99,6 +114,9 @@ pub struct SerializableFormatOptions {
 
     /// Print trailing commas wherever possible in multi-line comma-separated syntactic structures. Defaults to "all".
     pub trailing_comma: Option<SerializableTrailingComma>,
+
+    /// Whether objects written on multiple lines are kept expanded. Defaults to preserve.
+    pub object_wrap: Option<SerializableObjectWrap>,
 }
 
 impl From<SerializableFormatOptions> for JsFormatOptions {
@@ -125,6 +143,10 @@ impl From<SerializableFormatOptions> for JsFormatOptions {
                 test.trailing_comma
                     .map_or_else(|| TrailingComma::All, |value| value.into()),
             )
+            .with_object_wrap(
+                test.object_wrap
+                    .map_or_else(|| ObjectWrap::Preserve, |value| value.into()),
+            )
     }
 }
 
@@ -0,0 +1,10 @@
+// rome-ignore-start format: the matrices are aligned by hand
+const  identity  =  [
+    1, 0, 0,
+    0, 1, 0,
+    0, 0, 1,
+];
+const  zero  =  [0,0,0];
+// rome-ignore-end format
+
+const   formatted   =   [1,2,3];
@@ -0,0 +1,8 @@
+class Matrix {
+    // rome-ignore-start format: the values are aligned by hand
+    static  identity  =  [1,0, 0,1];
+    static  zero  =  [0,0, 0,0];
+    // rome-ignore-end format
+
+    scale  (  factor  )  {  return factor  }
+}
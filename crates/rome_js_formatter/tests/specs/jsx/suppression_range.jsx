@@ -0,0 +1,10 @@
+const table = (
+	<table>
+		{/* rome-ignore-start format: the cells are aligned by hand */}
+		<tr><td>1</td>  <td>0</td></tr>
+		<tr><td>0</td>  <td>1</td></tr>
+		{/* rome-ignore-end format */}
+	</table>
+);
+
+const   cell   =   <td   colSpan={2}  >1</td>;
@@ -0,0 +1,10 @@
+const a = {
+	b: 1, c: 2 };
+const d = { e: 1,
+	f: 2 };
+type T = {
+	a: string };
+type M = {
+	[K in keyof T]: T[K] };
+export {
+	a, b } from "mod";
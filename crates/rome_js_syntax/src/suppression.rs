@@ -11,8 +11,19 @@
 /// A suppression must specify a reason: this part has no semantic meaning but
 /// is required to document why a particular feature is being disable for this
 /// line (lint false-positive, specific formatting requirements, ...)
+///
+/// A suppression can also apply to a range of code, using a pair of
+/// `// rome-ignore-start { <category> }+: <reason>` and
+/// `// rome-ignore-end { <category> }+` comments (the reason is optional for
+/// the closing comment)
+///
+/// Finally, a `// rome-ignore-all { <category> }+: <reason>` comment placed at
+/// the top of a file applies to the whole file
 #[derive(Debug, PartialEq, Eq)]
 pub struct Suppression<'a> {
+    /// Whether this comment suppresses the next node or opens / closes a
+    /// suppressed range
+    pub kind: SuppressionKind,
     /// List of categories for this suppression
     ///
     /// Categories are pair of the category name +
@@ -22,6 +33,19 @@ pub struct Suppression<'a> {
     pub reason: &'a str,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SuppressionKind {
+    /// `rome-ignore`: suppresses the node following the comment
+    Next,
+    /// `rome-ignore-start`: starts a suppressed range
+    RangeStart,
+    /// `rome-ignore-end`: ends the suppressed range started by the matching
+    /// `rome-ignore-start` comment
+    RangeEnd,
+    /// `rome-ignore-all`: suppresses the whole file
+    All,
+}
+
 pub fn parse_suppression_comment(comment: &str) -> impl Iterator<Item = Suppression> {
     let (head, mut comment) = comment.split_at(2);
     let is_block_comment = match head {
@@ -46,13 +70,48 @@ pub fn parse_suppression_comment(comment: &str) -> impl Iterator<Item = Suppress
         }
 
         // Check for the rome-ignore token or skip the line entirely
-        line = line.strip_prefix("rome-ignore")?.trim_start();
+        line = line.strip_prefix("rome-ignore")?;
+
+        let kind = if let Some(rest) = line.strip_prefix("-start") {
+            line = rest;
+            SuppressionKind::RangeStart
+        } else if let Some(rest) = line.strip_prefix("-end") {
+            line = rest;
+            SuppressionKind::RangeEnd
+        } else if let Some(rest) = line.strip_prefix("-all") {
+            line = rest;
+            SuppressionKind::All
+        } else {
+            SuppressionKind::Next
+        };
+
+        // The kind must be followed by a separator, `rome-ignore-endformat` is
+        // not a suppression comment
+        if !line.is_empty()
+            && !line.starts_with(|c: char| c == ':' || c == '(' || c.is_whitespace())
+        {
+            return None;
+        }
+
+        line = line.trim_start();
 
         let mut categories = Vec::new();
 
         loop {
             // Find either a colon opening parenthesis or space
-            let separator = line.find(|c: char| c == ':' || c == '(' || c.is_whitespace())?;
+            let separator = match line.find(|c: char| c == ':' || c == '(' || c.is_whitespace()) {
+                Some(separator) => separator,
+                // The closing comment of a range doesn't require a reason
+                None if kind == SuppressionKind::RangeEnd => {
+                    if !line.is_empty() {
+                        categories.push((line, None));
+                    }
+
+                    line = "";
+                    break;
+                }
+                None => return None,
+            };
 
             let (category, rest) = line.split_at(separator);
             let category = category.trim_end();
@@ -93,7 +152,11 @@ pub fn parse_suppression_comment(comment: &str) -> impl Iterator<Item = Suppress
         }
 
         let reason = line.trim_end();
-        Some(Suppression { categories, reason })
+        Some(Suppression {
+            kind,
+            categories,
+            reason,
+        })
     })
 }
 
@@ -119,13 +182,14 @@ impl PartialEq<SuppressionCategory> for &'_ str {
 
 #[cfg(test)]
 mod tests {
-    use super::{parse_suppression_comment, Suppression};
+    use super::{parse_suppression_comment, Suppression, SuppressionKind};
 
     #[test]
     fn parse_simple_suppression() {
         assert_eq!(
             parse_suppression_comment("// rome-ignore parse: explanation1").collect::<Vec<_>>(),
             vec![Suppression {
+                kind: SuppressionKind::Next,
                 categories: vec![("parse", None)],
                 reason: "explanation1"
             }],
@@ -134,6 +198,7 @@ mod tests {
         assert_eq!(
             parse_suppression_comment("/** rome-ignore parse: explanation2 */").collect::<Vec<_>>(),
             vec![Suppression {
+                kind: SuppressionKind::Next,
                 categories: vec![("parse", None)],
                 reason: "explanation2"
             }],
@@ -147,6 +212,7 @@ mod tests {
             )
             .collect::<Vec<_>>(),
             vec![Suppression {
+                kind: SuppressionKind::Next,
                 categories: vec![("parse", None)],
                 reason: "explanation3"
             }],
@@ -161,6 +227,7 @@ mod tests {
             )
             .collect::<Vec<_>>(),
             vec![Suppression {
+                kind: SuppressionKind::Next,
                 categories: vec![("parse", None)],
                 reason: "explanation4"
             }],
@@ -171,6 +238,7 @@ mod tests {
         assert_eq!(
             parse_suppression_comment("/* rome-ignore format: explanation").collect::<Vec<_>>(),
             vec![Suppression {
+                kind: SuppressionKind::Next,
                 categories: vec![("format", None)],
                 reason: "explanation"
             }],
@@ -179,6 +247,7 @@ mod tests {
         assert_eq!(
             parse_suppression_comment("/* rome-ignore format: explanation *").collect::<Vec<_>>(),
             vec![Suppression {
+                kind: SuppressionKind::Next,
                 categories: vec![("format", None)],
                 reason: "explanation"
             }],
@@ -187,6 +256,7 @@ mod tests {
         assert_eq!(
             parse_suppression_comment("/* rome-ignore format: explanation /").collect::<Vec<_>>(),
             vec![Suppression {
+                kind: SuppressionKind::Next,
                 categories: vec![("format", None)],
                 reason: "explanation"
             }],
@@ -199,6 +269,7 @@ mod tests {
             parse_suppression_comment("// rome-ignore parse(foo) parse(dog): explanation")
                 .collect::<Vec<_>>(),
             vec![Suppression {
+                kind: SuppressionKind::Next,
                 categories: vec![("parse", Some("foo")), ("parse", Some("dog"))],
                 reason: "explanation"
             }],
@@ -208,6 +279,7 @@ mod tests {
             parse_suppression_comment("/** rome-ignore parse(bar) parse(cat): explanation */")
                 .collect::<Vec<_>>(),
             vec![Suppression {
+                kind: SuppressionKind::Next,
                 categories: vec![("parse", Some("bar")), ("parse", Some("cat"))],
                 reason: "explanation"
             }],
@@ -221,6 +293,7 @@ mod tests {
             )
             .collect::<Vec<_>>(),
             vec![Suppression {
+                kind: SuppressionKind::Next,
                 categories: vec![("parse", Some("yes")), ("parse", Some("frog"))],
                 reason: "explanation"
             }],
@@ -235,6 +308,7 @@ mod tests {
             )
             .collect::<Vec<_>>(),
             vec![Suppression {
+                kind: SuppressionKind::Next,
                 categories: vec![("parse", Some("wow")), ("parse", Some("fish"))],
                 reason: "explanation"
             }],
@@ -247,9 +321,81 @@ mod tests {
             parse_suppression_comment("// rome-ignore format lint: explanation")
                 .collect::<Vec<_>>(),
             vec![Suppression {
+                kind: SuppressionKind::Next,
                 categories: vec![("format", None), ("lint", None)],
                 reason: "explanation"
             }],
         );
     }
+
+    #[test]
+    fn parse_range_suppressions() {
+        assert_eq!(
+            parse_suppression_comment("// rome-ignore-start format: aligned table")
+                .collect::<Vec<_>>(),
+            vec![Suppression {
+                kind: SuppressionKind::RangeStart,
+                categories: vec![("format", None)],
+                reason: "aligned table"
+            }],
+        );
+
+        assert_eq!(
+            parse_suppression_comment("// rome-ignore-end format").collect::<Vec<_>>(),
+            vec![Suppression {
+                kind: SuppressionKind::RangeEnd,
+                categories: vec![("format", None)],
+                reason: ""
+            }],
+        );
+
+        assert_eq!(
+            parse_suppression_comment("/* rome-ignore-end format: aligned table */")
+                .collect::<Vec<_>>(),
+            vec![Suppression {
+                kind: SuppressionKind::RangeEnd,
+                categories: vec![("format", None)],
+                reason: "aligned table"
+            }],
+        );
+
+        assert_eq!(
+            parse_suppression_comment("// rome-ignore-start format").collect::<Vec<_>>(),
+            vec![],
+        );
+
+        assert_eq!(
+            parse_suppression_comment("// rome-ignore-endformat").collect::<Vec<_>>(),
+            vec![],
+        );
+
+        assert_eq!(
+            parse_suppression_comment("// rome-ignore-startformat: aligned table")
+                .collect::<Vec<_>>(),
+            vec![],
+        );
+    }
+
+    #[test]
+    fn parse_file_suppressions() {
+        assert_eq!(
+            parse_suppression_comment("// rome-ignore-all lint(style/noVar): generated code")
+                .collect::<Vec<_>>(),
+            vec![Suppression {
+                kind: SuppressionKind::All,
+                categories: vec![("lint", Some("style/noVar"))],
+                reason: "generated code"
+            }],
+        );
+
+        assert_eq!(
+            parse_suppression_comment("// rome-ignore-all lint").collect::<Vec<_>>(),
+            vec![],
+        );
+
+        assert_eq!(
+            parse_suppression_comment("// rome-ignore-allx lint: reason").collect::<Vec<_>>(),
+            vec![],
+        );
+    }
 }
@@ -0,0 +1,16 @@
+//! Best-effort type inference for JavaScript and TypeScript modules
+//!
+//! The [TypeModel] infers the types of the values of a module from its
+//! `TypeScript` annotations, its literals and the values returned by its
+//! functions, without type checking the module. The types that can't be
+//! inferred with confidence are [Type::Unknown], so the rules using the model
+//! can bail out instead of reporting false positives.
+
+mod model;
+mod types;
+
+#[cfg(test)]
+mod tests;
+
+pub use model::{ImportResolver, TypeModel};
+pub use types::{FunctionType, Literal, Type};
@@ -0,0 +1,920 @@
+use crate::{Literal, Type};
+use rome_js_semantic::{AllReferencesExtensions, SemanticModel};
+use rome_js_syntax::{
+    JsAnyArrayElement, JsAnyExportClause, JsAnyExportDefaultDeclaration, JsAnyExportNamedSpecifier,
+    JsAnyExpression, JsAnyFunction, JsAnyFunctionBody, JsAnyImportClause, JsAnyLiteralExpression,
+    JsAnyModuleItem, JsAnyStatement, JsBinaryOperator, JsBlockStatement, JsCallExpression,
+    JsFormalParameter, JsIdentifierBinding, JsLogicalOperator, JsModule, JsNamedImportSpecifier,
+    JsNewExpression, JsReferenceIdentifier, JsReturnStatement, JsShorthandNamedImportSpecifier,
+    JsStatementList, JsSyntaxKind, JsSyntaxNode, JsUnaryOperator, JsVariableDeclaration,
+    JsVariableDeclarator, TsAnyName, TsAnyReturnType, TsAnyVariableAnnotation, TsReferenceType,
+    TsType, TsTypeAliasDeclaration, WalkEvent,
+};
+use rome_rowan::{AstNode, AstNodeList, AstSeparatedList, SyntaxResult};
+use std::sync::Arc;
+
+/// Maximum number of declarations followed to infer the type of a value,
+/// breaking the cycles between recursive declarations
+const MAX_DEPTH: usize = 16;
+
+/// Resolves the types of the declarations imported from other modules
+pub trait ImportResolver: Send + Sync {
+    /// Return the type of the export `name` of the module imported with
+    /// `specifier` by the analyzed module, `name` being `default` for the
+    /// default export
+    fn export_type(&self, specifier: &str, name: &str) -> Type;
+}
+
+/// Infers the types of the values of a module, on demand
+///
+/// The types are inferred from the `TypeScript` annotations, the literals,
+/// the values returned by the local functions and a few well known global
+/// functions returning promises. The declarations imported from other modules
+/// are resolved by the [ImportResolver] of the model, if any.
+#[derive(Clone)]
+pub struct TypeModel {
+    semantic: SemanticModel,
+    imports: Option<Arc<dyn ImportResolver>>,
+}
+
+impl TypeModel {
+    pub fn new(semantic: SemanticModel) -> Self {
+        Self {
+            semantic,
+            imports: None,
+        }
+    }
+
+    /// Resolve the imported declarations using `resolver`
+    pub fn with_import_resolver(mut self, resolver: Arc<dyn ImportResolver>) -> Self {
+        self.imports = Some(resolver);
+        self
+    }
+
+    pub fn semantic_model(&self) -> &SemanticModel {
+        &self.semantic
+    }
+
+    /// Returns the type of the value `expression` evaluates to
+    pub fn expression_type(&self, expression: &JsAnyExpression) -> Type {
+        self.expression(expression, 0)
+    }
+
+    /// Returns the type of the value held by the variable, function or class
+    /// declared by `binding`
+    pub fn binding_type(&self, binding: &JsIdentifierBinding) -> Type {
+        self.binding(binding, 0)
+    }
+
+    /// Returns the type of `function`, a [Type::Function]
+    pub fn function_type(&self, function: &JsAnyFunction) -> Type {
+        self.function(function, 0)
+    }
+
+    /// Returns the type declared by the annotation `ty`
+    pub fn annotation_type(&self, ty: &TsType) -> Type {
+        self.annotation(ty, 0)
+    }
+
+    /// Returns the type of the export `name` of the module, `name` being
+    /// `default` for the default export
+    pub fn export_type(&self, name: &str) -> Type {
+        let scope = self.semantic.global_scope();
+        let root = scope.syntax().ancestors().last();
+        let module = match root.and_then(JsModule::cast) {
+            Some(module) => module,
+            None => return Type::Unknown,
+        };
+
+        for item in module.items() {
+            let clause = match item {
+                JsAnyModuleItem::JsExport(export) => export.export_clause(),
+                _ => continue,
+            };
+
+            let ty = match clause {
+                Ok(JsAnyExportClause::JsAnyDeclarationClause(declaration)) => {
+                    declared_bindings(declaration.syntax())
+                        .into_iter()
+                        .find(|binding| has_name(binding, name))
+                        .map(|binding| self.binding(&binding, 0))
+                }
+                Ok(JsAnyExportClause::JsExportDefaultExpressionClause(clause))
+                    if name == "default" =>
+                {
+                    clause
+                        .expression()
+                        .ok()
+                        .map(|expression| self.expression(&expression, 0))
+                }
+                Ok(JsAnyExportClause::JsExportDefaultDeclarationClause(clause))
+                    if name == "default" =>
+                {
+                    match clause.declaration() {
+                        Ok(JsAnyExportDefaultDeclaration::JsFunctionExportDefaultDeclaration(
+                            function,
+                        )) => Some(self.function(&function.into(), 0)),
+                        Ok(JsAnyExportDefaultDeclaration::JsClassExportDefaultDeclaration(_)) => {
+                            Some(class_type())
+                        }
+                        _ => None,
+                    }
+                }
+                Ok(JsAnyExportClause::JsExportNamedClause(clause)) => clause
+                    .specifiers()
+                    .iter()
+                    .filter_map(Result::ok)
+                    .find_map(|specifier| match specifier {
+                        JsAnyExportNamedSpecifier::JsExportNamedShorthandSpecifier(specifier) => {
+                            let local = specifier.name().ok()?;
+                            local.has_name(name).then_some(local)
+                        }
+                        JsAnyExportNamedSpecifier::JsExportNamedSpecifier(specifier) => {
+                            let exported = specifier.exported_name().ok()?.value().ok()?;
+                            let local = specifier.local_name().ok()?;
+                            (exported.text_trimmed() == name).then_some(local)
+                        }
+                    })
+                    .map(|local| self.reference(&local, 0)),
+                _ => None,
+            };
+
+            if let Some(ty) = ty {
+                return ty;
+            }
+        }
+
+        Type::Unknown
+    }
+
+    /// Returns the names and the types of the exports of the module, the
+    /// default export being named `default`
+    pub fn export_types(&self) -> Vec<(String, Type)> {
+        let scope = self.semantic.global_scope();
+        let root = scope.syntax().ancestors().last();
+        let module = match root.and_then(JsModule::cast) {
+            Some(module) => module,
+            None => return Vec::new(),
+        };
+
+        let mut names = Vec::new();
+        for item in module.items() {
+            let clause = match item {
+                JsAnyModuleItem::JsExport(export) => export.export_clause(),
+                _ => continue,
+            };
+
+            match clause {
+                Ok(JsAnyExportClause::JsAnyDeclarationClause(declaration)) => {
+                    names.extend(
+                        declared_bindings(declaration.syntax())
+                            .into_iter()
+                            .filter_map(|binding| binding.name_token().ok())
+                            .map(|name| name.text_trimmed().to_string()),
+                    );
+                }
+                Ok(
+                    JsAnyExportClause::JsExportDefaultExpressionClause(_)
+                    | JsAnyExportClause::JsExportDefaultDeclarationClause(_),
+                ) => names.push(String::from("default")),
+                Ok(JsAnyExportClause::JsExportNamedClause(clause)) => {
+                    names.extend(clause.specifiers().iter().filter_map(|specifier| {
+                        let name = match specifier.ok()? {
+                            JsAnyExportNamedSpecifier::JsExportNamedShorthandSpecifier(
+                                specifier,
+                            ) => specifier.name().ok()?.value_token().ok()?,
+                            JsAnyExportNamedSpecifier::JsExportNamedSpecifier(specifier) => {
+                                specifier.exported_name().ok()?.value().ok()?
+                            }
+                        };
+
+                        Some(name.text_trimmed().to_string())
+                    }));
+                }
+                _ => {}
+            }
+        }
+
+        names
+            .into_iter()
+            .map(|name| {
+                let ty = self.export_type(&name);
+                (name, ty)
+            })
+            .collect()
+    }
+
+    fn expression(&self, expression: &JsAnyExpression, depth: usize) -> Type {
+        let expression = expression.clone().omit_parentheses();
+
+        match &expression {
+            JsAnyExpression::JsAnyLiteralExpression(literal) => literal_type(literal),
+            JsAnyExpression::JsTemplate(template) if template.tag().is_none() => Type::String,
+            JsAnyExpression::JsIdentifierExpression(identifier) => match identifier.name() {
+                Ok(name) => self.reference(&name, depth),
+                Err(_) => Type::Unknown,
+            },
+            JsAnyExpression::JsArrowFunctionExpression(function) => {
+                self.function(&function.clone().into(), depth)
+            }
+            JsAnyExpression::JsFunctionExpression(function) => {
+                self.function(&function.clone().into(), depth)
+            }
+            JsAnyExpression::JsClassExpression(_) => class_type(),
+            JsAnyExpression::JsObjectExpression(_) | JsAnyExpression::JsxTagExpression(_) => {
+                Type::Object
+            }
+            JsAnyExpression::JsArrayExpression(array) => {
+                let elements: Vec<_> = array
+                    .elements()
+                    .iter()
+                    .map(|element| match element {
+                        Ok(JsAnyArrayElement::JsAnyExpression(element)) => {
+                            self.expression(&element, depth)
+                        }
+                        _ => Type::Unknown,
+                    })
+                    .collect();
+
+                if elements.is_empty() {
+                    Type::Array(Box::new(Type::Unknown))
+                } else {
+                    Type::Array(Box::new(Type::union(elements).widen()))
+                }
+            }
+            JsAnyExpression::JsAwaitExpression(expression) => match expression.argument() {
+                Ok(argument) => self.expression(&argument, depth).awaited(),
+                Err(_) => Type::Unknown,
+            },
+            JsAnyExpression::JsCallExpression(call) => self.call(call, depth),
+            JsAnyExpression::JsNewExpression(expression) => self.new_expression(expression),
+            JsAnyExpression::JsImportCallExpression(_) => Type::promise(Type::Unknown),
+            JsAnyExpression::JsUnaryExpression(expression) => match expression.operator() {
+                Ok(JsUnaryOperator::LogicalNot | JsUnaryOperator::Delete) => Type::Boolean,
+                Ok(JsUnaryOperator::Typeof) => Type::String,
+                Ok(JsUnaryOperator::Void) => Type::Undefined,
+                Ok(JsUnaryOperator::Plus) => Type::Number,
+                Ok(JsUnaryOperator::Minus | JsUnaryOperator::BitwiseNot) => {
+                    let argument = match expression.argument() {
+                        Ok(argument) => self.expression(&argument, depth).widen(),
+                        Err(_) => Type::Unknown,
+                    };
+
+                    match argument {
+                        Type::Number | Type::BigInt => argument,
+                        _ => Type::Unknown,
+                    }
+                }
+                Err(_) => Type::Unknown,
+            },
+            JsAnyExpression::JsBinaryExpression(expression) => {
+                if expression.is_comparison_operator() {
+                    return Type::Boolean;
+                }
+
+                let (left, right) = match (expression.left(), expression.right()) {
+                    (Ok(left), Ok(right)) => (
+                        self.expression(&left, depth).widen(),
+                        self.expression(&right, depth).widen(),
+                    ),
+                    _ => return Type::Unknown,
+                };
+
+                match expression.operator() {
+                    Ok(JsBinaryOperator::Plus) if left == Type::String || right == Type::String => {
+                        Type::String
+                    }
+                    Ok(_) if left == right && matches!(left, Type::Number | Type::BigInt) => left,
+                    _ => Type::Unknown,
+                }
+            }
+            JsAnyExpression::JsInExpression(_) | JsAnyExpression::JsInstanceofExpression(_) => {
+                Type::Boolean
+            }
+            JsAnyExpression::JsLogicalExpression(expression) => {
+                let (left, right) = match (expression.left(), expression.right()) {
+                    (Ok(left), Ok(right)) => (
+                        self.expression(&left, depth),
+                        self.expression(&right, depth),
+                    ),
+                    _ => return Type::Unknown,
+                };
+
+                match expression.operator() {
+                    Ok(JsLogicalOperator::LogicalAnd) => match left.truthiness() {
+                        Some(true) => right,
+                        Some(false) => left,
+                        None => Type::union([left, right]),
+                    },
+                    Ok(JsLogicalOperator::LogicalOr) => match left.truthiness() {
+                        Some(true) => left,
+                        Some(false) => right,
+                        None => Type::union([left, right]),
+                    },
+                    Ok(JsLogicalOperator::NullishCoalescing) => {
+                        Type::union([left.non_nullable(), right])
+                    }
+                    Err(_) => Type::Unknown,
+                }
+            }
+            JsAnyExpression::JsConditionalExpression(expression) => {
+                match (expression.consequent(), expression.alternate()) {
+                    (Ok(consequent), Ok(alternate)) => Type::union([
+                        self.expression(&consequent, depth),
+                        self.expression(&alternate, depth),
+                    ]),
+                    _ => Type::Unknown,
+                }
+            }
+            JsAnyExpression::JsAssignmentExpression(expression) => {
+                let is_plain_assignment = expression
+                    .operator_token()
+                    .map_or(false, |operator| operator.kind() == JsSyntaxKind::EQ);
+
+                match expression.right() {
+                    Ok(right) if is_plain_assignment => self.expression(&right, depth),
+                    _ => Type::Unknown,
+                }
+            }
+            JsAnyExpression::JsSequenceExpression(expression) => match expression.right() {
+                Ok(right) => self.expression(&right, depth),
+                Err(_) => Type::Unknown,
+            },
+            JsAnyExpression::TsAsExpression(expression) => match expression.ty() {
+                Ok(ty) => self.annotation(&ty, depth),
+                Err(_) => Type::Unknown,
+            },
+            JsAnyExpression::TsTypeAssertionExpression(expression) => match expression.ty() {
+                Ok(ty) => self.annotation(&ty, depth),
+                Err(_) => Type::Unknown,
+            },
+            JsAnyExpression::TsNonNullAssertionExpression(expression) => {
+                match expression.expression() {
+                    Ok(expression) => self.expression(&expression, depth).non_nullable(),
+                    Err(_) => Type::Unknown,
+                }
+            }
+            _ => Type::Unknown,
+        }
+    }
+
+    /// Returns the type of the value of the variable `name` refers to
+    fn reference(&self, name: &JsReferenceIdentifier, depth: usize) -> Type {
+        let binding = self
+            .semantic
+            .declaration(name)
+            .and_then(|binding| JsIdentifierBinding::cast(binding.syntax().clone()));
+
+        if let Some(binding) = binding {
+            return self.binding(&binding, depth + 1);
+        }
+
+        // The reference isn't declared in the module
+        match name.value_token() {
+            Ok(token) => match token.text_trimmed() {
+                "undefined" => Type::Undefined,
+                "NaN" | "Infinity" => Type::Number,
+                _ => Type::Unknown,
+            },
+            Err(_) => Type::Unknown,
+        }
+    }
+
+    fn binding(&self, binding: &JsIdentifierBinding, depth: usize) -> Type {
+        if depth > MAX_DEPTH {
+            return Type::Unknown;
+        }
+
+        let parent = match binding.syntax().parent() {
+            Some(parent) => parent,
+            None => return Type::Unknown,
+        };
+
+        match parent.kind() {
+            JsSyntaxKind::JS_VARIABLE_DECLARATOR => {
+                let declarator = JsVariableDeclarator::unwrap_cast(parent);
+                self.variable(binding, &declarator, depth)
+            }
+            JsSyntaxKind::JS_FORMAL_PARAMETER => {
+                // The callers may pass any value to the parameters without
+                // annotation, whatever their default value
+                let parameter = JsFormalParameter::unwrap_cast(parent);
+                match parameter
+                    .type_annotation()
+                    .map(|annotation| annotation.ty())
+                {
+                    Some(Ok(ty)) => self.annotation(&ty, depth),
+                    _ => Type::Unknown,
+                }
+            }
+            JsSyntaxKind::JS_FUNCTION_DECLARATION
+            | JsSyntaxKind::JS_FUNCTION_EXPRESSION
+            | JsSyntaxKind::JS_FUNCTION_EXPORT_DEFAULT_DECLARATION => {
+                match JsAnyFunction::cast(parent) {
+                    Some(function) => self.function(&function, depth),
+                    None => Type::Unknown,
+                }
+            }
+            JsSyntaxKind::JS_CLASS_DECLARATION
+            | JsSyntaxKind::JS_CLASS_EXPRESSION
+            | JsSyntaxKind::JS_CLASS_EXPORT_DEFAULT_DECLARATION => class_type(),
+            JsSyntaxKind::JS_SHORTHAND_NAMED_IMPORT_SPECIFIER
+            | JsSyntaxKind::JS_NAMED_IMPORT_SPECIFIER
+            | JsSyntaxKind::JS_DEFAULT_IMPORT_SPECIFIER
+            | JsSyntaxKind::JS_IMPORT_DEFAULT_CLAUSE => self.import(binding, &parent),
+            _ => Type::Unknown,
+        }
+    }
+
+    fn variable(
+        &self,
+        binding: &JsIdentifierBinding,
+        declarator: &JsVariableDeclarator,
+        depth: usize,
+    ) -> Type {
+        if let Some(TsAnyVariableAnnotation::TsTypeAnnotation(annotation)) =
+            declarator.variable_annotation()
+        {
+            return match annotation.ty() {
+                Ok(ty) => self.annotation(&ty, depth),
+                Err(_) => Type::Unknown,
+            };
+        }
+
+        let init = match declarator
+            .initializer()
+            .and_then(|init| init.expression().ok())
+        {
+            Some(init) => init,
+            None => return Type::Unknown,
+        };
+
+        let is_const = declarator
+            .syntax()
+            .ancestors()
+            .find_map(JsVariableDeclaration::cast)
+            .map_or(false, |declaration| declaration.is_const());
+
+        if is_const {
+            self.expression(&init, depth)
+        } else if binding.all_writes(&self.semantic).next().is_none() {
+            // Like `TypeScript`, the literal types of the mutable variables
+            // are widened to their primitive type
+            self.expression(&init, depth).widen()
+        } else {
+            Type::Unknown
+        }
+    }
+
+    /// Returns the type of the declaration imported by `specifier`
+    fn import(&self, binding: &JsIdentifierBinding, specifier: &JsSyntaxNode) -> Type {
+        let resolver = match &self.imports {
+            Some(resolver) => resolver,
+            None => return Type::Unknown,
+        };
+
+        let name = match specifier.kind() {
+            JsSyntaxKind::JS_NAMED_IMPORT_SPECIFIER => {
+                let specifier = JsNamedImportSpecifier::unwrap_cast(specifier.clone());
+                if specifier.type_token().is_some() {
+                    return Type::Unknown;
+                }
+
+                match specifier.name().and_then(|name| name.value()) {
+                    Ok(name) => name.text_trimmed().to_string(),
+                    Err(_) => return Type::Unknown,
+                }
+            }
+            JsSyntaxKind::JS_SHORTHAND_NAMED_IMPORT_SPECIFIER => {
+                let specifier = JsShorthandNamedImportSpecifier::unwrap_cast(specifier.clone());
+                match binding.name_token() {
+                    Ok(name) if specifier.type_token().is_none() => name.text_trimmed().to_string(),
+                    _ => return Type::Unknown,
+                }
+            }
+            _ => String::from("default"),
+        };
+
+        // The `import type` statements don't import any value
+        let clause = specifier.ancestors().find_map(JsAnyImportClause::cast);
+        let source = match clause {
+            Some(JsAnyImportClause::JsImportNamedClause(clause))
+                if clause.type_token().is_none() =>
+            {
+                clause.source().ok()
+            }
+            Some(JsAnyImportClause::JsImportDefaultClause(clause))
+                if clause.type_token().is_none() =>
+            {
+                clause.source().ok()
+            }
+            _ => None,
+        };
+
+        match source.and_then(|source| source.inner_string_text().ok()) {
+            Some(source) => resolver.export_type(source.text(), &name),
+            None => Type::Unknown,
+        }
+    }
+
+    fn function(&self, function: &JsAnyFunction, depth: usize) -> Type {
+        let is_async = function.is_async();
+        if depth > MAX_DEPTH || function.is_generator() {
+            return Type::function(is_async, Type::Unknown);
+        }
+
+        let returns = match function
+            .return_type_annotation()
+            .and_then(|annotation| annotation.ty().ok())
+        {
+            Some(TsAnyReturnType::TsType(ty)) => self.annotation(&ty, depth + 1),
+            Some(TsAnyReturnType::TsPredicateReturnType(_)) => Type::Boolean,
+            Some(TsAnyReturnType::TsAssertsReturnType(_)) => Type::Void,
+            None => self.returned_values(function, depth + 1),
+        };
+
+        let returns = if is_async {
+            Type::promise(returns.awaited())
+        } else {
+            returns
+        };
+
+        Type::function(is_async, returns)
+    }
+
+    /// Returns the union of the types of the values returned by `function`,
+    /// including `undefined` if the end of its body may be reached
+    fn returned_values(&self, function: &JsAnyFunction, depth: usize) -> Type {
+        let body = match function.body() {
+            Ok(JsAnyFunctionBody::JsAnyExpression(expression)) => {
+                return self.expression(&expression, depth)
+            }
+            Ok(JsAnyFunctionBody::JsFunctionBody(body)) => body,
+            Err(_) => return Type::Unknown,
+        };
+
+        let mut returns = Vec::new();
+        let mut iter = body.syntax().preorder();
+        while let Some(event) = iter.next() {
+            if let WalkEvent::Enter(node) = event {
+                if is_function_boundary(node.kind()) {
+                    iter.skip_subtree();
+                    continue;
+                }
+
+                if let Some(statement) = JsReturnStatement::cast(node) {
+                    returns.push(match statement.argument() {
+                        Some(argument) => self.expression(&argument, depth),
+                        None => Type::Undefined,
+                    });
+                }
+            }
+        }
+
+        if returns.is_empty() {
+            return Type::Void;
+        }
+
+        if can_complete_normally(body.statements()) {
+            returns.push(Type::Undefined);
+        }
+
+        Type::union(returns)
+    }
+
+    fn call(&self, call: &JsCallExpression, depth: usize) -> Type {
+        let callee = match call.callee() {
+            Ok(callee) => callee.omit_parentheses(),
+            Err(_) => return Type::Unknown,
+        };
+
+        if let JsAnyExpression::JsStaticMemberExpression(member) = &callee {
+            let (object, name) = match (member.object(), member.member()) {
+                (Ok(object), Ok(name)) => (object, name),
+                _ => return Type::Unknown,
+            };
+
+            let name = match name.as_js_name().and_then(|name| name.value_token().ok()) {
+                Some(name) => name,
+                None => return Type::Unknown,
+            };
+
+            // The static methods of `Promise` and the methods of the promises
+            // return promises
+            let returns_promise = match name.text_trimmed() {
+                "all" | "allSettled" | "any" | "race" | "reject" | "resolve" => {
+                    self.is_global(&object, "Promise")
+                }
+                "then" | "catch" | "finally" => {
+                    self.expression(&object, depth).is_promise() == Some(true)
+                }
+                _ => false,
+            };
+
+            return if returns_promise {
+                Type::promise(Type::Unknown)
+            } else {
+                Type::Unknown
+            };
+        }
+
+        if self.is_global(&callee, "fetch") {
+            return Type::promise(Type::Unknown);
+        }
+
+        match self.expression(&callee, depth) {
+            Type::Function(function) => function.returns,
+            _ => Type::Unknown,
+        }
+    }
+
+    fn new_expression(&self, expression: &JsNewExpression) -> Type {
+        let callee = match expression.callee() {
+            Ok(callee) => callee.omit_parentheses(),
+            Err(_) => return Type::Unknown,
+        };
+
+        if self.is_global(&callee, "Promise") {
+            return Type::promise(Type::Unknown);
+        }
+
+        // The instances of the local classes
+        let is_class = callee
+            .as_reference_identifier()
+            .and_then(|name| self.semantic.declaration(&name))
+            .and_then(|binding| binding.syntax().parent())
+            .map_or(false, |parent| {
+                matches!(
+                    parent.kind(),
+                    JsSyntaxKind::JS_CLASS_DECLARATION
+                        | JsSyntaxKind::JS_CLASS_EXPORT_DEFAULT_DECLARATION
+                )
+            });
+
+        if is_class {
+            Type::Object
+        } else {
+            Type::Unknown
+        }
+    }
+
+    /// Returns `true` if `expression` is a reference to the global `name`
+    /// that isn't shadowed by a declaration of the module
+    fn is_global(&self, expression: &JsAnyExpression, name: &str) -> bool {
+        expression
+            .as_reference_identifier()
+            .map_or(false, |reference| {
+                reference.has_name(name) && self.semantic.declaration(&reference).is_none()
+            })
+    }
+
+    fn annotation(&self, ty: &TsType, depth: usize) -> Type {
+        match ty {
+            TsType::TsAnyType(_) | TsType::TsUnknownType(_) => Type::Unknown,
+            TsType::TsNeverType(_) => Type::Never,
+            TsType::TsUndefinedType(_) => Type::Undefined,
+            TsType::TsVoidType(_) => Type::Void,
+            TsType::TsNullLiteralType(_) => Type::Null,
+            TsType::TsBooleanType(_) => Type::Boolean,
+            TsType::TsNumberType(_) => Type::Number,
+            TsType::TsBigintType(_) => Type::BigInt,
+            TsType::TsStringType(_) | TsType::TsTemplateLiteralType(_) => Type::String,
+            TsType::TsSymbolType(_) => Type::Symbol,
+            TsType::TsNonPrimitiveType(_) | TsType::TsObjectType(_) => Type::Object,
+            TsType::TsBooleanLiteralType(literal) => match literal.literal() {
+                Ok(value) => Type::Literal(Literal::Boolean(value.text_trimmed() == "true")),
+                Err(_) => Type::Boolean,
+            },
+            TsType::TsNumberLiteralType(literal) => match literal.literal_token() {
+                Ok(value) => {
+                    let sign = if literal.minus_token().is_some() {
+                        "-"
+                    } else {
+                        ""
+                    };
+                    let text = format!("{sign}{}", value.text_trimmed());
+                    Type::Literal(Literal::Number(text))
+                }
+                Err(_) => Type::Number,
+            },
+            TsType::TsBigIntLiteralType(literal) => match literal.literal_token() {
+                Ok(value) => {
+                    let sign = if literal.minus_token().is_some() {
+                        "-"
+                    } else {
+                        ""
+                    };
+                    let digits = value.text_trimmed().trim_end_matches('n');
+                    Type::Literal(Literal::BigInt(format!("{sign}{digits}")))
+                }
+                Err(_) => Type::BigInt,
+            },
+            TsType::TsStringLiteralType(literal) => match literal.literal_token() {
+                Ok(value) => {
+                    let text = value.text_trimmed();
+                    Type::Literal(Literal::String(text[1..text.len() - 1].to_string()))
+                }
+                Err(_) => Type::String,
+            },
+            TsType::TsArrayType(array) => match array.element_type() {
+                Ok(element) => Type::Array(Box::new(self.annotation(&element, depth))),
+                Err(_) => Type::Array(Box::new(Type::Unknown)),
+            },
+            TsType::TsTupleType(_) => Type::Array(Box::new(Type::Unknown)),
+            TsType::TsFunctionType(function) => {
+                let returns = match function.return_type() {
+                    Ok(TsAnyReturnType::TsType(ty)) => self.annotation(&ty, depth),
+                    Ok(TsAnyReturnType::TsPredicateReturnType(_)) => Type::Boolean,
+                    Ok(TsAnyReturnType::TsAssertsReturnType(_)) => Type::Void,
+                    Err(_) => Type::Unknown,
+                };
+
+                Type::function(false, returns)
+            }
+            TsType::TsConstructorType(_) => class_type(),
+            TsType::TsParenthesizedType(ty) => match ty.ty() {
+                Ok(ty) => self.annotation(&ty, depth),
+                Err(_) => Type::Unknown,
+            },
+            TsType::TsUnionType(union) => Type::union(union.types().iter().map(|ty| match ty {
+                Ok(ty) => self.annotation(&ty, depth),
+                Err(_) => Type::Unknown,
+            })),
+            TsType::TsReferenceType(reference) => self.type_reference(reference, depth),
+            _ => Type::Unknown,
+        }
+    }
+
+    fn type_reference(&self, reference: &TsReferenceType, depth: usize) -> Type {
+        if depth > MAX_DEPTH {
+            return Type::Unknown;
+        }
+
+        let name = match reference.name() {
+            Ok(TsAnyName::JsReferenceIdentifier(name)) => name,
+            _ => return Type::Unknown,
+        };
+
+        // The type aliases declared in the module
+        if let Some(binding) = self.semantic.declaration(&name) {
+            return binding
+                .syntax()
+                .parent()
+                .and_then(TsTypeAliasDeclaration::cast)
+                .and_then(|alias| alias.ty().ok())
+                .map_or(Type::Unknown, |ty| self.annotation(&ty, depth + 1));
+        }
+
+        let argument = || {
+            reference
+                .type_arguments()
+                .and_then(|arguments| arguments.ts_type_argument_list().iter().next())
+                .and_then(|argument| argument.ok())
+                .map_or(Type::Unknown, |argument| self.annotation(&argument, depth))
+        };
+
+        match name.value_token() {
+            Ok(token) => match token.text_trimmed() {
+                "Promise" | "PromiseLike" => Type::promise(argument()),
+                "Array" | "ReadonlyArray" => Type::Array(Box::new(argument())),
+                _ => Type::Unknown,
+            },
+            Err(_) => Type::Unknown,
+        }
+    }
+}
+
+fn literal_type(literal: &JsAnyLiteralExpression) -> Type {
+    match literal {
+        JsAnyLiteralExpression::JsBooleanLiteralExpression(literal) => {
+            match literal.value_token() {
+                Ok(value) => Type::Literal(Literal::Boolean(value.text_trimmed() == "true")),
+                Err(_) => Type::Boolean,
+            }
+        }
+        JsAnyLiteralExpression::JsNumberLiteralExpression(literal) => match literal.value_token() {
+            Ok(value) => Type::Literal(Literal::Number(value.text_trimmed().to_string())),
+            Err(_) => Type::Number,
+        },
+        JsAnyLiteralExpression::JsBigIntLiteralExpression(literal) => match literal.value_token() {
+            Ok(value) => {
+                let digits = value.text_trimmed().trim_end_matches('n');
+                Type::Literal(Literal::BigInt(digits.to_string()))
+            }
+            Err(_) => Type::BigInt,
+        },
+        JsAnyLiteralExpression::JsStringLiteralExpression(literal) => {
+            match literal.inner_string_text() {
+                Ok(value) => Type::Literal(Literal::String(value.text().to_string())),
+                Err(_) => Type::String,
+            }
+        }
+        JsAnyLiteralExpression::JsNullLiteralExpression(_) => Type::Null,
+        JsAnyLiteralExpression::JsRegexLiteralExpression(_) => Type::Object,
+    }
+}
+
+/// The type of a class, a function returning its instances
+fn class_type() -> Type {
+    Type::function(false, Type::Object)
+}
+
+/// Returns the bindings declared by the exported `declaration`, leaving out the
+/// parameters and the local declarations of its functions and classes
+fn declared_bindings(declaration: &JsSyntaxNode) -> Vec<JsIdentifierBinding> {
+    let mut bindings = Vec::new();
+
+    let mut iter = declaration.preorder();
+    while let Some(event) = iter.next() {
+        if let WalkEvent::Enter(node) = event {
+            if matches!(
+                node.kind(),
+                JsSyntaxKind::JS_PARAMETERS
+                    | JsSyntaxKind::JS_FUNCTION_BODY
+                    | JsSyntaxKind::JS_INITIALIZER_CLAUSE
+                    | JsSyntaxKind::JS_CLASS_MEMBER_LIST
+            ) {
+                iter.skip_subtree();
+                continue;
+            }
+
+            if let Some(binding) = JsIdentifierBinding::cast(node) {
+                bindings.push(binding);
+            }
+        }
+    }
+
+    bindings
+}
+
+fn has_name(binding: &JsIdentifierBinding, name: &str) -> bool {
+    binding
+        .name_token()
+        .map_or(false, |token| token.text_trimmed() == name)
+}
+
+/// Returns `true` unless one of the `statements` always returns or throws,
+/// the statements that aren't known to do so being assumed to complete
+fn can_complete_normally(statements: JsStatementList) -> bool {
+    statements
+        .iter()
+        .all(|statement| statement_can_complete(&statement))
+}
+
+fn statement_can_complete(statement: &JsAnyStatement) -> bool {
+    let block_can_complete = |block: SyntaxResult<JsBlockStatement>| match block {
+        Ok(block) => can_complete_normally(block.statements()),
+        Err(_) => true,
+    };
+
+    match statement {
+        JsAnyStatement::JsReturnStatement(_) | JsAnyStatement::JsThrowStatement(_) => false,
+        JsAnyStatement::JsBlockStatement(block) => can_complete_normally(block.statements()),
+        JsAnyStatement::JsIfStatement(statement) => {
+            let alternate = match statement.else_clause() {
+                Some(clause) => clause.alternate(),
+                None => return true,
+            };
+
+            match (statement.consequent(), alternate) {
+                (Ok(consequent), Ok(alternate)) => {
+                    statement_can_complete(&consequent) || statement_can_complete(&alternate)
+                }
+                _ => true,
+            }
+        }
+        JsAnyStatement::JsTryStatement(statement) => {
+            block_can_complete(statement.body())
+                || block_can_complete(statement.catch_clause().and_then(|clause| clause.body()))
+        }
+        JsAnyStatement::JsTryFinallyStatement(statement) => {
+            if !block_can_complete(statement.finally_clause().and_then(|clause| clause.body())) {
+                return false;
+            }
+
+            block_can_complete(statement.body())
+                || statement
+                    .catch_clause()
+                    .map_or(false, |clause| block_can_complete(clause.body()))
+        }
+        _ => true,
+    }
+}
+
+/// Returns `true` for the nodes whose `return` statements don't return from
+/// the function containing them
+fn is_function_boundary(kind: JsSyntaxKind) -> bool {
+    JsAnyFunction::can_cast(kind)
+        || matches!(
+            kind,
+            JsSyntaxKind::JS_METHOD_CLASS_MEMBER
+                | JsSyntaxKind::JS_GETTER_CLASS_MEMBER
+                | JsSyntaxKind::JS_SETTER_CLASS_MEMBER
+                | JsSyntaxKind::JS_CONSTRUCTOR_CLASS_MEMBER
+                | JsSyntaxKind::JS_PROPERTY_CLASS_MEMBER
+                | JsSyntaxKind::JS_STATIC_INITIALIZATION_BLOCK_CLASS_MEMBER
+                | JsSyntaxKind::JS_METHOD_OBJECT_MEMBER
+                | JsSyntaxKind::JS_GETTER_OBJECT_MEMBER
+                | JsSyntaxKind::JS_SETTER_OBJECT_MEMBER
+        )
+}
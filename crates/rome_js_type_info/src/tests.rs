@@ -0,0 +1,203 @@
+use crate::{ImportResolver, Literal, Type, TypeModel};
+use rome_diagnostics::file::FileId;
+use rome_js_parser::parse;
+use rome_js_semantic::{semantic_model, SemanticModelOptions};
+use rome_js_syntax::{JsIdentifierBinding, SourceType};
+use rome_rowan::AstNode;
+use std::sync::Arc;
+
+fn type_model(source: &str) -> TypeModel {
+    let parsed = parse(source, FileId::zero(), SourceType::ts());
+    TypeModel::new(semantic_model(
+        &parsed.tree(),
+        SemanticModelOptions::default(),
+    ))
+}
+
+/// Returns the type of the first binding named `name`, displayed using the
+/// `TypeScript` syntax
+fn binding_type(model: &TypeModel, name: &str) -> String {
+    let scope = model.semantic_model().global_scope();
+    let root = scope.syntax().ancestors().last().unwrap();
+    let binding = root
+        .descendants()
+        .filter_map(JsIdentifierBinding::cast)
+        .find(|binding| binding.syntax().text_trimmed() == name)
+        .unwrap();
+
+    model.binding_type(&binding).to_string()
+}
+
+#[test]
+fn literals_and_annotations() {
+    let model = type_model(
+        r#"
+        const a = "text";
+        let b = 1;
+        const c: string | null = get();
+        let d = 1;
+        d = "text";
+        const e = [1, 2];
+        "#,
+    );
+
+    assert_eq!(binding_type(&model, "a"), r#""text""#);
+    assert_eq!(binding_type(&model, "b"), "number");
+    assert_eq!(binding_type(&model, "c"), "string | null");
+    assert_eq!(binding_type(&model, "d"), "unknown");
+    assert_eq!(binding_type(&model, "e"), "number[]");
+}
+
+#[test]
+fn return_types_of_local_functions() {
+    let model = type_model(
+        r#"
+        async function load() { return 1; }
+        function save(): Promise<void> {}
+        function pick(flag) { if (flag) { return "a"; } return null; }
+        const loaded = load();
+        const saved = save();
+        const picked = pick(true);
+        const awaited = await load();
+        const fetched = fetch("/").then(parse);
+        "#,
+    );
+
+    assert_eq!(binding_type(&model, "load"), "() => Promise<1>");
+    assert_eq!(binding_type(&model, "loaded"), "Promise<1>");
+    assert_eq!(binding_type(&model, "saved"), "Promise<void>");
+    assert_eq!(binding_type(&model, "picked"), r#""a" | null"#);
+    assert_eq!(binding_type(&model, "awaited"), "1");
+    assert_eq!(binding_type(&model, "fetched"), "Promise<unknown>");
+}
+
+#[test]
+fn parameters_without_annotation_are_unknown() {
+    let model = type_model(
+        r#"
+        function f(a = 1, b: string = "text") {}
+        "#,
+    );
+
+    assert_eq!(binding_type(&model, "a"), "unknown");
+    assert_eq!(binding_type(&model, "b"), "string");
+}
+
+#[test]
+fn reaching_the_end_of_a_function_returns_undefined() {
+    let model = type_model(
+        r#"
+        function find(flag) { if (flag) { return "a"; } }
+        function pick(flag) { if (flag) { return "a"; } else { return "b"; } }
+        function parse(text) { try { return JSON.parse(text); } catch { throw text; } }
+        "#,
+    );
+
+    assert_eq!(binding_type(&model, "find"), r#"() => "a" | undefined"#);
+    assert_eq!(binding_type(&model, "pick"), r#"() => "a" | "b""#);
+    assert_eq!(binding_type(&model, "parse"), "() => unknown");
+}
+
+#[test]
+fn truthiness_of_literals() {
+    let number = |text: &str| Literal::Number(text.to_string()).truthiness();
+    let bigint = |text: &str| Literal::BigInt(text.to_string()).truthiness();
+
+    assert_eq!(number("0"), Some(false));
+    assert_eq!(number("0.5"), Some(true));
+    assert_eq!(number("-0"), Some(false));
+    assert_eq!(number("0x0"), None);
+    assert_eq!(number("0b1"), None);
+    assert_eq!(bigint("0"), Some(false));
+    assert_eq!(bigint("-10"), Some(true));
+    assert_eq!(bigint("0x0"), None);
+}
+
+#[test]
+fn recursive_declarations_are_unknown() {
+    let model = type_model(
+        r#"
+        function loop() { return loop(); }
+        const value = loop();
+        "#,
+    );
+
+    assert_eq!(binding_type(&model, "value"), "unknown");
+}
+
+#[derive(Debug)]
+struct TestResolver;
+
+impl ImportResolver for TestResolver {
+    fn export_type(&self, specifier: &str, name: &str) -> Type {
+        match (specifier, name) {
+            ("./api", "load") => Type::function(true, Type::promise(Type::String)),
+            _ => Type::Unknown,
+        }
+    }
+}
+
+#[test]
+fn imported_declarations() {
+    let model = type_model(
+        r#"
+        import { load } from "./api";
+        import { save } from "./api";
+        import type { Loader } from "./api";
+        const loaded = load();
+        const saved = save();
+        "#,
+    )
+    .with_import_resolver(Arc::new(TestResolver));
+
+    assert_eq!(binding_type(&model, "loaded"), "Promise<string>");
+    assert_eq!(binding_type(&model, "saved"), "unknown");
+}
+
+#[test]
+fn exported_declarations() {
+    let model = type_model(
+        r#"
+        export async function load() {}
+        const value = 1;
+        export { value as renamed };
+        export default "text";
+        "#,
+    );
+
+    assert_eq!(model.export_type("load").to_string(), "() => Promise<void>");
+    assert_eq!(model.export_type("renamed").to_string(), "1");
+    assert_eq!(model.export_type("default").to_string(), r#""text""#);
+    assert_eq!(model.export_type("value").to_string(), "unknown");
+}
+
+#[test]
+fn all_exported_declarations() {
+    let model = type_model(
+        r#"
+        export async function load(id) {}
+        export const a = 1, { b } = get();
+        const value = "text";
+        export { value, value as renamed };
+        export default 2;
+        "#,
+    );
+
+    let exports: Vec<_> = model
+        .export_types()
+        .into_iter()
+        .map(|(name, ty)| format!("{name}: {ty}"))
+        .collect();
+
+    assert_eq!(
+        exports,
+        [
+            "load: () => Promise<void>",
+            "a: 1",
+            "b: unknown",
+            r#"value: "text""#,
+            r#"renamed: "text""#,
+            "default: 2",
+        ]
+    );
+}
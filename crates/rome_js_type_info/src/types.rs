@@ -0,0 +1,253 @@
+use std::fmt::{self, Display, Formatter};
+
+/// The type of a value, as far as it could be inferred
+///
+/// The inference is best-effort: whenever the type of a value can't be
+/// determined with confidence it's [Type::Unknown], and the rules should not
+/// make any assumption about such a value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Type {
+    /// The type couldn't be inferred, or was declared as `any` or `unknown`
+    Unknown,
+    /// The type of the expressions that never complete
+    Never,
+    Undefined,
+    /// The return type of the functions declared as returning `void`
+    Void,
+    Null,
+    Boolean,
+    Number,
+    BigInt,
+    String,
+    Symbol,
+    /// A boolean, number, bigint or string literal type
+    Literal(Literal),
+    /// An object that isn't a function, an array or a promise
+    Object,
+    /// An array with elements of the given type
+    Array(Box<Type>),
+    /// A function, with the type returned by its calls
+    Function(Box<FunctionType>),
+    /// A promise resolving to the given type
+    Promise(Box<Type>),
+    /// A value of one of several types, never nested and never containing
+    /// [Type::Unknown]
+    Union(Vec<Type>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Literal {
+    Boolean(bool),
+    /// The text of a number literal, like `0` or `1.5`
+    Number(String),
+    /// The text of a bigint literal without the trailing `n`
+    BigInt(String),
+    /// The value of a string literal, without the quotes
+    String(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FunctionType {
+    /// Set to `true` for the `async` functions
+    pub is_async: bool,
+    /// The type returned by a call to the function, a [Type::Promise] for the
+    /// `async` functions
+    pub returns: Type,
+}
+
+impl Type {
+    /// Return the union of `types`, flattening the nested unions and removing
+    /// the duplicate types
+    ///
+    /// The union is [Type::Unknown] if any of the types is unknown, and
+    /// [Type::Never] if `types` is empty.
+    pub fn union(types: impl IntoIterator<Item = Type>) -> Type {
+        let mut variants = Vec::new();
+
+        for ty in types {
+            let members = match ty {
+                Type::Unknown => return Type::Unknown,
+                Type::Never => continue,
+                Type::Union(members) => members,
+                ty => vec![ty],
+            };
+
+            for member in members {
+                if !variants.contains(&member) {
+                    variants.push(member);
+                }
+            }
+        }
+
+        match variants.len() {
+            0 => Type::Never,
+            1 => variants.remove(0),
+            _ => Type::Union(variants),
+        }
+    }
+
+    pub fn promise(resolved: Type) -> Type {
+        Type::Promise(Box::new(resolved))
+    }
+
+    pub fn function(is_async: bool, returns: Type) -> Type {
+        Type::Function(Box::new(FunctionType { is_async, returns }))
+    }
+
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Type::Unknown)
+    }
+
+    /// Returns `Some(true)` if the value is a promise, or may be a promise
+    /// for a union, `Some(false)` if it's never a promise and `None` if it's
+    /// unknown
+    pub fn is_promise(&self) -> Option<bool> {
+        match self {
+            Type::Unknown => None,
+            Type::Promise(_) => Some(true),
+            Type::Union(members) => {
+                let mut is_promise = Some(false);
+                for member in members {
+                    match member.is_promise() {
+                        Some(true) => return Some(true),
+                        Some(false) => {}
+                        None => is_promise = None,
+                    }
+                }
+
+                is_promise
+            }
+            _ => Some(false),
+        }
+    }
+
+    /// Returns `Some(true)` if the value is always truthy, `Some(false)` if
+    /// it's always falsy, and `None` if it may be either or is unknown
+    pub fn truthiness(&self) -> Option<bool> {
+        match self {
+            Type::Unknown
+            | Type::Never
+            | Type::Boolean
+            | Type::Number
+            | Type::BigInt
+            | Type::String => None,
+            Type::Undefined | Type::Void | Type::Null => Some(false),
+            Type::Symbol | Type::Object | Type::Array(_) | Type::Function(_) | Type::Promise(_) => {
+                Some(true)
+            }
+            Type::Literal(literal) => literal.truthiness(),
+            Type::Union(members) => {
+                let mut members = members.iter().map(Type::truthiness);
+                let first = members.next()?;
+                if members.all(|truthiness| truthiness == first) {
+                    first
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    /// Returns the type of the value an `await` expression evaluates to
+    pub fn awaited(self) -> Type {
+        match self {
+            Type::Promise(resolved) => resolved.awaited(),
+            Type::Union(members) => Type::union(members.into_iter().map(Type::awaited)),
+            ty => ty,
+        }
+    }
+
+    /// Returns the primitive type of a literal type, like `TypeScript` does
+    /// for the variables declared with `let`
+    pub fn widen(self) -> Type {
+        match self {
+            Type::Literal(Literal::Boolean(_)) => Type::Boolean,
+            Type::Literal(Literal::Number(_)) => Type::Number,
+            Type::Literal(Literal::BigInt(_)) => Type::BigInt,
+            Type::Literal(Literal::String(_)) => Type::String,
+            Type::Union(members) => Type::union(members.into_iter().map(Type::widen)),
+            ty => ty,
+        }
+    }
+
+    /// Removes `null` and `undefined` from the type, like a non-null
+    /// assertion does
+    pub fn non_nullable(self) -> Type {
+        match self {
+            Type::Undefined | Type::Void | Type::Null => Type::Never,
+            Type::Union(members) => Type::union(members.into_iter().map(Type::non_nullable)),
+            ty => ty,
+        }
+    }
+}
+
+impl Literal {
+    /// Returns whether the value of the literal is truthy, or `None` if the
+    /// text of a number isn't a decimal literal the model can evaluate
+    pub fn truthiness(&self) -> Option<bool> {
+        match self {
+            Literal::Boolean(value) => Some(*value),
+            Literal::Number(text) => text.parse::<f64>().ok().map(|value| value != 0.0),
+            Literal::BigInt(text) => {
+                let digits = text.trim_start_matches('-');
+                if digits.is_empty() || !digits.bytes().all(|byte| byte.is_ascii_digit()) {
+                    return None;
+                }
+
+                Some(digits.bytes().any(|byte| byte != b'0'))
+            }
+            Literal::String(value) => Some(!value.is_empty()),
+        }
+    }
+}
+
+/// Types are displayed using the `TypeScript` syntax
+impl Display for Type {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Type::Unknown => write!(f, "unknown"),
+            Type::Never => write!(f, "never"),
+            Type::Undefined => write!(f, "undefined"),
+            Type::Void => write!(f, "void"),
+            Type::Null => write!(f, "null"),
+            Type::Boolean => write!(f, "boolean"),
+            Type::Number => write!(f, "number"),
+            Type::BigInt => write!(f, "bigint"),
+            Type::String => write!(f, "string"),
+            Type::Symbol => write!(f, "symbol"),
+            Type::Literal(literal) => write!(f, "{literal}"),
+            Type::Object => write!(f, "object"),
+            Type::Array(element) => match element.as_ref() {
+                Type::Union(_) | Type::Function(_) => write!(f, "({element})[]"),
+                element => write!(f, "{element}[]"),
+            },
+            Type::Function(function) => write!(f, "() => {}", function.returns),
+            Type::Promise(resolved) => write!(f, "Promise<{resolved}>"),
+            Type::Union(members) => {
+                for (index, member) in members.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, " | ")?;
+                    }
+
+                    match member {
+                        Type::Function(_) => write!(f, "({member})")?,
+                        member => write!(f, "{member}")?,
+                    }
+                }
+
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Display for Literal {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Literal::Boolean(value) => write!(f, "{value}"),
+            Literal::Number(text) => write!(f, "{text}"),
+            Literal::BigInt(text) => write!(f, "{text}n"),
+            Literal::String(value) => write!(f, "{value:?}"),
+        }
+    }
+}
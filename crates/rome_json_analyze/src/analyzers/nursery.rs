@@ -0,0 +1,5 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+use rome_analyze::declare_group;
+mod use_sorted_package_json;
+declare_group! { pub (crate) Nursery { name : "nursery" , rules : [self :: use_sorted_package_json :: UseSortedPackageJson ,] } }
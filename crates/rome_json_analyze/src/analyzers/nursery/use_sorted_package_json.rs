@@ -0,0 +1,340 @@
+use crate::JsonRuleAction;
+use rome_analyze::context::RuleContext;
+use rome_analyze::{declare_rule, ActionCategory, Ast, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_diagnostics::Applicability;
+use rome_json_factory::make;
+use rome_json_syntax::{JsonMember, JsonMemberList, JsonObject, JsonRoot, JsonSyntaxToken};
+use rome_rowan::{AstNode, AstNodeExt, AstSeparatedList, BatchMutationExt};
+
+declare_rule! {
+    /// Enforce a consistent order for the keys of `package.json` files.
+    ///
+    /// The top-level fields are sorted following the conventional order used
+    /// by the npm ecosystem (`name`, `version`, `description`, ...). Unknown
+    /// fields are moved after the known ones, keeping their relative order.
+    ///
+    /// The entries of the dependency maps (`dependencies`, `devDependencies`,
+    /// `peerDependencies`, `peerDependenciesMeta`, `optionalDependencies` and
+    /// `resolutions`) are sorted alphabetically, the same way package managers
+    /// write them.
+    ///
+    /// This rule only runs on `package.json` files.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```json,expect_diagnostic
+    /// {
+    ///   "version": "1.0.0",
+    ///   "name": "my-package"
+    /// }
+    /// ```
+    ///
+    /// ```json,expect_diagnostic
+    /// {
+    ///   "dependencies": {
+    ///     "react": "^18.0.0",
+    ///     "clsx": "^1.2.0"
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```json
+    /// {
+    ///   "name": "my-package",
+    ///   "version": "1.0.0",
+    ///   "dependencies": {
+    ///     "clsx": "^1.2.0",
+    ///     "react": "^18.0.0"
+    ///   }
+    /// }
+    /// ```
+    pub(crate) UseSortedPackageJson {
+        version: "11.0.0",
+        name: "useSortedPackageJson",
+        recommended: false,
+    }
+}
+
+/// Conventional order of the top-level fields of a `package.json` file
+const TOP_LEVEL_KEYS: &[&str] = &[
+    "$schema",
+    "name",
+    "displayName",
+    "version",
+    "private",
+    "description",
+    "categories",
+    "keywords",
+    "homepage",
+    "bugs",
+    "repository",
+    "funding",
+    "license",
+    "qna",
+    "author",
+    "maintainers",
+    "contributors",
+    "publisher",
+    "sideEffects",
+    "type",
+    "imports",
+    "exports",
+    "main",
+    "svelte",
+    "umd:main",
+    "jsdelivr",
+    "unpkg",
+    "module",
+    "source",
+    "jsnext:main",
+    "browser",
+    "react-native",
+    "types",
+    "typesVersions",
+    "typings",
+    "style",
+    "example",
+    "examplestyle",
+    "assets",
+    "bin",
+    "man",
+    "directories",
+    "files",
+    "workspaces",
+    "binary",
+    "scripts",
+    "betterScripts",
+    "contributes",
+    "activationEvents",
+    "husky",
+    "simple-git-hooks",
+    "pre-commit",
+    "commitlint",
+    "lint-staged",
+    "config",
+    "nodemonConfig",
+    "browserify",
+    "babel",
+    "browserslist",
+    "xo",
+    "prettier",
+    "eslintConfig",
+    "eslintIgnore",
+    "npmpackagejsonlint",
+    "release",
+    "remarkConfig",
+    "stylelint",
+    "ava",
+    "jest",
+    "mocha",
+    "nyc",
+    "c8",
+    "tap",
+    "resolutions",
+    "dependencies",
+    "devDependencies",
+    "dependenciesMeta",
+    "peerDependencies",
+    "peerDependenciesMeta",
+    "optionalDependencies",
+    "bundledDependencies",
+    "bundleDependencies",
+    "extensionPack",
+    "extensionDependencies",
+    "flat",
+    "packageManager",
+    "engines",
+    "engineStrict",
+    "volta",
+    "languageName",
+    "os",
+    "cpu",
+    "preferGlobal",
+    "publishConfig",
+    "icon",
+    "badges",
+    "galleryBanner",
+    "preview",
+    "markdown",
+];
+
+/// Top-level fields whose entries are sorted alphabetically
+const SORTED_MAPS: &[&str] = &[
+    "dependencies",
+    "devDependencies",
+    "peerDependencies",
+    "peerDependenciesMeta",
+    "optionalDependencies",
+    "resolutions",
+];
+
+/// The kind of object being checked by the rule
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ObjectKind {
+    /// The top-level object of the document
+    Root,
+    /// A dependency map, the name of the field is stored in the variant
+    Dependencies(&'static str),
+}
+
+pub(crate) struct SortedMembers {
+    kind: ObjectKind,
+    /// The members of the list, in the order they should be in
+    members: Vec<JsonMember>,
+}
+
+impl Rule for UseSortedPackageJson {
+    type Query = Ast<JsonMemberList>;
+    type State = SortedMembers;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let list = ctx.query();
+        let kind = object_kind(list)?;
+
+        let mut members = Vec::with_capacity(list.len());
+        for member in list.iter() {
+            members.push(member.ok()?);
+        }
+
+        let mut sorted = members
+            .iter()
+            .map(|member| Some((member_name(member)?, member.clone())))
+            .collect::<Option<Vec<_>>>()?;
+
+        // The sort is stable: the unknown top-level fields keep their relative order
+        match kind {
+            ObjectKind::Root => sorted.sort_by_key(|(name, _)| {
+                TOP_LEVEL_KEYS
+                    .iter()
+                    .position(|key| key == name)
+                    .unwrap_or(TOP_LEVEL_KEYS.len())
+            }),
+            ObjectKind::Dependencies(_) => sorted.sort_by(|(a, _), (b, _)| a.cmp(b)),
+        }
+
+        let sorted: Vec<_> = sorted.into_iter().map(|(_, member)| member).collect();
+
+        if sorted == members {
+            None
+        } else {
+            Some(SortedMembers {
+                kind,
+                members: sorted,
+            })
+        }
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let list = ctx.query();
+
+        let diagnostic = match state.kind {
+            ObjectKind::Root => RuleDiagnostic::new(
+                rule_category!(),
+                list.syntax().text_trimmed_range(),
+                markup! {
+                    "The fields of this "<Emphasis>"package.json"</Emphasis>" file are not in the conventional order."
+                },
+            ),
+            ObjectKind::Dependencies(name) => RuleDiagnostic::new(
+                rule_category!(),
+                list.syntax().text_trimmed_range(),
+                markup! {
+                    "The entries of "<Emphasis>{name}</Emphasis>" are not sorted alphabetically."
+                },
+            ),
+        };
+
+        Some(diagnostic)
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsonRuleAction> {
+        let list = ctx.query();
+        let mut mutation = ctx.root().begin();
+
+        // Each member takes the leading trivia of the first token and the
+        // trailing trivia of the last token of the member it replaces, this
+        // preserves the line breaks and the spacing of the list
+        let mut members = Vec::with_capacity(state.members.len());
+        for (original, member) in list.iter().zip(&state.members) {
+            let original = original.ok()?;
+            members.push(with_trivia_of(member.clone(), &original)?);
+        }
+
+        let separators = list.separators().collect::<Result<Vec<_>, _>>().ok()?;
+
+        mutation.replace_node(list.clone(), make::json_member_list(members, separators));
+
+        let message = match state.kind {
+            ObjectKind::Root => markup! { "Sort the fields in the conventional order." }.to_owned(),
+            ObjectKind::Dependencies(_) => {
+                markup! { "Sort the entries alphabetically." }.to_owned()
+            }
+        };
+
+        Some(JsonRuleAction {
+            mutation,
+            message,
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::Always,
+        })
+    }
+}
+
+/// Returns the kind of the object containing `list`, or [None] if the rule
+/// doesn't apply to this object
+fn object_kind(list: &JsonMemberList) -> Option<ObjectKind> {
+    let object = list.parent::<JsonObject>()?;
+
+    if object.parent::<JsonRoot>().is_some() {
+        return Some(ObjectKind::Root);
+    }
+
+    let member = object.parent::<JsonMember>()?;
+    let name = member_name(&member)?;
+    let name = SORTED_MAPS.iter().find(|field| **field == name)?;
+
+    // Only the dependency maps at the top-level of the document are sorted
+    let parent_list = member.parent::<JsonMemberList>()?;
+    let parent_object = parent_list.parent::<JsonObject>()?;
+    parent_object.parent::<JsonRoot>()?;
+
+    Some(ObjectKind::Dependencies(name))
+}
+
+/// Returns `member` with the leading and trailing trivia of `original`
+fn with_trivia_of(member: JsonMember, original: &JsonMember) -> Option<JsonMember> {
+    let original_first = original.syntax().first_token()?;
+    let original_last = original.syntax().last_token()?;
+
+    let first = member.syntax().first_token()?;
+    let member = member.replace_token_discard_trivia(
+        first.clone(),
+        first.with_leading_trivia_pieces(original_first.leading_trivia().pieces()),
+    )?;
+
+    let last = member.syntax().last_token()?;
+    member.replace_token_discard_trivia(
+        last.clone(),
+        last.with_trailing_trivia_pieces(original_last.trailing_trivia().pieces()),
+    )
+}
+
+fn key_token(member: &JsonMember) -> Option<JsonSyntaxToken> {
+    member.key().ok()?.json_string_literal_token().ok()
+}
+
+/// Returns the name of a member, without the quotes
+fn member_name(member: &JsonMember) -> Option<String> {
+    let token = key_token(member)?;
+    let text = token.text_trimmed();
+    let name = text.strip_prefix('"')?.strip_suffix('"')?;
+
+    Some(name.to_string())
+}
@@ -0,0 +1,185 @@
+use rome_analyze::{
+    AnalysisFilter, Analyzer, AnalyzerContext, AnalyzerOptions, AnalyzerSignal,
+    AnalyzerSuppression, ControlFlow, LanguageRoot, MetadataRegistry, Phases, RuleAction,
+    RuleRegistry, ServiceBag, SyntaxVisitor,
+};
+use rome_diagnostics::file::FileId;
+use rome_json_syntax::JsonLanguage;
+
+mod analyzers;
+mod registry;
+
+pub use crate::registry::visit_registry;
+
+pub(crate) type JsonRuleAction = RuleAction<JsonLanguage>;
+
+/// Return the static [MetadataRegistry] for the JSON analyzer rules
+pub fn metadata() -> &'static MetadataRegistry {
+    lazy_static::lazy_static! {
+        static ref METADATA: MetadataRegistry = {
+            let mut metadata = MetadataRegistry::default();
+            visit_registry(&mut metadata);
+            metadata
+        };
+    }
+
+    &METADATA
+}
+
+/// Run the analyzer on the provided `root`: this process will use the given `filter`
+/// to selectively restrict analysis to specific rules / a specific source range,
+/// then call `emit_signal` when an analysis rule emits a diagnostic or action
+pub fn analyze<'a, F, B>(
+    file_id: FileId,
+    root: &LanguageRoot<JsonLanguage>,
+    filter: AnalysisFilter,
+    options: &'a AnalyzerOptions,
+    mut emit_signal: F,
+) -> Option<B>
+where
+    F: FnMut(&dyn AnalyzerSignal<JsonLanguage>) -> ControlFlow<B> + 'a,
+    B: 'a,
+{
+    // JSON documents can't contain comments, so they can't contain suppressions either
+    fn parse_linter_suppression_comment(_text: &str) -> Vec<AnalyzerSuppression> {
+        Vec::new()
+    }
+
+    let mut registry = RuleRegistry::builder(&filter);
+    visit_registry(&mut registry);
+
+    let mut analyzer = Analyzer::new(
+        metadata(),
+        registry.build(),
+        parse_linter_suppression_comment,
+        &mut emit_signal,
+    );
+    analyzer.add_visitor(Phases::Syntax, SyntaxVisitor::default());
+
+    analyzer.run(AnalyzerContext {
+        file_id,
+        root: root.clone(),
+        range: filter.range,
+        services: ServiceBag::default(),
+        options,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::analyze;
+    use rome_analyze::{AnalysisFilter, AnalyzerOptions, ControlFlow, Never};
+    use rome_diagnostics::file::FileId;
+    use rome_json_parser::parse_json;
+
+    /// Applies the actions emitted for `text` and returns the resulting document
+    fn apply_fixes(text: &str) -> String {
+        let parse = parse_json(text, FileId::zero());
+        assert!(!parse.has_errors(), "{:?}", parse.diagnostics());
+
+        let mut code = text.to_string();
+        let options = AnalyzerOptions::default();
+        analyze(
+            FileId::zero(),
+            &parse.tree(),
+            AnalysisFilter::default(),
+            &options,
+            |signal| {
+                if let Some(action) = signal.actions().into_iter().next() {
+                    code = action.mutation.commit().to_string();
+                    return ControlFlow::Break(());
+                }
+
+                ControlFlow::<()>::Continue(())
+            },
+        );
+
+        code
+    }
+
+    fn count_diagnostics(text: &str) -> usize {
+        let parse = parse_json(text, FileId::zero());
+
+        let mut count = 0;
+        let options = AnalyzerOptions::default();
+        analyze(
+            FileId::zero(),
+            &parse.tree(),
+            AnalysisFilter::default(),
+            &options,
+            |signal| {
+                if signal.diagnostic().is_some() {
+                    count += 1;
+                }
+
+                ControlFlow::<Never>::Continue(())
+            },
+        );
+
+        count
+    }
+
+    #[test]
+    fn sorted_package_json() {
+        const SOURCE: &str = r#"{
+  "name": "rome",
+  "version": "10.0.0",
+  "scripts": {
+    "build": "tsc"
+  },
+  "dependencies": {
+    "a": "1.0.0",
+    "b": "1.0.0"
+  },
+  "unknown": true
+}
+"#;
+
+        assert_eq!(count_diagnostics(SOURCE), 0);
+    }
+
+    #[test]
+    fn sorts_top_level_keys() {
+        const SOURCE: &str = r#"{
+  "version": "10.0.0",
+  "custom": 1,
+  "name": "rome",
+  "other": 2,
+  "license": "MIT"
+}
+"#;
+
+        assert_eq!(count_diagnostics(SOURCE), 1);
+        assert_eq!(
+            apply_fixes(SOURCE),
+            r#"{
+  "name": "rome",
+  "version": "10.0.0",
+  "license": "MIT",
+  "custom": 1,
+  "other": 2
+}
+"#
+        );
+    }
+
+    #[test]
+    fn sorts_dependencies() {
+        const SOURCE: &str =
+            r#"{ "devDependencies": { "zod": "3", "@types/node": "18", "esbuild": "0.15" } }"#;
+
+        assert_eq!(
+            apply_fixes(SOURCE),
+            r#"{ "devDependencies": { "@types/node": "18", "esbuild": "0.15", "zod": "3" } }"#
+        );
+    }
+
+    #[test]
+    fn ignores_non_object_documents() {
+        assert_eq!(count_diagnostics(r#"["b", "a"]"#), 0);
+        assert_eq!(
+            count_diagnostics(r#"{ "scripts": { "b": "", "a": "" } }"#),
+            0
+        );
+    }
+}
@@ -0,0 +1,252 @@
+//! Splits the text of a JSON document into tokens, including the whitespace
+//! and line breaks that end up as trivia in the syntax tree
+
+use rome_diagnostics::file::FileId;
+use rome_json_syntax::{JsonSyntaxKind, TextRange, TextSize, T};
+use rome_parser::ParseDiagnostic;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Token {
+    pub(crate) kind: JsonSyntaxKind,
+    pub(crate) range: TextRange,
+}
+
+pub(crate) struct Lexer<'src> {
+    text: &'src str,
+    position: usize,
+    file_id: FileId,
+    diagnostics: Vec<ParseDiagnostic>,
+}
+
+impl<'src> Lexer<'src> {
+    pub(crate) fn new(text: &'src str, file_id: FileId) -> Self {
+        Self {
+            text,
+            position: 0,
+            file_id,
+            diagnostics: Vec::new(),
+        }
+    }
+
+    /// Lexes the whole document, returning the list of tokens and the
+    /// diagnostics emitted for invalid tokens
+    pub(crate) fn lex(mut self) -> (Vec<Token>, Vec<ParseDiagnostic>) {
+        let mut tokens = Vec::new();
+
+        while self.position < self.text.len() {
+            let start = self.position;
+            let kind = self.lex_token();
+            tokens.push(Token {
+                kind,
+                range: self.range_from(start),
+            });
+        }
+
+        (tokens, self.diagnostics)
+    }
+
+    fn lex_token(&mut self) -> JsonSyntaxKind {
+        let bytes = self.text.as_bytes();
+        let start = self.position;
+
+        match bytes[start] {
+            b' ' | b'\t' => {
+                self.eat_while(|byte| byte == b' ' || byte == b'\t');
+                JsonSyntaxKind::WHITESPACE
+            }
+            b'\n' => {
+                self.position += 1;
+                JsonSyntaxKind::NEWLINE
+            }
+            b'\r' => {
+                self.position += 1;
+                if self.current_byte() == Some(b'\n') {
+                    self.position += 1;
+                }
+                JsonSyntaxKind::NEWLINE
+            }
+            b'{' => self.punct(T!['{']),
+            b'}' => self.punct(T!['}']),
+            b'[' => self.punct(T!['[']),
+            b']' => self.punct(T![']']),
+            b':' => self.punct(T![:]),
+            b',' => self.punct(T![,]),
+            b'"' => self.lex_string(),
+            b'-' | b'0'..=b'9' => self.lex_number(),
+            byte if byte.is_ascii_alphabetic() => self.lex_keyword(),
+            _ => {
+                // Skip the whole character, it may span multiple bytes
+                let len = self.text[start..]
+                    .chars()
+                    .next()
+                    .map_or(1, |c| c.len_utf8());
+                self.position += len;
+
+                self.diagnostics.push(ParseDiagnostic::new(
+                    self.file_id,
+                    "unexpected character",
+                    self.range_from(start),
+                ));
+                JsonSyntaxKind::ERROR_TOKEN
+            }
+        }
+    }
+
+    fn punct(&mut self, kind: JsonSyntaxKind) -> JsonSyntaxKind {
+        self.position += 1;
+        kind
+    }
+
+    fn lex_string(&mut self) -> JsonSyntaxKind {
+        let start = self.position;
+        // Opening quote
+        self.position += 1;
+
+        loop {
+            match self.current_byte() {
+                Some(b'"') => {
+                    self.position += 1;
+                    return JsonSyntaxKind::JSON_STRING_LITERAL;
+                }
+                Some(b'\\') => {
+                    let escape_start = self.position;
+                    self.position += 1;
+
+                    match self.current_byte() {
+                        Some(b'"' | b'\\' | b'/' | b'b' | b'f' | b'n' | b'r' | b't') => {
+                            self.position += 1;
+                        }
+                        Some(b'u') => {
+                            self.position += 1;
+                            let digits = self.text.as_bytes()[self.position..]
+                                .iter()
+                                .take(4)
+                                .take_while(|byte| byte.is_ascii_hexdigit())
+                                .count();
+                            self.position += digits;
+
+                            if digits < 4 {
+                                self.diagnostics.push(ParseDiagnostic::new(
+                                    self.file_id,
+                                    "invalid unicode escape sequence",
+                                    self.range_from(escape_start),
+                                ));
+                            }
+                        }
+                        _ => {
+                            self.diagnostics.push(ParseDiagnostic::new(
+                                self.file_id,
+                                "invalid escape sequence",
+                                self.range_from(escape_start),
+                            ));
+                        }
+                    }
+                }
+                Some(b'\n' | b'\r') | None => {
+                    self.diagnostics.push(ParseDiagnostic::new(
+                        self.file_id,
+                        "unterminated string literal",
+                        self.range_from(start),
+                    ));
+                    return JsonSyntaxKind::ERROR_TOKEN;
+                }
+                Some(_) => {
+                    // Strings may contain multi-byte characters, all the
+                    // bytes of the character are skipped at once
+                    let len = self.text[self.position..]
+                        .chars()
+                        .next()
+                        .map_or(1, |c| c.len_utf8());
+                    self.position += len;
+                }
+            }
+        }
+    }
+
+    fn lex_number(&mut self) -> JsonSyntaxKind {
+        let start = self.position;
+
+        if self.current_byte() == Some(b'-') {
+            self.position += 1;
+        }
+
+        let mut valid = match self.current_byte() {
+            Some(b'0') => {
+                self.position += 1;
+                // Leading zeros are not allowed
+                !matches!(self.current_byte(), Some(b'0'..=b'9'))
+            }
+            Some(b'1'..=b'9') => {
+                self.eat_while(|byte| byte.is_ascii_digit());
+                true
+            }
+            _ => false,
+        };
+
+        if self.current_byte() == Some(b'.') {
+            self.position += 1;
+            valid &= self.eat_while(|byte| byte.is_ascii_digit()) > 0;
+        }
+
+        if matches!(self.current_byte(), Some(b'e' | b'E')) {
+            self.position += 1;
+            if matches!(self.current_byte(), Some(b'+' | b'-')) {
+                self.position += 1;
+            }
+            valid &= self.eat_while(|byte| byte.is_ascii_digit()) > 0;
+        }
+
+        // Consume the rest of a malformed number, e.g. `01` or `1.2.3`
+        let rest = self.eat_while(|byte| byte.is_ascii_alphanumeric() || byte == b'.');
+
+        if valid && rest == 0 {
+            JsonSyntaxKind::JSON_NUMBER_LITERAL
+        } else {
+            self.diagnostics.push(ParseDiagnostic::new(
+                self.file_id,
+                "invalid number",
+                self.range_from(start),
+            ));
+            JsonSyntaxKind::ERROR_TOKEN
+        }
+    }
+
+    fn lex_keyword(&mut self) -> JsonSyntaxKind {
+        let start = self.position;
+        self.eat_while(|byte| byte.is_ascii_alphanumeric() || byte == b'_');
+
+        match &self.text[start..self.position] {
+            "true" => T![true],
+            "false" => T![false],
+            "null" => T![null],
+            _ => {
+                self.diagnostics.push(ParseDiagnostic::new(
+                    self.file_id,
+                    "unexpected identifier, expected `true`, `false` or `null`",
+                    self.range_from(start),
+                ));
+                JsonSyntaxKind::ERROR_TOKEN
+            }
+        }
+    }
+
+    fn current_byte(&self) -> Option<u8> {
+        self.text.as_bytes().get(self.position).copied()
+    }
+
+    /// Advances while `predicate` returns true, and returns the number of bytes consumed
+    fn eat_while(&mut self, predicate: impl Fn(u8) -> bool) -> usize {
+        let start = self.position;
+        while self.current_byte().map_or(false, &predicate) {
+            self.position += 1;
+        }
+        self.position - start
+    }
+
+    fn range_from(&self, start: usize) -> TextRange {
+        TextRange::new(
+            TextSize::from(start as u32),
+            TextSize::from(self.position as u32),
+        )
+    }
+}
@@ -0,0 +1,135 @@
+//! Parser for JSON documents
+//!
+//! The parser is lossless: the whitespace and line breaks of the document are
+//! stored as trivia of the tokens, and invalid parts of the document are
+//! wrapped in [JsonUnknown](rome_json_syntax::JsonUnknown) nodes.
+
+mod lexer;
+mod parser;
+
+use rome_diagnostics::file::FileId;
+use rome_json_syntax::{JsonRoot, JsonSyntaxNode};
+pub use rome_parser::ParseDiagnostic;
+use rome_rowan::AstNode;
+
+/// Parses the text of a JSON document
+///
+/// ## Examples
+///
+/// ```
+/// use rome_diagnostics::file::FileId;
+/// use rome_json_parser::parse_json;
+///
+/// let parse = parse_json(r#"{ "name": "rome" }"#, FileId::zero());
+///
+/// assert!(!parse.has_errors());
+/// assert_eq!(parse.syntax().to_string(), r#"{ "name": "rome" }"#);
+/// ```
+pub fn parse_json(text: &str, file_id: FileId) -> JsonParse {
+    parser::parse(text, file_id)
+}
+
+/// A utility struct for managing the result of a JSON parser job
+#[derive(Debug, Clone)]
+pub struct JsonParse {
+    root: JsonSyntaxNode,
+    diagnostics: Vec<ParseDiagnostic>,
+}
+
+impl JsonParse {
+    pub(crate) fn new(root: JsonSyntaxNode, diagnostics: Vec<ParseDiagnostic>) -> Self {
+        Self { root, diagnostics }
+    }
+
+    /// The syntax node represented by this Parse result
+    pub fn syntax(&self) -> JsonSyntaxNode {
+        self.root.clone()
+    }
+
+    /// Convert this parse result into a typed AST node.
+    pub fn tree(&self) -> JsonRoot {
+        JsonRoot::unwrap_cast(self.syntax())
+    }
+
+    /// Get the diagnostics which occurred when parsing
+    pub fn diagnostics(&self) -> &[ParseDiagnostic] {
+        &self.diagnostics
+    }
+
+    /// Get the diagnostics which occurred when parsing
+    pub fn into_diagnostics(self) -> Vec<ParseDiagnostic> {
+        self.diagnostics
+    }
+
+    /// Returns [true] if the parser encountered some errors during the parsing.
+    pub fn has_errors(&self) -> bool {
+        self.diagnostics
+            .iter()
+            .any(|diagnostic| diagnostic.is_error())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse_json;
+    use rome_diagnostics::file::FileId;
+    use rome_json_syntax::{JsonSyntaxKind, JsonValue};
+    use rome_rowan::{AstNode, AstSeparatedList};
+
+    #[test]
+    fn parses_object() {
+        let text =
+            "{\n  \"name\": \"rome\",\n  \"private\": true,\n  \"files\": [1, -2.5e3, null]\n}\n";
+        let parse = parse_json(text, FileId::zero());
+
+        assert!(!parse.has_errors(), "{:?}", parse.diagnostics());
+        assert_eq!(parse.syntax().to_string(), text);
+
+        let object = match parse.tree().json_value().unwrap() {
+            JsonValue::JsonObject(object) => object,
+            value => panic!("expected an object, found {value:?}"),
+        };
+
+        let keys: Vec<_> = object
+            .json_member_list()
+            .iter()
+            .map(|member| {
+                member
+                    .unwrap()
+                    .key()
+                    .unwrap()
+                    .syntax()
+                    .text_trimmed()
+                    .to_string()
+            })
+            .collect();
+
+        assert_eq!(keys, ["\"name\"", "\"private\"", "\"files\""]);
+    }
+
+    #[test]
+    fn parses_empty_containers() {
+        let parse = parse_json("{ \"a\": {}, \"b\": [] }", FileId::zero());
+        assert!(!parse.has_errors(), "{:?}", parse.diagnostics());
+    }
+
+    #[test]
+    fn reports_trailing_comma() {
+        let text = "{ \"a\": 1, }";
+        let parse = parse_json(text, FileId::zero());
+
+        assert!(parse.has_errors());
+        assert_eq!(parse.syntax().to_string(), text);
+    }
+
+    #[test]
+    fn reports_invalid_tokens() {
+        for text in ["{ a: 1 }", "[01]", "\"unterminated", "{} {}", "[1 2]", ""] {
+            let parse = parse_json(text, FileId::zero());
+
+            assert!(parse.has_errors(), "{text:?} should have errors");
+            assert_eq!(parse.syntax().to_string(), text);
+            assert_eq!(parse.syntax().kind(), JsonSyntaxKind::JSON_ROOT);
+        }
+    }
+}
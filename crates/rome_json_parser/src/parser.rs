@@ -0,0 +1,312 @@
+use crate::lexer::{Lexer, Token};
+use crate::JsonParse;
+use rome_diagnostics::file::FileId;
+use rome_json_factory::JsonSyntaxTreeBuilder;
+use rome_json_syntax::{JsonSyntaxKind, TextRange, TextSize, T};
+use rome_parser::ParseDiagnostic;
+use rome_rowan::{TriviaPiece, TriviaPieceKind};
+
+pub(crate) fn parse(text: &str, file_id: FileId) -> JsonParse {
+    let (tokens, diagnostics) = Lexer::new(text, file_id).lex();
+
+    let mut parser = Parser {
+        text,
+        tokens,
+        position: 0,
+        file_id,
+        diagnostics,
+        builder: JsonSyntaxTreeBuilder::new(),
+    };
+
+    parser.parse_root();
+
+    let Parser {
+        builder,
+        diagnostics,
+        ..
+    } = parser;
+
+    JsonParse::new(builder.finish(), diagnostics)
+}
+
+struct Parser<'src> {
+    text: &'src str,
+    tokens: Vec<Token>,
+    /// Index of the next token to be consumed (including trivia tokens)
+    position: usize,
+    file_id: FileId,
+    diagnostics: Vec<ParseDiagnostic>,
+    builder: JsonSyntaxTreeBuilder,
+}
+
+impl<'src> Parser<'src> {
+    fn parse_root(&mut self) {
+        self.builder.start_node(JsonSyntaxKind::JSON_ROOT);
+
+        if self.at(T![EOF]) {
+            self.error("expected a JSON value", self.current_range());
+
+            // Keep the whitespace of an otherwise empty document in the tree
+            if self.position < self.tokens.len() {
+                self.builder.start_node(JsonSyntaxKind::JSON_UNKNOWN);
+                self.bump_trivia_only();
+                self.builder.finish_node();
+            }
+        } else {
+            let checkpoint = self.builder.checkpoint();
+            self.parse_value();
+
+            if !self.at(T![EOF]) {
+                let start = self.current_range().start();
+                self.builder
+                    .start_node_at(checkpoint, JsonSyntaxKind::JSON_UNKNOWN);
+                while !self.at(T![EOF]) {
+                    self.bump();
+                }
+                self.builder.finish_node();
+
+                let end = TextSize::of(self.text);
+                self.error(
+                    "expected the end of the document",
+                    TextRange::new(start, end),
+                );
+            }
+        }
+
+        self.builder.finish_node();
+    }
+
+    /// Parses a value, returns `false` if the current token can't start a
+    /// value. In this case no token has been consumed.
+    fn parse_value(&mut self) -> bool {
+        match self.current() {
+            T!['{'] => self.parse_object(),
+            T!['['] => self.parse_array(),
+            JsonSyntaxKind::JSON_STRING_LITERAL => self.parse_string(),
+            JsonSyntaxKind::JSON_NUMBER_LITERAL => {
+                self.builder.start_node(JsonSyntaxKind::JSON_NUMBER);
+                self.bump();
+                self.builder.finish_node();
+            }
+            T![true] | T![false] => {
+                self.builder.start_node(JsonSyntaxKind::JSON_BOOLEAN);
+                self.bump();
+                self.builder.finish_node();
+            }
+            T![null] => {
+                self.builder.start_node(JsonSyntaxKind::JSON_NULL);
+                self.bump();
+                self.builder.finish_node();
+            }
+            // The lexer already emitted a diagnostic for this token
+            JsonSyntaxKind::ERROR_TOKEN => {
+                self.builder.start_node(JsonSyntaxKind::JSON_UNKNOWN);
+                self.bump();
+                self.builder.finish_node();
+            }
+            _ => {
+                self.error("expected a JSON value", self.current_range());
+                return false;
+            }
+        }
+
+        true
+    }
+
+    fn parse_string(&mut self) {
+        self.builder.start_node(JsonSyntaxKind::JSON_STRING);
+        self.bump();
+        self.builder.finish_node();
+    }
+
+    fn parse_object(&mut self) {
+        self.builder.start_node(JsonSyntaxKind::JSON_OBJECT);
+        self.bump();
+
+        self.builder.start_node(JsonSyntaxKind::JSON_MEMBER_LIST);
+        loop {
+            if self.at(T!['}']) || self.at(T![EOF]) {
+                break;
+            }
+
+            self.parse_member();
+
+            match self.current() {
+                T![,] => {
+                    self.bump();
+                    if self.at(T!['}']) {
+                        self.error(
+                            "trailing commas are not allowed in JSON",
+                            self.current_range(),
+                        );
+                    }
+                }
+                T!['}'] | T![EOF] => break,
+                _ => self.error("expected `,` or `}`", self.current_range()),
+            }
+        }
+        self.builder.finish_node();
+
+        self.expect(T!['}'], "expected `}`");
+        self.builder.finish_node();
+    }
+
+    fn parse_member(&mut self) {
+        if !self.at(JsonSyntaxKind::JSON_STRING_LITERAL) {
+            self.error("expected a property name", self.current_range());
+
+            // Always make progress, the caller already checked that the
+            // current token isn't the end of the object
+            self.builder.start_node(JsonSyntaxKind::JSON_UNKNOWN);
+            self.bump();
+            self.builder.finish_node();
+            return;
+        }
+
+        self.builder.start_node(JsonSyntaxKind::JSON_MEMBER);
+        self.parse_string();
+
+        if self.expect(T![:], "expected `:`") {
+            self.parse_value();
+        }
+
+        self.builder.finish_node();
+    }
+
+    fn parse_array(&mut self) {
+        self.builder.start_node(JsonSyntaxKind::JSON_ARRAY);
+        self.bump();
+
+        self.builder
+            .start_node(JsonSyntaxKind::JSON_ARRAY_ELEMENT_LIST);
+        loop {
+            if self.at(T![']']) || self.at(T![EOF]) {
+                break;
+            }
+
+            if !self.parse_value() {
+                // Always make progress, the current token is neither
+                // the end of the array nor the start of a value
+                self.builder.start_node(JsonSyntaxKind::JSON_UNKNOWN);
+                self.bump();
+                self.builder.finish_node();
+            }
+
+            match self.current() {
+                T![,] => {
+                    self.bump();
+                    if self.at(T![']']) {
+                        self.error(
+                            "trailing commas are not allowed in JSON",
+                            self.current_range(),
+                        );
+                    }
+                }
+                T![']'] | T![EOF] => break,
+                _ => self.error("expected `,` or `]`", self.current_range()),
+            }
+        }
+        self.builder.finish_node();
+
+        self.expect(T![']'], "expected `]`");
+        self.builder.finish_node();
+    }
+
+    /// Consumes the current token if it's of the given `kind`, emits a
+    /// diagnostic with the provided `message` otherwise
+    fn expect(&mut self, kind: JsonSyntaxKind, message: &str) -> bool {
+        if self.at(kind) {
+            self.bump();
+            true
+        } else {
+            self.error(message, self.current_range());
+            false
+        }
+    }
+
+    fn error(&mut self, message: &str, range: TextRange) {
+        self.diagnostics
+            .push(ParseDiagnostic::new(self.file_id, message, range));
+    }
+
+    fn at(&self, kind: JsonSyntaxKind) -> bool {
+        self.current() == kind
+    }
+
+    /// Index of the next non-trivia token, starting at `from`
+    fn next_non_trivia(&self, from: usize) -> Option<usize> {
+        (from..self.tokens.len()).find(|index| !self.tokens[*index].kind.is_trivia())
+    }
+
+    fn current(&self) -> JsonSyntaxKind {
+        self.next_non_trivia(self.position)
+            .map_or(T![EOF], |index| self.tokens[index].kind)
+    }
+
+    fn current_range(&self) -> TextRange {
+        match self.next_non_trivia(self.position) {
+            Some(index) => self.tokens[index].range,
+            None => TextRange::empty(TextSize::of(self.text)),
+        }
+    }
+
+    /// Adds the current token to the tree, along with its leading trivia (all
+    /// the trivia since the previous token) and its trailing trivia (the
+    /// trivia up to the next line break). The last token of the document
+    /// takes all the remaining trivia as trailing trivia.
+    fn bump(&mut self) {
+        let index = self
+            .next_non_trivia(self.position)
+            .expect("bump called at the end of the document");
+
+        let mut end = index + 1;
+        while end < self.tokens.len() && self.tokens[end].kind == JsonSyntaxKind::WHITESPACE {
+            end += 1;
+        }
+
+        if self.next_non_trivia(end).is_none() {
+            end = self.tokens.len();
+        }
+
+        let leading = self.trivia_pieces(self.position..index);
+        let trailing = self.trivia_pieces(index + 1..end);
+
+        let range = TextRange::new(
+            self.tokens[self.position].range.start(),
+            self.tokens[end - 1].range.end(),
+        );
+
+        self.builder.token_with_trivia(
+            self.tokens[index].kind,
+            &self.text[range],
+            &leading,
+            &trailing,
+        );
+
+        self.position = end;
+    }
+
+    /// Adds an empty token holding all the remaining trivia to the tree
+    fn bump_trivia_only(&mut self) {
+        let leading = self.trivia_pieces(self.position..self.tokens.len());
+        let range = TextRange::new(
+            self.tokens[self.position].range.start(),
+            TextSize::of(self.text),
+        );
+
+        self.builder
+            .token_with_trivia(T![EOF], &self.text[range], &leading, &[]);
+
+        self.position = self.tokens.len();
+    }
+
+    fn trivia_pieces(&self, range: std::ops::Range<usize>) -> Vec<TriviaPiece> {
+        self.tokens[range]
+            .iter()
+            .map(|token| {
+                let kind = TriviaPieceKind::try_from(token.kind).expect("expected a trivia token");
+                TriviaPiece::new(kind, token.range.len())
+            })
+            .collect()
+    }
+}
@@ -79,6 +79,16 @@ pub(crate) fn code_actions(
         None
     };
 
+    // Count the actions emitted by each rule for each diagnostic, a rule
+    // offering several alternative fixes for a diagnostic should not have any
+    // of them marked as preferred
+    let mut actions_per_rule = HashMap::new();
+    for action in &result.actions {
+        *actions_per_rule
+            .entry((action.rule_name.clone(), action.diagnostic_range))
+            .or_insert(0usize) += 1;
+    }
+
     let mut has_fixes = false;
     let mut actions: Vec<_> = result
         .actions
@@ -90,9 +100,17 @@ pub(crate) fn code_actions(
                 return None;
             }
 
-            let action = utils::code_fix_to_lsp(&url, &doc.line_index, &diagnostics, action);
+            let has_alternatives = actions_per_rule
+                .get(&(action.rule_name.clone(), action.diagnostic_range))
+                .map_or(false, |count| *count > 1);
+
+            let mut action = utils::code_fix_to_lsp(&url, &doc.line_index, &diagnostics, action);
             has_fixes |= action.diagnostics.is_some();
 
+            if has_alternatives {
+                action.is_preferred = None;
+            }
+
             Some(CodeActionOrCommand::CodeAction(action))
         })
         .chain(fix_all)
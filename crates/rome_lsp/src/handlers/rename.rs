@@ -1,8 +1,13 @@
-use std::collections::HashMap;
-
+use crate::line_index::LineIndex;
 use crate::{session::Session, utils};
 use anyhow::{Context, Result};
-use tower_lsp::lsp_types::{RenameParams, WorkspaceEdit};
+use rome_fs::{FileSystem, OpenOptions};
+use rome_text_edit::TextEdit;
+use std::collections::HashMap;
+use tower_lsp::lsp_types::{
+    DocumentChanges, OneOf, OptionalVersionedTextDocumentIdentifier, RenameParams,
+    TextDocumentEdit, Url, WorkspaceEdit,
+};
 use tracing::trace;
 
 #[tracing::instrument(level = "trace", skip(session), err)]
@@ -29,14 +34,85 @@ pub(crate) fn rename(session: &Session, params: RenameParams) -> Result<Option<W
             new_name: params.new_name,
         })?;
 
-    let mut changes = HashMap::new();
-    changes.insert(url, utils::text_edit(&doc.line_index, result.indels));
+    let mut document_changes = vec![text_document_edit(
+        url,
+        Some(doc.version),
+        &doc.line_index,
+        result.indels,
+    )];
+
+    // The files importing the renamed binding aren't necessarily open in the
+    // editor, in which case their content is read from the disk
+    for file in result.files {
+        let uri = match Url::from_file_path(file.path.as_path()) {
+            Ok(uri) => uri,
+            Err(()) => continue,
+        };
+
+        let change = match session.document(&uri) {
+            Ok(doc) => text_document_edit(uri, Some(doc.version), &doc.line_index, file.indels),
+            Err(_) => {
+                let mut content = String::new();
+                session
+                    .fs
+                    .open_with_options(file.path.as_path(), OpenOptions::default().read(true))
+                    .and_then(|mut handle| handle.read_to_string(&mut content))
+                    .with_context(|| format!("failed to read the file {}", file.path.display()))?;
+
+                text_document_edit(uri, None, &LineIndex::new(&content), file.indels)
+            }
+        };
 
-    let workspace_edit = WorkspaceEdit {
-        changes: Some(changes),
-        document_changes: None,
-        change_annotations: None,
+        document_changes.push(change);
+    }
+
+    // The clients that don't support `documentChanges` receive the edits of
+    // the files without their versions
+    let workspace_edit = if session.supports_document_changes() {
+        WorkspaceEdit {
+            changes: None,
+            document_changes: Some(DocumentChanges::Edits(document_changes)),
+            change_annotations: None,
+        }
+    } else {
+        let changes = document_changes
+            .into_iter()
+            .map(|change| {
+                let edits = change
+                    .edits
+                    .into_iter()
+                    .map(|edit| match edit {
+                        OneOf::Left(edit) => edit,
+                        OneOf::Right(edit) => edit.text_edit,
+                    })
+                    .collect();
+
+                (change.text_document.uri, edits)
+            })
+            .collect::<HashMap<_, _>>();
+
+        WorkspaceEdit {
+            changes: Some(changes),
+            document_changes: None,
+            change_annotations: None,
+        }
     };
 
     Ok(Some(workspace_edit))
 }
+
+/// Convert the edit of the document at `uri` to its LSP representation
+fn text_document_edit(
+    uri: Url,
+    version: Option<i32>,
+    line_index: &LineIndex,
+    indels: TextEdit,
+) -> TextDocumentEdit {
+    TextDocumentEdit {
+        text_document: OptionalVersionedTextDocumentIdentifier { uri, version },
+        edits: utils::text_edit(line_index, indels)
+            .into_iter()
+            .map(OneOf::Left)
+            .collect(),
+    }
+}
@@ -21,6 +21,8 @@ pub(crate) async fn did_open(
     let rome_path = session.file_path(&url);
     let doc = Document::new(version, &content);
 
+    session.load_nested_configurations(&rome_path);
+
     session.workspace.open_file(OpenFileParams {
         path: rome_path,
         version,
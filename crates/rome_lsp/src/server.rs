@@ -6,7 +6,7 @@ use crate::{handlers, requests};
 use futures::future::ready;
 use futures::FutureExt;
 use rome_console::markup;
-use rome_fs::CONFIG_NAME;
+use rome_fs::{OsFileSystem, CONFIG_NAME};
 use rome_service::workspace::{RageEntry, RageParams, RageResult};
 use rome_service::{workspace, Workspace};
 use std::collections::HashMap;
@@ -440,7 +440,7 @@ impl ServerFactory {
         let workspace = self
             .workspace
             .clone()
-            .unwrap_or_else(workspace::server_sync);
+            .unwrap_or_else(|| workspace::server_sync(Arc::new(OsFileSystem)));
 
         let session_key = SessionKey(self.next_session_key.fetch_add(1, Ordering::Relaxed));
 
@@ -479,6 +479,7 @@ impl ServerFactory {
         workspace_method!(builder, change_file);
         workspace_method!(builder, close_file);
         workspace_method!(builder, pull_diagnostics);
+        workspace_method!(builder, pull_project_diagnostics);
         workspace_method!(builder, pull_actions);
         workspace_method!(builder, format_file);
         workspace_method!(builder, format_range);
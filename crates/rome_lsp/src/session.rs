@@ -10,12 +10,13 @@ use rome_console::markup;
 use rome_diagnostics::file::FileId;
 use rome_fs::{FileSystem, OsFileSystem, RomePath};
 use rome_service::configuration::Configuration;
+use rome_service::configuration::NestedConfigurations;
 use rome_service::workspace::{FeatureName, PullDiagnosticsParams, SupportsFeatureParams};
 use rome_service::workspace::{RageEntry, RageParams, RageResult, UpdateSettingsParams};
-use rome_service::{load_config, Workspace};
+use rome_service::{load_config_with_chain, Workspace};
 use rome_service::{DynRef, RomeError};
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::RwLock;
 use std::sync::{Arc, Mutex};
 use tokio::sync::Notify;
@@ -58,6 +59,10 @@ pub(crate) struct Session {
     /// The configuration coming from `rome.json` file
     pub(crate) configuration: RwLock<Option<Configuration>>,
 
+    /// The `rome.json` files of the nested directories of the workspace, they're
+    /// loaded when a document inside them is opened
+    nested_configurations: RwLock<Option<NestedConfigurations>>,
+
     pub(crate) root_uri: RwLock<Option<Url>>,
 
     documents: RwLock<HashMap<lsp_types::Url, Document>>,
@@ -92,6 +97,7 @@ impl Session {
             config,
             fs: DynRef::Owned(Box::new(OsFileSystem)),
             configuration,
+            nested_configurations: RwLock::new(None),
             root_uri,
             cancellation,
         }
@@ -197,6 +203,18 @@ impl Session {
             == Some(true)
     }
 
+    /// True if the client supports the `documentChanges` of the workspace edits
+    pub(crate) fn supports_document_changes(&self) -> bool {
+        self.client_capabilities
+            .read()
+            .unwrap()
+            .as_ref()
+            .and_then(|c| c.workspace.as_ref())
+            .and_then(|c| c.workspace_edit.as_ref())
+            .and_then(|c| c.document_changes)
+            == Some(true)
+    }
+
     pub(crate) fn base_path(&self) -> Option<PathBuf> {
         let root_uri = self.root_uri.read().unwrap();
         root_uri.as_ref().and_then(|root_uri| match root_uri.to_file_path() {
@@ -212,18 +230,54 @@ impl Session {
     pub(crate) async fn update_configuration(&self) {
         let base_path = self.base_path();
 
-        match load_config(&self.fs, base_path) {
-            Ok(Some(configuration)) => {
-                info!("Configuration found, and it is valid!");
-                self.configuration.write().unwrap().replace(configuration);
+        match load_config_with_chain(&self.fs, base_path.clone()) {
+            Ok(loaded) => {
+                if let Some(base_path) = base_path {
+                    let nested = NestedConfigurations::new(base_path, loaded.as_ref());
+                    self.nested_configurations.write().unwrap().replace(nested);
+                }
+
+                if let Some(loaded) = loaded {
+                    info!("Configuration found, and it is valid!");
+                    self.configuration
+                        .write()
+                        .unwrap()
+                        .replace(loaded.configuration);
+                }
             }
             Err(err) => {
                 error!("Couldn't load the configuration file, reason:\n {}", err);
             }
-            _ => {}
         };
     }
 
+    /// Registers the `rome.json` files of the directories containing the file
+    /// at `path` in the [Workspace], if they weren't loaded yet
+    pub(crate) fn load_nested_configurations(&self, path: &Path) {
+        let nested_configurations = self.nested_configurations.read().unwrap();
+        let nested_configurations = match nested_configurations.as_ref() {
+            Some(nested_configurations) => nested_configurations,
+            None => return,
+        };
+
+        let result = nested_configurations.discover(&*self.fs, path, |directory, configuration| {
+            trace!(
+                "The LSP will now use the following configuration for {:?}: \n {:?}",
+                directory,
+                &configuration
+            );
+
+            self.workspace.update_settings(UpdateSettingsParams {
+                configuration,
+                directory: Some(directory.to_path_buf()),
+            })
+        });
+
+        if let Err(err) = result {
+            error!("Couldn't load the configuration file, reason:\n {}", err);
+        }
+    }
+
     /// Requests "workspace/configuration" from client and updates Session config
     pub(crate) async fn fetch_client_configuration(&self) {
         let item = lsp_types::ConfigurationItem {
@@ -269,9 +323,10 @@ impl Session {
                 &configuration
             );
 
-            let result = self
-                .workspace
-                .update_settings(UpdateSettingsParams { configuration });
+            let result = self.workspace.update_settings(UpdateSettingsParams {
+                configuration,
+                directory: None,
+            });
 
             if let Err(error) = result {
                 error!("{:?}", &error)
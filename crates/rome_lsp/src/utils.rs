@@ -11,7 +11,9 @@ use rome_console::fmt::{self, Formatter};
 use rome_console::MarkupBuf;
 use rome_diagnostics::termcolor::NoColor;
 use rome_diagnostics::{
-    v2::{Diagnostic, DiagnosticTags, Location, PrintDescription, Severity, Visit},
+    v2::{
+        Diagnostic, DiagnosticTags, FilePath, Location, PrintDescription, Resource, Severity, Visit,
+    },
     Applicability,
 };
 use rome_rowan::{TextRange, TextSize};
@@ -249,11 +251,29 @@ impl Visit for RelatedInformationVisitor<'_> {
 
         let related_information = self.related_information.get_or_insert_with(Vec::new);
 
-        related_information.push(lsp::DiagnosticRelatedInformation {
-            location: lsp::Location {
+        // Frames carrying their own source code point to another file
+        let other_file = match (location.resource, location.source_code) {
+            (Resource::File(FilePath::Path(path)), Some(source_code)) => {
+                lsp::Url::from_file_path(path)
+                    .ok()
+                    .map(|url| (url, LineIndex::new(source_code.text)))
+            }
+            _ => None,
+        };
+
+        let location = match &other_file {
+            Some((url, line_index)) => lsp::Location {
+                uri: url.clone(),
+                range: range(line_index, span),
+            },
+            None => lsp::Location {
                 uri: self.url.clone(),
                 range: range(self.line_index, span),
             },
+        };
+
+        related_information.push(lsp::DiagnosticRelatedInformation {
+            location,
             message: String::new(),
         });
 
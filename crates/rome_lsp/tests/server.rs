@@ -663,6 +663,152 @@ async fn pull_quick_fixes() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn pull_alternative_quick_fixes() -> Result<()> {
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create().into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server.open_document("if(a == undefined) {}").await?;
+
+    let res: CodeActionResponse = server
+        .request(
+            "textDocument/codeAction",
+            "pull_code_actions",
+            CodeActionParams {
+                text_document: TextDocumentIdentifier {
+                    uri: Url::parse("test://workspace/document.js")?,
+                },
+                range: Range {
+                    start: Position {
+                        line: 0,
+                        character: 6,
+                    },
+                    end: Position {
+                        line: 0,
+                        character: 6,
+                    },
+                },
+                context: CodeActionContext {
+                    diagnostics: vec![],
+                    only: Some(vec![CodeActionKind::QUICKFIX]),
+                },
+                work_done_progress_params: WorkDoneProgressParams {
+                    work_done_token: None,
+                },
+                partial_result_params: PartialResultParams {
+                    partial_result_token: None,
+                },
+            },
+        )
+        .await?
+        .context("codeAction returned None")?;
+
+    let code_actions: Vec<_> = res
+        .iter()
+        .map(|action| match action {
+            CodeActionOrCommand::Command(_) => panic!("unexpected command"),
+            CodeActionOrCommand::CodeAction(action) => {
+                assert_eq!(action.is_preferred, None);
+                &action.title
+            }
+        })
+        .collect();
+
+    assert_eq!(
+        code_actions.as_slice(),
+        &["Use ===", "Compare with null instead"]
+    );
+
+    server.close_document().await?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn pull_quick_fixes_of_several_diagnostics() -> Result<()> {
+    let factory = ServerFactory::default();
+    let (service, client) = factory.create().into_inner();
+    let (stream, sink) = client.split();
+    let mut server = Server::new(service);
+
+    let (sender, _) = channel(CHANNEL_BUFFER_SIZE);
+    let reader = tokio::spawn(client_handler(stream, sink, sender));
+
+    server.initialize().await?;
+    server.initialized().await?;
+
+    server.open_document("if(a == -0 && b == -0) {}").await?;
+
+    let res: CodeActionResponse = server
+        .request(
+            "textDocument/codeAction",
+            "pull_code_actions",
+            CodeActionParams {
+                text_document: TextDocumentIdentifier {
+                    uri: Url::parse("test://workspace/document.js")?,
+                },
+                range: Range {
+                    start: Position {
+                        line: 0,
+                        character: 0,
+                    },
+                    end: Position {
+                        line: 0,
+                        character: 25,
+                    },
+                },
+                context: CodeActionContext {
+                    diagnostics: vec![],
+                    only: Some(vec![CodeActionKind::QUICKFIX]),
+                },
+                work_done_progress_params: WorkDoneProgressParams {
+                    work_done_token: None,
+                },
+                partial_result_params: PartialResultParams {
+                    partial_result_token: None,
+                },
+            },
+        )
+        .await?
+        .context("codeAction returned None")?;
+
+    // The rule emits a single fix for each of its diagnostics, these are not
+    // alternatives to each other
+    let code_actions: Vec<_> = res
+        .iter()
+        .map(|action| match action {
+            CodeActionOrCommand::Command(_) => panic!("unexpected command"),
+            CodeActionOrCommand::CodeAction(action) => {
+                assert_eq!(action.is_preferred, Some(true));
+                &action.title
+            }
+        })
+        .collect();
+
+    assert_eq!(
+        code_actions.as_slice(),
+        &["Replace -0 with 0", "Replace -0 with 0"]
+    );
+
+    server.close_document().await?;
+
+    server.shutdown().await?;
+    reader.abort();
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn pull_refactors() -> Result<()> {
     let factory = ServerFactory::default();
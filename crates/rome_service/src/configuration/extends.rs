@@ -0,0 +1,179 @@
+//! Resolution of the `extends` field of the configuration
+//!
+//! Each configuration file is merged on top of the files it extends, in the order they
+//! are listed. Objects are merged key by key, the rules of the linter are merged rule by
+//! rule and any other value (arrays included) replaces the value of the extended file.
+
+use crate::configuration::linter::anchor_plugin_paths;
+use crate::configuration::{Configuration, ConfigurationError, LoadedConfiguration};
+use crate::RomeError;
+use rome_fs::{FileSystem, OpenOptions};
+use serde_json::{Map, Value};
+use std::path::{Component, Path, PathBuf};
+
+/// Loads the files extended by the configuration file at `path`, and merges them
+/// with its `content`
+pub(super) fn resolve_extends(
+    file_system: &dyn FileSystem,
+    path: PathBuf,
+    content: &str,
+    configuration: Configuration,
+) -> Result<LoadedConfiguration, RomeError> {
+    let mut resolver = ExtendsResolver {
+        file_system,
+        chain: Vec::new(),
+        stack: Vec::new(),
+    };
+
+    let value = resolver.load_value(normalize_path(&path), content)?;
+    let mut merged: Configuration = serde_json::from_value(value.clone()).map_err(|err| {
+        RomeError::Configuration(ConfigurationError::DeserializationError(err.to_string()))
+    })?;
+    merged.extends = configuration.extends;
+
+    Ok(LoadedConfiguration {
+        configuration: merged,
+        chain: resolver.chain,
+        value,
+    })
+}
+
+struct ExtendsResolver<'a> {
+    file_system: &'a dyn FileSystem,
+    /// All the files loaded so far, in order. A file extended by several
+    /// files is only listed once
+    chain: Vec<PathBuf>,
+    /// The files currently being loaded, used to detect cycles
+    stack: Vec<PathBuf>,
+}
+
+impl ExtendsResolver<'_> {
+    /// Returns the configuration file at `path` merged on top of the files it extends
+    fn load_value(&mut self, path: PathBuf, content: &str) -> Result<Value, RomeError> {
+        let mut value: Value = serde_json::from_str(content).map_err(|err| {
+            RomeError::Configuration(ConfigurationError::DeserializationError(err.to_string()))
+        })?;
+
+        let extends = match &mut value {
+            Value::Object(map) => map.remove("extends"),
+            _ => None,
+        };
+
+        let directory = path.parent().unwrap_or_else(|| Path::new(""));
+        anchor_plugin_paths(&mut value, directory);
+
+        if !self.chain.contains(&path) {
+            self.chain.push(path.clone());
+        }
+        self.stack.push(path.clone());
+
+        let mut merged = Map::new();
+        if let Some(Value::Array(extends)) = extends {
+            for entry in extends {
+                // The configuration was already validated, `extends` only contains strings
+                if let Value::String(entry) = entry {
+                    let extended_path = normalize_path(&directory.join(entry));
+                    if let Value::Object(extended) = self.load_extended(extended_path)? {
+                        merge_objects(&mut merged, extended, &mut Vec::new());
+                    }
+                }
+            }
+        }
+
+        if let Value::Object(value) = value {
+            merge_objects(&mut merged, value, &mut Vec::new());
+        }
+
+        self.stack.pop();
+
+        Ok(Value::Object(merged))
+    }
+
+    fn load_extended(&mut self, path: PathBuf) -> Result<Value, RomeError> {
+        if self.stack.contains(&path) {
+            let cycle = self
+                .stack
+                .iter()
+                .chain(Some(&path))
+                .map(|path| path.display().to_string())
+                .collect::<Vec<_>>()
+                .join(" -> ");
+
+            return Err(RomeError::Configuration(
+                ConfigurationError::CircularExtends(cycle),
+            ));
+        }
+
+        let options = OpenOptions::default().read(true);
+        let mut file = self
+            .file_system
+            .open_with_options(&path, options)
+            .map_err(|_| RomeError::CantReadFile(path.clone()))?;
+
+        let mut content = String::new();
+        file.read_to_string(&mut content)
+            .map_err(|_| RomeError::CantReadFile(path.clone()))?;
+
+        // Each file is validated on its own, so the errors point at the file that
+        // contains the invalid value
+        serde_json::from_str::<Configuration>(&content).map_err(|err| {
+            RomeError::Configuration(ConfigurationError::InvalidExtendedConfiguration(
+                path.display().to_string(),
+                err.to_string(),
+            ))
+        })?;
+
+        self.load_value(path, &content)
+    }
+}
+
+/// Merges the configuration `other` on top of `base`
+pub(super) fn merge_values(base: &mut Value, other: Value) {
+    match (base, other) {
+        (Value::Object(base), Value::Object(other)) => merge_objects(base, other, &mut Vec::new()),
+        (base, other) => *base = other,
+    }
+}
+
+/// Merges `other` into `base`. `path` contains the keys of `base` from the root
+/// of the configuration.
+fn merge_objects(base: &mut Map<String, Value>, other: Map<String, Value>, path: &mut Vec<String>) {
+    // The configuration of a rule is replaced as a whole, its options are not merged
+    let is_rule_group = path.len() >= 2 && path[path.len() - 2] == "rules";
+
+    for (key, value) in other {
+        match (base.get_mut(&key), value) {
+            (Some(Value::Object(current)), Value::Object(value)) if !is_rule_group => {
+                path.push(key);
+                merge_objects(current, value, path);
+                path.pop();
+            }
+            (_, value) => {
+                base.insert(key, value);
+            }
+        }
+    }
+}
+
+/// Lexically resolves the `.` and `..` components of `path`, the file system
+/// may not support canonicalizing paths
+pub(crate) fn normalize_path(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if matches!(
+                    normalized.components().next_back(),
+                    Some(Component::Normal(_))
+                ) {
+                    normalized.pop();
+                } else {
+                    normalized.push("..");
+                }
+            }
+            component => normalized.push(component.as_os_str()),
+        }
+    }
+    normalized
+}
@@ -36,6 +36,14 @@ pub struct FormatterConfiguration {
         serialize_with = "crate::serialize_set_of_strings"
     )]
     pub ignore: Option<IndexSet<String>>,
+
+    /// Stores whether the CLI should remember which files are already formatted,
+    /// and skip them in subsequent runs of `rome format` and `rome ci`
+    pub cache: bool,
+
+    /// The directory where the formatter cache is stored. Defaults to `node_modules/.cache/rome`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cache_directory: Option<String>,
 }
 
 impl Default for FormatterConfiguration {
@@ -47,6 +55,8 @@ impl Default for FormatterConfiguration {
             indent_style: PlainIndentStyle::default(),
             line_width: LineWidth::default(),
             ignore: None,
+            cache: false,
+            cache_directory: None,
         }
     }
 }
@@ -92,6 +102,15 @@ where
     LineWidth::try_from(value).map_err(serde::de::Error::custom)
 }
 
+pub(crate) fn deserialize_optional_line_width<'de, D>(
+    deserializer: D,
+) -> Result<Option<LineWidth>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    deserialize_line_width(deserializer).map(Some)
+}
+
 pub fn serialize_line_width<S>(line_width: &LineWidth, s: S) -> Result<S::Ok, S::Error>
 where
     S: serde::ser::Serializer,
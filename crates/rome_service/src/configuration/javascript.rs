@@ -1,5 +1,7 @@
 use indexmap::IndexSet;
-use rome_js_formatter::context::{trailing_comma::TrailingComma, QuoteProperties, QuoteStyle};
+use rome_js_formatter::context::{
+    trailing_comma::TrailingComma, ObjectWrap, QuoteProperties, QuoteStyle,
+};
 use serde::{Deserialize, Serialize};
 
 #[derive(Default, Debug, Deserialize, Serialize, Eq, PartialEq)]
@@ -42,6 +44,9 @@ pub struct JavascriptFormatter {
     /// Print trailing commas wherever possible in multi-line comma-separated syntactic structures. Defaults to "all".
     #[serde(with = "PlainTrailingComma")]
     pub trailing_comma: TrailingComma,
+    /// Whether objects written on multiple lines are kept expanded, even if they fit on a single line. Defaults to "preserve".
+    #[serde(with = "PlainObjectWrap")]
+    pub object_wrap: ObjectWrap,
 }
 
 #[derive(Deserialize, Serialize, Debug, Eq, PartialEq, Default)]
@@ -71,3 +76,12 @@ pub enum PlainTrailingComma {
     ES5,
     None,
 }
+
+#[derive(Deserialize, Default, Serialize, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", remote = "ObjectWrap")]
+pub enum PlainObjectWrap {
+    #[default]
+    Preserve,
+    Collapse,
+}
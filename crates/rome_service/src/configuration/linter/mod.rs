@@ -2,15 +2,21 @@
 mod rules;
 
 pub use crate::configuration::linter::rules::Rules;
-use crate::settings::LinterSettings;
+use crate::configuration::normalize_path;
+use crate::plugins::WasmPlugin;
+use crate::settings::{DeadCodeSettings, LinterSettings};
 use crate::{ConfigurationError, MatchOptions, Matcher, RomeError};
-use indexmap::IndexSet;
+use indexmap::{IndexMap, IndexSet};
+use rome_analyze::AnalyzerPlugin;
 use rome_diagnostics::v2::Severity;
+use rome_fs::{FileSystem, OpenOptions};
 pub use rules::*;
 #[cfg(feature = "schemars")]
 use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::path::Path;
+use std::sync::Arc;
 
 #[derive(Deserialize, Serialize, Debug)]
 #[cfg_attr(feature = "schemars", derive(JsonSchema))]
@@ -19,10 +25,28 @@ pub struct LinterConfiguration {
     /// if `false`, it disables the feature and the linter won't be executed. `true` by default
     pub enabled: bool,
 
+    /// If `true`, the `eslint-disable` comments suppress the equivalent Rome rules. `false` by default
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub eslint_suppression_compat: Option<bool>,
+
     /// List of rules
     #[serde(skip_serializing_if = "Option::is_none")]
     pub rules: Option<Rules>,
 
+    /// Lint rules written as code patterns, by name of the rule
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_rules: Option<IndexMap<String, CustomRuleConfiguration>>,
+
+    /// WebAssembly modules providing lint rules, by name of the plugin. The name of the
+    /// plugin is the group of its rules
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub plugins: Option<IndexMap<String, PluginConfiguration>>,
+
+    /// If set, the exports that aren't imported by any file and the files that can't be
+    /// reached from the entry points are reported once all the files are checked
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dead_code: Option<DeadCodeConfiguration>,
+
     /// A list of Unix shell style patterns. The formatter will ignore files/folders that will
     /// match these patterns.
     #[serde(
@@ -37,39 +61,237 @@ impl Default for LinterConfiguration {
     fn default() -> Self {
         Self {
             enabled: true,
+            eslint_suppression_compat: None,
             rules: Some(Rules::default()),
+            custom_rules: None,
+            plugins: None,
+            dead_code: None,
             ignore: None,
         }
     }
 }
 
+/// The plugins of the configuration aren't loaded by this conversion, as they're
+/// read from the file system, see [load_plugins]
 impl TryFrom<LinterConfiguration> for LinterSettings {
     type Error = RomeError;
 
-    fn try_from(conf: LinterConfiguration) -> Result<Self, Self::Error> {
-        let mut matcher = Matcher::new(MatchOptions {
-            case_sensitive: true,
-            require_literal_leading_dot: false,
-            require_literal_separator: false,
-        });
-        if let Some(ignore) = conf.ignore {
-            for pattern in ignore {
-                matcher.add_pattern(&pattern).map_err(|err| {
-                    RomeError::Configuration(ConfigurationError::InvalidIgnorePattern(
-                        pattern.to_string(),
-                        err.msg.to_string(),
-                    ))
-                })?;
-            }
+    fn try_from(mut conf: LinterConfiguration) -> Result<Self, Self::Error> {
+        let matcher = patterns_matcher(conf.ignore)?;
+        let mut levels = conf
+            .rules
+            .as_mut()
+            .and_then(|rules| rules.custom.take())
+            .unwrap_or_default();
+
+        let mut custom_rules = Vec::new();
+        for (name, rule) in conf.custom_rules.unwrap_or_default() {
+            let severity = match levels.remove(&name) {
+                Some(RulePlainConfiguration::Off) => None,
+                Some(level) => Some((&level).into()),
+                None => Some(Severity::Error),
+            };
+
+            let rule = rome_js_analyze::compile_custom_rule(
+                &name,
+                &rule.pattern,
+                &rule.message,
+                rule.constraints,
+                rule.rewrite,
+                severity,
+            )
+            .map_err(|reason| {
+                RomeError::Configuration(ConfigurationError::InvalidCustomRule(name, reason))
+            })?;
+            custom_rules.push(Arc::new(rule));
+        }
+
+        if let Some(name) = levels.into_keys().next() {
+            return Err(RomeError::Configuration(ConfigurationError::UnknownRule(
+                format!("custom/{name}"),
+            )));
         }
+
+        let dead_code = conf.dead_code.map(DeadCodeSettings::try_from).transpose()?;
+
         Ok(Self {
             enabled: conf.enabled,
+            eslint_suppression_compat: conf.eslint_suppression_compat.unwrap_or_default(),
             rules: conf.rules,
+            custom_rules,
+            plugins: Vec::new(),
+            dead_code,
             ignored_files: matcher,
         })
     }
 }
 
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct DeadCodeConfiguration {
+    /// A list of Unix shell style patterns matching the entry points of the project. The
+    /// files that aren't imported by an entry point, directly or not, are reported
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::deserialize_set_of_strings",
+        serialize_with = "crate::serialize_set_of_strings"
+    )]
+    pub entry_points: Option<IndexSet<String>>,
+
+    /// A list of Unix shell style patterns matching the files whose exports are the public
+    /// API of the project, they are never reported
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::deserialize_set_of_strings",
+        serialize_with = "crate::serialize_set_of_strings"
+    )]
+    pub public_api: Option<IndexSet<String>>,
+}
+
+impl TryFrom<DeadCodeConfiguration> for DeadCodeSettings {
+    type Error = RomeError;
+
+    fn try_from(conf: DeadCodeConfiguration) -> Result<Self, Self::Error> {
+        Ok(Self {
+            entry_points: patterns_matcher(conf.entry_points)?,
+            public_api: patterns_matcher(conf.public_api)?,
+        })
+    }
+}
+
+/// Create a [Matcher] matching the paths of `patterns`
+fn patterns_matcher(patterns: Option<IndexSet<String>>) -> Result<Matcher, RomeError> {
+    let mut matcher = Matcher::new(MatchOptions {
+        case_sensitive: true,
+        require_literal_leading_dot: false,
+        require_literal_separator: false,
+    });
+
+    for pattern in patterns.unwrap_or_default() {
+        matcher.add_pattern(&pattern).map_err(|err| {
+            RomeError::Configuration(ConfigurationError::InvalidIgnorePattern(
+                pattern.to_string(),
+                err.msg.to_string(),
+            ))
+        })?;
+    }
+
+    Ok(matcher)
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct CustomRuleConfiguration {
+    /// The code matched by the rule. The identifiers starting with `$` are metavariables
+    /// matching any node, and `$$$name` matches any number of elements in a list
+    pub pattern: String,
+
+    /// The message of the diagnostic emitted for each match
+    pub message: String,
+
+    /// Regular expressions that the code matched by a metavariable must match, by name of
+    /// the metavariable
+    #[serde(rename = "where", default, skip_serializing_if = "IndexMap::is_empty")]
+    pub constraints: IndexMap<String, String>,
+
+    /// The code replacing each match in the fix of the rule, the metavariables are replaced
+    /// with the code they matched
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rewrite: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct PluginConfiguration {
+    /// The path of the WebAssembly module of the plugin, relative to the configuration file
+    pub path: String,
+
+    /// The severity of the rules of the plugin, by name of the rule. The rules are
+    /// emitting errors by default
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rules: Option<IndexMap<String, RulePlainConfiguration>>,
+}
+
+/// Resolve the paths of the plugins of the configuration file `value` relative to
+/// `directory`, the directory of the file. Returns `true` if `value` has plugins
+pub(crate) fn anchor_plugin_paths(value: &mut Value, directory: &Path) -> bool {
+    let plugins = match value.pointer_mut("/linter/plugins") {
+        Some(Value::Object(plugins)) => plugins,
+        _ => return false,
+    };
+
+    for plugin in plugins.values_mut() {
+        if let Some(Value::String(path)) = plugin.get_mut("path") {
+            *path = normalize_path(&directory.join(&*path))
+                .to_string_lossy()
+                .into_owned();
+        }
+    }
+
+    true
+}
+
+/// Read the WebAssembly modules of the `plugins` from `fs` and compile them
+pub(crate) fn load_plugins(
+    fs: &dyn FileSystem,
+    plugins: IndexMap<String, PluginConfiguration>,
+) -> Result<Vec<Arc<dyn AnalyzerPlugin>>, RomeError> {
+    let mut loaded: Vec<Arc<dyn AnalyzerPlugin>> = Vec::with_capacity(plugins.len());
+    for (name, plugin) in plugins {
+        let plugin = load_plugin(fs, &name, plugin).map_err(|reason| {
+            RomeError::Configuration(ConfigurationError::InvalidPlugin(name, reason))
+        })?;
+        loaded.push(Arc::new(plugin));
+    }
+
+    Ok(loaded)
+}
+
+/// Read and compile the WebAssembly module of a plugin, its path was resolved
+/// relative to its configuration file by [anchor_plugin_paths]
+fn load_plugin(
+    fs: &dyn FileSystem,
+    name: &str,
+    conf: PluginConfiguration,
+) -> Result<WasmPlugin, String> {
+    if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return Err(String::from(
+            "the name of a plugin must only contain letters and digits",
+        ));
+    }
+
+    if rome_js_analyze::metadata().find_group(name).is_some() {
+        return Err(String::from(
+            "the name of a plugin can't be the name of a group of rules",
+        ));
+    }
+
+    let mut bytes = Vec::new();
+    let options = OpenOptions::default().read(true).write(true);
+    fs.open_with_options(Path::new(&conf.path), options)
+        .and_then(|mut file| file.read_to_end(&mut bytes))
+        .map_err(|error| format!("couldn't read the file {}: {error}", conf.path))?;
+
+    let levels = conf.rules.unwrap_or_default();
+    let plugin = WasmPlugin::new(name, &bytes, |rule| match levels.get(rule) {
+        Some(RulePlainConfiguration::Off) => None,
+        Some(level) => Some(level.into()),
+        None => Some(Severity::Error),
+    })?;
+
+    if let Some(rule) = levels
+        .keys()
+        .find(|rule| !plugin.rules().iter().any(|known| known.name == *rule))
+    {
+        return Err(format!("the plugin doesn't declare the rule `{rule}`"));
+    }
+
+    Ok(plugin)
+}
+
 #[derive(Deserialize, Serialize, Debug, Clone)]
 #[cfg_attr(feature = "schemars", derive(JsonSchema))]
 #[serde(rename_all = "camelCase", deny_unknown_fields, untagged)]
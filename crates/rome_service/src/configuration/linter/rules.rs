@@ -1,5 +1,6 @@
 //! Generated file, do not edit by hand, see `xtask/codegen`
 
+use crate::configuration::linter::RulePlainConfiguration;
 use crate::{ConfigurationError, RomeError, RuleConfiguration};
 use indexmap::{IndexMap, IndexSet};
 use rome_analyze::RuleFilter;
@@ -26,6 +27,10 @@ pub struct Rules {
     pub security: Option<Security>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub style: Option<Style>,
+    #[doc = r" The level of the custom rules of `linter.customRules`, by name of the rule."]
+    #[doc = r" The custom rules are emitting errors by default"]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom: Option<IndexMap<String, RulePlainConfiguration>>,
 }
 impl Default for Rules {
     fn default() -> Self {
@@ -37,6 +42,7 @@ impl Default for Rules {
             nursery: None,
             security: None,
             style: None,
+            custom: None,
         }
     }
 }
@@ -272,6 +278,52 @@ impl Rules {
         }
         enabled_rules.difference(&disabled_rules).cloned().collect()
     }
+    #[doc = r" Merges `other` into these rules. The groups are merged rule by rule,"]
+    #[doc = r" and the values of `other` take precedence"]
+    pub fn merge_with(&mut self, other: Rules) {
+        if other.recommended.is_some() {
+            self.recommended = other.recommended;
+        }
+        if let Some(group) = other.a11y {
+            match self.a11y.as_mut() {
+                Some(current) => current.merge_with(group),
+                None => self.a11y = Some(group),
+            }
+        }
+        if let Some(group) = other.complexity {
+            match self.complexity.as_mut() {
+                Some(current) => current.merge_with(group),
+                None => self.complexity = Some(group),
+            }
+        }
+        if let Some(group) = other.correctness {
+            match self.correctness.as_mut() {
+                Some(current) => current.merge_with(group),
+                None => self.correctness = Some(group),
+            }
+        }
+        if let Some(group) = other.nursery {
+            match self.nursery.as_mut() {
+                Some(current) => current.merge_with(group),
+                None => self.nursery = Some(group),
+            }
+        }
+        if let Some(group) = other.security {
+            match self.security.as_mut() {
+                Some(current) => current.merge_with(group),
+                None => self.security = Some(group),
+            }
+        }
+        if let Some(group) = other.style {
+            match self.style.as_mut() {
+                Some(current) => current.merge_with(group),
+                None => self.style = Some(group),
+            }
+        }
+        if let Some(custom) = other.custom {
+            self.custom.get_or_insert_with(IndexMap::new).extend(custom);
+        }
+    }
 }
 #[derive(Deserialize, Default, Serialize, Debug, Clone)]
 #[cfg_attr(feature = "schemars", derive(JsonSchema))]
@@ -370,6 +422,14 @@ impl A11y {
     pub(crate) fn recommended_rules_as_filters() -> [RuleFilter<'static>; 9] {
         Self::RECOMMENDED_RULES_AS_FILTERS
     }
+    #[doc = r" Merges `other` into this group. The rules configured in `other` replace"]
+    #[doc = r" the configuration of the same rules in this group"]
+    pub(crate) fn merge_with(&mut self, other: Self) {
+        if other.recommended.is_some() {
+            self.recommended = other.recommended;
+        }
+        self.rules.extend(other.rules);
+    }
 }
 fn deserialize_a11y_rules<'de, D>(
     deserializer: D,
@@ -452,6 +512,14 @@ impl Complexity {
     pub(crate) fn recommended_rules_as_filters() -> [RuleFilter<'static>; 2] {
         Self::RECOMMENDED_RULES_AS_FILTERS
     }
+    #[doc = r" Merges `other` into this group. The rules configured in `other` replace"]
+    #[doc = r" the configuration of the same rules in this group"]
+    pub(crate) fn merge_with(&mut self, other: Self) {
+        if other.recommended.is_some() {
+            self.recommended = other.recommended;
+        }
+        self.rules.extend(other.rules);
+    }
 }
 fn deserialize_complexity_rules<'de, D>(
     deserializer: D,
@@ -646,6 +714,14 @@ impl Correctness {
     pub(crate) fn recommended_rules_as_filters() -> [RuleFilter<'static>; 27] {
         Self::RECOMMENDED_RULES_AS_FILTERS
     }
+    #[doc = r" Merges `other` into this group. The rules configured in `other` replace"]
+    #[doc = r" the configuration of the same rules in this group"]
+    pub(crate) fn merge_with(&mut self, other: Self) {
+        if other.recommended.is_some() {
+            self.recommended = other.recommended;
+        }
+        self.rules.extend(other.rules);
+    }
 }
 fn deserialize_correctness_rules<'de, D>(
     deserializer: D,
@@ -687,29 +763,51 @@ pub struct Nursery {
 #[allow(dead_code)]
 #[doc = r" A list of rules that belong to this group"]
 struct NurserySchema {
+    no_async_array_callback: Option<RuleConfiguration>,
+    no_await_in_loop: Option<RuleConfiguration>,
     no_banned_types: Option<RuleConfiguration>,
     no_const_assign: Option<RuleConfiguration>,
     no_dupe_keys: Option<RuleConfiguration>,
+    no_excessive_complexity: Option<RuleConfiguration>,
     no_explicit_any: Option<RuleConfiguration>,
+    no_floating_promises: Option<RuleConfiguration>,
+    no_import_cycles: Option<RuleConfiguration>,
     no_invalid_constructor_super: Option<RuleConfiguration>,
+    no_promise_in_condition: Option<RuleConfiguration>,
+    no_self_assign: Option<RuleConfiguration>,
+    no_use_before_assign: Option<RuleConfiguration>,
+    no_useless_assignment: Option<RuleConfiguration>,
     use_camel_case: Option<RuleConfiguration>,
     use_exhaustive_dependencies: Option<RuleConfiguration>,
     use_flat_map: Option<RuleConfiguration>,
+    use_hook_at_top_level: Option<RuleConfiguration>,
     use_numeric_literals: Option<RuleConfiguration>,
+    use_sorted_package_json: Option<RuleConfiguration>,
     use_valid_for_direction: Option<RuleConfiguration>,
 }
 impl Nursery {
     const CATEGORY_NAME: &'static str = "nursery";
-    pub(crate) const CATEGORY_RULES: [&'static str; 10] = [
+    pub(crate) const CATEGORY_RULES: [&'static str; 21] = [
+        "noAsyncArrayCallback",
+        "noAwaitInLoop",
         "noBannedTypes",
         "noConstAssign",
         "noDupeKeys",
+        "noExcessiveComplexity",
         "noExplicitAny",
+        "noFloatingPromises",
+        "noImportCycles",
         "noInvalidConstructorSuper",
+        "noPromiseInCondition",
+        "noSelfAssign",
+        "noUseBeforeAssign",
+        "noUselessAssignment",
         "useCamelCase",
         "useExhaustiveDependencies",
         "useFlatMap",
+        "useHookAtTopLevel",
         "useNumericLiterals",
+        "useSortedPackageJson",
         "useValidForDirection",
     ];
     const RECOMMENDED_RULES: [&'static str; 0] = [];
@@ -742,6 +840,14 @@ impl Nursery {
     pub(crate) fn recommended_rules_as_filters() -> [RuleFilter<'static>; 0] {
         Self::RECOMMENDED_RULES_AS_FILTERS
     }
+    #[doc = r" Merges `other` into this group. The rules configured in `other` replace"]
+    #[doc = r" the configuration of the same rules in this group"]
+    pub(crate) fn merge_with(&mut self, other: Self) {
+        if other.recommended.is_some() {
+            self.recommended = other.recommended;
+        }
+        self.rules.extend(other.rules);
+    }
 }
 fn deserialize_nursery_rules<'de, D>(
     deserializer: D,
@@ -828,6 +934,14 @@ impl Security {
     pub(crate) fn recommended_rules_as_filters() -> [RuleFilter<'static>; 2] {
         Self::RECOMMENDED_RULES_AS_FILTERS
     }
+    #[doc = r" Merges `other` into this group. The rules configured in `other` replace"]
+    #[doc = r" the configuration of the same rules in this group"]
+    pub(crate) fn merge_with(&mut self, other: Self) {
+        if other.recommended.is_some() {
+            self.recommended = other.recommended;
+        }
+        self.rules.extend(other.rules);
+    }
 }
 fn deserialize_security_rules<'de, D>(
     deserializer: D,
@@ -948,6 +1062,14 @@ impl Style {
     pub(crate) fn recommended_rules_as_filters() -> [RuleFilter<'static>; 10] {
         Self::RECOMMENDED_RULES_AS_FILTERS
     }
+    #[doc = r" Merges `other` into this group. The rules configured in `other` replace"]
+    #[doc = r" the configuration of the same rules in this group"]
+    pub(crate) fn merge_with(&mut self, other: Self) {
+        if other.recommended.is_some() {
+            self.recommended = other.recommended;
+        }
+        self.rules.extend(other.rules);
+    }
 }
 fn deserialize_style_rules<'de, D>(
     deserializer: D,
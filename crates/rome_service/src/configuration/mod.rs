@@ -9,20 +9,31 @@ use rome_fs::{FileSystem, OpenOptions};
 use serde::de::{SeqAccess, Visitor};
 use serde::ser::SerializeSeq;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use std::fmt::{Debug, Display, Formatter};
 use std::io::ErrorKind;
 use std::marker::PhantomData;
 use std::num::NonZeroU64;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use tracing::{error, info};
 
+mod extends;
 mod formatter;
 mod javascript;
 pub mod linter;
-use crate::settings::{LanguagesSettings, LinterSettings};
+mod nested;
+mod overrides;
+use crate::settings::LanguagesSettings;
+pub(crate) use extends::normalize_path;
 pub use formatter::{FormatterConfiguration, PlainIndentStyle};
 pub use javascript::{JavascriptConfiguration, JavascriptFormatter};
+use linter::anchor_plugin_paths;
 pub use linter::{LinterConfiguration, RuleConfiguration, Rules};
+pub use nested::NestedConfigurations;
+pub use overrides::{
+    OverrideFormatterConfiguration, OverrideJavascriptConfiguration, OverrideJavascriptFormatter,
+    OverrideLinterConfiguration, OverridePattern,
+};
 use rome_analyze::{AnalyzerConfiguration, AnalyzerRules, MetadataRegistry};
 use rome_js_analyze::metadata;
 
@@ -31,6 +42,22 @@ use rome_js_analyze::metadata;
 #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct Configuration {
+    /// A list of paths to other configuration files, relative to this file. The
+    /// configuration of this file is merged on top of the extended files, in order.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::deserialize_set_of_strings",
+        serialize_with = "crate::serialize_set_of_strings"
+    )]
+    pub extends: Option<IndexSet<String>>,
+
+    /// When `true`, the configuration files of the parent directories are ignored.
+    /// A configuration file that isn't the root is merged on top of the configuration
+    /// files of the parent directories.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub root: Option<bool>,
+
     /// The configuration of the filesystem
     #[serde(skip_serializing_if = "Option::is_none")]
     pub files: Option<FilesConfiguration>,
@@ -46,11 +73,18 @@ pub struct Configuration {
     /// Specific configuration for the JavaScript language
     #[serde(skip_serializing_if = "Option::is_none")]
     pub javascript: Option<JavascriptConfiguration>,
+
+    /// A list of configurations applied to specific files. When several overrides
+    /// match a file, they are applied in order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub overrides: Option<Vec<OverridePattern>>,
 }
 
 impl Default for Configuration {
     fn default() -> Self {
         Self {
+            extends: None,
+            root: None,
             files: None,
             linter: Some(LinterConfiguration {
                 enabled: true,
@@ -58,6 +92,7 @@ impl Default for Configuration {
             }),
             formatter: None,
             javascript: None,
+            overrides: None,
         }
     }
 }
@@ -70,6 +105,10 @@ impl Configuration {
     pub fn is_linter_disabled(&self) -> bool {
         self.linter.as_ref().map(|f| !f.enabled).unwrap_or(false)
     }
+
+    pub fn is_root(&self) -> bool {
+        self.root.unwrap_or(false)
+    }
 }
 
 /// The configuration of the filesystem
@@ -80,6 +119,10 @@ pub struct FilesConfiguration {
     /// The maximum allowed size for source code files in bytes. Files above
     /// this limit will be ignored for performance reason. Defaults to 1 MiB
     pub max_size: Option<NonZeroU64>,
+
+    /// Whether the JavaScript and TypeScript code blocks of the Markdown files
+    /// are formatted and linted. Defaults to `false`
+    pub markdown: Option<bool>,
 }
 
 /// Series of errors that can be thrown while computing the configuration
@@ -102,6 +145,18 @@ pub enum ConfigurationError {
 
     /// Thrown when the pattern inside the `ignore` field errors
     InvalidIgnorePattern(String, String),
+
+    /// Thrown when a rule of `linter.customRules` can't be compiled
+    InvalidCustomRule(String, String),
+
+    /// Thrown when a plugin of `linter.plugins` can't be loaded
+    InvalidPlugin(String, String),
+
+    /// Thrown when a configuration file extends itself, directly or through other files
+    CircularExtends(String),
+
+    /// Thrown when a file listed in `extends` contains an invalid configuration
+    InvalidExtendedConfiguration(String, String),
 }
 
 impl Debug for ConfigurationError {
@@ -112,6 +167,12 @@ impl Debug for ConfigurationError {
             ConfigurationError::ConfigAlreadyExists => std::fmt::Display::fmt(self, f),
             ConfigurationError::UnknownRule(_) => std::fmt::Display::fmt(self, f),
             ConfigurationError::InvalidIgnorePattern(_, _) => std::fmt::Display::fmt(self, f),
+            ConfigurationError::InvalidCustomRule(_, _) => std::fmt::Display::fmt(self, f),
+            ConfigurationError::InvalidPlugin(_, _) => std::fmt::Display::fmt(self, f),
+            ConfigurationError::CircularExtends(_) => std::fmt::Display::fmt(self, f),
+            ConfigurationError::InvalidExtendedConfiguration(_, _) => {
+                std::fmt::Display::fmt(self, f)
+            }
         }
     }
 }
@@ -142,10 +203,36 @@ impl Display for ConfigurationError {
             ConfigurationError::InvalidIgnorePattern(pattern, reason) => {
                 write!(f, "couldn't parse the pattern {pattern}, reason: {reason}")
             }
+            ConfigurationError::InvalidCustomRule(name, reason) => {
+                write!(f, "invalid custom rule `{name}`: {reason}")
+            }
+            ConfigurationError::InvalidPlugin(name, reason) => {
+                write!(f, "invalid plugin `{name}`: {reason}")
+            }
+            ConfigurationError::CircularExtends(chain) => {
+                write!(f, "the configuration extends itself: {chain}")
+            }
+            ConfigurationError::InvalidExtendedConfiguration(path, reason) => {
+                write!(
+                    f,
+                    "Rome couldn't load the configuration file {path}, here's why: \n{reason}"
+                )
+            }
         }
     }
 }
 
+/// A configuration loaded from the file system, with the files it extends
+#[derive(Debug)]
+pub struct LoadedConfiguration {
+    /// The configuration resulting from the merge of all the files
+    pub configuration: Configuration,
+    /// The paths of the loaded files, starting from the configuration file itself
+    pub chain: Vec<PathBuf>,
+    /// The merged content of the files, used to merge the nested configuration files
+    pub(crate) value: Value,
+}
+
 /// This function is responsible to load the rome configuration.
 ///
 /// The `file_system` will read the configuration file. A base path can be passed
@@ -153,6 +240,16 @@ pub fn load_config(
     file_system: &DynRef<dyn FileSystem>,
     base_path: Option<PathBuf>,
 ) -> Result<Option<Configuration>, RomeError> {
+    let loaded = load_config_with_chain(file_system, base_path)?;
+    Ok(loaded.map(|loaded| loaded.configuration))
+}
+
+/// Same as [load_config], but it also returns the paths of the files that were
+/// loaded through the `extends` field
+pub fn load_config_with_chain(
+    file_system: &DynRef<dyn FileSystem>,
+    base_path: Option<PathBuf>,
+) -> Result<Option<LoadedConfiguration>, RomeError> {
     let config_name = file_system.config_name();
     let configuration_path = if let Some(base_path) = base_path {
         base_path.join(config_name)
@@ -169,13 +266,9 @@ pub fn load_config(
         Ok(mut file) => {
             let mut buffer = String::new();
             file.read_to_string(&mut buffer)
-                .map_err(|_| RomeError::CantReadFile(configuration_path))?;
+                .map_err(|_| RomeError::CantReadFile(configuration_path.clone()))?;
 
-            let configuration: Configuration = serde_json::from_str(&buffer).map_err(|err| {
-                RomeError::Configuration(ConfigurationError::DeserializationError(err.to_string()))
-            })?;
-
-            Ok(Some(configuration))
+            parse_config(&**file_system, configuration_path, &buffer).map(Some)
         }
         Err(err) => {
             // We throw an error only when the error is found.
@@ -194,6 +287,38 @@ pub fn load_config(
     }
 }
 
+/// Parses the content of the configuration file at `path`, and resolves the files it extends
+pub(crate) fn parse_config(
+    file_system: &dyn FileSystem,
+    path: PathBuf,
+    content: &str,
+) -> Result<LoadedConfiguration, RomeError> {
+    let mut configuration: Configuration = serde_json::from_str(content).map_err(|err| {
+        RomeError::Configuration(ConfigurationError::DeserializationError(err.to_string()))
+    })?;
+
+    if configuration.extends.is_none() {
+        let mut value = serde_json::from_str(content).map_err(|err| {
+            RomeError::Configuration(ConfigurationError::DeserializationError(err.to_string()))
+        })?;
+
+        let directory = path.parent().unwrap_or_else(|| Path::new(""));
+        if anchor_plugin_paths(&mut value, directory) {
+            configuration = serde_json::from_value(value.clone()).map_err(|err| {
+                RomeError::Configuration(ConfigurationError::DeserializationError(err.to_string()))
+            })?;
+        }
+
+        return Ok(LoadedConfiguration {
+            configuration,
+            chain: vec![path],
+            value,
+        });
+    }
+
+    extends::resolve_extends(file_system, path, content, configuration)
+}
+
 /// Creates a new configuration on file system
 ///
 /// ## Errors
@@ -296,7 +421,9 @@ where
 /// The function needs access to a filter, in order to have an easy access to the [metadata] of the
 /// rules.
 ///
-/// The third argument is a closure that accepts a reference to `linter_settings`.
+/// The first argument are the rules that apply to the file, see [OverrideSettings](crate::settings::OverrideSettings).
+///
+/// The third argument is a closure that accepts a reference to `language_settings`.
 ///
 /// The closure is responsible to map the globals from the correct
 /// location of the settings.
@@ -310,7 +437,7 @@ where
 /// settings.languages.javascript.globals = Some(["jQuery".to_string(), "React".to_string()].into());
 /// // map globals from JS language
 /// let analyzer_configuration =
-///     to_analyzer_configuration(&settings.linter, &settings.languages, |settings| {
+///     to_analyzer_configuration(settings.linter.rules.as_ref(), &settings.languages, |settings| {
 ///         if let Some(globals) = settings.javascript.globals.as_ref() {
 ///             globals
 ///                 .iter()
@@ -331,7 +458,7 @@ where
 /// [metadata]: rome_analyze::RegistryRuleMetadata
 /// [configuration for the analyzer]: AnalyzerConfiguration
 pub fn to_analyzer_configuration<ToGlobals>(
-    linter_settings: &LinterSettings,
+    rules: Option<&Rules>,
     language_settings: &LanguagesSettings,
     to_globals: ToGlobals,
 ) -> AnalyzerConfiguration
@@ -342,7 +469,7 @@ where
 
     let mut analyzer_rules = AnalyzerRules::default();
 
-    if let Some(rules) = linter_settings.rules.as_ref() {
+    if let Some(rules) = rules {
         if let Some(rules) = rules.correctness.as_ref() {
             push_rules("correctness", metadata(), &mut analyzer_rules, &rules.rules);
         }
@@ -357,6 +484,7 @@ where
     AnalyzerConfiguration {
         globals,
         rules: analyzer_rules,
+        ..AnalyzerConfiguration::default()
     }
 }
 
@@ -0,0 +1,203 @@
+//! Discovery of the configuration files of the nested directories of a workspace
+//!
+//! Each directory below the root of the workspace can contain its own configuration
+//! file, that applies to the files below it. Unless it's marked as `root`, the file is
+//! merged on top of the configuration of its parent directories, following the same
+//! rules as `extends`. The patterns inherited from a parent directory keep matching the
+//! paths relative to this parent.
+
+use crate::configuration::extends::merge_values;
+use crate::configuration::{
+    normalize_path, parse_config, Configuration, ConfigurationError, LoadedConfiguration,
+};
+use crate::RomeError;
+use rome_fs::{FileSystem, OpenOptions};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use tracing::info;
+
+/// Loads the configuration files of the directories of a workspace as the files
+/// inside them are visited
+#[derive(Debug)]
+pub struct NestedConfigurations {
+    /// The directory of the root configuration file
+    root_directory: PathBuf,
+    /// The content of the root configuration file, if any
+    root: Option<Value>,
+    /// The directories visited so far, with the content of their configuration file,
+    /// if they have one
+    directories: Mutex<HashMap<PathBuf, Option<Value>>>,
+}
+
+impl NestedConfigurations {
+    /// Creates a new instance for a workspace whose root is `root_directory`. `root` is the
+    /// configuration file of this directory, if any.
+    pub fn new(root_directory: impl AsRef<Path>, root: Option<&LoadedConfiguration>) -> Self {
+        Self {
+            root_directory: normalize_path(root_directory.as_ref()),
+            root: root.map(|root| root.value.clone()),
+            directories: Mutex::default(),
+        }
+    }
+
+    /// Loads the configuration files of the directories between the root of the workspace
+    /// and the file at `path`.
+    ///
+    /// `register` is called with each configuration file found and its directory, starting
+    /// from the outermost one. Each directory is only visited once: `register` is called
+    /// before any other call to this function returns, so the configuration of a file is
+    /// always registered before the file is processed.
+    pub fn discover<F>(
+        &self,
+        file_system: &dyn FileSystem,
+        path: &Path,
+        mut register: F,
+    ) -> Result<(), RomeError>
+    where
+        F: FnMut(&Path, Configuration) -> Result<(), RomeError>,
+    {
+        let path = normalize_path(path);
+        let relative = match path
+            .parent()
+            .and_then(|parent| parent.strip_prefix(&self.root_directory).ok())
+        {
+            // Files outside of the workspace only use the root configuration
+            Some(relative) if relative.is_relative() => relative,
+            _ => return Ok(()),
+        };
+
+        let mut directories = self.directories.lock().unwrap();
+        let mut directory = self.root_directory.clone();
+
+        for component in relative.components() {
+            directory.push(component);
+
+            if directories.contains_key(&directory) {
+                continue;
+            }
+
+            // The directory is marked as visited first, so an invalid configuration
+            // file is only reported once
+            directories.insert(directory.clone(), None);
+
+            let loaded = match load_nested_config(file_system, &directory)? {
+                Some(loaded) => loaded,
+                None => continue,
+            };
+
+            directories.insert(directory.clone(), Some(loaded.value));
+            let value = self.resolve(&directories, &directory);
+
+            let configuration: Configuration = serde_json::from_value(value).map_err(|err| {
+                RomeError::Configuration(ConfigurationError::DeserializationError(err.to_string()))
+            })?;
+
+            register(&directory, configuration)?;
+        }
+
+        Ok(())
+    }
+
+    /// Merges the configuration files of `directory` and of its parents, up to the
+    /// first one marked as `root` or to the root of the workspace
+    ///
+    /// The patterns of the parent configurations are prefixed with `../` components,
+    /// so they keep matching the paths relative to the directory that declared them.
+    fn resolve(&self, directories: &HashMap<PathBuf, Option<Value>>, directory: &Path) -> Value {
+        let mut layers = Vec::new();
+        let mut is_root = false;
+
+        for ancestor in directory
+            .ancestors()
+            .take_while(|ancestor| ancestor.starts_with(&self.root_directory))
+        {
+            if let Some(Some(value)) = directories.get(ancestor) {
+                layers.push((ancestor, value));
+                if value.get("root") == Some(&Value::Bool(true)) {
+                    is_root = true;
+                    break;
+                }
+            }
+        }
+
+        if !is_root {
+            if let Some(root) = &self.root {
+                layers.push((self.root_directory.as_path(), root));
+            }
+        }
+
+        let mut merged = Value::Object(Map::new());
+        for (ancestor, value) in layers.into_iter().rev() {
+            let mut value = value.clone();
+            if ancestor != directory {
+                if let Value::Object(map) = &mut value {
+                    map.remove("root");
+                }
+
+                let parents = directory
+                    .strip_prefix(ancestor)
+                    .map_or(0, |relative| relative.components().count());
+                anchor_patterns(&mut value, parents);
+            }
+
+            merge_values(&mut merged, value);
+        }
+
+        merged
+    }
+}
+
+/// Prefixes the patterns of the configuration `value` with `parents` times `../`
+fn anchor_patterns(value: &mut Value, parents: usize) {
+    let prefix = "../".repeat(parents);
+    let anchor = |patterns: Option<&mut Value>| {
+        if let Some(Value::Array(patterns)) = patterns {
+            for pattern in patterns {
+                if let Value::String(pattern) = pattern {
+                    pattern.insert_str(0, &prefix);
+                }
+            }
+        }
+    };
+
+    anchor(value.pointer_mut("/formatter/ignore"));
+    anchor(value.pointer_mut("/linter/ignore"));
+    anchor(value.pointer_mut("/linter/deadCode/entryPoints"));
+    anchor(value.pointer_mut("/linter/deadCode/publicApi"));
+
+    if let Some(Value::Array(overrides)) = value.get_mut("overrides") {
+        for pattern in overrides {
+            anchor(pattern.get_mut("include"));
+            anchor(pattern.get_mut("ignore"));
+        }
+    }
+}
+
+/// Loads the configuration file of `directory`, if it has one
+fn load_nested_config(
+    file_system: &dyn FileSystem,
+    directory: &Path,
+) -> Result<Option<LoadedConfiguration>, RomeError> {
+    let configuration_path = directory.join(file_system.config_name());
+    let options = OpenOptions::default().read(true).write(true);
+
+    let mut file = match file_system.open_with_options(&configuration_path, options) {
+        Ok(file) => file,
+        Err(err) if err.kind() == ErrorKind::NotFound => return Ok(None),
+        Err(_) => return Err(RomeError::CantReadFile(configuration_path)),
+    };
+
+    info!(
+        "Loading the nested configuration file at path {:?}",
+        configuration_path
+    );
+
+    let mut buffer = String::new();
+    file.read_to_string(&mut buffer)
+        .map_err(|_| RomeError::CantReadFile(configuration_path.clone()))?;
+
+    parse_config(file_system, configuration_path, &buffer).map(Some)
+}
@@ -0,0 +1,319 @@
+use crate::configuration::formatter::deserialize_optional_line_width;
+use crate::configuration::javascript::{
+    PlainObjectWrap, PlainQuoteProperties, PlainQuoteStyle, PlainTrailingComma,
+};
+use crate::configuration::PlainIndentStyle;
+use crate::settings::{
+    LanguagesSettings, OverrideFormatSettings, OverrideLinterSettings, OverrideSettingPattern,
+    OverrideSettings,
+};
+use crate::{ConfigurationError, MatchOptions, Matcher, RomeError, Rules};
+use indexmap::IndexSet;
+use rome_formatter::{IndentStyle, LineWidth};
+use rome_js_formatter::context::{
+    trailing_comma::TrailingComma, ObjectWrap, QuoteProperties, QuoteStyle,
+};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// A configuration applied to the files that match the `include` patterns and don't
+/// match the `ignore` patterns
+#[derive(Default, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct OverridePattern {
+    /// A list of Unix shell style patterns. The override is applied to the files that
+    /// match at least one of these patterns.
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::deserialize_set_of_strings",
+        serialize_with = "crate::serialize_set_of_strings"
+    )]
+    pub include: Option<IndexSet<String>>,
+
+    /// A list of Unix shell style patterns. The override isn't applied to the files that
+    /// match these patterns.
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::deserialize_set_of_strings",
+        serialize_with = "crate::serialize_set_of_strings"
+    )]
+    pub ignore: Option<IndexSet<String>>,
+
+    /// Specific configuration for the formatter
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub formatter: Option<OverrideFormatterConfiguration>,
+
+    /// Specific configuration for the linter
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub linter: Option<OverrideLinterConfiguration>,
+
+    /// Specific configuration for the JavaScript language. The options of the `formatter`
+    /// of this section are merged over the ones of the top-level configuration, its
+    /// `globals` replace the top-level ones.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub javascript: Option<OverrideJavascriptConfiguration>,
+}
+
+/// The options of the formatter that can be changed by an override. The options that
+/// are omitted keep the value of the top-level configuration.
+#[derive(Default, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct OverrideFormatterConfiguration {
+    // if `false`, it disables the feature
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+
+    /// Stores whether formatting should be allowed to proceed if a given file
+    /// has syntax errors
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format_with_errors: Option<bool>,
+
+    /// The indent style.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub indent_style: Option<PlainIndentStyle>,
+
+    /// The size of the indentation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub indent_size: Option<u8>,
+
+    /// What's the max width of a line.
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "deserialize_optional_line_width"
+    )]
+    pub line_width: Option<LineWidth>,
+}
+
+/// The options of the linter that can be changed by an override
+#[derive(Default, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct OverrideLinterConfiguration {
+    /// if `false`, it disables the linter
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+
+    /// List of rules. They are merged rule by rule with the rules of the top-level configuration
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rules: Option<Rules>,
+}
+
+/// The options of the JavaScript language that can be changed by an override
+#[derive(Default, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(default, deny_unknown_fields)]
+pub struct OverrideJavascriptConfiguration {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub formatter: Option<OverrideJavascriptFormatter>,
+
+    /// A list of global bindings that should be ignored by the analyzers
+    ///
+    /// If defined here, they replace the ones of the top-level configuration.
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::deserialize_set_of_strings",
+        serialize_with = "crate::serialize_set_of_strings"
+    )]
+    pub globals: Option<IndexSet<String>>,
+}
+
+/// The options of the JavaScript formatter that can be changed by an override. The
+/// options that are omitted keep the value of the top-level configuration.
+#[derive(Default, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct OverrideJavascriptFormatter {
+    /// The style for quotes.
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "deserialize_optional_quote_style",
+        serialize_with = "serialize_optional_quote_style"
+    )]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<PlainQuoteStyle>"))]
+    pub quote_style: Option<QuoteStyle>,
+
+    /// When properties in objects are quoted.
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "deserialize_optional_quote_properties",
+        serialize_with = "serialize_optional_quote_properties"
+    )]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<PlainQuoteProperties>"))]
+    pub quote_properties: Option<QuoteProperties>,
+
+    /// Print trailing commas wherever possible in multi-line comma-separated syntactic structures.
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "deserialize_optional_trailing_comma",
+        serialize_with = "serialize_optional_trailing_comma"
+    )]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<PlainTrailingComma>"))]
+    pub trailing_comma: Option<TrailingComma>,
+
+    /// Whether objects written on multiple lines are kept expanded, even if they fit on a single line.
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "deserialize_optional_object_wrap",
+        serialize_with = "serialize_optional_object_wrap"
+    )]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<PlainObjectWrap>"))]
+    pub object_wrap: Option<ObjectWrap>,
+}
+
+fn deserialize_optional_quote_style<'de, D>(deserializer: D) -> Result<Option<QuoteStyle>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    PlainQuoteStyle::deserialize(deserializer).map(Some)
+}
+
+fn serialize_optional_quote_style<S>(value: &Option<QuoteStyle>, s: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match value {
+        Some(value) => PlainQuoteStyle::serialize(value, s),
+        None => s.serialize_none(),
+    }
+}
+
+fn deserialize_optional_quote_properties<'de, D>(
+    deserializer: D,
+) -> Result<Option<QuoteProperties>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    PlainQuoteProperties::deserialize(deserializer).map(Some)
+}
+
+fn serialize_optional_quote_properties<S>(
+    value: &Option<QuoteProperties>,
+    s: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match value {
+        Some(value) => PlainQuoteProperties::serialize(value, s),
+        None => s.serialize_none(),
+    }
+}
+
+fn deserialize_optional_trailing_comma<'de, D>(
+    deserializer: D,
+) -> Result<Option<TrailingComma>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    PlainTrailingComma::deserialize(deserializer).map(Some)
+}
+
+fn serialize_optional_trailing_comma<S>(
+    value: &Option<TrailingComma>,
+    s: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match value {
+        Some(value) => PlainTrailingComma::serialize(value, s),
+        None => s.serialize_none(),
+    }
+}
+
+fn deserialize_optional_object_wrap<'de, D>(deserializer: D) -> Result<Option<ObjectWrap>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    PlainObjectWrap::deserialize(deserializer).map(Some)
+}
+
+fn serialize_optional_object_wrap<S>(value: &Option<ObjectWrap>, s: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match value {
+        Some(value) => PlainObjectWrap::serialize(value, s),
+        None => s.serialize_none(),
+    }
+}
+
+impl TryFrom<Vec<OverridePattern>> for OverrideSettings {
+    type Error = RomeError;
+
+    fn try_from(overrides: Vec<OverridePattern>) -> Result<Self, Self::Error> {
+        let patterns = overrides
+            .into_iter()
+            .map(OverrideSettingPattern::try_from)
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { patterns })
+    }
+}
+
+impl TryFrom<OverridePattern> for OverrideSettingPattern {
+    type Error = RomeError;
+
+    fn try_from(conf: OverridePattern) -> Result<Self, Self::Error> {
+        let formatter = conf.formatter.unwrap_or_default();
+        let indent_style = match (formatter.indent_style, formatter.indent_size) {
+            (Some(PlainIndentStyle::Tab), _) => Some(IndentStyle::Tab),
+            (Some(PlainIndentStyle::Space), size) => Some(IndentStyle::Space(
+                size.unwrap_or(IndentStyle::DEFAULT_SPACES),
+            )),
+            (None, Some(size)) => Some(IndentStyle::Space(size)),
+            (None, None) => None,
+        };
+
+        let linter = conf.linter.unwrap_or_default();
+
+        let mut languages = LanguagesSettings::default();
+        if let Some(javascript) = conf.javascript {
+            // The options that are omitted stay [None], keeping the value of the
+            // top-level configuration
+            if let Some(formatter) = javascript.formatter {
+                languages.javascript.format.quote_style = formatter.quote_style;
+                languages.javascript.format.quote_properties = formatter.quote_properties;
+                languages.javascript.format.trailing_comma = formatter.trailing_comma;
+                languages.javascript.format.object_wrap = formatter.object_wrap;
+            }
+            languages.javascript.globals = javascript.globals;
+        }
+
+        Ok(Self {
+            include: to_matcher(conf.include)?,
+            exclude: to_matcher(conf.ignore)?,
+            formatter: OverrideFormatSettings {
+                enabled: formatter.enabled,
+                format_with_errors: formatter.format_with_errors,
+                indent_style,
+                line_width: formatter.line_width,
+            },
+            linter: OverrideLinterSettings {
+                enabled: linter.enabled,
+                rules: linter.rules,
+            },
+            languages,
+        })
+    }
+}
+
+fn to_matcher(patterns: Option<IndexSet<String>>) -> Result<Matcher, RomeError> {
+    let mut matcher = Matcher::new(MatchOptions {
+        case_sensitive: true,
+        require_literal_leading_dot: false,
+        require_literal_separator: false,
+    });
+    if let Some(patterns) = patterns {
+        for pattern in patterns {
+            matcher.add_pattern(&pattern).map_err(|err| {
+                RomeError::Configuration(ConfigurationError::InvalidIgnorePattern(
+                    pattern.to_string(),
+                    err.msg.to_string(),
+                ))
+            })?;
+        }
+    }
+    Ok(matcher)
+}
@@ -1,20 +1,25 @@
 use crate::{
-    settings::{FormatSettings, Language, LanguageSettings, LanguagesSettings, SettingsHandle},
+    settings::{
+        FormatSettings, Language, LanguageSettings, LanguagesSettings, OverrideSettings,
+        SettingsHandle,
+    },
     workspace::{
-        server::AnyParse, CodeAction, FixAction, FixFileMode, FixFileResult, GetSyntaxTreeResult,
+        server::AnyParse, CodeAction, FixFileMode, FixFileResult, GetSyntaxTreeResult,
         PullActionsResult, RenameResult,
     },
     RomeError, Rules,
 };
 use rome_analyze::{
-    AnalysisFilter, AnalyzerOptions, ControlFlow, GroupCategory, Never, QueryMatch,
-    RegistryVisitor, RuleCategories, RuleCategory, RuleFilter, RuleGroup,
+    AnalysisFilter, AnalyzerOptions, ControlFlow, GroupCategory, ModuleGraph, Never, Profile,
+    QueryMatch, RegistryVisitor, RuleCategories, RuleCategory, RuleFilter, RuleGroup,
 };
-use rome_diagnostics::{v2::category, Applicability, CodeSuggestion};
-use rome_formatter::{FormatError, Printed};
+use rome_diagnostics::{v2::Diagnostic, CodeSuggestion};
+use rome_formatter::{CstFormatContext, FormatError, Printed};
 use rome_fs::RomePath;
-use rome_js_analyze::{analyze, analyze_with_inspect_matcher, visit_registry, RuleError};
-use rome_js_formatter::context::{trailing_comma::TrailingComma, QuoteProperties, QuoteStyle};
+use rome_js_analyze::{analyze, analyze_with_inspect_matcher, visit_registry};
+use rome_js_formatter::context::{
+    trailing_comma::TrailingComma, ObjectWrap, QuoteProperties, QuoteStyle,
+};
 use rome_js_formatter::{context::JsFormatOptions, format_node};
 use rome_js_parser::Parse;
 use rome_js_semantic::{semantic_model, SemanticModelOptions};
@@ -24,16 +29,16 @@ use rome_js_syntax::{
 use rome_rowan::{AstNode, BatchMutationExt, Direction};
 
 use super::{
-    AnalyzerCapabilities, DebugCapabilities, ExtensionHandler, FormatterCapabilities, LintParams,
-    LintResults, Mime, ParserCapabilities,
+    apply_fixes, AnalyzerCapabilities, DebugCapabilities, ExtensionHandler, FormatterCapabilities,
+    LintCollector, LintParams, LintResults, Mime, ParserCapabilities,
 };
-use crate::configuration::to_analyzer_configuration;
+use crate::configuration::{normalize_path, to_analyzer_configuration};
 use crate::file_handlers::{FixAllParams, Language as LanguageId};
 use indexmap::IndexSet;
-use rome_diagnostics::{v2, v2::Diagnostic};
 use rome_js_analyze::utils::rename::{RenameError, RenameSymbolExtensions};
 use std::borrow::Cow;
 use std::fmt::Debug;
+use std::sync::Arc;
 use tracing::debug;
 
 #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
@@ -42,6 +47,7 @@ pub struct JsFormatSettings {
     pub quote_style: Option<QuoteStyle>,
     pub quote_properties: Option<QuoteProperties>,
     pub trailing_comma: Option<TrailingComma>,
+    pub object_wrap: Option<ObjectWrap>,
 }
 
 #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
@@ -61,15 +67,19 @@ impl Language for JsLanguage {
 
     fn resolve_format_options(
         global: &FormatSettings,
+        overrides: &OverrideSettings,
         language: &JsFormatSettings,
         path: &RomePath,
     ) -> JsFormatOptions {
-        JsFormatOptions::new(path.as_path().try_into().unwrap_or_default())
+        let options = JsFormatOptions::new(path.as_path().try_into().unwrap_or_default())
             .with_indent_style(global.indent_style.unwrap_or_default())
             .with_line_width(global.line_width.unwrap_or_default())
             .with_quote_style(language.quote_style.unwrap_or_default())
             .with_quote_properties(language.quote_properties.unwrap_or_default())
             .with_trailing_comma(language.trailing_comma.unwrap_or_default())
+            .with_object_wrap(language.object_wrap.unwrap_or_default());
+
+        overrides.override_js_format_options(path.as_path(), options)
     }
 }
 
@@ -210,62 +220,55 @@ fn debug_formatter_ir(
 }
 
 fn lint(params: LintParams) -> LintResults {
-    let tree = params.parse.tree();
-    let mut diagnostics = params.parse.into_diagnostics();
-
-    let file_id = params.rome_path.file_id();
-    let analyzer_options = compute_analyzer_options(&params.settings);
-
-    let mut diagnostic_count = diagnostics.len() as u64;
-    let mut errors = diagnostics
-        .iter()
-        .filter(|diag| diag.severity() <= v2::Severity::Error)
-        .count();
-
-    let has_lint = params.filter.categories.contains(RuleCategories::LINT);
-
-    analyze(file_id, &tree, params.filter, &analyzer_options, |signal| {
-        if let Some(mut diagnostic) = signal.diagnostic() {
-            // Do not report unused suppression comment diagnostics if this is a syntax-only analyzer pass
-            if !has_lint && diagnostic.category() == Some(category!("suppressions/unused")) {
-                return ControlFlow::<Never>::Continue(());
-            }
-
-            diagnostic_count += 1;
-
-            // We do now check if the severity of the diagnostics should be changed.
-            // The configuration allows to change the severity of the diagnostics emitted by rules.
-            let severity = diagnostic
-                .category()
-                .filter(|category| category.name().starts_with("lint/"))
-                .and_then(|category| params.rules.as_ref()?.get_severity_from_code(category))
-                .unwrap_or(v2::Severity::Error);
-
-            if severity <= v2::Severity::Error {
-                errors += 1;
-            }
+    let LintParams {
+        rome_path,
+        parse,
+        filter,
+        rules,
+        settings,
+        max_diagnostics,
+        module_graph,
+        profile,
+    } = params;
 
-            if diagnostic_count <= params.max_diagnostics {
-                diagnostic.set_severity(severity);
+    lint_parse(
+        rome_path,
+        parse,
+        filter,
+        rules,
+        &settings,
+        max_diagnostics,
+        module_graph,
+        profile,
+    )
+}
 
-                if let Some(action) = signal.action() {
-                    diagnostic.add_code_suggestion(action.into());
-                }
+/// Lints a JavaScript syntax tree, this is also used to lint the code
+/// embedded in files of other languages
+pub(super) fn lint_parse(
+    rome_path: &RomePath,
+    parse: AnyParse,
+    filter: AnalysisFilter,
+    rules: Option<&Rules>,
+    settings: &SettingsHandle,
+    max_diagnostics: u64,
+    module_graph: Arc<dyn ModuleGraph>,
+    profile: Option<Arc<Profile>>,
+) -> LintResults {
+    let tree = parse.tree();
+    let mut collector =
+        LintCollector::new(parse.into_diagnostics(), &filter, rules, max_diagnostics);
 
-                diagnostics.push(v2::serde::Diagnostic::new(diagnostic));
-            }
-        }
+    let file_id = rome_path.file_id();
+    let analyzer_options =
+        compute_analyzer_options(rome_path, rules, settings, module_graph, profile);
 
+    analyze(file_id, &tree, filter, &analyzer_options, |signal| {
+        collector.push_signal(signal);
         ControlFlow::<Never>::Continue(())
     });
 
-    let skipped_diagnostics = diagnostic_count - diagnostics.len() as u64;
-
-    LintResults {
-        diagnostics,
-        errors,
-        skipped_diagnostics,
-    }
+    collector.finish()
 }
 
 struct ActionsVisitor<'a> {
@@ -302,6 +305,7 @@ fn code_actions(
     range: TextRange,
     rules: Option<&Rules>,
     settings: SettingsHandle,
+    module_graph: Arc<dyn ModuleGraph>,
 ) -> PullActionsResult {
     let tree = parse.tree();
 
@@ -330,13 +334,19 @@ fn code_actions(
 
     let file_id = rome_path.file_id();
 
-    let analyzer_options = compute_analyzer_options(&settings);
+    let analyzer_options =
+        compute_analyzer_options(rome_path, rules, &settings, module_graph, None);
 
     analyze(file_id, &tree, filter, &analyzer_options, |signal| {
-        if let Some(action) = signal.action() {
+        let diagnostic_range = signal
+            .diagnostic()
+            .and_then(|diagnostic| diagnostic.location()?.span);
+
+        for action in signal.actions() {
             actions.push(CodeAction {
                 category: action.category,
                 rule_name: Cow::Borrowed(action.rule_name),
+                diagnostic_range,
                 suggestion: CodeSuggestion::from(action),
             });
         }
@@ -357,11 +367,32 @@ fn fix_all(params: FixAllParams) -> Result<FixFileResult, RomeError> {
         rules,
         fix_file_mode,
         settings,
+        module_graph,
+        profile,
     } = params;
 
-    let mut tree: JsAnyRoot = parse.tree();
-    let mut actions = Vec::new();
+    fix_all_parse(
+        rome_path,
+        parse,
+        rules,
+        fix_file_mode,
+        &settings,
+        module_graph,
+        profile,
+    )
+}
 
+/// Applies all the safe fixes to a JavaScript syntax tree, this is also used
+/// to fix the code embedded in files of other languages
+pub(super) fn fix_all_parse(
+    rome_path: &RomePath,
+    parse: AnyParse,
+    rules: Option<&Rules>,
+    fix_file_mode: FixFileMode,
+    settings: &SettingsHandle,
+    module_graph: Arc<dyn ModuleGraph>,
+    profile: Option<Arc<Profile>>,
+) -> Result<FixFileResult, RomeError> {
     let enabled_rules: Option<Vec<RuleFilter>> = if let Some(rules) = rules {
         let enabled: IndexSet<RuleFilter> = rules.as_enabled_rules();
         Some(enabled.into_iter().collect())
@@ -376,62 +407,18 @@ fn fix_all(params: FixAllParams) -> Result<FixFileResult, RomeError> {
 
     filter.categories = RuleCategories::SYNTAX | RuleCategories::LINT;
     let file_id = rome_path.file_id();
-    let mut skipped_suggested_fixes = 0;
-    let analyzer_options = compute_analyzer_options(&settings);
-    loop {
-        let action = analyze(file_id, &tree, filter, &analyzer_options, |signal| {
-            if let Some(action) = signal.action() {
-                match fix_file_mode {
-                    FixFileMode::SafeFixes => {
-                        if action.applicability == Applicability::MaybeIncorrect {
-                            skipped_suggested_fixes += 1;
-                        }
-                        if action.applicability == Applicability::Always {
-                            return ControlFlow::Break(action);
-                        }
-                    }
-                    FixFileMode::SafeAndSuggestedFixes => {
-                        if matches!(
-                            action.applicability,
-                            Applicability::Always | Applicability::MaybeIncorrect
-                        ) {
-                            return ControlFlow::Break(action);
-                        }
-                    }
-                }
-            }
-
-            ControlFlow::Continue(())
-        });
+    let analyzer_options =
+        compute_analyzer_options(rome_path, rules, settings, module_graph, profile);
 
-        match action {
-            Some(action) => {
-                if let Some((range, _)) = action.mutation.as_text_edits() {
-                    tree = match JsAnyRoot::cast(action.mutation.commit()) {
-                        Some(tree) => tree,
-                        None => {
-                            return Err(RomeError::RuleError(
-                                RuleError::ReplacedRootWithNonRootError {
-                                    rule_name: Cow::Borrowed(action.rule_name),
-                                },
-                            ))
-                        }
-                    };
-                    actions.push(FixAction {
-                        rule_name: Cow::Borrowed(action.rule_name),
-                        range,
-                    });
-                }
-            }
-            None => {
-                return Ok(FixFileResult {
-                    code: tree.syntax().to_string(),
-                    skipped_suggested_fixes,
-                    actions,
-                });
-            }
-        }
-    }
+    apply_fixes(
+        parse.tree::<JsAnyRoot>(),
+        fix_file_mode,
+        |tree, emit_signal| {
+            analyze(file_id, tree, filter, &analyzer_options, |signal| {
+                emit_signal(signal)
+            })
+        },
+    )
 }
 
 #[tracing::instrument(level = "debug", skip(parse))]
@@ -446,9 +433,10 @@ fn format(
 
     let tree = parse.syntax();
     let formatted = format_node(options, &tree)?;
+    let unmatched_suppressions = formatted.context().comments().unmatched_suppressions();
 
     match formatted.print() {
-        Ok(printed) => Ok(printed),
+        Ok(printed) => Ok(printed.with_unmatched_suppressions(unmatched_suppressions.to_vec())),
         Err(error) => Err(RomeError::FormatError(error.into())),
     }
 }
@@ -529,7 +517,11 @@ fn rename(
                     }))
                 } else {
                     let (range, indels) = batch.as_text_edits().unwrap_or_default();
-                    Ok(RenameResult { range, indels })
+                    Ok(RenameResult {
+                        range,
+                        indels,
+                        files: Vec::new(),
+                    })
                 }
             }
             Err(err) => Err(RomeError::RenameError(err)),
@@ -539,12 +531,20 @@ fn rename(
     }
 }
 
-fn compute_analyzer_options(settings: &SettingsHandle) -> AnalyzerOptions {
-    let configuration = to_analyzer_configuration(
-        settings.as_ref().linter(),
-        &settings.as_ref().languages,
-        |settings| {
-            if let Some(globals) = settings.javascript.globals.as_ref() {
+fn compute_analyzer_options(
+    rome_path: &RomePath,
+    rules: Option<&Rules>,
+    settings: &SettingsHandle,
+    module_graph: Arc<dyn ModuleGraph>,
+    profile: Option<Arc<Profile>>,
+) -> AnalyzerOptions {
+    let overrides = &settings.as_ref().override_settings;
+    let path = settings.as_ref().relative_path(rome_path.as_path());
+    let mut configuration =
+        to_analyzer_configuration(rules, &settings.as_ref().languages, |settings| {
+            let globals =
+                overrides.override_js_globals(&path, settings.javascript.globals.as_ref());
+            if let Some(globals) = globals {
                 globals
                     .iter()
                     .map(|global| global.to_string())
@@ -552,7 +552,15 @@ fn compute_analyzer_options(settings: &SettingsHandle) -> AnalyzerOptions {
             } else {
                 vec![]
             }
-        },
-    );
-    AnalyzerOptions { configuration }
+        });
+    configuration.eslint_suppression_compat = settings.as_ref().linter.eslint_suppression_compat;
+    configuration.custom_rules = settings.as_ref().linter.custom_rules.clone();
+    configuration.plugins = settings.as_ref().linter.plugins.clone();
+    AnalyzerOptions {
+        configuration,
+        // The module graph records the modules by normalized path
+        file_path: normalize_path(rome_path),
+        module_graph: Some(module_graph),
+        profile,
+    }
 }
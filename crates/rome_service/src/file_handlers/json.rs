@@ -1,8 +1,36 @@
-use super::{ExtensionHandler, Mime};
+use super::{
+    apply_fixes, AnalyzerCapabilities, ExtensionHandler, FixAllParams, Language as LanguageId,
+    LintCollector, LintParams, LintResults, Mime, ParserCapabilities,
+};
+use crate::{
+    workspace::{server::AnyParse, FixFileResult},
+    RomeError,
+};
+use rome_analyze::{
+    AnalysisFilter, AnalyzerOptions, ControlFlow, Never, RuleCategories, RuleFilter,
+};
+use rome_fs::RomePath;
+use rome_json_analyze::analyze;
+use rome_json_parser::JsonParse;
+use rome_json_syntax::JsonRoot;
+use rome_rowan::AstNode;
+
 #[derive(Debug, Default, PartialEq, Eq)]
 pub(crate) struct JsonFileHandler;
 
 impl ExtensionHandler for JsonFileHandler {
+    fn capabilities(&self) -> super::Capabilities {
+        super::Capabilities {
+            parser: ParserCapabilities { parse: Some(parse) },
+            analyzer: AnalyzerCapabilities {
+                lint: Some(lint),
+                fix_all: Some(fix_all),
+                ..AnalyzerCapabilities::default()
+            },
+            ..super::Capabilities::default()
+        }
+    }
+
     fn language(&self) -> super::Language {
         super::Language::Json
     }
@@ -15,3 +43,91 @@ impl ExtensionHandler for JsonFileHandler {
         true
     }
 }
+
+fn parse(rome_path: &RomePath, _language_hint: LanguageId, text: &str) -> AnyParse {
+    let parse = rome_json_parser::parse_json(text, rome_path.file_id());
+    AnyParse::from(parse)
+}
+
+impl From<JsonParse> for AnyParse {
+    fn from(parse: JsonParse) -> Self {
+        let root = parse.syntax();
+        let diagnostics = parse.into_diagnostics();
+
+        Self {
+            // SAFETY: the parser should always return a root node
+            root: root.as_send().unwrap(),
+            diagnostics,
+        }
+    }
+}
+
+fn lint(params: LintParams) -> LintResults {
+    let LintParams {
+        rome_path,
+        parse,
+        filter,
+        rules,
+        max_diagnostics,
+        ..
+    } = params;
+
+    let tree: JsonRoot = parse.tree();
+    let mut collector =
+        LintCollector::new(parse.into_diagnostics(), &filter, rules, max_diagnostics);
+
+    let analyzer_options = AnalyzerOptions::default();
+
+    analyze(
+        rome_path.file_id(),
+        &tree,
+        filter,
+        &analyzer_options,
+        |signal| {
+            collector.push_signal(signal);
+            ControlFlow::<Never>::Continue(())
+        },
+    );
+
+    collector.finish()
+}
+
+/// Applies all the safe fixes to the given syntax tree. Documents containing
+/// syntax errors are returned unchanged.
+fn fix_all(params: FixAllParams) -> Result<FixFileResult, RomeError> {
+    let FixAllParams {
+        rome_path,
+        parse,
+        rules,
+        fix_file_mode,
+        ..
+    } = params;
+
+    let tree: JsonRoot = parse.tree();
+
+    if parse.has_errors() {
+        return Ok(FixFileResult {
+            code: tree.syntax().to_string(),
+            skipped_suggested_fixes: 0,
+            actions: Vec::new(),
+        });
+    }
+
+    let enabled_rules: Option<Vec<RuleFilter>> =
+        rules.map(|rules| rules.as_enabled_rules().into_iter().collect());
+
+    let mut filter = match &enabled_rules {
+        Some(rules) => AnalysisFilter::from_enabled_rules(Some(rules.as_slice())),
+        _ => AnalysisFilter::default(),
+    };
+
+    filter.categories = RuleCategories::LINT;
+    let file_id = rome_path.file_id();
+    let analyzer_options = AnalyzerOptions::default();
+
+    apply_fixes(tree, fix_file_mode, |tree, emit_signal| {
+        analyze(file_id, tree, filter, &analyzer_options, |signal| {
+            emit_signal(signal)
+        })
+    })
+}
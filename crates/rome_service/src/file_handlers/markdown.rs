@@ -0,0 +1,434 @@
+use super::{
+    javascript, AnalyzerCapabilities, ExtensionHandler, FixAllParams, FormatterCapabilities,
+    Language as LanguageId, LintParams, LintResults, Mime, ParserCapabilities,
+};
+use crate::{
+    settings::SettingsHandle,
+    workspace::{server::AnyParse, FixAction, FixFileResult},
+    RomeError,
+};
+use rome_analyze::RuleCategories;
+use rome_formatter::Printed;
+use rome_fs::RomePath;
+use rome_js_formatter::format_node;
+use rome_js_syntax::{JsLanguage, SourceType, TextSize};
+use rome_rowan::raw_language::{RawLanguage, RawLanguageKind, RawLanguageSyntaxFactory};
+use rome_rowan::TreeBuilder;
+use std::ops::Range;
+
+/// Markdown files are not parsed into a syntax tree: the handler only cares
+/// about the fenced code blocks containing JavaScript or TypeScript code, the
+/// rest of the document is never modified.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(crate) struct MarkdownFileHandler;
+
+impl ExtensionHandler for MarkdownFileHandler {
+    fn capabilities(&self) -> super::Capabilities {
+        super::Capabilities {
+            parser: ParserCapabilities { parse: Some(parse) },
+            analyzer: AnalyzerCapabilities {
+                lint: Some(lint),
+                fix_all: Some(fix_all),
+                ..AnalyzerCapabilities::default()
+            },
+            formatter: FormatterCapabilities {
+                format: Some(format),
+                ..FormatterCapabilities::default()
+            },
+            ..super::Capabilities::default()
+        }
+    }
+
+    fn language(&self) -> super::Language {
+        super::Language::Markdown
+    }
+
+    fn mime(&self) -> Mime {
+        Mime::Text
+    }
+
+    fn may_use_tabs(&self) -> bool {
+        true
+    }
+}
+
+/// The content of the document is stored verbatim in a single token, the
+/// code blocks are extracted and parsed on demand by each capability
+fn parse(_rome_path: &RomePath, _language_hint: LanguageId, text: &str) -> AnyParse {
+    let mut builder: TreeBuilder<RawLanguage, RawLanguageSyntaxFactory> = TreeBuilder::new();
+    builder.start_node(RawLanguageKind::ROOT);
+    builder.token(RawLanguageKind::STRING_TOKEN, text);
+    builder.finish_node();
+
+    let root = builder.finish();
+
+    AnyParse {
+        // SAFETY: the tree builder always returns a root node
+        root: root.as_send().unwrap(),
+        diagnostics: Vec::new(),
+    }
+}
+
+fn document_text(parse: &AnyParse) -> String {
+    parse.syntax::<RawLanguage>().text().to_string()
+}
+
+fn lint(params: LintParams) -> LintResults {
+    let LintParams {
+        rome_path,
+        parse,
+        filter,
+        rules,
+        settings,
+        max_diagnostics,
+        module_graph,
+        profile,
+    } = params;
+
+    let mut results = LintResults {
+        diagnostics: Vec::new(),
+        errors: 0,
+        skipped_diagnostics: 0,
+    };
+
+    // A markdown document doesn't have syntax errors of its own, and the code
+    // blocks that fail to parse are left untouched by the formatter
+    if !filter.categories.contains(RuleCategories::LINT) {
+        return results;
+    }
+
+    let text = document_text(&parse);
+
+    for block in code_blocks(&text) {
+        // Each block is parsed on its own, the text ranges of its diagnostics
+        // are then moved to the location of the block in the markdown document
+        let source = &text[block.content.clone()];
+        let parse = rome_js_parser::parse(source, rome_path.file_id(), block.source_type());
+
+        let max_diagnostics = max_diagnostics.saturating_sub(results.diagnostics.len() as u64);
+        let block_results = javascript::lint_parse(
+            rome_path,
+            AnyParse::from(parse),
+            filter,
+            rules,
+            &settings,
+            max_diagnostics,
+            module_graph.clone(),
+            profile.clone(),
+        );
+
+        let offset = block.offset();
+        results.diagnostics.extend(
+            block_results
+                .diagnostics
+                .into_iter()
+                .map(|diagnostic| diagnostic.with_offset(offset)),
+        );
+        results.errors += block_results.errors;
+        results.skipped_diagnostics += block_results.skipped_diagnostics;
+    }
+
+    results
+}
+
+/// Applies the fixes to each code block, the rest of the document is left
+/// untouched
+fn fix_all(params: FixAllParams) -> Result<FixFileResult, RomeError> {
+    let FixAllParams {
+        rome_path,
+        parse,
+        rules,
+        fix_file_mode,
+        settings,
+        module_graph,
+        profile,
+    } = params;
+
+    let text = document_text(&parse);
+
+    let mut code = String::with_capacity(text.len());
+    let mut actions = Vec::new();
+    let mut skipped_suggested_fixes = 0;
+    let mut last_end = 0;
+
+    for block in code_blocks(&text) {
+        let source = &text[block.content.clone()];
+        let parse = rome_js_parser::parse(source, rome_path.file_id(), block.source_type());
+
+        // Blocks containing invalid code are left untouched
+        if parse.has_errors() {
+            continue;
+        }
+
+        let fixed = javascript::fix_all_parse(
+            rome_path,
+            AnyParse::from(parse),
+            rules,
+            fix_file_mode,
+            &settings,
+            module_graph.clone(),
+            profile.clone(),
+        )?;
+
+        code.push_str(&text[last_end..block.content.start]);
+
+        // The ranges of the actions point to the fixed code of the block
+        let offset = TextSize::from(code.len() as u32);
+        actions.extend(fixed.actions.into_iter().map(|action| FixAction {
+            range: action.range + offset,
+            ..action
+        }));
+        skipped_suggested_fixes += fixed.skipped_suggested_fixes;
+
+        code.push_str(&fixed.code);
+        last_end = block.content.end;
+    }
+
+    code.push_str(&text[last_end..]);
+
+    Ok(FixFileResult {
+        code,
+        actions,
+        skipped_suggested_fixes,
+    })
+}
+
+fn format(
+    rome_path: &RomePath,
+    parse: AnyParse,
+    settings: SettingsHandle,
+) -> Result<Printed, RomeError> {
+    let text = document_text(&parse);
+
+    let mut output = String::with_capacity(text.len());
+    let mut last_end = 0;
+
+    for block in code_blocks(&text) {
+        let indent = &text[block.indent.clone()];
+        let content = &text[block.content.clone()];
+
+        let source = dedent(content, indent);
+        let parse = rome_js_parser::parse(&source, rome_path.file_id(), block.source_type());
+
+        // Blocks containing invalid code are left untouched
+        if parse.has_errors() {
+            continue;
+        }
+
+        let block_path = RomePath::new(
+            rome_path.with_extension(block.extension),
+            rome_path.file_id(),
+        );
+        let options = settings.format_options::<JsLanguage>(&block_path);
+        let formatted = format_node(options, &parse.syntax())?;
+        let printed = formatted
+            .print()
+            .map_err(|error| RomeError::FormatError(error.into()))?;
+
+        output.push_str(&text[last_end..block.content.start]);
+        let line_ending = block.line_ending(&text);
+        for line in printed.as_code().lines() {
+            if !line.is_empty() {
+                output.push_str(indent);
+                output.push_str(line);
+            }
+            output.push_str(line_ending);
+        }
+
+        last_end = block.content.end;
+    }
+
+    output.push_str(&text[last_end..]);
+
+    Ok(Printed::new(output, None, Vec::new(), Vec::new()))
+}
+
+/// A fenced code block of a markdown document containing JavaScript or TypeScript code
+#[derive(Debug, PartialEq, Eq)]
+struct CodeBlock {
+    /// Extension corresponding to the info string of the block
+    extension: &'static str,
+    /// Range of the whitespace preceding the opening fence. The same
+    /// indentation is removed from each line of the block before formatting it
+    indent: Range<usize>,
+    /// Range of the lines between the opening and the closing fences
+    content: Range<usize>,
+}
+
+impl CodeBlock {
+    /// Offset of the code of the block in the markdown document
+    fn offset(&self) -> TextSize {
+        TextSize::from(self.content.start as u32)
+    }
+
+    /// Returns the line ending used by the block, the formatted code uses
+    /// the same line endings as the original code
+    fn line_ending(&self, text: &str) -> &'static str {
+        if text[self.content.clone()].contains("\r\n") {
+            "\r\n"
+        } else {
+            "\n"
+        }
+    }
+
+    fn source_type(&self) -> SourceType {
+        match self.extension {
+            "jsx" => SourceType::jsx(),
+            "ts" => SourceType::ts(),
+            "tsx" => SourceType::tsx(),
+            _ => SourceType::js_module(),
+        }
+    }
+}
+
+/// Opening fence of the code block being visited
+struct OpenFence {
+    fence_char: char,
+    fence_len: usize,
+    extension: Option<&'static str>,
+    indent: Range<usize>,
+    content_start: usize,
+}
+
+/// Returns the fenced code blocks of `text` whose info string is one of the
+/// JavaScript family languages. Code blocks that are never closed are ignored.
+fn code_blocks(text: &str) -> Vec<CodeBlock> {
+    let mut blocks = Vec::new();
+    let mut open: Option<OpenFence> = None;
+    let mut line_start = 0;
+
+    for line in text.split_inclusive('\n') {
+        let line_end = line_start + line.len();
+        let trimmed = line.trim_start_matches(|c: char| c == ' ' || c == '\t');
+        let indent_len = line.len() - trimmed.len();
+
+        if let Some((fence_char, fence_len)) = parse_fence(trimmed) {
+            let info = trimmed[fence_len..].trim();
+
+            match &open {
+                None => {
+                    // The info string of a backtick fence can't contain backticks
+                    if fence_char == '`' && info.contains('`') {
+                        line_start = line_end;
+                        continue;
+                    }
+
+                    open = Some(OpenFence {
+                        fence_char,
+                        fence_len,
+                        extension: extension_from_info(info),
+                        indent: line_start..line_start + indent_len,
+                        content_start: line_end,
+                    });
+                }
+                Some(fence)
+                    if fence.fence_char == fence_char
+                        && fence_len >= fence.fence_len
+                        && info.is_empty() =>
+                {
+                    if let Some(extension) = fence.extension {
+                        blocks.push(CodeBlock {
+                            extension,
+                            indent: fence.indent.clone(),
+                            content: fence.content_start..line_start,
+                        });
+                    }
+
+                    open = None;
+                }
+                Some(_) => {}
+            }
+        }
+
+        line_start = line_end;
+    }
+
+    blocks
+}
+
+/// Returns the character and the length of the fence at the start of `line`, if any
+fn parse_fence(line: &str) -> Option<(char, usize)> {
+    let fence_char = line.chars().next().filter(|c| *c == '`' || *c == '~')?;
+    let fence_len = line.len() - line.trim_start_matches(fence_char).len();
+
+    if fence_len >= 3 {
+        Some((fence_char, fence_len))
+    } else {
+        None
+    }
+}
+
+/// Maps the first word of the info string of a code block to the extension
+/// of the corresponding language
+fn extension_from_info(info: &str) -> Option<&'static str> {
+    let language = info
+        .split(|c: char| c.is_whitespace() || c == '{' || c == ',')
+        .next()?;
+
+    match language.to_lowercase().as_str() {
+        "js" | "javascript" | "mjs" | "cjs" => Some("js"),
+        "jsx" => Some("jsx"),
+        "ts" | "typescript" | "mts" | "cts" => Some("ts"),
+        "tsx" => Some("tsx"),
+        _ => None,
+    }
+}
+
+/// Removes the indentation of the opening fence from each line of `content`
+fn dedent(content: &str, indent: &str) -> String {
+    content
+        .split_inclusive('\n')
+        .map(|line| {
+            let whitespace = line.len() - line.trim_start_matches([' ', '\t']).len();
+            &line[whitespace.min(indent.len())..]
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{code_blocks, dedent, CodeBlock};
+
+    #[test]
+    fn finds_javascript_code_blocks() {
+        let text = "# Title\n\n```js\nlet a;\n```\n\n```rust\nlet a = 1;\n```\n\n- item\n\n  ~~~tsx title=\"App\"\n  <App />\n  ~~~\n";
+
+        assert_eq!(
+            code_blocks(text),
+            vec![
+                CodeBlock {
+                    extension: "js",
+                    indent: 9..9,
+                    content: 15..22,
+                },
+                CodeBlock {
+                    extension: "tsx",
+                    indent: 59..61,
+                    content: 80..90,
+                },
+            ]
+        );
+        assert_eq!(&text[15..22], "let a;\n");
+        assert_eq!(&text[80..90], "  <App />\n");
+    }
+
+    #[test]
+    fn ignores_unclosed_code_blocks() {
+        assert_eq!(code_blocks("```js\nlet a;\n"), vec![]);
+        assert_eq!(code_blocks("````js\nlet a;\n```\n"), vec![]);
+    }
+
+    #[test]
+    fn removes_fence_indentation() {
+        assert_eq!(dedent("  let a;\n    b;\n\n", "  "), "let a;\n  b;\n\n");
+    }
+
+    #[test]
+    fn keeps_line_endings() {
+        let text = "```js\r\nlet a;\r\n```\r\n\n```js\nlet a;\n```\n";
+        let blocks = code_blocks(text);
+
+        assert_eq!(blocks[0].line_ending(text), "\r\n");
+        assert_eq!(blocks[1].line_ending(text), "\n");
+    }
+}
@@ -1,21 +1,34 @@
-use self::{javascript::JsFileHandler, json::JsonFileHandler, unknown::UnknownFileHandler};
+use self::{
+    javascript::JsFileHandler, json::JsonFileHandler, markdown::MarkdownFileHandler,
+    unknown::UnknownFileHandler,
+};
 use crate::workspace::FixFileMode;
 use crate::{
     settings::SettingsHandle,
     workspace::{
-        server::AnyParse, FixFileResult, GetSyntaxTreeResult, PullActionsResult, RenameResult,
+        server::AnyParse, FixAction, FixFileResult, GetSyntaxTreeResult, PullActionsResult,
+        RenameResult,
     },
     RomeError, Rules,
 };
 pub use javascript::JsFormatSettings;
-use rome_analyze::AnalysisFilter;
+use rome_analyze::{
+    AnalysisFilter, AnalyzerAction, AnalyzerSignal, ControlFlow, ModuleGraph, Profile,
+    RuleCategories, RuleError,
+};
+use rome_diagnostics::v2::{category, Diagnostic};
+use rome_diagnostics::{v2, Applicability};
 use rome_formatter::Printed;
 use rome_fs::RomePath;
 use rome_js_syntax::{TextRange, TextSize};
+use rome_rowan::AstNode;
+use std::borrow::Cow;
 use std::ffi::OsStr;
+use std::sync::Arc;
 
 mod javascript;
 mod json;
+mod markdown;
 mod unknown;
 
 /// Supported languages by Rome
@@ -32,6 +45,8 @@ pub enum Language {
     TypeScriptReact,
     /// JSON
     Json,
+    /// Markdown, only the fenced code blocks containing JavaScript or TypeScript are processed
+    Markdown,
     /// Any language that is not supported
     #[default]
     Unknown,
@@ -46,6 +61,7 @@ impl Language {
             "ts" | "mts" | "cts" => Language::TypeScript,
             "tsx" => Language::TypeScriptReact,
             "json" => Language::Json,
+            "md" | "mdx" => Language::Markdown,
             _ => Language::Unknown,
         }
     }
@@ -63,6 +79,7 @@ impl Language {
             "javascriptreact" => Language::JavaScriptReact,
             "typescriptreact" => Language::TypeScriptReact,
             "json" => Language::Json,
+            "markdown" | "mdx" => Language::Markdown,
             _ => Language::Unknown,
         }
     }
@@ -124,6 +141,206 @@ pub struct FixAllParams<'a> {
     pub(crate) rules: Option<&'a Rules>,
     pub(crate) fix_file_mode: FixFileMode,
     pub(crate) settings: SettingsHandle<'a>,
+    pub(crate) module_graph: Arc<dyn ModuleGraph>,
+    pub(crate) profile: Option<Arc<Profile>>,
+}
+
+/// Selects the action `fix_all` should apply among all the `actions` of a signal
+///
+/// An action is only applied if it's the only safe action of the signal, or
+/// the only suggested action when `fix_file_mode` is
+/// [FixFileMode::SafeAndSuggestedFixes]: when a rule offers several
+/// alternative fixes, the choice is left to the user. Signals with fixes that
+/// are not applied are counted in `skipped_suggested_fixes`.
+pub(crate) fn select_fix_action<L: rome_rowan::Language>(
+    actions: Vec<AnalyzerAction<L>>,
+    fix_file_mode: FixFileMode,
+    skipped_suggested_fixes: &mut u32,
+) -> Option<AnalyzerAction<L>> {
+    let (safe, suggested): (Vec<_>, Vec<_>) = actions
+        .into_iter()
+        .filter(|action| {
+            matches!(
+                action.applicability,
+                Applicability::Always | Applicability::MaybeIncorrect
+            )
+        })
+        .partition(|action| action.applicability == Applicability::Always);
+
+    let has_suggested_fixes = !suggested.is_empty();
+    let mut candidates = match fix_file_mode {
+        FixFileMode::SafeAndSuggestedFixes if safe.is_empty() => suggested,
+        FixFileMode::SafeFixes | FixFileMode::SafeAndSuggestedFixes => safe,
+    };
+
+    if candidates.len() == 1 {
+        return candidates.pop();
+    }
+
+    if !candidates.is_empty() || has_suggested_fixes {
+        *skipped_suggested_fixes += 1;
+    }
+
+    None
+}
+
+/// Applies the fixes selected by [select_fix_action] to `tree`, one at a time,
+/// until the analyzer doesn't emit any more of them
+///
+/// `analyze` runs the analyzer of the language on the tree, passing each of
+/// the signals it emits to the provided callback.
+pub(crate) fn apply_fixes<R, F>(
+    mut tree: R,
+    fix_file_mode: FixFileMode,
+    mut analyze: F,
+) -> Result<FixFileResult, RomeError>
+where
+    R: AstNode,
+    F: FnMut(
+        &R,
+        &mut dyn FnMut(&dyn AnalyzerSignal<R::Language>) -> ControlFlow<AnalyzerAction<R::Language>>,
+    ) -> Option<AnalyzerAction<R::Language>>,
+{
+    let mut actions = Vec::new();
+    let mut skipped_suggested_fixes = 0;
+
+    loop {
+        let action = analyze(&tree, &mut |signal| match select_fix_action(
+            signal.actions(),
+            fix_file_mode,
+            &mut skipped_suggested_fixes,
+        ) {
+            Some(action) => ControlFlow::Break(action),
+            None => ControlFlow::Continue(()),
+        });
+
+        match action {
+            Some(action) => {
+                if let Some((range, _)) = action.mutation.as_text_edits() {
+                    tree = match R::cast(action.mutation.commit()) {
+                        Some(tree) => tree,
+                        None => {
+                            return Err(RomeError::RuleError(
+                                RuleError::ReplacedRootWithNonRootError {
+                                    rule_name: Cow::Borrowed(action.rule_name),
+                                },
+                            ))
+                        }
+                    };
+                    actions.push(FixAction {
+                        rule_name: Cow::Borrowed(action.rule_name),
+                        range,
+                    });
+                }
+            }
+            None => {
+                return Ok(FixFileResult {
+                    code: tree.syntax().to_string(),
+                    skipped_suggested_fixes,
+                    actions,
+                });
+            }
+        }
+    }
+}
+
+/// Collects the diagnostics of the signals emitted by the analyzer into
+/// [LintResults], with the severity configured for their rule
+pub(crate) struct LintCollector<'a> {
+    rules: Option<&'a Rules>,
+    /// Whether the lint rules are run, the diagnostics of the suppression
+    /// comments are only reported along with them
+    has_lint: bool,
+    max_diagnostics: u64,
+    diagnostics: Vec<v2::serde::Diagnostic>,
+    /// Number of diagnostics emitted, including the ones exceeding `max_diagnostics`
+    diagnostic_count: u64,
+    errors: usize,
+}
+
+impl<'a> LintCollector<'a> {
+    /// Creates a collector starting with the `diagnostics` of the parser
+    pub(crate) fn new(
+        diagnostics: Vec<v2::serde::Diagnostic>,
+        filter: &AnalysisFilter,
+        rules: Option<&'a Rules>,
+        max_diagnostics: u64,
+    ) -> Self {
+        let errors = diagnostics
+            .iter()
+            .filter(|diag| diag.severity() <= v2::Severity::Error)
+            .count();
+
+        Self {
+            rules,
+            has_lint: filter.categories.contains(RuleCategories::LINT),
+            max_diagnostics,
+            diagnostic_count: diagnostics.len() as u64,
+            diagnostics,
+            errors,
+        }
+    }
+
+    pub(crate) fn push_signal<L: rome_rowan::Language>(&mut self, signal: &dyn AnalyzerSignal<L>) {
+        let mut diagnostic = match signal.diagnostic() {
+            Some(diagnostic) => diagnostic,
+            None => return,
+        };
+
+        // Do not report unused, unmatched or misplaced suppression comment diagnostics if this is a syntax-only analyzer pass
+        if !self.has_lint
+            && matches!(
+                diagnostic.category(),
+                Some(category) if category == category!("suppressions/unused")
+                    || category == category!("suppressions/unmatchedRange")
+                    || category == category!("suppressions/misplaced")
+            )
+        {
+            return;
+        }
+
+        self.diagnostic_count += 1;
+
+        // We do now check if the severity of the diagnostics should be changed.
+        // The configuration allows to change the severity of the diagnostics emitted by rules.
+        // The severity of the custom rules and of the rules of the plugins is set by the analyzer
+        let severity = if diagnostic.category() == Some(category!("lint/custom"))
+            || diagnostic.category() == Some(category!("lint/plugin"))
+        {
+            diagnostic.severity()
+        } else {
+            diagnostic
+                .category()
+                .filter(|category| category.name().starts_with("lint/"))
+                .and_then(|category| self.rules?.get_severity_from_code(category))
+                .unwrap_or(v2::Severity::Error)
+        };
+
+        if severity <= v2::Severity::Error {
+            self.errors += 1;
+        }
+
+        if self.diagnostic_count <= self.max_diagnostics {
+            diagnostic.set_severity(severity);
+
+            for action in signal.actions() {
+                diagnostic.add_code_suggestion(action.into());
+            }
+
+            self.diagnostics
+                .push(v2::serde::Diagnostic::new(diagnostic));
+        }
+    }
+
+    pub(crate) fn finish(self) -> LintResults {
+        let skipped_diagnostics = self.diagnostic_count - self.diagnostics.len() as u64;
+
+        LintResults {
+            diagnostics: self.diagnostics,
+            errors: self.errors,
+            skipped_diagnostics,
+        }
+    }
 }
 
 #[derive(Default)]
@@ -164,6 +381,8 @@ pub(crate) struct LintParams<'a> {
     pub(crate) rules: Option<&'a Rules>,
     pub(crate) settings: SettingsHandle<'a>,
     pub(crate) max_diagnostics: u64,
+    pub(crate) module_graph: Arc<dyn ModuleGraph>,
+    pub(crate) profile: Option<Arc<Profile>>,
 }
 
 pub(crate) struct LintResults {
@@ -173,8 +392,14 @@ pub(crate) struct LintResults {
 }
 
 type Lint = fn(LintParams) -> LintResults;
-type CodeActions =
-    fn(&RomePath, AnyParse, TextRange, Option<&Rules>, SettingsHandle) -> PullActionsResult;
+type CodeActions = fn(
+    &RomePath,
+    AnyParse,
+    TextRange,
+    Option<&Rules>,
+    SettingsHandle,
+    Arc<dyn ModuleGraph>,
+) -> PullActionsResult;
 type FixAll = fn(FixAllParams) -> Result<FixFileResult, RomeError>;
 type Rename = fn(&RomePath, AnyParse, TextSize, String) -> Result<RenameResult, RomeError>;
 
@@ -235,6 +460,7 @@ pub(crate) trait ExtensionHandler {
 pub(crate) struct Features {
     js: JsFileHandler,
     json: JsonFileHandler,
+    markdown: MarkdownFileHandler,
     unknown: UnknownFileHandler,
 }
 
@@ -243,6 +469,7 @@ impl Features {
         Features {
             js: JsFileHandler {},
             json: JsonFileHandler {},
+            markdown: MarkdownFileHandler {},
             unknown: UnknownFileHandler::default(),
         }
     }
@@ -267,7 +494,12 @@ impl Features {
             | Language::JavaScriptReact
             | Language::TypeScript
             | Language::TypeScriptReact => self.js.capabilities(),
-            Language::Json => self.json.capabilities(),
+            // Only `package.json` files are parsed and analyzed for now
+            Language::Json if rome_path.file_name() == Some(OsStr::new("package.json")) => {
+                self.json.capabilities()
+            }
+            Language::Json => Capabilities::default(),
+            Language::Markdown => self.markdown.capabilities(),
             Language::Unknown => self.unknown.capabilities(),
         }
     }
@@ -1,18 +1,21 @@
+use rome_analyze::RuleError;
 use rome_console::fmt::Bytes;
 use rome_console::{Console, EnvConsole};
 use rome_formatter::FormatError;
 use rome_fs::{FileSystem, OsFileSystem, RomePath};
 use rome_js_analyze::utils::rename::RenameError;
-use rome_js_analyze::RuleError;
 use serde::{Deserialize, Serialize};
 use std::error::Error;
 use std::ffi::OsStr;
 use std::fmt::{self, Debug, Display, Formatter};
 use std::ops::{Deref, DerefMut};
 use std::path::PathBuf;
+use std::sync::Arc;
 
 pub mod configuration;
 mod file_handlers;
+mod module_graph;
+mod plugins;
 pub mod settings;
 pub mod workspace;
 
@@ -22,7 +25,8 @@ pub mod matcher;
 pub mod workspace_types;
 
 pub use crate::configuration::{
-    create_config, load_config, Configuration, ConfigurationError, RuleConfiguration, Rules,
+    create_config, load_config, load_config_with_chain, Configuration, ConfigurationError,
+    LoadedConfiguration, NestedConfigurations, RuleConfiguration, Rules,
 };
 pub use crate::matcher::{MatchOptions, Matcher, Pattern};
 
@@ -229,19 +233,21 @@ impl Display for TransportError {
 impl Default for App<'static> {
     fn default() -> Self {
         Self::with_filesystem_and_console(
-            DynRef::Owned(Box::new(OsFileSystem)),
+            Arc::new(OsFileSystem),
             DynRef::Owned(Box::new(EnvConsole::default())),
         )
     }
 }
 
 impl<'app> App<'app> {
-    /// Create a new instance of the app using the specified [FileSystem] and [Console] implementation
+    /// Create a new instance of the app using the specified [FileSystem] and [Console] implementation,
+    /// the workspace of the app reads the files that aren't open from the same [FileSystem]
     pub fn with_filesystem_and_console(
-        fs: DynRef<'app, dyn FileSystem>,
+        fs: Arc<dyn FileSystem>,
         console: DynRef<'app, dyn Console>,
     ) -> Self {
-        Self::new(fs, console, WorkspaceRef::Owned(workspace::server()))
+        let workspace = WorkspaceRef::Owned(workspace::server(fs.clone()));
+        Self::new(DynRef::Owned(Box::new(fs)), console, workspace)
     }
 
     /// Create a new instance of the app using the specified [FileSystem], [Console] and [Workspace] implementation
@@ -2,7 +2,7 @@ pub mod pattern;
 
 pub use pattern::{MatchOptions, Pattern, PatternError};
 use std::collections::HashMap;
-use std::path::Path;
+use std::path::{Component, Path, PathBuf};
 use std::sync::RwLock;
 
 /// A data structure to use when there's need to match a string or a path a against
@@ -10,6 +10,13 @@ use std::sync::RwLock;
 #[derive(Debug)]
 pub struct Matcher {
     patterns: Vec<Pattern>,
+    /// The patterns starting with `../` components, with the number of these components.
+    /// They are declared by the configuration file of a parent directory, and match the
+    /// paths relative to this directory
+    parent_patterns: Vec<(usize, Pattern)>,
+    /// The directory the matched paths are relative to, the parent patterns are
+    /// resolved against it
+    directory: Option<PathBuf>,
     options: MatchOptions,
     already_ignored: RwLock<HashMap<String, bool>>,
 }
@@ -21,18 +28,73 @@ impl Matcher {
     pub fn new(options: MatchOptions) -> Self {
         Self {
             patterns: Vec::new(),
+            parent_patterns: Vec::new(),
+            directory: None,
             options,
             already_ignored: RwLock::new(HashMap::default()),
         }
     }
 
     /// It adds a unix shell style pattern
+    ///
+    /// The pattern can start with `../` components, it then matches the paths relative
+    /// to the corresponding parent of the [directory](Matcher::set_directory) of the matcher.
     pub fn add_pattern(&mut self, pattern: &str) -> Result<(), PatternError> {
-        let pattern = Pattern::new(pattern)?;
-        self.patterns.push(pattern);
+        let mut parents = 0;
+        let mut rest = pattern;
+        while let Some(stripped) = rest.strip_prefix("../") {
+            parents += 1;
+            rest = stripped;
+        }
+
+        let pattern = Pattern::new(rest)?;
+        if parents == 0 {
+            self.patterns.push(pattern);
+        } else {
+            self.parent_patterns.push((parents, pattern));
+        }
         Ok(())
     }
 
+    /// Sets the directory the matched paths are relative to. The patterns starting with
+    /// `../` components never match until it's set.
+    pub fn set_directory(&mut self, directory: &Path) {
+        self.directory = Some(directory.to_path_buf());
+        self.already_ignored.write().unwrap().clear();
+    }
+
+    /// Returns [true] if no pattern was added to this matcher
+    pub fn is_empty(&self) -> bool {
+        self.patterns.is_empty() && self.parent_patterns.is_empty()
+    }
+
+    /// Returns whether `source` matches one of the patterns of a parent directory.
+    /// These patterns are matched against `source` prefixed with the components of the
+    /// directory of the matcher they go up through.
+    fn matches_parent_patterns(&self, source: &Path) -> bool {
+        let directory = match &self.directory {
+            Some(directory) => directory,
+            None => return false,
+        };
+        let components: Vec<_> = directory
+            .components()
+            .filter(|component| matches!(component, Component::Normal(_)))
+            .collect();
+
+        self.parent_patterns.iter().any(|(parents, pattern)| {
+            let start = match components.len().checked_sub(*parents) {
+                Some(start) => start,
+                None => return false,
+            };
+            let source: PathBuf = components[start..].iter().collect::<PathBuf>().join(source);
+
+            pattern.matches_path_with(&source, self.options)
+                || source
+                    .to_str()
+                    .map_or(false, |source| source.contains(pattern.as_str()))
+        })
+    }
+
     /// It matches the given string against the stored patterns.
     ///
     /// It returns [true] if there's at least a match
@@ -47,8 +109,9 @@ impl Matcher {
                 return true;
             }
         }
-        already_ignored.insert(source.to_string(), false);
-        false
+        let matches = self.matches_parent_patterns(Path::new(source));
+        already_ignored.insert(source.to_string(), matches);
+        matches
     }
 
     /// It matches the given path against the stored patterns
@@ -78,7 +141,7 @@ impl Matcher {
                 }
             }
 
-            false
+            self.matches_parent_patterns(source)
         };
 
         if let Some(source_as_string) = source_as_string {
@@ -94,6 +157,7 @@ mod test {
     use crate::matcher::pattern::MatchOptions;
     use crate::matcher::Matcher;
     use std::env;
+    use std::path::Path;
 
     #[test]
     fn matches() {
@@ -133,4 +197,22 @@ mod test {
 
         assert!(result);
     }
+
+    #[test]
+    fn matches_parent_patterns() {
+        let mut ignore = Matcher::new(MatchOptions::default());
+        ignore.add_pattern("../../generated/*").unwrap();
+
+        // The directory isn't known, the pattern can't be resolved
+        assert!(!ignore.matches_path(Path::new("generated/file.js")));
+
+        ignore.set_directory(Path::new("root/packages/a"));
+        assert!(!ignore.matches_path(Path::new("generated/file.js")));
+
+        let mut ignore = Matcher::new(MatchOptions::default());
+        ignore.add_pattern("../../packages/a/generated/*").unwrap();
+        ignore.set_directory(Path::new("root/packages/a"));
+        assert!(ignore.matches_path(Path::new("generated/file.js")));
+        assert!(!ignore.matches_path(Path::new("src/file.js")));
+    }
 }
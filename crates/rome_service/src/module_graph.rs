@@ -0,0 +1,671 @@
+//! The module graph of the project, giving the lint rules access to the
+//! imports of the modules of the project other than the one being analyzed
+//!
+//! The graph is updated with the imports of each JavaScript file parsed by the
+//! workspace, and loads the other modules from the file system when their
+//! imports are requested.
+
+pub(crate) mod dead_code;
+pub(crate) mod rename;
+mod resolver;
+
+use crate::configuration::normalize_path;
+use dashmap::DashMap;
+use dead_code::{project_module, read_module_exports, ModuleExport};
+use resolver::ModuleResolver;
+use rome_analyze::{ImportComponents, ImportKind, ModuleGraph, ModuleImport};
+use rome_diagnostics::{v2, FileId};
+use rome_fs::{AtomicInterner, FileSystem, PathInterner, RomePath, TraversalContext};
+use rome_js_syntax::{
+    JsAnyBinding, JsAnyCallArgument, JsAnyExpression, JsAnyLiteralExpression, JsAnyNamedImport,
+    JsAnyNamedImportSpecifier, JsAnyRoot, JsCallArguments, JsCallExpression, JsExportFromClause,
+    JsExportNamedFromClause, JsImportCallExpression, JsImportDefaultClause, JsImportNamedClause,
+    JsLiteralExportName, JsModuleSource, JsSyntaxKind, JsSyntaxNode, SourceType,
+};
+use rome_rowan::{AstNode, AstSeparatedList, TextRange};
+use std::collections::{BTreeSet, HashSet};
+use std::fmt::{self, Debug, Formatter};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+pub(crate) struct ProjectModuleGraph {
+    resolver: ModuleResolver,
+    /// The imports of the modules loaded so far, by normalized path
+    modules: DashMap<PathBuf, Arc<[ProjectImport]>>,
+    /// The exports of the modules that aren't open, loaded once by the
+    /// detection of the dead code
+    exports: DashMap<PathBuf, Arc<[ModuleExport]>>,
+    /// The import cycles of the modules, computed once for the whole project
+    import_components: ImportComponents,
+}
+
+/// An import of a module of the project, with the bindings it imports
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ProjectImport {
+    pub(crate) import: ModuleImport,
+    pub(crate) bindings: ImportedBindings,
+}
+
+/// The exports of a module used by a module importing it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum ImportedBindings {
+    /// `import "./a"` only evaluates the module
+    None,
+    /// `import { a, b as c } from`, `import d from` and `export { a } from`
+    /// use the exports with these names, the default export being `default`
+    Named(Vec<ImportedName>),
+    /// `import * as ns from`, `import()` and `require()` may use any export
+    All,
+    /// `export * from` re-exports the exports of the module, they are used
+    /// if the modules importing the re-export use them
+    ReExportAll,
+}
+
+/// The name of an export in an import or a re-export
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ImportedName {
+    /// The name of the export in the imported module
+    pub(crate) name: String,
+    /// The range of the name in the importing module
+    pub(crate) range: TextRange,
+    /// Whether the name is followed by an `as` clause
+    pub(crate) is_aliased: bool,
+}
+
+impl ProjectModuleGraph {
+    pub(crate) fn new(fs: Arc<dyn FileSystem>) -> Self {
+        Self {
+            resolver: ModuleResolver::new(fs),
+            modules: DashMap::default(),
+            exports: DashMap::default(),
+            import_components: ImportComponents::default(),
+        }
+    }
+
+    /// Update the imports of the module at `path` with its syntax tree
+    pub(crate) fn update_module(&self, path: &Path, root: &JsAnyRoot) {
+        let path = normalize_path(path);
+        let imports: Arc<[ProjectImport]> = self.collect_imports(&path, root).into();
+        let previous = self.modules.insert(path.clone(), imports.clone());
+        self.exports.remove(&path);
+
+        if previous.as_ref() != Some(&imports) {
+            self.invalidate_components(&path);
+        }
+    }
+
+    /// Forget the imports of the module at `path`, they're collected again
+    /// the next time the module is parsed or loaded
+    pub(crate) fn remove_module(&self, path: &Path) {
+        let path = normalize_path(path);
+        self.modules.remove(&path);
+        self.exports.remove(&path);
+        self.invalidate_components(&path);
+    }
+
+    /// Forget the import cycles if the module at `path` is part of the graph
+    /// they were computed with
+    fn invalidate_components(&self, path: &Path) {
+        if self.import_components.contains(path) {
+            self.import_components.clear();
+        }
+    }
+
+    /// Return the imports of the module at `path` along with the bindings
+    /// they import, loading the module if needed
+    pub(crate) fn module_imports(&self, path: &Path) -> Option<Arc<[ProjectImport]>> {
+        self.load_module(&normalize_path(path))
+    }
+
+    /// Read and parse the module at `path` if it wasn't loaded yet
+    fn load_module(&self, path: &Path) -> Option<Arc<[ProjectImport]>> {
+        if let Some(imports) = self.modules.get(path) {
+            return Some(imports.clone());
+        }
+
+        let source_type = SourceType::try_from(path).ok()?;
+        let content = self.resolver.read_file(path)?;
+        let parse = rome_js_parser::parse(&content, FileId::zero(), source_type);
+
+        let imports: Arc<[ProjectImport]> = self.collect_imports(path, &parse.tree()).into();
+        self.modules.insert(path.to_path_buf(), imports.clone());
+        Some(imports)
+    }
+
+    /// Load the modules of the project in `root`, along with the modules the
+    /// modules of the graph import, directly or not, except the dependencies
+    /// of the project
+    pub(crate) fn load_project_modules(&self, root: &Path) {
+        let mut queue: Vec<_> = self
+            .modules
+            .iter()
+            .map(|module| module.key().clone())
+            .collect();
+        queue.extend(self.project_files(root));
+        let mut visited = HashSet::new();
+
+        while let Some(path) = queue.pop() {
+            if !visited.insert(path.clone()) {
+                continue;
+            }
+
+            for import in self
+                .load_module(&path)
+                .iter()
+                .flat_map(|imports| imports.iter())
+            {
+                if let Some(target) = project_module(import.import.resolved_path()) {
+                    queue.push(target);
+                }
+            }
+        }
+    }
+
+    /// Return the JavaScript files in `root`, outside of the `node_modules`
+    /// directories
+    fn project_files(&self, root: &Path) -> Vec<PathBuf> {
+        let (interner, _) = AtomicInterner::new();
+        let context = ProjectFiles {
+            interner,
+            files: Mutex::default(),
+        };
+
+        self.resolver.fs().traversal(Box::new(|scope| {
+            scope.spawn(&context, root.to_path_buf());
+        }));
+
+        context
+            .files
+            .into_inner()
+            .unwrap_or_else(|err| err.into_inner())
+    }
+
+    /// Return the directory of the project containing the module at `path`:
+    /// the nearest directory with a `package.json` file, or the directory of
+    /// the module
+    pub(crate) fn project_root(&self, path: &Path) -> PathBuf {
+        let path = normalize_path(path);
+        let directory = path.parent().unwrap_or_else(|| Path::new(""));
+
+        directory
+            .ancestors()
+            .find(|ancestor| self.resolver.is_package(ancestor))
+            .unwrap_or(directory)
+            .to_path_buf()
+    }
+
+    /// Return the exports of the module at `path`, reading and parsing the
+    /// module the first time they're requested
+    pub(crate) fn module_exports(&self, path: &Path) -> Arc<[ModuleExport]> {
+        let path = normalize_path(path);
+        if let Some(exports) = self.exports.get(&path) {
+            return exports.clone();
+        }
+
+        let exports: Arc<[ModuleExport]> = read_module_exports(self, &path).into();
+        self.exports.insert(path, exports.clone());
+        exports
+    }
+
+    /// Read the content of the module at `path`
+    pub(crate) fn source_text(&self, path: &Path) -> Option<String> {
+        self.resolver.read_file(&normalize_path(path))
+    }
+
+    fn collect_imports(&self, path: &Path, root: &JsAnyRoot) -> Vec<ProjectImport> {
+        root.syntax()
+            .descendants()
+            .filter_map(|node| import_specifier(&node))
+            .map(|(specifier, range, kind, bindings)| ProjectImport {
+                import: ModuleImport {
+                    resolution: self.resolver.resolve(path, &specifier),
+                    specifier,
+                    range,
+                    kind,
+                },
+                bindings,
+            })
+            .collect()
+    }
+}
+
+impl ModuleGraph for ProjectModuleGraph {
+    fn imports(&self, path: &Path) -> Option<Vec<ModuleImport>> {
+        let imports = self.module_imports(path)?;
+        Some(imports.iter().map(|import| import.import.clone()).collect())
+    }
+
+    fn importers(&self, path: &Path) -> Vec<PathBuf> {
+        let path = normalize_path(path);
+        let mut importers: Vec<_> = self
+            .modules
+            .iter()
+            .filter(|module| {
+                module
+                    .value()
+                    .iter()
+                    .any(|import| import.import.resolved_path() == Some(path.as_path()))
+            })
+            .map(|module| module.key().clone())
+            .collect();
+
+        importers.sort();
+        importers
+    }
+
+    fn source_text(&self, path: &Path) -> Option<String> {
+        ProjectModuleGraph::source_text(self, path)
+    }
+
+    fn import_component(&self, path: &Path, include_type_imports: bool) -> Arc<BTreeSet<PathBuf>> {
+        self.import_components
+            .component(&normalize_path(path), include_type_imports, |path| {
+                self.cycle_imports(path, include_type_imports)
+                    .into_iter()
+                    .map(|(target, _)| target)
+                    .collect()
+            })
+    }
+}
+
+/// Collects the files found by a traversal of the project
+struct ProjectFiles {
+    interner: AtomicInterner,
+    files: Mutex<Vec<PathBuf>>,
+}
+
+impl TraversalContext for ProjectFiles {
+    fn interner(&self) -> &dyn PathInterner {
+        &self.interner
+    }
+
+    fn push_diagnostic(&self, _: v2::Error) {}
+
+    fn can_handle(&self, path: &RomePath) -> bool {
+        SourceType::try_from(path.as_path()).is_ok()
+            && project_module(Some(path.as_path())).is_some()
+    }
+
+    fn handle_file(&self, path: &Path, _: FileId) {
+        if let Ok(mut files) = self.files.lock() {
+            files.push(normalize_path(path));
+        }
+    }
+}
+
+impl Debug for ProjectModuleGraph {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        fmt.debug_struct("ProjectModuleGraph")
+            .field("modules", &self.modules.len())
+            .finish()
+    }
+}
+
+/// Return the specifier of the import `node` is the source of, with its
+/// range, the kind of the import and the bindings it imports
+fn import_specifier(
+    node: &JsSyntaxNode,
+) -> Option<(String, TextRange, ImportKind, ImportedBindings)> {
+    if let Some(source) = JsModuleSource::cast_ref(node) {
+        let specifier = source.inner_string_text().ok()?;
+        let kind = import_kind(&source)?;
+        let bindings = imported_bindings(&source)?;
+        return Some((specifier.text().to_string(), source.range(), kind, bindings));
+    }
+
+    if let Some(call) = JsImportCallExpression::cast_ref(node) {
+        let (specifier, range) = string_argument(call.arguments().ok()?)?;
+        return Some((specifier, range, ImportKind::Dynamic, ImportedBindings::All));
+    }
+
+    let call = JsCallExpression::cast_ref(node).filter(is_require_call)?;
+    let (specifier, range) = string_argument(call.arguments().ok()?)?;
+    Some((specifier, range, ImportKind::Require, ImportedBindings::All))
+}
+
+/// Return the bindings imported by the import or export `source` is the
+/// specifier of
+fn imported_bindings(source: &JsModuleSource) -> Option<ImportedBindings> {
+    let parent = source.syntax().parent()?;
+
+    let bindings = match parent.kind() {
+        JsSyntaxKind::JS_IMPORT_BARE_CLAUSE => ImportedBindings::None,
+        JsSyntaxKind::JS_IMPORT_DEFAULT_CLAUSE => {
+            let clause = JsImportDefaultClause::unwrap_cast(parent);
+            let local_name = clause.local_name().ok()?;
+            ImportedBindings::Named(vec![ImportedName {
+                name: String::from("default"),
+                range: local_name.range(),
+                is_aliased: true,
+            }])
+        }
+        JsSyntaxKind::JS_IMPORT_NAMED_CLAUSE => {
+            let clause = JsImportNamedClause::unwrap_cast(parent);
+            let mut names = Vec::new();
+
+            if let Some(default_specifier) = clause.default_specifier() {
+                names.push(ImportedName {
+                    name: String::from("default"),
+                    range: default_specifier.local_name().ok()?.range(),
+                    is_aliased: true,
+                });
+            }
+
+            let specifiers = match clause.named_import().ok()? {
+                JsAnyNamedImport::JsNamedImportSpecifiers(specifiers) => specifiers,
+                JsAnyNamedImport::JsNamespaceImportSpecifier(_) => {
+                    return Some(ImportedBindings::All)
+                }
+            };
+
+            for specifier in specifiers.specifiers().iter().flatten() {
+                match specifier {
+                    JsAnyNamedImportSpecifier::JsNamedImportSpecifier(specifier) => {
+                        let name = specifier.name().ok()?;
+                        names.push(ImportedName {
+                            name: export_name_text(&name)?,
+                            range: name.range(),
+                            is_aliased: true,
+                        });
+                    }
+                    JsAnyNamedImportSpecifier::JsShorthandNamedImportSpecifier(specifier) => {
+                        if let JsAnyBinding::JsIdentifierBinding(binding) =
+                            specifier.local_name().ok()?
+                        {
+                            let name = binding.name_token().ok()?;
+                            names.push(ImportedName {
+                                name: name.text_trimmed().to_string(),
+                                range: name.text_trimmed_range(),
+                                is_aliased: false,
+                            });
+                        }
+                    }
+                    JsAnyNamedImportSpecifier::JsUnknownNamedImportSpecifier(_) => {}
+                }
+            }
+
+            ImportedBindings::Named(names)
+        }
+        JsSyntaxKind::JS_EXPORT_FROM_CLAUSE => {
+            let clause = JsExportFromClause::unwrap_cast(parent);
+            if clause.export_as().is_some() {
+                ImportedBindings::All
+            } else {
+                ImportedBindings::ReExportAll
+            }
+        }
+        JsSyntaxKind::JS_EXPORT_NAMED_FROM_CLAUSE => {
+            let clause = JsExportNamedFromClause::unwrap_cast(parent);
+            let mut names = Vec::new();
+
+            for specifier in clause.specifiers().iter().flatten() {
+                let name = specifier.source_name().ok()?;
+                names.push(ImportedName {
+                    name: export_name_text(&name)?,
+                    range: name.range(),
+                    is_aliased: specifier.export_as().is_some(),
+                });
+            }
+
+            ImportedBindings::Named(names)
+        }
+        _ => ImportedBindings::All,
+    };
+
+    Some(bindings)
+}
+
+/// Return the text of an export name, without the quotes of the names that
+/// are string literals
+pub(crate) fn export_name_text(name: &JsLiteralExportName) -> Option<String> {
+    let token = name.value().ok()?;
+    let text = token.text_trimmed();
+
+    let text = match token.kind() {
+        JsSyntaxKind::JS_STRING_LITERAL => &text[1..text.len() - 1],
+        _ => text,
+    };
+
+    Some(text.to_string())
+}
+
+/// Return the kind of the import or export `source` is the specifier of
+fn import_kind(source: &JsModuleSource) -> Option<ImportKind> {
+    let parent = source.syntax().parent()?;
+    let has_type_token = parent
+        .children_with_tokens()
+        .filter_map(|element| element.into_token())
+        .any(|token| token.kind() == JsSyntaxKind::TYPE_KW);
+
+    let kind = match parent.kind() {
+        JsSyntaxKind::JS_IMPORT_BARE_CLAUSE => ImportKind::Static,
+        JsSyntaxKind::JS_IMPORT_DEFAULT_CLAUSE
+        | JsSyntaxKind::JS_IMPORT_NAMED_CLAUSE
+        | JsSyntaxKind::JS_IMPORT_NAMESPACE_CLAUSE
+            if has_type_token =>
+        {
+            ImportKind::TypeOnly
+        }
+        JsSyntaxKind::JS_IMPORT_DEFAULT_CLAUSE
+        | JsSyntaxKind::JS_IMPORT_NAMED_CLAUSE
+        | JsSyntaxKind::JS_IMPORT_NAMESPACE_CLAUSE => ImportKind::Static,
+        JsSyntaxKind::JS_EXPORT_NAMED_FROM_CLAUSE if has_type_token => ImportKind::TypeOnly,
+        JsSyntaxKind::JS_EXPORT_FROM_CLAUSE | JsSyntaxKind::JS_EXPORT_NAMED_FROM_CLAUSE => {
+            ImportKind::ReExport
+        }
+        JsSyntaxKind::TS_EXTERNAL_MODULE_REFERENCE => ImportKind::Require,
+        _ => return None,
+    };
+
+    Some(kind)
+}
+
+/// Return whether `call` is a call to `require`
+fn is_require_call(call: &JsCallExpression) -> bool {
+    match call.callee() {
+        Ok(JsAnyExpression::JsIdentifierExpression(callee)) => callee
+            .name()
+            .and_then(|name| name.value_token())
+            .map_or(false, |token| token.text_trimmed() == "require"),
+        _ => false,
+    }
+}
+
+/// Return the text and range of the only argument of a call if it's a string
+fn string_argument(arguments: JsCallArguments) -> Option<(String, TextRange)> {
+    let arguments = arguments.args();
+    if arguments.len() != 1 {
+        return None;
+    }
+
+    match arguments.first()?.ok()? {
+        JsAnyCallArgument::JsAnyExpression(JsAnyExpression::JsAnyLiteralExpression(
+            JsAnyLiteralExpression::JsStringLiteralExpression(string),
+        )) => {
+            let text = string.inner_string_text().ok()?;
+            Some((text.text().to_string(), string.range()))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ProjectModuleGraph;
+    use rome_analyze::{
+        AnalysisFilter, AnalyzerOptions, ControlFlow, ImportKind, ModuleGraph, ModuleResolution,
+        Never, RuleFilter,
+    };
+    use rome_diagnostics::FileId;
+    use rome_fs::MemoryFileSystem;
+    use rome_js_syntax::SourceType;
+    use std::path::{Path, PathBuf};
+    use std::sync::Arc;
+
+    #[test]
+    fn collects_imports() {
+        let mut fs = MemoryFileSystem::default();
+        fs.insert(
+            PathBuf::from("/project/main.ts"),
+            r#"
+import { a } from "./a";
+import type { B } from "./b";
+export * from "./c";
+const d = require("./d");
+const e = import("./e");
+import f = require("fs");
+"#,
+        );
+        fs.insert(PathBuf::from("/project/a.ts"), "export const a = 1;");
+        fs.insert(PathBuf::from("/project/b.ts"), "export type B = string;");
+        fs.insert(PathBuf::from("/project/c.ts"), r#"import "./a";"#);
+        fs.insert(PathBuf::from("/project/d.js"), "module.exports = {};");
+
+        let graph = ProjectModuleGraph::new(Arc::new(fs));
+
+        let imports: Vec<_> = graph
+            .imports(Path::new("/project/main.ts"))
+            .unwrap()
+            .into_iter()
+            .map(|import| (import.specifier, import.kind, import.resolution))
+            .collect();
+
+        let file = |path: &str| ModuleResolution::File(PathBuf::from(path));
+        assert_eq!(
+            imports,
+            [
+                ("./a".into(), ImportKind::Static, file("/project/a.ts")),
+                ("./b".into(), ImportKind::TypeOnly, file("/project/b.ts")),
+                ("./c".into(), ImportKind::ReExport, file("/project/c.ts")),
+                ("./d".into(), ImportKind::Require, file("/project/d.js")),
+                (
+                    "./e".into(),
+                    ImportKind::Dynamic,
+                    ModuleResolution::Unresolved
+                ),
+                ("fs".into(), ImportKind::Require, ModuleResolution::Builtin),
+            ]
+        );
+
+        // Loading the imports of `c.ts` adds it to the importers of `a.ts`
+        assert!(graph.imports(Path::new("/project/c.ts")).is_some());
+        assert_eq!(
+            graph.importers(Path::new("/project/a.ts")),
+            [
+                PathBuf::from("/project/c.ts"),
+                PathBuf::from("/project/main.ts")
+            ]
+        );
+    }
+
+    #[test]
+    fn removed_modules_are_loaded_again() {
+        let mut fs = MemoryFileSystem::default();
+        fs.insert(PathBuf::from("/project/main.ts"), "export const main = 1;");
+        fs.insert(PathBuf::from("/project/a.ts"), "export const a = 1;");
+
+        let graph = ProjectModuleGraph::new(Arc::new(fs));
+
+        // The content of the open document differs from the one on disk
+        let parse = rome_js_parser::parse(
+            r#"import { a } from "./a";"#,
+            rome_diagnostics::FileId::zero(),
+            SourceType::ts(),
+        );
+        graph.update_module(Path::new("/project/main.ts"), &parse.tree());
+        assert_eq!(
+            graph.importers(Path::new("/project/a.ts")),
+            [PathBuf::from("/project/main.ts")]
+        );
+
+        // Closing the document reads the module from the file system again
+        graph.remove_module(Path::new("/project/main.ts"));
+        assert!(graph.importers(Path::new("/project/a.ts")).is_empty());
+        assert_eq!(
+            graph.imports(Path::new("/project/main.ts")),
+            Some(Vec::new())
+        );
+    }
+
+    #[test]
+    fn import_components_are_invalidated() {
+        let mut fs = MemoryFileSystem::default();
+        fs.insert(PathBuf::from("/project/a.ts"), r#"import "./b";"#);
+        fs.insert(PathBuf::from("/project/b.ts"), r#"import "./a";"#);
+        fs.insert(PathBuf::from("/project/c.ts"), r#"import "./a";"#);
+
+        let graph = ProjectModuleGraph::new(Arc::new(fs));
+        let component = |path: &str| {
+            let component = graph.import_component(Path::new(path), false);
+            component.iter().cloned().collect::<Vec<_>>()
+        };
+
+        let cycle = [
+            PathBuf::from("/project/a.ts"),
+            PathBuf::from("/project/b.ts"),
+        ];
+        assert_eq!(component("/project/a.ts"), cycle);
+        assert_eq!(component("/project/b.ts"), cycle);
+        assert_eq!(component("/project/c.ts"), [PathBuf::from("/project/c.ts")]);
+
+        // Removing the import of `b.ts` breaks the cycle
+        let parse = rome_js_parser::parse(
+            "export const b = 1;",
+            rome_diagnostics::FileId::zero(),
+            SourceType::ts(),
+        );
+        graph.update_module(Path::new("/project/b.ts"), &parse.tree());
+        assert_eq!(component("/project/a.ts"), [PathBuf::from("/project/a.ts")]);
+    }
+
+    #[test]
+    fn imported_types_are_inferred_from_the_graph() {
+        let main = r#"
+import { load } from "./index";
+import { count } from "./api";
+load();
+count();
+"#;
+
+        let mut fs = MemoryFileSystem::default();
+        fs.insert(PathBuf::from("/project/main.ts"), main);
+        fs.insert(
+            PathBuf::from("/project/index.ts"),
+            r#"import { load } from "./api"; export { load };"#,
+        );
+        fs.insert(
+            PathBuf::from("/project/api.ts"),
+            "export async function load() {} export function count() { return 1; }",
+        );
+
+        let graph = ProjectModuleGraph::new(Arc::new(fs));
+        let options = AnalyzerOptions {
+            file_path: PathBuf::from("/project/main.ts"),
+            module_graph: Some(Arc::new(graph)),
+            ..AnalyzerOptions::default()
+        };
+
+        let rule_filter = RuleFilter::Rule("nursery", "noFloatingPromises");
+        let filter = AnalysisFilter {
+            enabled_rules: Some(std::slice::from_ref(&rule_filter)),
+            ..AnalysisFilter::default()
+        };
+
+        // The promise returned by `load`, re-exported by `index.ts`, is floating
+        let parse = rome_js_parser::parse(main, FileId::zero(), SourceType::ts());
+        let mut floating = Vec::new();
+        rome_js_analyze::analyze(FileId::zero(), &parse.tree(), filter, &options, |signal| {
+            if let Some(diagnostic) = signal.diagnostic() {
+                floating.push(diagnostic.get_span());
+            }
+
+            ControlFlow::<Never>::Continue(())
+        });
+
+        let load = main.find("load()").unwrap() as u32;
+        assert_eq!(
+            floating,
+            [Some(rome_rowan::TextRange::at(load.into(), 6.into()))]
+        );
+    }
+}
@@ -0,0 +1,446 @@
+//! Detection of the dead code of the project: the exports that aren't
+//! imported by any module, and the modules that can't be reached from the
+//! entry points of the project
+//!
+//! The analysis runs once all the modules of the project have been processed,
+//! on the module graph: the bindings used by each import are recorded in the
+//! graph, while the exports of the modules are collected with the semantic
+//! model of the parse of their document, or loaded once by the graph for the
+//! modules that aren't open.
+
+use super::{export_name_text, ImportedBindings, ProjectModuleGraph};
+use crate::configuration::normalize_path;
+use rome_diagnostics::file::FileId;
+use rome_diagnostics::v2::{Diagnostic, Error};
+use rome_js_semantic::{semantic_model, SemanticModel, SemanticModelOptions};
+use rome_js_syntax::{
+    JsAnyRoot, JsExportDefaultDeclarationClause, JsExportDefaultExpressionClause,
+    JsExportFromClause, JsExportNamedFromSpecifier, JsExportNamedShorthandSpecifier,
+    JsExportNamedSpecifier, JsIdentifierBinding, JsSyntaxKind, JsSyntaxNode, SourceType,
+    TsIdentifierBinding,
+};
+use rome_rowan::{AstNode, TextRange};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::path::{Component, Path, PathBuf};
+use std::sync::Arc;
+
+/// A module of the project checked for dead code
+#[derive(Debug)]
+pub(crate) struct DeadCodeModule {
+    pub(crate) path: PathBuf,
+    /// Whether the module is an entry point of the project
+    pub(crate) is_entry_point: bool,
+    /// Whether the exports of the module are the public API of the project,
+    /// imported by code outside of it
+    pub(crate) is_public_api: bool,
+    /// The exports of the module
+    pub(crate) exports: Arc<[ModuleExport]>,
+}
+
+/// A binding exported by a module
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ModuleExport {
+    /// The name the binding is exported with, `default` for the default export
+    pub(crate) name: String,
+    /// The range of the exported name in the module
+    pub(crate) range: TextRange,
+}
+
+#[derive(Debug, Diagnostic)]
+#[diagnostic(category = "deadCode/unusedExport")]
+struct UnusedExportDiagnostic {
+    #[message]
+    #[description]
+    message: String,
+    #[location(span)]
+    span: TextRange,
+}
+
+#[derive(Debug, Diagnostic)]
+#[diagnostic(
+    category = "deadCode/unreachableFile",
+    message = "This file isn't imported by the entry points of the project, directly or not."
+)]
+struct UnreachableFileDiagnostic;
+
+/// The exports of a module used by the modules importing it
+#[derive(Debug)]
+enum UsedExports {
+    All,
+    Named(HashSet<String>),
+}
+
+impl Default for UsedExports {
+    fn default() -> Self {
+        Self::Named(HashSet::new())
+    }
+}
+
+impl UsedExports {
+    /// Mark the exports of `names` as used, returning whether they weren't
+    /// all used before
+    fn extend<'a>(&mut self, names: impl IntoIterator<Item = &'a str>) -> bool {
+        match self {
+            UsedExports::All => false,
+            UsedExports::Named(used) => names.into_iter().fold(false, |changed, name| {
+                used.insert(name.to_string()) || changed
+            }),
+        }
+    }
+
+    /// Mark all the exports as used, returning whether they weren't before
+    fn set_all(&mut self) -> bool {
+        let changed = !matches!(self, UsedExports::All);
+        *self = UsedExports::All;
+        changed
+    }
+
+    fn contains(&self, name: &str) -> bool {
+        match self {
+            UsedExports::All => true,
+            UsedExports::Named(used) => used.contains(name),
+        }
+    }
+}
+
+/// Return the diagnostics of the dead code of each module of `modules`
+///
+/// The modules that can't be reached from the entry points are only reported
+/// if `check_reachability` is set, the exports of the modules that aren't
+/// part of the public API are reported if no module imports them.
+pub(crate) fn dead_code_diagnostics(
+    graph: &ProjectModuleGraph,
+    modules: &[DeadCodeModule],
+    check_reachability: bool,
+) -> Vec<Vec<Error>> {
+    let paths: Vec<_> = modules
+        .iter()
+        .map(|module| normalize_path(&module.path))
+        .collect();
+
+    // Load the imports of the modules and of the modules they import
+    let mut imports = HashMap::new();
+    let mut queue: VecDeque<_> = paths.iter().cloned().collect();
+    while let Some(path) = queue.pop_front() {
+        if imports.contains_key(&path) {
+            continue;
+        }
+
+        let module_imports = match graph.module_imports(&path) {
+            Some(module_imports) => module_imports,
+            None => continue,
+        };
+
+        for import in module_imports.iter() {
+            if let Some(target) = project_module(import.import.resolved_path()) {
+                queue.push_back(target);
+            }
+        }
+
+        imports.insert(path, module_imports);
+    }
+
+    let reachable = if check_reachability {
+        let mut reachable = HashSet::new();
+        let mut queue: VecDeque<_> = modules
+            .iter()
+            .zip(&paths)
+            .filter(|(module, _)| module.is_entry_point)
+            .map(|(_, path)| path.clone())
+            .collect();
+
+        while let Some(path) = queue.pop_front() {
+            if !reachable.insert(path.clone()) {
+                continue;
+            }
+
+            for import in imports.get(&path).iter().flat_map(|imports| imports.iter()) {
+                if let Some(target) = project_module(import.import.resolved_path()) {
+                    queue.push_back(target);
+                }
+            }
+        }
+
+        Some(reachable)
+    } else {
+        None
+    };
+
+    // Collect the exports used by the imports, then forward the exports used
+    // through the `export * from` statements until nothing changes
+    let mut used: HashMap<PathBuf, UsedExports> = HashMap::new();
+    let mut re_exports = Vec::new();
+    for (path, module_imports) in &imports {
+        for import in module_imports.iter() {
+            let target = match project_module(import.import.resolved_path()) {
+                Some(target) => target,
+                None => continue,
+            };
+
+            match &import.bindings {
+                ImportedBindings::None => {}
+                ImportedBindings::Named(names) => {
+                    used.entry(target)
+                        .or_default()
+                        .extend(names.iter().map(|name| name.name.as_str()));
+                }
+                ImportedBindings::All => {
+                    used.entry(target).or_default().set_all();
+                }
+                ImportedBindings::ReExportAll => re_exports.push((path.clone(), target)),
+            }
+        }
+    }
+
+    // The exports of the public API are used outside of the project
+    for (module, path) in modules.iter().zip(&paths) {
+        if module.is_public_api {
+            used.entry(path.clone()).or_default().set_all();
+        }
+    }
+
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for (barrel, target) in &re_exports {
+            let names = match used.get(barrel) {
+                Some(UsedExports::All) => None,
+                Some(UsedExports::Named(names)) => Some(names.iter().cloned().collect::<Vec<_>>()),
+                None => continue,
+            };
+
+            let target = used.entry(target.clone()).or_default();
+            changed |= match &names {
+                None => target.set_all(),
+                Some(names) => target.extend(names.iter().map(String::as_str)),
+            };
+        }
+    }
+
+    modules
+        .iter()
+        .zip(&paths)
+        .map(|(module, path)| {
+            if let Some(reachable) = &reachable {
+                if !reachable.contains(path) {
+                    return vec![Error::from(UnreachableFileDiagnostic)];
+                }
+            }
+
+            let used = used.get(path);
+            if matches!(used, Some(UsedExports::All)) {
+                return Vec::new();
+            }
+
+            module
+                .exports
+                .iter()
+                .filter(|export| !used.map_or(false, |used| used.contains(&export.name)))
+                .map(|export| {
+                    Error::from(UnusedExportDiagnostic {
+                        message: format!(
+                            "The export {} isn't imported by any module of the project.",
+                            export.name
+                        ),
+                        span: export.range,
+                    })
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Return the path of the module an import resolves to, if it's a module of
+/// the project and not one of its dependencies
+pub(super) fn project_module(resolved_path: Option<&Path>) -> Option<PathBuf> {
+    let path = resolved_path?;
+    let is_dependency = path
+        .components()
+        .any(|component| component == Component::Normal("node_modules".as_ref()));
+
+    if is_dependency {
+        None
+    } else {
+        Some(path.to_path_buf())
+    }
+}
+
+/// Read the module at `path` and return its exports
+pub(super) fn read_module_exports(graph: &ProjectModuleGraph, path: &Path) -> Vec<ModuleExport> {
+    let source_type = match SourceType::try_from(path) {
+        Ok(source_type) => source_type,
+        Err(_) => return Vec::new(),
+    };
+
+    let content = match graph.source_text(path) {
+        Some(content) => content,
+        None => return Vec::new(),
+    };
+
+    let parse = rome_js_parser::parse(&content, FileId::zero(), source_type);
+    let root = parse.tree();
+    let model = semantic_model(&root, SemanticModelOptions::default());
+    collect_exports(&root, &model)
+}
+
+/// Return the exports of the module `root`
+pub(crate) fn collect_exports(root: &JsAnyRoot, model: &SemanticModel) -> Vec<ModuleExport> {
+    root.syntax()
+        .descendants()
+        .filter_map(|node| export_name(&node, model))
+        .collect()
+}
+
+/// Return the name of the export declared by `node`, if any
+fn export_name(node: &JsSyntaxNode, model: &SemanticModel) -> Option<ModuleExport> {
+    let (name, range) = match node.kind() {
+        JsSyntaxKind::JS_IDENTIFIER_BINDING | JsSyntaxKind::TS_IDENTIFIER_BINDING => {
+            let is_exported = match JsIdentifierBinding::cast_ref(node) {
+                Some(binding) => model.is_exported(&binding),
+                None => model.is_exported(&TsIdentifierBinding::cast_ref(node)?),
+            };
+
+            // The bindings of the default export and the bindings exported with
+            // an export clause are exported under another name
+            let is_declaration_exported = is_exported
+                && node
+                    .ancestors()
+                    .find(|ancestor| {
+                        matches!(
+                            ancestor.kind(),
+                            JsSyntaxKind::JS_EXPORT
+                                | JsSyntaxKind::JS_EXPORT_DEFAULT_DECLARATION_CLAUSE
+                        )
+                    })
+                    .map_or(false, |ancestor| ancestor.kind() == JsSyntaxKind::JS_EXPORT);
+
+            if !is_declaration_exported {
+                return None;
+            }
+
+            let name = node.first_token()?;
+            (name.text_trimmed().to_string(), name.text_trimmed_range())
+        }
+        JsSyntaxKind::JS_EXPORT_DEFAULT_DECLARATION_CLAUSE => {
+            let clause = JsExportDefaultDeclarationClause::unwrap_cast(node.clone());
+            (
+                String::from("default"),
+                clause.default_token().ok()?.text_trimmed_range(),
+            )
+        }
+        JsSyntaxKind::JS_EXPORT_DEFAULT_EXPRESSION_CLAUSE => {
+            let clause = JsExportDefaultExpressionClause::unwrap_cast(node.clone());
+            (
+                String::from("default"),
+                clause.default_token().ok()?.text_trimmed_range(),
+            )
+        }
+        JsSyntaxKind::JS_EXPORT_NAMED_SHORTHAND_SPECIFIER => {
+            let specifier = JsExportNamedShorthandSpecifier::unwrap_cast(node.clone());
+            let name = specifier.name().ok()?.value_token().ok()?;
+            (name.text_trimmed().to_string(), name.text_trimmed_range())
+        }
+        JsSyntaxKind::JS_EXPORT_NAMED_SPECIFIER => {
+            let specifier = JsExportNamedSpecifier::unwrap_cast(node.clone());
+            let name = specifier.exported_name().ok()?;
+            (export_name_text(&name)?, name.range())
+        }
+        JsSyntaxKind::JS_EXPORT_NAMED_FROM_SPECIFIER => {
+            let specifier = JsExportNamedFromSpecifier::unwrap_cast(node.clone());
+            let name = match specifier.export_as() {
+                Some(export_as) => export_as.exported_name().ok()?,
+                None => specifier.source_name().ok()?,
+            };
+            (export_name_text(&name)?, name.range())
+        }
+        JsSyntaxKind::JS_EXPORT_FROM_CLAUSE => {
+            let clause = JsExportFromClause::unwrap_cast(node.clone());
+            let name = clause.export_as()?.exported_name().ok()?;
+            (export_name_text(&name)?, name.range())
+        }
+        _ => return None,
+    };
+
+    Some(ModuleExport { name, range })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{dead_code_diagnostics, DeadCodeModule};
+    use crate::module_graph::ProjectModuleGraph;
+    use rome_diagnostics::v2::{Diagnostic, Error};
+    use rome_fs::MemoryFileSystem;
+    use std::path::{Path, PathBuf};
+    use std::sync::Arc;
+
+    fn describe(diagnostics: &[Error]) -> Vec<String> {
+        diagnostics
+            .iter()
+            .map(|diagnostic| {
+                let category = diagnostic.category().unwrap().name();
+                let span = diagnostic.location().and_then(|location| location.span);
+                format!("{category} {span:?}")
+            })
+            .collect()
+    }
+
+    #[test]
+    fn reports_dead_code() {
+        let mut fs = MemoryFileSystem::default();
+        fs.insert(
+            PathBuf::from("/project/main.ts"),
+            r#"import { used, type Props } from "./lib";
+import * as all from "./all";
+export { helper } from "./barrel";
+"#,
+        );
+        fs.insert(
+            PathBuf::from("/project/lib.ts"),
+            "export const used = 1;\nexport const unused = 2;\nexport interface Props {}\n",
+        );
+        fs.insert(PathBuf::from("/project/all.ts"), "export const a = 1;");
+        fs.insert(
+            PathBuf::from("/project/barrel.ts"),
+            r#"export * from "./helpers";"#,
+        );
+        fs.insert(
+            PathBuf::from("/project/helpers.ts"),
+            "export function helper() {}\nexport default function () {}\n",
+        );
+        fs.insert(PathBuf::from("/project/orphan.ts"), "export const a = 1;");
+
+        let graph = ProjectModuleGraph::new(Arc::new(fs));
+        let module = |path: &str, is_entry_point| DeadCodeModule {
+            path: PathBuf::from(path),
+            is_entry_point,
+            is_public_api: is_entry_point,
+            exports: graph.module_exports(Path::new(path)),
+        };
+
+        let modules = [
+            module("/project/main.ts", true),
+            module("/project/lib.ts", false),
+            module("/project/all.ts", false),
+            module("/project/barrel.ts", false),
+            module("/project/helpers.ts", false),
+            module("/project/orphan.ts", false),
+        ];
+
+        let diagnostics: Vec<_> = dead_code_diagnostics(&graph, &modules, true)
+            .iter()
+            .map(|diagnostics| describe(diagnostics))
+            .collect();
+
+        assert_eq!(
+            diagnostics,
+            [
+                vec![],
+                vec![String::from("deadCode/unusedExport Some(36..42)")],
+                vec![],
+                vec![],
+                vec![String::from("deadCode/unusedExport Some(35..42)")],
+                vec![String::from("deadCode/unreachableFile None")],
+            ]
+        );
+    }
+}
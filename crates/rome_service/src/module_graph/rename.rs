@@ -0,0 +1,347 @@
+//! Propagation of the rename of an exported binding to the modules of the
+//! project importing it
+//!
+//! The rename of a binding only edits the module declaring it. When the
+//! binding is exported, the name of the export changes too: the modules
+//! importing it are found with the module graph, and their import and
+//! re-export specifiers are updated so that they keep referring to it, as
+//! well as the members of their namespace imports.
+
+use super::dead_code::collect_exports;
+use super::{ImportedBindings, ProjectModuleGraph};
+use crate::configuration::normalize_path;
+use rome_diagnostics::file::FileId;
+use rome_js_semantic::{semantic_model, AllReferencesExtensions, SemanticModelOptions};
+use rome_js_syntax::{
+    JsAnyName, JsAnyNamedImport, JsIdentifierBinding, JsIdentifierExpression, JsImportNamedClause,
+    JsImportNamespaceClause, JsModuleSource, JsStaticMemberExpression, JsSyntaxNode, JsSyntaxToken,
+    SourceType, TsQualifiedName,
+};
+use rome_rowan::{AstNode, TextRange, TextSize};
+use rome_text_edit::TextEdit;
+use std::collections::{BTreeMap, HashSet, VecDeque};
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+/// The edit of a module importing a renamed export
+#[derive(Debug)]
+pub(crate) struct ImporterEdit {
+    pub(crate) path: PathBuf,
+    pub(crate) indels: TextEdit,
+}
+
+/// Return the old and new names of the export of the module at `path` that
+/// was renamed, by comparing the exports of the module before and after the
+/// rename
+pub(crate) fn renamed_export(
+    path: &Path,
+    old_text: &str,
+    new_text: &str,
+) -> Option<(String, String)> {
+    let source_type = SourceType::try_from(path).ok()?;
+    let export_names = |text: &str| {
+        let parse = rome_js_parser::parse(text, FileId::zero(), source_type);
+        let root = parse.tree();
+        let model = semantic_model(&root, SemanticModelOptions::default());
+        let mut names: Vec<_> = collect_exports(&root, &model)
+            .into_iter()
+            .map(|export| export.name)
+            .collect();
+        names.sort();
+        names
+    };
+
+    let old_names = export_names(old_text);
+    let new_names = export_names(new_text);
+
+    let mut removed = old_names.iter().filter(|name| !new_names.contains(name));
+    let mut added = new_names.iter().filter(|name| !old_names.contains(name));
+
+    match (removed.next(), added.next(), removed.next(), added.next()) {
+        (Some(old_name), Some(new_name), None, None) => Some((old_name.clone(), new_name.clone())),
+        _ => None,
+    }
+}
+
+/// Return the edits of the modules of the graph importing the export
+/// `old_name` of the module at `path`, now named `new_name`.
+///
+/// The names of the aliased specifiers are replaced, while an `as` alias is
+/// added to the shorthand specifiers, so that the modules importing them keep
+/// using the same local names and the re-exports keep the same public name.
+/// The modules re-exporting all the exports of the module are followed, the
+/// export is imported from them with the same name. The members of the
+/// namespace imports of the module named `old_name` are renamed.
+///
+/// The modules of the project in `root` are loaded first, the ones that
+/// import the module may not be known to the graph yet.
+///
+/// `source_text` returns the content of a module, the ranges recorded in the
+/// graph being the ones of this content.
+pub(crate) fn rename_importers(
+    graph: &ProjectModuleGraph,
+    root: &Path,
+    path: &Path,
+    old_name: &str,
+    new_name: &str,
+    source_text: impl Fn(&Path) -> Option<String>,
+) -> Vec<ImporterEdit> {
+    graph.load_project_modules(root);
+
+    let mut replacements: BTreeMap<PathBuf, Vec<(TextRange, String)>> = BTreeMap::new();
+    let mut visited = HashSet::new();
+    let mut queue = VecDeque::from([normalize_path(path)]);
+
+    while let Some(module) = queue.pop_front() {
+        if !visited.insert(module.clone()) {
+            continue;
+        }
+
+        for importer in rome_analyze::ModuleGraph::importers(graph, &module) {
+            let imports = match graph.module_imports(&importer) {
+                Some(imports) => imports,
+                None => continue,
+            };
+
+            let imports = imports
+                .iter()
+                .filter(|import| import.import.resolved_path() == Some(module.as_path()));
+
+            for import in imports {
+                match &import.bindings {
+                    ImportedBindings::Named(names) => {
+                        let names = names.iter().filter(|name| name.name == old_name);
+                        for name in names {
+                            let replacement = if name.is_aliased {
+                                new_name.to_string()
+                            } else {
+                                format!("{new_name} as {old_name}")
+                            };
+
+                            replacements
+                                .entry(importer.clone())
+                                .or_default()
+                                .push((name.range, replacement));
+                        }
+                    }
+                    ImportedBindings::All => {
+                        let content = match source_text(&importer) {
+                            Some(content) => content,
+                            None => continue,
+                        };
+
+                        for range in
+                            namespace_members(&importer, &content, import.import.range, old_name)
+                        {
+                            replacements
+                                .entry(importer.clone())
+                                .or_default()
+                                .push((range, new_name.to_string()));
+                        }
+                    }
+                    ImportedBindings::ReExportAll => queue.push_back(importer.clone()),
+                    ImportedBindings::None => {}
+                }
+            }
+        }
+    }
+
+    replacements
+        .into_iter()
+        .filter_map(|(path, mut replacements)| {
+            let content = source_text(&path)?;
+            replacements.sort_by_key(|(range, _)| range.start());
+            let indels = replace_ranges(&content, &replacements)?;
+            Some(ImporterEdit { path, indels })
+        })
+        .collect()
+}
+
+/// Return the ranges of the members named `name` of the namespace imported
+/// by the namespace import of the module at `path` whose specifier is at
+/// `source_range`, like `ns.name` or `ns.Name` in a type.
+///
+/// The members of `import()` and `require()` aren't renamed, they aren't
+/// bound to a namespace.
+fn namespace_members(
+    path: &Path,
+    content: &str,
+    source_range: TextRange,
+    name: &str,
+) -> Vec<TextRange> {
+    let source_type = match SourceType::try_from(path) {
+        Ok(source_type) => source_type,
+        Err(_) => return Vec::new(),
+    };
+
+    let parse = rome_js_parser::parse(content, FileId::zero(), source_type);
+    let root = parse.tree();
+
+    let binding = root
+        .syntax()
+        .descendants()
+        .filter_map(JsModuleSource::cast)
+        .find(|source| source.range() == source_range)
+        .and_then(|source| source.syntax().parent())
+        .and_then(|clause| namespace_binding(&clause));
+
+    let binding = match binding {
+        Some(binding) => binding,
+        None => return Vec::new(),
+    };
+
+    let model = semantic_model(&root, SemanticModelOptions::default());
+    binding
+        .all_references(&model)
+        .filter_map(|reference| namespace_member(reference.node()))
+        .filter(|member| member.text_trimmed() == name)
+        .map(|member| member.text_trimmed_range())
+        .collect()
+}
+
+/// Return the local name of the namespace of `import * as ns from` and
+/// `import a, * as ns from`
+fn namespace_binding(clause: &JsSyntaxNode) -> Option<JsIdentifierBinding> {
+    let local_name = if let Some(clause) = JsImportNamespaceClause::cast_ref(clause) {
+        clause.local_name().ok()?
+    } else {
+        match JsImportNamedClause::cast_ref(clause)?.named_import().ok()? {
+            JsAnyNamedImport::JsNamespaceImportSpecifier(specifier) => {
+                specifier.local_name().ok()?
+            }
+            JsAnyNamedImport::JsNamedImportSpecifiers(_) => return None,
+        }
+    };
+
+    local_name.as_js_identifier_binding().cloned()
+}
+
+/// Return the token of the member accessed on the namespace `reference` refers
+/// to, in `ns.member` and in the qualified type `ns.Member`
+fn namespace_member(reference: &JsSyntaxNode) -> Option<JsSyntaxToken> {
+    let parent = reference.parent()?;
+
+    let member = if let Some(qualified_name) = TsQualifiedName::cast_ref(&parent) {
+        qualified_name.right().ok()?
+    } else {
+        let expression = JsIdentifierExpression::cast(parent)?;
+        let member_expression = JsStaticMemberExpression::cast(expression.syntax().parent()?)?;
+        if member_expression.object().ok()?.syntax() != expression.syntax() {
+            return None;
+        }
+
+        match member_expression.member().ok()? {
+            JsAnyName::JsName(name) => name,
+            JsAnyName::JsPrivateName(_) => return None,
+        }
+    };
+
+    member.value_token().ok()
+}
+
+/// Build the edit of `content` replacing the text of each range, the ranges
+/// being sorted and disjoint
+fn replace_ranges(content: &str, replacements: &[(TextRange, String)]) -> Option<TextEdit> {
+    let text = |range: TextRange| content.get(Range::<usize>::from(range));
+    let mut builder = TextEdit::builder();
+    let mut offset = TextSize::from(0);
+
+    for (range, replacement) in replacements {
+        if range.start() < offset {
+            continue;
+        }
+
+        builder.equal(text(TextRange::new(offset, range.start()))?);
+        builder.replace(text(*range)?, replacement);
+        offset = range.end();
+    }
+
+    builder.equal(text(TextRange::new(offset, TextSize::of(content)))?);
+    Some(builder.finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{rename_importers, renamed_export};
+    use crate::module_graph::ProjectModuleGraph;
+    use rome_fs::MemoryFileSystem;
+    use std::path::{Path, PathBuf};
+    use std::sync::Arc;
+
+    #[test]
+    fn renames_the_imports_of_an_export() {
+        let mut fs = MemoryFileSystem::default();
+        fs.insert(
+            PathBuf::from("/project/main.ts"),
+            r#"import { value, other } from "./lib";
+import { value as renamed } from "./barrel";
+export { value } from "./lib";
+"#,
+        );
+        fs.insert(
+            PathBuf::from("/project/barrel.ts"),
+            r#"export * from "./lib";"#,
+        );
+        fs.insert(
+            PathBuf::from("/project/namespace.ts"),
+            r#"import * as lib from "./lib";
+console.log(lib.value, lib.other);
+"#,
+        );
+        fs.insert(
+            PathBuf::from("/project/lib.ts"),
+            "export const value = 1;\nexport const other = 2;\n",
+        );
+
+        // The graph doesn't know the modules importing the export yet
+        let graph = ProjectModuleGraph::new(Arc::new(fs));
+
+        let renamed = renamed_export(
+            Path::new("/project/lib.ts"),
+            "export const value = 1;\nexport const other = 2;\n",
+            "export const total = 1;\nexport const other = 2;\n",
+        );
+        assert_eq!(renamed, Some(("value".into(), "total".into())));
+
+        let source_text = |path: &Path| graph.source_text(path);
+        let edits = rename_importers(
+            &graph,
+            Path::new("/project"),
+            Path::new("/project/lib.ts"),
+            "value",
+            "total",
+            source_text,
+        );
+
+        let edits: Vec<_> = edits
+            .into_iter()
+            .map(|edit| {
+                let content = source_text(&edit.path).unwrap();
+                (edit.path, edit.indels.new_string(&content))
+            })
+            .collect();
+
+        assert_eq!(
+            edits,
+            [
+                (
+                    PathBuf::from("/project/main.ts"),
+                    String::from(
+                        r#"import { total as value, other } from "./lib";
+import { total as renamed } from "./barrel";
+export { total as value } from "./lib";
+"#
+                    )
+                ),
+                (
+                    PathBuf::from("/project/namespace.ts"),
+                    String::from(
+                        r#"import * as lib from "./lib";
+console.log(lib.total, lib.other);
+"#
+                    )
+                )
+            ]
+        );
+    }
+}
@@ -0,0 +1,682 @@
+//! Resolution of the specifiers of the imports to the files they refer to
+//!
+//! The resolution follows the algorithm of Node.js and TypeScript: the
+//! `paths` and `baseUrl` of the nearest `tsconfig.json` are tried first, then
+//! relative specifiers are resolved from the directory of the importing module
+//! and bare specifiers are looked up in the `node_modules` directories of its
+//! ancestors, following the `exports` and `main` fields of `package.json`.
+
+use crate::configuration::normalize_path;
+use dashmap::DashMap;
+use rome_analyze::ModuleResolution;
+use rome_fs::{FileSystem, OpenOptions};
+use serde_json::Value;
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+use std::str::Chars;
+use std::sync::Arc;
+
+/// The extensions tried, in order, when a specifier doesn't have one
+const EXTENSIONS: &[&str] = &[
+    ".ts", ".tsx", ".d.ts", ".js", ".jsx", ".mjs", ".cjs", ".mts", ".cts", ".json",
+];
+
+/// The conditions of the `exports` field of `package.json` supported by the
+/// resolver, in order of priority
+const CONDITIONS: &[&str] = &["types", "import", "require", "node", "default"];
+
+/// The modules of Node.js, that can also be imported with the `node:` prefix
+const NODE_BUILTINS: &[&str] = &[
+    "assert",
+    "async_hooks",
+    "buffer",
+    "child_process",
+    "cluster",
+    "console",
+    "constants",
+    "crypto",
+    "dgram",
+    "diagnostics_channel",
+    "dns",
+    "domain",
+    "events",
+    "fs",
+    "http",
+    "http2",
+    "https",
+    "inspector",
+    "module",
+    "net",
+    "os",
+    "path",
+    "perf_hooks",
+    "process",
+    "punycode",
+    "querystring",
+    "readline",
+    "repl",
+    "stream",
+    "string_decoder",
+    "sys",
+    "timers",
+    "tls",
+    "trace_events",
+    "tty",
+    "url",
+    "util",
+    "v8",
+    "vm",
+    "wasi",
+    "worker_threads",
+    "zlib",
+];
+
+/// Resolves the specifiers of the imports, caching the `package.json` and
+/// `tsconfig.json` files it reads
+pub(crate) struct ModuleResolver {
+    fs: Arc<dyn FileSystem>,
+    /// The `package.json` files read so far, by directory
+    manifests: DashMap<PathBuf, Option<Arc<PackageManifest>>>,
+    /// The nearest `tsconfig.json` of the directories visited so far
+    tsconfigs: DashMap<PathBuf, Option<Arc<TsConfig>>>,
+}
+
+/// The fields of `package.json` used by the resolver
+struct PackageManifest {
+    main: Option<String>,
+    exports: Option<Value>,
+}
+
+/// The options of `tsconfig.json` used by the resolver
+struct TsConfig {
+    /// The directory `baseUrl` points to, if any
+    base_url: Option<PathBuf>,
+    /// The directory the targets of `paths` are relative to
+    paths_base: PathBuf,
+    /// The patterns of `paths`, with their targets
+    paths: Vec<(String, Vec<String>)>,
+}
+
+impl ModuleResolver {
+    pub(crate) fn new(fs: Arc<dyn FileSystem>) -> Self {
+        Self {
+            fs,
+            manifests: DashMap::default(),
+            tsconfigs: DashMap::default(),
+        }
+    }
+
+    pub(crate) fn fs(&self) -> &dyn FileSystem {
+        self.fs.as_ref()
+    }
+
+    /// Whether `directory` has a `package.json` file
+    pub(crate) fn is_package(&self, directory: &Path) -> bool {
+        self.read_manifest(directory).is_some()
+    }
+
+    /// Read the content of the file at `path`, if it exists
+    pub(crate) fn read_file(&self, path: &Path) -> Option<String> {
+        let options = OpenOptions::default().read(true);
+        let mut file = self.fs.open_with_options(path, options).ok()?;
+
+        let mut content = String::new();
+        file.read_to_string(&mut content).ok()?;
+        Some(content)
+    }
+
+    /// Resolve the `specifier` of an import of the module at `importer`
+    pub(crate) fn resolve(&self, importer: &Path, specifier: &str) -> ModuleResolution {
+        if is_builtin(specifier) {
+            return ModuleResolution::Builtin;
+        }
+
+        let directory = importer.parent().unwrap_or_else(|| Path::new(""));
+
+        let resolved = if is_relative(specifier) || Path::new(specifier).is_absolute() {
+            self.resolve_path(&directory.join(specifier))
+        } else {
+            self.resolve_with_tsconfig(directory, specifier)
+                .or_else(|| self.resolve_node_module(directory, specifier))
+        };
+
+        match resolved {
+            Some(path) => ModuleResolution::File(path),
+            None => ModuleResolution::Unresolved,
+        }
+    }
+
+    /// Resolve a bare specifier with the `paths` and `baseUrl` options of the
+    /// nearest `tsconfig.json`
+    fn resolve_with_tsconfig(&self, directory: &Path, specifier: &str) -> Option<PathBuf> {
+        let tsconfig = self.find_tsconfig(directory)?;
+
+        let matched = tsconfig
+            .paths
+            .iter()
+            .filter_map(|(pattern, targets)| {
+                match_pattern(pattern, specifier).map(|star| (pattern, targets, star))
+            })
+            // The pattern with the longest prefix before `*` wins
+            .max_by_key(|(pattern, _, _)| pattern.find('*').unwrap_or(pattern.len()));
+
+        if let Some((_, targets, star)) = matched {
+            let resolved = targets.iter().find_map(|target| {
+                self.resolve_path(&tsconfig.paths_base.join(target.replacen('*', star, 1)))
+            });
+
+            if resolved.is_some() {
+                return resolved;
+            }
+        }
+
+        let base_url = tsconfig.base_url.as_ref()?;
+        self.resolve_path(&base_url.join(specifier))
+    }
+
+    /// Resolve a bare specifier from the `node_modules` directories of the
+    /// ancestors of `directory`
+    fn resolve_node_module(&self, directory: &Path, specifier: &str) -> Option<PathBuf> {
+        let (package, subpath) = split_package_name(specifier)?;
+
+        for ancestor in directory.ancestors() {
+            let package_directory = ancestor.join("node_modules").join(package);
+
+            if let Some(manifest) = self.read_manifest(&package_directory) {
+                if let Some(exports) = &manifest.exports {
+                    let subpath = match subpath {
+                        Some(subpath) => format!("./{subpath}"),
+                        None => String::from("."),
+                    };
+
+                    // The files not listed in `exports` can't be imported
+                    return resolve_exports(exports, &subpath)
+                        .and_then(|target| self.resolve_file(&package_directory.join(target)));
+                }
+            }
+
+            let resolved = match subpath {
+                Some(subpath) => self.resolve_path(&package_directory.join(subpath)),
+                None => self.resolve_directory(&package_directory),
+            };
+
+            if resolved.is_some() {
+                return resolved;
+            }
+        }
+
+        None
+    }
+
+    /// Resolve a path to a file, trying the known extensions if it doesn't
+    /// exist, then the `main` field or the `index` file if it's a directory
+    fn resolve_path(&self, path: &Path) -> Option<PathBuf> {
+        self.resolve_file(path)
+            .or_else(|| self.resolve_directory(path))
+    }
+
+    fn resolve_file(&self, path: &Path) -> Option<PathBuf> {
+        let path = &normalize_path(path);
+        if self.is_file(path) {
+            return Some(path.to_path_buf());
+        }
+
+        for extension in EXTENSIONS {
+            let mut candidate = OsString::from(path.as_os_str());
+            candidate.push(extension);
+            let candidate = PathBuf::from(candidate);
+
+            if self.is_file(&candidate) {
+                return Some(candidate);
+            }
+        }
+
+        // TypeScript modules are imported with the extension of the file
+        // they are compiled to
+        let replacements: &[&str] = match path.extension().and_then(|ext| ext.to_str()) {
+            Some("js") => &["ts", "tsx", "d.ts"],
+            Some("jsx") => &["tsx"],
+            Some("mjs") => &["mts", "d.mts"],
+            Some("cjs") => &["cts", "d.cts"],
+            _ => &[],
+        };
+
+        replacements
+            .iter()
+            .map(|extension| path.with_extension(extension))
+            .find(|candidate| self.is_file(candidate))
+    }
+
+    fn resolve_directory(&self, path: &Path) -> Option<PathBuf> {
+        if let Some(main) = self
+            .read_manifest(path)
+            .and_then(|manifest| manifest.main.clone())
+        {
+            let main = path.join(main);
+            let resolved = self
+                .resolve_file(&main)
+                .or_else(|| self.resolve_file(&main.join("index")));
+
+            if resolved.is_some() {
+                return resolved;
+            }
+        }
+
+        self.resolve_file(&path.join("index"))
+    }
+
+    fn is_file(&self, path: &Path) -> bool {
+        let options = OpenOptions::default().read(true);
+        // Directories can be opened for reading on some platforms, but
+        // reading their content fails
+        self.fs
+            .open_with_options(path, options)
+            .and_then(|mut file| file.read_to_end(&mut Vec::new()))
+            .is_ok()
+    }
+
+    /// Return the `package.json` file of `directory`, if it has one
+    fn read_manifest(&self, directory: &Path) -> Option<Arc<PackageManifest>> {
+        if let Some(manifest) = self.manifests.get(directory) {
+            return manifest.clone();
+        }
+
+        let manifest = self
+            .read_file(&directory.join("package.json"))
+            .and_then(|content| serde_json::from_str::<Value>(&content).ok())
+            .map(|value| {
+                Arc::new(PackageManifest {
+                    main: value.get("main").and_then(Value::as_str).map(String::from),
+                    exports: value.get("exports").cloned(),
+                })
+            });
+
+        self.manifests
+            .insert(directory.to_path_buf(), manifest.clone());
+        manifest
+    }
+
+    /// Return the nearest `tsconfig.json` file of `directory`
+    fn find_tsconfig(&self, directory: &Path) -> Option<Arc<TsConfig>> {
+        if let Some(tsconfig) = self.tsconfigs.get(directory) {
+            return tsconfig.clone();
+        }
+
+        let tsconfig = match self.read_file(&directory.join("tsconfig.json")) {
+            Some(content) => parse_tsconfig(directory, &content).map(Arc::new),
+            None => directory
+                .parent()
+                .and_then(|parent| self.find_tsconfig(parent)),
+        };
+
+        self.tsconfigs
+            .insert(directory.to_path_buf(), tsconfig.clone());
+        tsconfig
+    }
+}
+
+fn parse_tsconfig(directory: &Path, content: &str) -> Option<TsConfig> {
+    let value: Value = serde_json::from_str(&strip_json_comments(content)).ok()?;
+    let compiler_options = value.get("compilerOptions");
+
+    let base_url = compiler_options
+        .and_then(|options| options.get("baseUrl"))
+        .and_then(Value::as_str)
+        .map(|base_url| normalize_path(&directory.join(base_url)));
+
+    let paths = compiler_options
+        .and_then(|options| options.get("paths"))
+        .and_then(Value::as_object)
+        .map(|paths| {
+            paths
+                .iter()
+                .map(|(pattern, targets)| {
+                    let targets = targets
+                        .as_array()
+                        .into_iter()
+                        .flatten()
+                        .filter_map(Value::as_str)
+                        .map(String::from)
+                        .collect();
+                    (pattern.clone(), targets)
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Some(TsConfig {
+        paths_base: base_url.clone().unwrap_or_else(|| directory.to_path_buf()),
+        base_url,
+        paths,
+    })
+}
+
+/// Remove the comments and the trailing commas allowed in `tsconfig.json`
+fn strip_json_comments(content: &str) -> String {
+    let mut output = String::with_capacity(content.len());
+    let mut chars = content.chars();
+    let mut in_string = false;
+
+    loop {
+        if !in_string && skip_comment(&mut chars) {
+            continue;
+        }
+
+        let char = match chars.next() {
+            Some(char) => char,
+            None => break,
+        };
+
+        if in_string {
+            output.push(char);
+            match char {
+                '\\' => output.extend(chars.next()),
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+
+        match char {
+            '"' => {
+                in_string = true;
+                output.push(char);
+            }
+            ',' => {
+                // Only keep the comma if it's followed by another value
+                let mut rest = chars.clone();
+                let next = loop {
+                    if skip_comment(&mut rest) {
+                        continue;
+                    }
+                    match rest.next() {
+                        Some(char) if char.is_whitespace() => {}
+                        next => break next,
+                    }
+                };
+
+                if !matches!(next, Some('}' | ']')) {
+                    output.push(char);
+                }
+            }
+            _ => output.push(char),
+        }
+    }
+
+    output
+}
+
+/// Advance `chars` past the comment it starts with, if any
+fn skip_comment(chars: &mut Chars) -> bool {
+    let mut lookahead = chars.clone();
+    if lookahead.next() != Some('/') {
+        return false;
+    }
+
+    match lookahead.next() {
+        Some('/') => {
+            for char in lookahead.by_ref() {
+                if char == '\n' {
+                    break;
+                }
+            }
+        }
+        Some('*') => {
+            let mut previous = None;
+            for char in lookahead.by_ref() {
+                if previous == Some('*') && char == '/' {
+                    break;
+                }
+                previous = Some(char);
+            }
+        }
+        _ => return false,
+    }
+
+    *chars = lookahead;
+    true
+}
+
+/// Resolve the `subpath` of a package with its `exports` field, returning the
+/// target relative to the directory of the package
+fn resolve_exports(exports: &Value, subpath: &str) -> Option<String> {
+    let subpaths = match exports {
+        Value::Object(map) if map.keys().any(|key| key.starts_with('.')) => map,
+        // The field only lists the conditions of the main entry point
+        _ => {
+            return if subpath == "." {
+                resolve_export_target(exports, "")
+            } else {
+                None
+            };
+        }
+    };
+
+    if let Some(target) = subpaths.get(subpath) {
+        return resolve_export_target(target, "");
+    }
+
+    subpaths
+        .iter()
+        .filter_map(|(pattern, target)| {
+            match_pattern(pattern, subpath).map(|star| (pattern, target, star))
+        })
+        .max_by_key(|(pattern, _, _)| pattern.find('*').unwrap_or(pattern.len()))
+        .and_then(|(_, target, star)| resolve_export_target(target, star))
+}
+
+fn resolve_export_target(target: &Value, star: &str) -> Option<String> {
+    match target {
+        Value::String(target) => Some(target.replace('*', star)),
+        Value::Array(targets) => targets
+            .iter()
+            .find_map(|target| resolve_export_target(target, star)),
+        Value::Object(conditions) => CONDITIONS
+            .iter()
+            .filter_map(|condition| conditions.get(*condition))
+            .find_map(|target| resolve_export_target(target, star)),
+        _ => None,
+    }
+}
+
+/// Match `value` against a pattern containing at most one `*`, returning the
+/// text matched by the `*`
+fn match_pattern<'a>(pattern: &str, value: &'a str) -> Option<&'a str> {
+    match pattern.split_once('*') {
+        Some((prefix, suffix)) => value.strip_prefix(prefix)?.strip_suffix(suffix),
+        None if pattern == value => Some(""),
+        None => None,
+    }
+}
+
+/// Split a bare specifier into the name of the package and the path inside it
+fn split_package_name(specifier: &str) -> Option<(&str, Option<&str>)> {
+    let name_length = if specifier.starts_with('@') {
+        let (scope, rest) = specifier.split_once('/')?;
+        scope.len() + 1 + rest.find('/').unwrap_or(rest.len())
+    } else {
+        specifier.find('/').unwrap_or(specifier.len())
+    };
+
+    let (name, rest) = specifier.split_at(name_length);
+    let subpath = rest.strip_prefix('/').filter(|subpath| !subpath.is_empty());
+    Some((name, subpath))
+}
+
+fn is_relative(specifier: &str) -> bool {
+    specifier == "."
+        || specifier == ".."
+        || specifier.starts_with("./")
+        || specifier.starts_with("../")
+}
+
+fn is_builtin(specifier: &str) -> bool {
+    if specifier.starts_with("node:") {
+        return true;
+    }
+
+    let name = specifier.split('/').next().unwrap_or(specifier);
+    NODE_BUILTINS.contains(&name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{strip_json_comments, ModuleResolver};
+    use rome_analyze::ModuleResolution;
+    use rome_fs::MemoryFileSystem;
+    use std::path::{Path, PathBuf};
+    use std::sync::Arc;
+
+    fn resolver(files: &[(&str, &str)]) -> ModuleResolver {
+        let mut fs = MemoryFileSystem::default();
+        for (path, content) in files {
+            fs.insert(PathBuf::from(path), *content);
+        }
+        ModuleResolver::new(Arc::new(fs))
+    }
+
+    fn resolved(path: &str) -> ModuleResolution {
+        ModuleResolution::File(PathBuf::from(path))
+    }
+
+    #[test]
+    fn relative_specifiers() {
+        let resolver = resolver(&[
+            ("/project/src/utils.ts", ""),
+            ("/project/src/components/index.tsx", ""),
+            ("/project/lib/helpers.js", ""),
+        ]);
+        let importer = Path::new("/project/src/main.ts");
+
+        assert_eq!(
+            resolver.resolve(importer, "./utils"),
+            resolved("/project/src/utils.ts")
+        );
+        assert_eq!(
+            resolver.resolve(importer, "./utils.js"),
+            resolved("/project/src/utils.ts")
+        );
+        assert_eq!(
+            resolver.resolve(importer, "./components"),
+            resolved("/project/src/components/index.tsx")
+        );
+        assert_eq!(
+            resolver.resolve(importer, "../lib/helpers"),
+            resolved("/project/lib/helpers.js")
+        );
+        assert_eq!(
+            resolver.resolve(importer, "./missing"),
+            ModuleResolution::Unresolved
+        );
+    }
+
+    #[test]
+    fn builtin_modules() {
+        let resolver = resolver(&[]);
+        let importer = Path::new("/project/main.js");
+
+        assert_eq!(resolver.resolve(importer, "fs"), ModuleResolution::Builtin);
+        assert_eq!(
+            resolver.resolve(importer, "fs/promises"),
+            ModuleResolution::Builtin
+        );
+        assert_eq!(
+            resolver.resolve(importer, "node:test"),
+            ModuleResolution::Builtin
+        );
+    }
+
+    #[test]
+    fn node_modules() {
+        let resolver = resolver(&[
+            (
+                "/project/node_modules/main-package/package.json",
+                r#"{ "main": "./dist/main.js" }"#,
+            ),
+            ("/project/node_modules/main-package/dist/main.js", ""),
+            ("/project/node_modules/main-package/dist/other.js", ""),
+            (
+                "/project/node_modules/@scope/exports/package.json",
+                r#"{
+                    "exports": {
+                        ".": { "import": "./esm/index.mjs", "require": "./cjs/index.cjs" },
+                        "./features/*": "./esm/features/*.mjs"
+                    }
+                }"#,
+            ),
+            ("/project/node_modules/@scope/exports/esm/index.mjs", ""),
+            (
+                "/project/node_modules/@scope/exports/esm/features/a.mjs",
+                "",
+            ),
+            ("/project/node_modules/@scope/exports/esm/private.mjs", ""),
+        ]);
+        let importer = Path::new("/project/src/main.js");
+
+        assert_eq!(
+            resolver.resolve(importer, "main-package"),
+            resolved("/project/node_modules/main-package/dist/main.js")
+        );
+        assert_eq!(
+            resolver.resolve(importer, "main-package/dist/other"),
+            resolved("/project/node_modules/main-package/dist/other.js")
+        );
+        assert_eq!(
+            resolver.resolve(importer, "@scope/exports"),
+            resolved("/project/node_modules/@scope/exports/esm/index.mjs")
+        );
+        assert_eq!(
+            resolver.resolve(importer, "@scope/exports/features/a"),
+            resolved("/project/node_modules/@scope/exports/esm/features/a.mjs")
+        );
+        assert_eq!(
+            resolver.resolve(importer, "@scope/exports/esm/private.mjs"),
+            ModuleResolution::Unresolved
+        );
+    }
+
+    #[test]
+    fn tsconfig_paths() {
+        let resolver = resolver(&[
+            (
+                "/project/tsconfig.json",
+                r#"{
+                    // Comments are allowed
+                    "compilerOptions": {
+                        /* The directory of the sources */
+                        "baseUrl": "./src",
+                        "paths": {
+                            "@app/*": ["app/*"],
+                            "config": ["../config/index.ts"], // Outside of `baseUrl`
+                        },
+                    },
+                }"#,
+            ),
+            ("/project/src/app/store.ts", ""),
+            ("/project/src/shared/format.ts", ""),
+            ("/project/config/index.ts", ""),
+        ]);
+        let importer = Path::new("/project/src/app/main.ts");
+
+        assert_eq!(
+            resolver.resolve(importer, "@app/store"),
+            resolved("/project/src/app/store.ts")
+        );
+        assert_eq!(
+            resolver.resolve(importer, "config"),
+            resolved("/project/config/index.ts")
+        );
+        assert_eq!(
+            resolver.resolve(importer, "shared/format"),
+            resolved("/project/src/shared/format.ts")
+        );
+    }
+
+    #[test]
+    fn strips_json_comments() {
+        assert_eq!(
+            strip_json_comments(r#"{ "a": "//b", /* c */ "d": [1, 2,], }"#),
+            r#"{ "a": "//b",  "d": [1, 2] }"#
+        );
+    }
+}
@@ -0,0 +1,292 @@
+//! Lint rule plugins compiled to WebAssembly
+//!
+//! A plugin is a WebAssembly module executed by an interpreter inside the
+//! process. No function is imported into the module, so the plugins can't access
+//! the file system, the network or the clock, and each call is limited to a fixed
+//! amount of fuel and of memory. The module must export:
+//! - `memory`, its linear memory;
+//! - `rome_alloc(len: i32) -> i32`, allocating `len` bytes and returning their address;
+//! - `rome_rules() -> i64`, returning the JSON array of the names of its rules;
+//! - `rome_lint(address: i32, len: i32) -> i64`, receiving the JSON document of a
+//!   file, see [AnalyzerPlugin::evaluate], and returning the JSON array of its diagnostics.
+//!
+//! The strings returned by the plugins are encoded as `address << 32 | len`.
+
+use rome_analyze::{intern_rule_name, AnalyzerPlugin, PluginRule};
+use rome_diagnostics::v2::Severity;
+use std::fmt::{self, Debug, Formatter};
+use wasmi::{
+    Config, Engine, Extern, Instance, Linker, Module, Store, StoreLimits, StoreLimitsBuilder,
+    TypedFunc, WasmParams, WasmResults,
+};
+
+/// Fuel given to each call of a plugin, roughly the number of instructions it
+/// can execute
+const FUEL: u64 = 1_000_000_000;
+
+/// Maximum size of the linear memory of a plugin, in bytes. Growing the memory
+/// past this size makes the call fail
+const MEMORY_SIZE: usize = 64 * 1024 * 1024;
+
+/// A plugin loaded from a WebAssembly module
+pub(crate) struct WasmPlugin {
+    name: &'static str,
+    rules: Vec<PluginRule>,
+    engine: Engine,
+    module: Module,
+}
+
+impl Debug for WasmPlugin {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WasmPlugin")
+            .field("name", &self.name)
+            .field("rules", &self.rules)
+            .finish()
+    }
+}
+
+impl WasmPlugin {
+    /// Compile the plugin `name` from the content of its module, `severity` returns
+    /// the severity of each rule of the plugin, or `None` if the rule is disabled
+    pub(crate) fn new<F>(name: &str, bytes: &[u8], severity: F) -> Result<Self, String>
+    where
+        F: Fn(&str) -> Option<Severity>,
+    {
+        let mut config = Config::default();
+        config.consume_fuel(true);
+        let engine = Engine::new(&config);
+        let module = Module::new(&engine, bytes).map_err(|error| error.to_string())?;
+
+        let mut plugin = Self {
+            name: intern_rule_name(name),
+            rules: Vec::new(),
+            engine,
+            module,
+        };
+
+        let rules = plugin.call("rome_rules", None)?;
+        let rules: Vec<String> = serde_json::from_str(&rules)
+            .map_err(|error| format!("invalid list of rules: {error}"))?;
+
+        plugin.rules = rules
+            .iter()
+            .map(|rule| PluginRule {
+                name: intern_rule_name(rule),
+                severity: severity(rule),
+            })
+            .collect();
+
+        Ok(plugin)
+    }
+
+    /// Instantiate the module and call its function `name`, with the address
+    /// and length of `input` if it's provided. Returns the string returned by
+    /// the function
+    fn call(&self, name: &str, input: Option<&str>) -> Result<String, String> {
+        let limits = StoreLimitsBuilder::new()
+            .memory_size(MEMORY_SIZE)
+            .trap_on_grow_failure(true)
+            .build();
+        let mut store = Store::new(&self.engine, limits);
+        store.limiter(|limits| limits);
+        store.add_fuel(FUEL).map_err(|error| error.to_string())?;
+
+        let linker = Linker::<StoreLimits>::new(&self.engine);
+        let instance = linker
+            .instantiate(&mut store, &self.module)
+            .map_err(|error| error.to_string())?
+            .start(&mut store)
+            .map_err(|error| error.to_string())?;
+
+        let memory = instance
+            .get_export(&store, "memory")
+            .and_then(Extern::into_memory)
+            .ok_or_else(|| String::from("the module doesn't export its memory"))?;
+
+        let result = match input {
+            Some(input) => {
+                let len = i32::try_from(input.len())
+                    .map_err(|_| String::from("the file is too large"))?;
+                let address = typed_func::<i32, i32>(&instance, &store, "rome_alloc")?
+                    .call(&mut store, len)
+                    .map_err(|error| error.to_string())?;
+                memory
+                    .write(&mut store, address as u32 as usize, input.as_bytes())
+                    .map_err(|error| error.to_string())?;
+
+                typed_func::<(i32, i32), i64>(&instance, &store, name)?
+                    .call(&mut store, (address, len))
+            }
+            None => typed_func::<(), i64>(&instance, &store, name)?.call(&mut store, ()),
+        }
+        .map_err(|error| error.to_string())?;
+
+        let result = result as u64;
+        let address = (result >> 32) as usize;
+        let len = (result & u64::from(u32::MAX)) as usize;
+
+        let mut buffer = vec![0; len];
+        memory
+            .read(&store, address, &mut buffer)
+            .map_err(|error| error.to_string())?;
+
+        String::from_utf8(buffer).map_err(|_| format!("`{name}` returned invalid UTF-8"))
+    }
+}
+
+impl AnalyzerPlugin for WasmPlugin {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn rules(&self) -> &[PluginRule] {
+        &self.rules
+    }
+
+    fn evaluate(&self, input: &str) -> Result<String, String> {
+        self.call("rome_lint", Some(input))
+    }
+}
+
+fn typed_func<Params, Results>(
+    instance: &Instance,
+    store: &Store<StoreLimits>,
+    name: &str,
+) -> Result<TypedFunc<Params, Results>, String>
+where
+    Params: WasmParams,
+    Results: WasmResults,
+{
+    instance
+        .get_export(store, name)
+        .and_then(Extern::into_func)
+        .ok_or_else(|| format!("the module doesn't export the function `{name}`"))?
+        .typed::<Params, Results>(store)
+        .map_err(|error| error.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WasmPlugin;
+    use crate::configuration::{linter::load_plugins, parse_config};
+    use rome_analyze::{AnalysisFilter, AnalyzerOptions, AnalyzerPlugin, ControlFlow, Never};
+    use rome_diagnostics::file::FileId;
+    use rome_diagnostics::v2::{category, Diagnostic, Severity};
+    use rome_fs::MemoryFileSystem;
+    use rome_js_analyze::analyze;
+    use rome_js_parser::parse;
+    use rome_js_syntax::SourceType;
+    use std::path::PathBuf;
+    use std::sync::Arc;
+
+    /// A plugin always returning the same diagnostic:
+    ///
+    /// ```wat
+    /// (module
+    ///   (memory (export "memory") 1)
+    ///   (func (export "rome_alloc") (param i32) (result i32) i32.const 1024)
+    ///   (func (export "rome_rules") (result i64) i64.const 9)
+    ///   (func (export "rome_lint") (param i32 i32) (result i64)
+    ///     i64.const 274877907007) ;; 64 << 32 | 63
+    ///   (data (i32.const 0) "[\"noFoo\"]")
+    ///   (data (i32.const 64)
+    ///     "[{\"rule\":\"noFoo\",\"start\":0,\"end\":3,\"message\":\"Do not use foo\"}]"))
+    /// ```
+    const PLUGIN: &[u8] =
+        b"\x00asm\x01\x00\x00\x00\x01\x10\x03`\x01\x7f\x01\x7f`\x00\x01~`\x02\x7f\x7f\x01~\
+        \x03\x04\x03\x00\x01\x02\x05\x03\x01\x00\x01\x070\x04\x06memory\x02\x00\x0arome_\
+        alloc\x00\x00\x0arome_rules\x00\x01\x09rome_lint\x00\x02\x0a\x16\x03\x05\x00A\
+        \x80\x08\x0b\x04\x00B\x09\x0b\x09\x00B\xbf\x80\x80\x80\x80\x08\x0b\x0bT\x02\x00A\
+        \x00\x0b\x09[\"noFoo\"]\x00A\xc0\x00\x0b?[{\"rule\":\"noFoo\",\"start\":0,\"end\
+        \":3,\"message\":\"Do not use foo\"}]";
+
+    #[test]
+    fn runs_plugin() {
+        let plugin = WasmPlugin::new("example", PLUGIN, |_| Some(Severity::Warning)).unwrap();
+
+        assert_eq!(plugin.name(), "example");
+        assert_eq!(plugin.rules().len(), 1);
+        assert_eq!(plugin.rules()[0].name, "noFoo");
+        assert_eq!(plugin.rules()[0].severity, Some(Severity::Warning));
+
+        assert_eq!(
+            plugin.evaluate("{}").unwrap(),
+            r#"[{"rule":"noFoo","start":0,"end":3,"message":"Do not use foo"}]"#
+        );
+    }
+
+    /// A plugin growing its memory past the limit when it runs:
+    ///
+    /// ```wat
+    /// (module
+    ///   (memory (export "memory") 1)
+    ///   (func (export "rome_alloc") (param i32) (result i32) i32.const 1024)
+    ///   (func (export "rome_rules") (result i64) i64.const 9)
+    ///   (func (export "rome_lint") (param i32 i32) (result i64)
+    ///     (drop (memory.grow (i32.const 2048))) ;; 128 MiB
+    ///     i64.const 0)
+    ///   (data (i32.const 0) "[\"noFoo\"]"))
+    /// ```
+    const GROWING_PLUGIN: &[u8] =
+        b"\x00asm\x01\x00\x00\x00\x01\x10\x03`\x01\x7f\x01\x7f`\x00\x01~`\x02\x7f\x7f\x01~\
+        \x03\x04\x03\x00\x01\x02\x05\x03\x01\x00\x01\x070\x04\x06memory\x02\x00\x0arome_\
+        alloc\x00\x00\x0arome_rules\x00\x01\x09rome_lint\x00\x02\x0a\x17\x03\x05\x00A\
+        \x80\x08\x0b\x04\x00B\x09\x0b\x0a\x00A\x80\x10@\x00\x1aB\x00\x0b\x0b\x0f\x01\x00A\
+        \x00\x0b\x09[\"noFoo\"]";
+
+    #[test]
+    fn limits_plugin_memory() {
+        let plugin =
+            WasmPlugin::new("example", GROWING_PLUGIN, |_| Some(Severity::Warning)).unwrap();
+        assert!(plugin.evaluate("{}").is_err());
+
+        // The failure is reported as a diagnostic of the plugin
+        let mut options = AnalyzerOptions::default();
+        options.configuration.plugins = vec![Arc::new(plugin)];
+
+        let parsed = parse("foo;", FileId::zero(), SourceType::js_module());
+        let mut diagnostics = Vec::new();
+        analyze(
+            FileId::zero(),
+            &parsed.tree(),
+            AnalysisFilter::default(),
+            &options,
+            |signal| {
+                if let Some(diagnostic) = signal.diagnostic() {
+                    if diagnostic.category() == Some(category!("lint/plugin")) {
+                        diagnostics.push(diagnostic.severity());
+                    }
+                }
+                ControlFlow::<Never>::Continue(())
+            },
+        );
+
+        assert_eq!(diagnostics, [Severity::Error]);
+    }
+
+    #[test]
+    fn rejects_invalid_modules() {
+        assert!(WasmPlugin::new("example", b"\0asm", |_| None).is_err());
+        assert!(WasmPlugin::new("example", &PLUGIN[..PLUGIN.len() - 1], |_| None).is_err());
+    }
+
+    #[test]
+    fn loads_plugins_relative_to_configuration() {
+        let mut fs = MemoryFileSystem::default();
+        fs.insert(PathBuf::from("packages/a/plugins/example.wasm"), PLUGIN);
+
+        let loaded = parse_config(
+            &fs,
+            PathBuf::from("packages/a/rome.json"),
+            r#"{ "linter": { "plugins": { "example": { "path": "./plugins/example.wasm" } } } }"#,
+        )
+        .unwrap();
+
+        let plugins = loaded.configuration.linter.unwrap().plugins.unwrap();
+        assert_eq!(plugins["example"].path, "packages/a/plugins/example.wasm");
+
+        let plugins = load_plugins(&fs, plugins).unwrap();
+        assert_eq!(plugins[0].name(), "example");
+        assert_eq!(plugins[0].rules()[0].severity, Some(Severity::Error));
+    }
+}
@@ -1,16 +1,63 @@
 use crate::{
-    configuration::FilesConfiguration, Configuration, MatchOptions, Matcher, RomeError, Rules,
+    configuration::{linter::load_plugins, normalize_path, FilesConfiguration},
+    Configuration, MatchOptions, Matcher, RomeError, Rules,
 };
 use indexmap::IndexSet;
+use rome_analyze::{AnalyzerPlugin, CustomRule};
 use rome_diagnostics::v2::Category;
 use rome_formatter::{IndentStyle, LineWidth};
-use rome_fs::RomePath;
+use rome_fs::{FileSystem, RomePath};
+use rome_js_formatter::context::JsFormatOptions;
 use rome_js_syntax::JsLanguage;
 use std::{
+    borrow::Cow,
+    collections::BTreeMap,
     num::NonZeroU64,
-    sync::{RwLock, RwLockReadGuard},
+    path::{Path, PathBuf},
+    sync::{Arc, RwLock, RwLockReadGuard},
 };
 
+/// Settings of the projects of the workspace: the root project, and the nested projects
+/// that have their own configuration file
+#[derive(Debug, Default)]
+pub struct ProjectsSettings {
+    /// Settings of the root of the workspace
+    root: WorkspaceSettings,
+    /// Settings of the nested projects, keyed by their directory
+    nested: BTreeMap<PathBuf, WorkspaceSettings>,
+}
+
+impl ProjectsSettings {
+    /// Retrieves the settings of the project containing `path`
+    pub fn get(&self, path: &Path) -> &WorkspaceSettings {
+        if self.nested.is_empty() {
+            return &self.root;
+        }
+
+        normalize_path(path)
+            .ancestors()
+            .find_map(|directory| self.nested.get(directory))
+            .unwrap_or(&self.root)
+    }
+
+    /// Retrieves the settings of the project of `directory`, or the settings of the
+    /// root of the workspace if it's [None]
+    pub fn get_mut(&mut self, directory: Option<&Path>) -> &mut WorkspaceSettings {
+        match directory {
+            None => &mut self.root,
+            Some(directory) => {
+                let directory = normalize_path(directory);
+                self.nested
+                    .entry(directory.clone())
+                    .or_insert_with(|| WorkspaceSettings {
+                        directory: Some(directory),
+                        ..WorkspaceSettings::default()
+                    })
+            }
+        }
+    }
+}
+
 /// Global settings for the entire workspace
 #[derive(Debug, Default)]
 pub struct WorkspaceSettings {
@@ -22,6 +69,11 @@ pub struct WorkspaceSettings {
     pub languages: LanguagesSettings,
     /// Filesystem settings for the workspace
     pub files: FilesSettings,
+    /// Settings applied to specific files of the workspace
+    pub override_settings: OverrideSettings,
+    /// The directory of a nested project, the patterns of its configuration are
+    /// relative to it. It's [None] for the root of the workspace
+    pub directory: Option<PathBuf>,
 }
 
 impl WorkspaceSettings {
@@ -35,11 +87,25 @@ impl WorkspaceSettings {
         &self.linter
     }
 
-    /// The (configuration)[Configuration] is merged into the workspace
-    #[tracing::instrument(level = "debug", skip(self))]
+    /// Returns `path` relative to the directory of the project, the path that is
+    /// matched against the patterns of the configuration
+    pub fn relative_path<'a>(&self, path: &'a Path) -> Cow<'a, Path> {
+        match &self.directory {
+            None => Cow::Borrowed(path),
+            Some(directory) => match normalize_path(path).strip_prefix(directory) {
+                Ok(relative) => Cow::Owned(relative.to_path_buf()),
+                Err(_) => Cow::Borrowed(path),
+            },
+        }
+    }
+
+    /// The (configuration)[Configuration] is merged into the workspace, its
+    /// plugins are read from `fs`
+    #[tracing::instrument(level = "debug", skip(self, fs))]
     pub fn merge_with_configuration(
         &mut self,
         configuration: Configuration,
+        fs: &dyn FileSystem,
     ) -> Result<(), RomeError> {
         // formatter part
         if let Some(formatter) = configuration.formatter {
@@ -53,11 +119,14 @@ impl WorkspaceSettings {
             self.languages.javascript.format.quote_style = Some(formatter.quote_style);
             self.languages.javascript.format.quote_properties = Some(formatter.quote_properties);
             self.languages.javascript.format.trailing_comma = Some(formatter.trailing_comma);
+            self.languages.javascript.format.object_wrap = Some(formatter.object_wrap);
         }
 
         // linter part
-        if let Some(linter) = configuration.linter {
+        if let Some(mut linter) = configuration.linter {
+            let plugins = linter.plugins.take();
             self.linter = LinterSettings::try_from(linter)?;
+            self.linter.plugins = load_plugins(fs, plugins.unwrap_or_default())?;
         }
 
         let globals = configuration.javascript.and_then(|j| j.globals);
@@ -68,6 +137,28 @@ impl WorkspaceSettings {
             self.files = FilesSettings::try_from(files)?;
         }
 
+        // overrides part, the overrides of a previous configuration are dropped
+        // when it's reloaded without them
+        self.override_settings = match configuration.overrides {
+            Some(overrides) => OverrideSettings::try_from(overrides)?,
+            None => OverrideSettings::default(),
+        };
+
+        // The patterns inherited from the configuration of a parent directory go up
+        // through the directory of the project
+        if let Some(directory) = &self.directory {
+            self.formatter.ignored_files.set_directory(directory);
+            self.linter.ignored_files.set_directory(directory);
+            if let Some(dead_code) = &mut self.linter.dead_code {
+                dead_code.entry_points.set_directory(directory);
+                dead_code.public_api.set_directory(directory);
+            }
+            for pattern in &mut self.override_settings.patterns {
+                pattern.include.set_directory(directory);
+                pattern.exclude.set_directory(directory);
+            }
+        }
+
         Ok(())
     }
 
@@ -125,18 +216,43 @@ pub struct LinterSettings {
     /// Enabled by default
     pub enabled: bool,
 
+    /// Whether the `eslint-disable` comments suppress the equivalent Rome rules
+    pub eslint_suppression_compat: bool,
+
     /// List of rules
     pub rules: Option<Rules>,
 
+    /// Lint rules written as code patterns
+    pub custom_rules: Vec<Arc<CustomRule>>,
+
+    /// Plugins providing lint rules
+    pub plugins: Vec<Arc<dyn AnalyzerPlugin>>,
+
+    /// The settings of the detection of the dead code of the project, if it's enabled
+    pub dead_code: Option<DeadCodeSettings>,
+
     /// List of paths/files to matcher
     pub ignored_files: Matcher,
 }
 
+/// Settings of the detection of the dead code of the project
+#[derive(Debug)]
+pub struct DeadCodeSettings {
+    /// The files the other files of the project must be reachable from
+    pub entry_points: Matcher,
+    /// The files whose exports are used outside of the project
+    pub public_api: Matcher,
+}
+
 impl Default for LinterSettings {
     fn default() -> Self {
         Self {
             enabled: true,
+            eslint_suppression_compat: false,
             rules: Some(Rules::default()),
+            custom_rules: Vec::new(),
+            plugins: Vec::new(),
+            dead_code: None,
             ignored_files: Matcher::new(MatchOptions {
                 case_sensitive: true,
                 require_literal_leading_dot: false,
@@ -165,9 +281,11 @@ pub trait Language: rome_rowan::Language {
     fn lookup_settings(languages: &LanguagesSettings) -> &LanguageSettings<Self>;
 
     /// Resolve the formatter options from the global (workspace level),
-    /// per-language and editor provided formatter settings
+    /// per-language and editor provided formatter settings, then apply the
+    /// overrides matching `path`
     fn resolve_format_options(
         global: &FormatSettings,
+        overrides: &OverrideSettings,
         language: &Self::FormatSettings,
         path: &RomePath,
     ) -> Self::FormatOptions;
@@ -190,6 +308,8 @@ pub struct LanguageSettings<L: Language> {
 pub struct FilesSettings {
     /// File size limit in bytes
     pub max_size: NonZeroU64,
+    /// Whether the code blocks of the Markdown files are processed
+    pub markdown: bool,
 }
 
 /// Limit the size of files to 1.0 MiB by default
@@ -201,6 +321,7 @@ impl Default for FilesSettings {
     fn default() -> Self {
         Self {
             max_size: DEFAULT_FILE_SIZE_LIMIT,
+            markdown: false,
         }
     }
 }
@@ -211,41 +332,201 @@ impl TryFrom<FilesConfiguration> for FilesSettings {
     fn try_from(config: FilesConfiguration) -> Result<Self, Self::Error> {
         Ok(Self {
             max_size: config.max_size.unwrap_or(DEFAULT_FILE_SIZE_LIMIT),
+            markdown: config.markdown.unwrap_or(false),
         })
     }
 }
 
+/// Settings of the `overrides` of the configuration, they are applied in order
+/// to the files matching their patterns
+#[derive(Debug, Default)]
+pub struct OverrideSettings {
+    pub patterns: Vec<OverrideSettingPattern>,
+}
+
+impl OverrideSettings {
+    /// Returns the overrides that apply to `path`, in the order they are declared
+    fn matching<'a: 'p, 'p>(
+        &'a self,
+        path: &'p Path,
+    ) -> impl Iterator<Item = &'a OverrideSettingPattern> + 'p {
+        self.patterns
+            .iter()
+            .filter(move |pattern| pattern.is_match(path))
+    }
+
+    /// Returns whether the formatter is enabled for `path`
+    pub fn formatter_enabled(&self, path: &Path, enabled: bool) -> bool {
+        self.matching(path).fold(enabled, |enabled, pattern| {
+            pattern.formatter.enabled.unwrap_or(enabled)
+        })
+    }
+
+    /// Returns whether `path` can be formatted when it contains syntax errors
+    pub fn format_with_errors(&self, path: &Path, format_with_errors: bool) -> bool {
+        self.matching(path)
+            .fold(format_with_errors, |format_with_errors, pattern| {
+                pattern
+                    .formatter
+                    .format_with_errors
+                    .unwrap_or(format_with_errors)
+            })
+    }
+
+    /// Returns whether the linter is enabled for `path`
+    pub fn linter_enabled(&self, path: &Path, enabled: bool) -> bool {
+        self.matching(path).fold(enabled, |enabled, pattern| {
+            pattern.linter.enabled.unwrap_or(enabled)
+        })
+    }
+
+    /// Merges the rules of the overrides matching `path` into `rules`.
+    ///
+    /// The rules are only cloned when at least one override changes them.
+    pub fn override_linter_rules<'a>(
+        &self,
+        path: &Path,
+        rules: Option<&'a Rules>,
+    ) -> Option<Cow<'a, Rules>> {
+        let mut rules = rules.map(Cow::Borrowed);
+
+        for pattern in self.matching(path) {
+            if let Some(pattern_rules) = &pattern.linter.rules {
+                match rules.as_mut() {
+                    Some(rules) => rules.to_mut().merge_with(pattern_rules.clone()),
+                    None => rules = Some(Cow::Owned(pattern_rules.clone())),
+                }
+            }
+        }
+
+        rules
+    }
+
+    /// Returns the JavaScript globals of `path`, the globals of the last matching
+    /// override replace the global ones
+    pub fn override_js_globals<'a>(
+        &'a self,
+        path: &Path,
+        globals: Option<&'a IndexSet<String>>,
+    ) -> Option<&'a IndexSet<String>> {
+        self.matching(path).fold(globals, |globals, pattern| {
+            pattern.languages.javascript.globals.as_ref().or(globals)
+        })
+    }
+
+    /// Applies the formatter settings of the overrides matching `path` to `options`
+    pub fn override_js_format_options(
+        &self,
+        path: &Path,
+        mut options: JsFormatOptions,
+    ) -> JsFormatOptions {
+        for pattern in self.matching(path) {
+            let formatter = &pattern.formatter;
+            if let Some(indent_style) = formatter.indent_style {
+                options = options.with_indent_style(indent_style);
+            }
+            if let Some(line_width) = formatter.line_width {
+                options = options.with_line_width(line_width);
+            }
+
+            let language = &pattern.languages.javascript.format;
+            if let Some(quote_style) = language.quote_style {
+                options = options.with_quote_style(quote_style);
+            }
+            if let Some(quote_properties) = language.quote_properties {
+                options = options.with_quote_properties(quote_properties);
+            }
+            if let Some(trailing_comma) = language.trailing_comma {
+                options = options.with_trailing_comma(trailing_comma);
+            }
+            if let Some(object_wrap) = language.object_wrap {
+                options = options.with_object_wrap(object_wrap);
+            }
+        }
+
+        options
+    }
+}
+
+/// The settings of a single entry of the `overrides` of the configuration
+#[derive(Debug)]
+pub struct OverrideSettingPattern {
+    /// The override applies to the files matching these patterns, or to all the
+    /// files when there are none
+    pub include: Matcher,
+    /// The override doesn't apply to the files matching these patterns
+    pub exclude: Matcher,
+    /// Formatter settings applied to the matching files
+    pub formatter: OverrideFormatSettings,
+    /// Linter settings applied to the matching files
+    pub linter: OverrideLinterSettings,
+    /// Language specific settings applied to the matching files
+    pub languages: LanguagesSettings,
+}
+
+impl OverrideSettingPattern {
+    /// Returns whether this override applies to `path`
+    pub fn is_match(&self, path: &Path) -> bool {
+        (self.include.is_empty() || self.include.matches_path(path))
+            && !self.exclude.matches_path(path)
+    }
+}
+
+/// Formatter settings of an override, [None] keeps the value of the top-level settings
+#[derive(Debug, Default)]
+pub struct OverrideFormatSettings {
+    pub enabled: Option<bool>,
+    pub format_with_errors: Option<bool>,
+    pub indent_style: Option<IndentStyle>,
+    pub line_width: Option<LineWidth>,
+}
+
+/// Linter settings of an override, [None] keeps the value of the top-level settings
+#[derive(Debug, Default)]
+pub struct OverrideLinterSettings {
+    pub enabled: Option<bool>,
+    /// Rules merged rule by rule into the top-level ones
+    pub rules: Option<Rules>,
+}
+
 /// Handle object holding a temporary lock on the workspace settings until
 /// the deferred language-specific options resolution is called
 #[derive(Debug)]
 pub struct SettingsHandle<'a> {
-    inner: RwLockReadGuard<'a, WorkspaceSettings>,
+    inner: RwLockReadGuard<'a, ProjectsSettings>,
+    /// The path of the file the settings are resolved for
+    path: PathBuf,
 }
 
 impl<'a> SettingsHandle<'a> {
-    pub(crate) fn new(settings: &'a RwLock<WorkspaceSettings>) -> Self {
+    pub(crate) fn new(settings: &'a RwLock<ProjectsSettings>, path: &Path) -> Self {
         Self {
             inner: settings.read().unwrap(),
+            path: path.to_path_buf(),
         }
     }
 }
 
 impl<'a> AsRef<WorkspaceSettings> for SettingsHandle<'a> {
     fn as_ref(&self) -> &WorkspaceSettings {
-        &self.inner
+        self.inner.get(&self.path)
     }
 }
 
 impl<'a> SettingsHandle<'a> {
     /// Resolve the formatting context for the given language
-    pub(crate) fn format_options<L>(self, path: &RomePath) -> L::FormatOptions
+    pub(crate) fn format_options<L>(&self, path: &RomePath) -> L::FormatOptions
     where
         L: Language,
     {
+        let settings = self.as_ref();
+        // The overrides of a nested project match the paths relative to its directory
+        let path = RomePath::new(settings.relative_path(path.as_path()), path.file_id());
         L::resolve_format_options(
-            &self.inner.formatter,
-            &L::lookup_settings(&self.inner.languages).format,
-            path,
+            &settings.formatter,
+            &settings.override_settings,
+            &L::lookup_settings(&settings.languages).format,
+            &path,
         )
     }
 }
@@ -52,15 +52,15 @@
 //! format a file with a language that does not have a formatter
 
 use crate::{Configuration, Deserialize, RomeError, Serialize};
-use rome_analyze::ActionCategory;
 pub use rome_analyze::RuleCategories;
+use rome_analyze::{ActionCategory, Profile};
 use rome_console::{markup, Markup, MarkupBuf};
 use rome_diagnostics::{v2, CodeSuggestion};
 use rome_formatter::Printed;
-use rome_fs::RomePath;
+use rome_fs::{FileSystem, RomePath};
 use rome_js_syntax::{TextRange, TextSize};
 use rome_text_edit::TextEdit;
-use std::{borrow::Cow, panic::RefUnwindSafe, sync::Arc};
+use std::{borrow::Cow, panic::RefUnwindSafe, path::PathBuf, sync::Arc};
 
 pub use self::client::{TransportRequest, WorkspaceClient, WorkspaceTransport};
 pub use crate::file_handlers::Language;
@@ -120,6 +120,11 @@ pub enum FeatureName {
 #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct UpdateSettingsParams {
     pub configuration: Configuration,
+    /// The directory of the configuration file of a nested project, the settings
+    /// apply to the files below it. The settings of the root of the workspace are
+    /// updated when it's [None]
+    #[serde(default)]
+    pub directory: Option<PathBuf>,
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -188,6 +193,29 @@ pub struct PullDiagnosticsResult {
     pub skipped_diagnostics: u64,
 }
 
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct PullProjectDiagnosticsParams {
+    /// The files of the project, that were all checked
+    pub paths: Vec<RomePath>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct PullProjectDiagnosticsResult {
+    /// The files of the project having diagnostics
+    pub files: Vec<ProjectFileDiagnostics>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ProjectFileDiagnostics {
+    pub path: RomePath,
+    /// The content of the file the diagnostics point to
+    pub content: String,
+    pub diagnostics: Vec<v2::serde::Diagnostic>,
+}
+
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct PullActionsParams {
@@ -206,6 +234,9 @@ pub struct PullActionsResult {
 pub struct CodeAction {
     pub category: ActionCategory,
     pub rule_name: Cow<'static, str>,
+    /// The range of the diagnostic emitted along with this action, the
+    /// actions of a rule sharing the same range are alternative fixes
+    pub diagnostic_range: Option<TextRange>,
     pub suggestion: CodeSuggestion,
 }
 
@@ -282,6 +313,17 @@ pub struct RenameResult {
     pub range: TextRange,
     /// List of text edit operations to apply on the source code
     pub indels: TextEdit,
+    /// The edits of the other files of the project, when the renamed binding
+    /// is exported and imported by these files
+    pub files: Vec<FileTextEdit>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct FileTextEdit {
+    pub path: RomePath,
+    /// List of text edit operations to apply on the content of the file
+    pub indels: TextEdit,
 }
 
 #[derive(Debug, Eq, PartialEq, Clone, Default, Deserialize, Serialize)]
@@ -377,6 +419,13 @@ pub trait Workspace: Send + Sync + RefUnwindSafe {
         params: PullDiagnosticsParams,
     ) -> Result<PullDiagnosticsResult, RomeError>;
 
+    /// Retrieves the diagnostics of the analyses of the whole project, like
+    /// the detection of the dead code, once all its files were checked
+    fn pull_project_diagnostics(
+        &self,
+        params: PullProjectDiagnosticsParams,
+    ) -> Result<PullProjectDiagnosticsResult, RomeError>;
+
     /// Retrieves the list of code actions available for a given cursor
     /// position within a file
     fn pull_actions(&self, params: PullActionsParams) -> Result<PullActionsResult, RomeError>;
@@ -403,16 +452,22 @@ pub trait Workspace: Send + Sync + RefUnwindSafe {
 
     /// Returns information about the server this workspace is connected to or `None` if the workspace isn't connected to a server.
     fn server_info(&self) -> Option<&ServerInfo>;
+
+    /// Returns the profile recording the time spent in the lint rules by this
+    /// workspace, or `None` if the rules don't run in this process.
+    fn rule_profile(&self) -> Option<&Profile>;
 }
 
-/// Convenience function for constructing a server instance of [Workspace]
-pub fn server() -> Box<dyn Workspace> {
-    Box::new(server::WorkspaceServer::new())
+/// Convenience function for constructing a server instance of [Workspace],
+/// the files that aren't open in the workspace are read from `fs`
+pub fn server(fs: Arc<dyn FileSystem>) -> Box<dyn Workspace> {
+    Box::new(server::WorkspaceServer::new(fs))
 }
 
-/// Convenience function for constructing a server instance of [Workspace]
-pub fn server_sync() -> Arc<dyn Workspace> {
-    Arc::new(server::WorkspaceServer::new())
+/// Convenience function for constructing a server instance of [Workspace],
+/// the files that aren't open in the workspace are read from `fs`
+pub fn server_sync(fs: Arc<dyn FileSystem>) -> Arc<dyn Workspace> {
+    Arc::new(server::WorkspaceServer::new(fs))
 }
 
 /// Convenience function for constructing a client instance of [Workspace]
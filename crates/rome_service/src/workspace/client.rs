@@ -3,6 +3,7 @@ use std::{
     sync::atomic::{AtomicU64, Ordering},
 };
 
+use rome_analyze::Profile;
 use rome_formatter::Printed;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use serde_json::json;
@@ -14,8 +15,9 @@ use super::{
     ChangeFileParams, CloseFileParams, FixFileParams, FixFileResult, FormatFileParams,
     FormatOnTypeParams, FormatRangeParams, GetControlFlowGraphParams, GetFormatterIRParams,
     GetSyntaxTreeParams, GetSyntaxTreeResult, OpenFileParams, PullActionsParams, PullActionsResult,
-    PullDiagnosticsParams, PullDiagnosticsResult, RenameParams, RenameResult,
-    SupportsFeatureParams, UpdateSettingsParams,
+    PullDiagnosticsParams, PullDiagnosticsResult, PullProjectDiagnosticsParams,
+    PullProjectDiagnosticsResult, RenameParams, RenameResult, SupportsFeatureParams,
+    UpdateSettingsParams,
 };
 
 pub struct WorkspaceClient<T> {
@@ -147,6 +149,13 @@ where
         self.request("rome/pull_diagnostics", params)
     }
 
+    fn pull_project_diagnostics(
+        &self,
+        params: PullProjectDiagnosticsParams,
+    ) -> Result<PullProjectDiagnosticsResult, RomeError> {
+        self.request("rome/pull_project_diagnostics", params)
+    }
+
     fn pull_actions(&self, params: PullActionsParams) -> Result<PullActionsResult, RomeError> {
         self.request("rome/pull_actions", params)
     }
@@ -178,4 +187,8 @@ where
     fn server_info(&self) -> Option<&ServerInfo> {
         self.server_info.as_ref()
     }
+
+    fn rule_profile(&self) -> Option<&Profile> {
+        None
+    }
 }
@@ -1,36 +1,60 @@
 use super::{
-    ChangeFileParams, CloseFileParams, FeatureName, FixFileResult, FormatFileParams,
+    ChangeFileParams, CloseFileParams, FeatureName, FileTextEdit, FixFileResult, FormatFileParams,
     FormatOnTypeParams, FormatRangeParams, GetControlFlowGraphParams, GetFormatterIRParams,
-    GetSyntaxTreeParams, GetSyntaxTreeResult, OpenFileParams, PullActionsParams, PullActionsResult,
-    PullDiagnosticsParams, PullDiagnosticsResult, RenameResult, SupportsFeatureParams,
-    UpdateSettingsParams,
+    GetSyntaxTreeParams, GetSyntaxTreeResult, OpenFileParams, ProjectFileDiagnostics,
+    PullActionsParams, PullActionsResult, PullDiagnosticsParams, PullDiagnosticsResult,
+    PullProjectDiagnosticsParams, PullProjectDiagnosticsResult, RenameResult,
+    SupportsFeatureParams, UpdateSettingsParams,
 };
+use crate::configuration::normalize_path;
 use crate::file_handlers::{Capabilities, FixAllParams, Language, LintParams};
+use crate::module_graph::dead_code::{
+    collect_exports, dead_code_diagnostics, DeadCodeModule, ModuleExport,
+};
+use crate::module_graph::rename::{rename_importers, renamed_export};
+use crate::module_graph::ProjectModuleGraph;
 use crate::workspace::{RageEntry, RageParams, RageResult, ServerInfo, SupportsFeatureResult};
 use crate::{
     file_handlers::Features,
-    settings::{SettingsHandle, WorkspaceSettings},
+    settings::{ProjectsSettings, SettingsHandle},
     RomeError, Rules, Workspace,
 };
 use dashmap::{mapref::entry::Entry, DashMap};
 use indexmap::IndexSet;
-use rome_analyze::{AnalysisFilter, RuleFilter};
+use rome_analyze::{AnalysisFilter, Profile, RuleFilter};
 use rome_diagnostics::v2::{serde::Diagnostic, DiagnosticExt};
+use rome_diagnostics::FileId;
 use rome_formatter::Printed;
-use rome_fs::RomePath;
+use rome_fs::{FileSystem, RomePath};
 use rome_js_parser::ParseDiagnostic;
+use rome_js_semantic::{semantic_model, SemanticModelOptions};
+use rome_js_syntax::{JsAnyRoot, JsLanguage};
 use rome_rowan::{AstNode, Language as RowanLanguage, SendNode, SyntaxNode};
-use std::{any::type_name, panic::RefUnwindSafe, sync::RwLock};
+use std::{
+    any::type_name,
+    borrow::Cow,
+    panic::RefUnwindSafe,
+    path::Path,
+    sync::{Arc, RwLock},
+};
 
 pub(super) struct WorkspaceServer {
     /// features available throughout the application
     features: Features,
-    /// global settings object for this workspace
-    settings: RwLock<WorkspaceSettings>,
+    /// settings of the root of the workspace and of its nested projects
+    settings: RwLock<ProjectsSettings>,
     /// Stores the document (text content + version number) associated with a URL
     documents: DashMap<RomePath, Document>,
     /// Stores the result of the parser (syntax tree + diagnostics) for a given URL
     syntax: DashMap<RomePath, AnyParse>,
+    /// The imports of the JavaScript modules of the project, updated as the
+    /// files are parsed
+    module_graph: Arc<ProjectModuleGraph>,
+    /// The file system the files that aren't open in the workspace, like the
+    /// plugins of the configuration, are read from
+    fs: Arc<dyn FileSystem>,
+    /// The time spent in the lint rules, recorded when the profiling is enabled
+    rule_profile: Arc<Profile>,
 }
 
 /// The `Workspace` object is long lived, so we want it to be able to cross
@@ -88,7 +112,7 @@ impl AnyParse {
         self.diagnostics.into_iter().map(Diagnostic::new).collect()
     }
 
-    fn has_errors(&self) -> bool {
+    pub(crate) fn has_errors(&self) -> bool {
         self.diagnostics.iter().any(|diag| diag.is_error())
     }
 }
@@ -99,17 +123,34 @@ impl WorkspaceServer {
     /// This is implemented as a crate-private method instead of using
     /// [Default] to disallow instances of [Workspace] from being created
     /// outside of a [crate::App]
-    pub(crate) fn new() -> Self {
+    ///
+    /// The modules of the project that aren't open in the workspace are read
+    /// from `fs` when their imports are needed by the module graph
+    pub(crate) fn new(fs: Arc<dyn FileSystem>) -> Self {
         Self {
             features: Features::new(),
             settings: RwLock::default(),
             documents: DashMap::default(),
             syntax: DashMap::default(),
+            module_graph: Arc::new(ProjectModuleGraph::new(fs.clone())),
+            fs,
+            rule_profile: Arc::default(),
+        }
+    }
+
+    /// Returns the rule profile if the profiling is enabled, so the analyzer
+    /// doesn't measure the rules otherwise
+    fn enabled_rule_profile(&self) -> Option<Arc<Profile>> {
+        if self.rule_profile.is_enabled() {
+            Some(self.rule_profile.clone())
+        } else {
+            None
         }
     }
 
-    fn settings(&self) -> SettingsHandle {
-        SettingsHandle::new(&self.settings)
+    /// Get the settings of the project containing `path`
+    fn settings(&self, path: &RomePath) -> SettingsHandle {
+        SettingsHandle::new(&self.settings, path.as_path())
     }
 
     /// Get the supported capabilities for a given file path
@@ -178,7 +219,7 @@ impl WorkspaceServer {
                     .ok_or_else(self.build_capability_error(rome_path))?;
 
                 let size_limit = {
-                    let settings = self.settings();
+                    let settings = self.settings(rome_path);
                     let settings = settings.as_ref();
                     let limit = settings.files.max_size.get();
                     usize::try_from(limit).unwrap_or(usize::MAX)
@@ -195,28 +236,80 @@ impl WorkspaceServer {
 
                 let parsed = parse(rome_path, document.language_hint, &document.content);
 
+                if let Some(root) = parsed.root.clone().into_node::<JsLanguage>() {
+                    self.module_graph
+                        .update_module(rome_path.as_path(), &JsAnyRoot::unwrap_cast(root));
+                }
+
                 Ok(entry.insert(parsed).clone())
             }
         }
     }
 
+    /// Returns whether the file at `rome_path` can be formatted when it has syntax errors
+    fn format_with_errors(&self, settings: &SettingsHandle, rome_path: &RomePath) -> bool {
+        let settings = settings.as_ref();
+        settings.override_settings.format_with_errors(
+            &settings.relative_path(rome_path.as_path()),
+            settings.formatter().format_with_errors,
+        )
+    }
+
+    /// Returns the rules of the linter that apply to the file at `rome_path`
+    fn linter_rules<'a>(
+        &self,
+        settings: &'a SettingsHandle,
+        rome_path: &RomePath,
+    ) -> Option<Cow<'a, Rules>> {
+        let settings = settings.as_ref();
+        settings.override_settings.override_linter_rules(
+            &settings.relative_path(rome_path.as_path()),
+            settings.linter().rules.as_ref(),
+        )
+    }
+
+    /// Returns the content of the module at the normalized `path`, the one of
+    /// its document when the file is open
+    fn module_source_text(&self, path: &Path) -> Option<String> {
+        let document = self
+            .documents
+            .iter()
+            .find(|document| normalize_path(document.key()) == path)
+            .map(|document| document.content.clone());
+
+        document.or_else(|| self.module_graph.source_text(path))
+    }
+
+    /// Returns the exports of the module at `path`, collected from the parse
+    /// of its document when the file is open
+    fn module_exports(&self, path: &RomePath) -> Arc<[ModuleExport]> {
+        if self.documents.contains_key(path) {
+            let root = self
+                .get_parse(path.clone(), None)
+                .ok()
+                .and_then(|parse| parse.root.into_node::<JsLanguage>());
+
+            if let Some(root) = root {
+                let root = JsAnyRoot::unwrap_cast(root);
+                let model = semantic_model(&root, SemanticModelOptions::default());
+                return collect_exports(&root, &model).into();
+            }
+        }
+
+        self.module_graph.module_exports(path.as_path())
+    }
+
     /// Takes as input the path of the file that workspace is currently processing and
     /// a list of paths to match against.
     ///
     /// If the file path matches, than `true` is returned and it should be considered ignored.
     fn is_file_ignored(&self, rome_path: &RomePath, feature: &FeatureName) -> bool {
-        let settings = self.settings();
+        let settings = self.settings(rome_path);
+        let settings = settings.as_ref();
+        let path = settings.relative_path(rome_path.as_path());
         match feature {
-            FeatureName::Format => settings
-                .as_ref()
-                .formatter
-                .ignored_files
-                .matches_path(rome_path.as_path()),
-            FeatureName::Lint => settings
-                .as_ref()
-                .linter
-                .ignored_files
-                .matches_path(rome_path.as_path()),
+            FeatureName::Format => settings.formatter.ignored_files.matches_path(&path),
+            FeatureName::Lint => settings.linter.ignored_files.matches_path(&path),
         }
     }
 }
@@ -227,15 +320,24 @@ impl Workspace for WorkspaceServer {
         params: SupportsFeatureParams,
     ) -> Result<SupportsFeatureResult, RomeError> {
         let capabilities = self.get_capabilities(&params.path);
-        let settings = self.settings.read().unwrap();
+        let settings = self.settings(&params.path);
+        let settings = settings.as_ref();
+        let path = settings.relative_path(params.path.as_path());
         let is_ignored = self.is_file_ignored(&params.path, &params.feature);
+        // The code blocks of the Markdown files are only processed when enabled
+        let is_disabled_language =
+            Features::get_language(&params.path) == Language::Markdown && !settings.files.markdown;
         let result = match params.feature {
             FeatureName::Format => {
                 if is_ignored {
                     SupportsFeatureResult::ignored()
                 } else if capabilities.formatter.format.is_none() {
                     SupportsFeatureResult::file_not_supported()
-                } else if !settings.formatter().enabled {
+                } else if is_disabled_language
+                    || !settings
+                        .override_settings
+                        .formatter_enabled(&path, settings.formatter().enabled)
+                {
                     SupportsFeatureResult::disabled()
                 } else {
                     SupportsFeatureResult { reason: None }
@@ -246,7 +348,11 @@ impl Workspace for WorkspaceServer {
                     SupportsFeatureResult::ignored()
                 } else if capabilities.analyzer.lint.is_none() {
                     SupportsFeatureResult::file_not_supported()
-                } else if !settings.linter().enabled {
+                } else if is_disabled_language
+                    || !settings
+                        .override_settings
+                        .linter_enabled(&path, settings.linter().enabled)
+                {
                     SupportsFeatureResult::disabled()
                 } else {
                     SupportsFeatureResult { reason: None }
@@ -256,7 +362,8 @@ impl Workspace for WorkspaceServer {
         Ok(result)
     }
 
-    /// Update the global settings for this workspace
+    /// Update the global settings for this workspace, or the settings of the
+    /// nested project in `params.directory`
     ///
     /// ## Panics
     /// This function may panic if the internal settings mutex has been poisoned
@@ -264,7 +371,9 @@ impl Workspace for WorkspaceServer {
     #[tracing::instrument(level = "debug", skip(self))]
     fn update_settings(&self, params: UpdateSettingsParams) -> Result<(), RomeError> {
         let mut settings = self.settings.write().unwrap();
-        settings.merge_with_configuration(params.configuration)?;
+        settings
+            .get_mut(params.directory.as_deref())
+            .merge_with_configuration(params.configuration, &*self.fs)?;
         Ok(())
     }
 
@@ -321,10 +430,10 @@ impl Workspace for WorkspaceServer {
             .debug
             .debug_formatter_ir
             .ok_or_else(self.build_capability_error(&params.path))?;
-        let settings = self.settings();
+        let settings = self.settings(&params.path);
         let parse = self.get_parse(params.path.clone(), Some(FeatureName::Format))?;
 
-        if !settings.as_ref().formatter().format_with_errors && parse.has_errors() {
+        if !self.format_with_errors(&settings, &params.path) && parse.has_errors() {
             return Err(RomeError::FormatWithErrorsDisabled);
         }
 
@@ -343,6 +452,7 @@ impl Workspace for WorkspaceServer {
         document.content = params.content;
 
         self.syntax.remove(&params.path);
+        self.module_graph.remove_module(params.path.as_path());
         Ok(())
     }
 
@@ -353,6 +463,7 @@ impl Workspace for WorkspaceServer {
             .ok_or(RomeError::NotFound)?;
 
         self.syntax.remove(&params.path);
+        self.module_graph.remove_module(params.path.as_path());
         Ok(())
     }
 
@@ -367,14 +478,15 @@ impl Workspace for WorkspaceServer {
             .lint
             .ok_or_else(self.build_capability_error(&params.path))?;
 
-        let settings = self.settings.read().unwrap();
+        let settings = self.settings(&params.path);
         let feature = if params.categories.is_syntax() {
             FeatureName::Format
         } else {
             FeatureName::Lint
         };
         let parse = self.get_parse(params.path.clone(), Some(feature))?;
-        let rules = settings.linter().rules.as_ref();
+        let rules = self.linter_rules(&settings, &params.path);
+        let rules = rules.as_deref();
         let rule_filter_list = self.build_rule_filter_list(rules);
         let mut filter = AnalysisFilter::from_enabled_rules(Some(rule_filter_list.as_slice()));
         filter.categories = params.categories;
@@ -384,8 +496,10 @@ impl Workspace for WorkspaceServer {
             parse,
             filter,
             rules,
-            settings: self.settings(),
+            settings: self.settings(&params.path),
             max_diagnostics: params.max_diagnostics,
+            module_graph: self.module_graph.clone(),
+            profile: self.enabled_rule_profile(),
         });
 
         Ok(PullDiagnosticsResult {
@@ -402,6 +516,73 @@ impl Workspace for WorkspaceServer {
         })
     }
 
+    /// Retrieves the diagnostics of the dead code of the project, once all
+    /// its files were checked
+    fn pull_project_diagnostics(
+        &self,
+        params: PullProjectDiagnosticsParams,
+    ) -> Result<PullProjectDiagnosticsResult, RomeError> {
+        let mut paths = Vec::new();
+        let mut modules = Vec::new();
+        let mut check_reachability = false;
+
+        for path in params.paths {
+            let (is_entry_point, is_public_api) = {
+                let settings = self.settings(&path);
+                let settings = settings.as_ref();
+                let dead_code = match &settings.linter().dead_code {
+                    Some(dead_code) => dead_code,
+                    None => continue,
+                };
+
+                let relative_path = settings.relative_path(path.as_path());
+                check_reachability |= !dead_code.entry_points.is_empty();
+                (
+                    dead_code.entry_points.matches_path(&relative_path),
+                    dead_code.public_api.matches_path(&relative_path),
+                )
+            };
+
+            // The settings are released before parsing the module
+            modules.push(DeadCodeModule {
+                path: path.as_path().to_path_buf(),
+                is_entry_point,
+                is_public_api,
+                exports: self.module_exports(&path),
+            });
+            paths.push(path);
+        }
+
+        let diagnostics = dead_code_diagnostics(&self.module_graph, &modules, check_reachability);
+
+        let files = paths
+            .into_iter()
+            .zip(diagnostics)
+            .filter(|(_, diagnostics)| !diagnostics.is_empty())
+            .map(|(path, diagnostics)| {
+                let file_path = path.as_path().display().to_string();
+                let content = match self.documents.get(&path) {
+                    Some(document) => document.content.clone(),
+                    None => self
+                        .module_graph
+                        .source_text(path.as_path())
+                        .unwrap_or_default(),
+                };
+
+                ProjectFileDiagnostics {
+                    content,
+                    diagnostics: diagnostics
+                        .into_iter()
+                        .map(|diag| Diagnostic::new(diag.with_file_path(file_path.as_str())))
+                        .collect(),
+                    path,
+                }
+            })
+            .collect();
+
+        Ok(PullProjectDiagnosticsResult { files })
+    }
+
     /// Retrieves the list of code actions available for a given cursor
     /// position within a file
     fn pull_actions(&self, params: PullActionsParams) -> Result<PullActionsResult, RomeError> {
@@ -412,14 +593,15 @@ impl Workspace for WorkspaceServer {
             .ok_or_else(self.build_capability_error(&params.path))?;
 
         let parse = self.get_parse(params.path.clone(), Some(FeatureName::Lint))?;
-        let settings = self.settings.read().unwrap();
-        let rules = settings.linter().rules.as_ref();
+        let settings = self.settings(&params.path);
+        let rules = self.linter_rules(&settings, &params.path);
         Ok(code_actions(
             &params.path,
             parse,
             params.range,
-            rules,
-            self.settings(),
+            rules.as_deref(),
+            self.settings(&params.path),
+            self.module_graph.clone(),
         ))
     }
 
@@ -431,10 +613,10 @@ impl Workspace for WorkspaceServer {
             .formatter
             .format
             .ok_or_else(self.build_capability_error(&params.path))?;
-        let settings = self.settings();
+        let settings = self.settings(&params.path);
         let parse = self.get_parse(params.path.clone(), Some(FeatureName::Format))?;
 
-        if !settings.as_ref().formatter().format_with_errors && parse.has_errors() {
+        if !self.format_with_errors(&settings, &params.path) && parse.has_errors() {
             return Err(RomeError::FormatWithErrorsDisabled);
         }
 
@@ -447,10 +629,10 @@ impl Workspace for WorkspaceServer {
             .formatter
             .format_range
             .ok_or_else(self.build_capability_error(&params.path))?;
-        let settings = self.settings();
+        let settings = self.settings(&params.path);
         let parse = self.get_parse(params.path.clone(), Some(FeatureName::Format))?;
 
-        if !settings.as_ref().formatter().format_with_errors && parse.has_errors() {
+        if !self.format_with_errors(&settings, &params.path) && parse.has_errors() {
             return Err(RomeError::FormatWithErrorsDisabled);
         }
 
@@ -464,9 +646,9 @@ impl Workspace for WorkspaceServer {
             .format_on_type
             .ok_or_else(self.build_capability_error(&params.path))?;
 
-        let settings = self.settings();
+        let settings = self.settings(&params.path);
         let parse = self.get_parse(params.path.clone(), Some(FeatureName::Format))?;
-        if !settings.as_ref().formatter().format_with_errors && parse.has_errors() {
+        if !self.format_with_errors(&settings, &params.path) && parse.has_errors() {
             return Err(RomeError::FormatWithErrorsDisabled);
         }
 
@@ -479,16 +661,18 @@ impl Workspace for WorkspaceServer {
             .analyzer
             .fix_all
             .ok_or_else(self.build_capability_error(&params.path))?;
-        let settings = self.settings.read().unwrap();
+        let settings = self.settings(&params.path);
         let parse = self.get_parse(params.path.clone(), Some(FeatureName::Lint))?;
 
-        let rules = settings.linter().rules.as_ref();
+        let rules = self.linter_rules(&settings, &params.path);
         fix_all(FixAllParams {
             rome_path: &params.path,
             parse,
-            rules,
+            rules: rules.as_deref(),
             fix_file_mode: params.fix_file_mode,
-            settings: self.settings(),
+            settings: self.settings(&params.path),
+            module_graph: self.module_graph.clone(),
+            profile: self.enabled_rule_profile(),
         })
     }
 
@@ -500,7 +684,43 @@ impl Workspace for WorkspaceServer {
             .ok_or_else(self.build_capability_error(&params.path))?;
 
         let parse = self.get_parse(params.path.clone(), None)?;
-        let result = rename(&params.path, parse, params.symbol_at, params.new_name)?;
+        let mut result = rename(&params.path, parse, params.symbol_at, params.new_name)?;
+
+        // When the renamed binding is exported, the files importing it are
+        // updated to import the new name
+        let content = self.module_source_text(&normalize_path(&params.path));
+        if let Some(content) = content {
+            let new_content = result.indels.new_string(&content);
+            if let Some((old_name, new_name)) =
+                renamed_export(params.path.as_path(), &content, &new_content)
+            {
+                // The modules importing the binding are looked up in the
+                // nested project of the module, or the package containing it
+                let root = self
+                    .settings(&params.path)
+                    .as_ref()
+                    .directory
+                    .clone()
+                    .unwrap_or_else(|| self.module_graph.project_root(params.path.as_path()));
+
+                let edits = rename_importers(
+                    &self.module_graph,
+                    &root,
+                    params.path.as_path(),
+                    &old_name,
+                    &new_name,
+                    |path| self.module_source_text(path),
+                );
+
+                result.files = edits
+                    .into_iter()
+                    .map(|edit| FileTextEdit {
+                        path: RomePath::new(edit.path, FileId::zero()),
+                        indels: edit.indels,
+                    })
+                    .collect();
+            }
+        }
 
         Ok(result)
     }
@@ -517,4 +737,8 @@ impl Workspace for WorkspaceServer {
     fn server_info(&self) -> Option<&ServerInfo> {
         None
     }
+
+    fn rule_profile(&self) -> Option<&Profile> {
+        Some(&self.rule_profile)
+    }
 }
@@ -442,7 +442,7 @@ macro_rules! workspace_method {
 }
 
 /// Returns a list of signature for all the methods in the [Workspace] trait
-pub fn methods() -> [WorkspaceMethod; 15] {
+pub fn methods() -> [WorkspaceMethod; 16] {
     [
         WorkspaceMethod::of::<SupportsFeatureParams, SupportsFeatureResult>("supports_feature"),
         workspace_method!(update_settings),
@@ -453,6 +453,7 @@ pub fn methods() -> [WorkspaceMethod; 15] {
         workspace_method!(get_control_flow_graph),
         workspace_method!(get_formatter_ir),
         workspace_method!(pull_diagnostics),
+        workspace_method!(pull_project_diagnostics),
         workspace_method!(pull_actions),
         workspace_method!(format_file),
         workspace_method!(format_range),
@@ -1,10 +1,13 @@
 use js_sys::Error;
+use std::sync::Arc;
 use wasm_bindgen::prelude::*;
 
+use rome_fs::MemoryFileSystem;
 use rome_service::workspace::{
     self, ChangeFileParams, CloseFileParams, FixFileParams, FormatFileParams, FormatOnTypeParams,
     FormatRangeParams, GetControlFlowGraphParams, GetFormatterIRParams, GetSyntaxTreeParams,
-    PullActionsParams, PullDiagnosticsParams, RenameParams, UpdateSettingsParams,
+    PullActionsParams, PullDiagnosticsParams, PullProjectDiagnosticsParams, RenameParams,
+    UpdateSettingsParams,
 };
 use rome_service::workspace::{OpenFileParams, SupportsFeatureParams};
 
@@ -30,8 +33,10 @@ impl Workspace {
     #[wasm_bindgen(constructor)]
     #[allow(clippy::new_without_default)]
     pub fn new() -> Workspace {
+        // The playground has no file system, the files are only read from
+        // the documents opened in the workspace
         Workspace {
-            inner: workspace::server(),
+            inner: workspace::server(Arc::new(MemoryFileSystem::default())),
         }
     }
 
@@ -112,6 +117,21 @@ impl Workspace {
             .map_err(into_error)
     }
 
+    #[wasm_bindgen(js_name = pullProjectDiagnostics)]
+    pub fn pull_project_diagnostics(
+        &self,
+        params: IPullProjectDiagnosticsParams,
+    ) -> Result<IPullProjectDiagnosticsResult, Error> {
+        let params: PullProjectDiagnosticsParams = params.into_serde().map_err(into_error)?;
+        let result = self
+            .inner
+            .pull_project_diagnostics(params)
+            .map_err(into_error)?;
+        JsValue::from_serde(&result)
+            .map(IPullProjectDiagnosticsResult::from)
+            .map_err(into_error)
+    }
+
     #[wasm_bindgen(js_name = pullActions)]
     pub fn pull_actions(&self, params: IPullActionsParams) -> Result<IPullActionsResult, Error> {
         let params: PullActionsParams = params.into_serde().map_err(into_error)?;
@@ -22,11 +22,19 @@ export type UnsupportedReason =
 	| "FileNotSupported";
 export interface UpdateSettingsParams {
 	configuration: Configuration;
+	/**
+	 * The directory of the configuration file of a nested project, the settings apply to the files below it. The settings of the root of the workspace are updated when it's [None]
+	 */
+	directory?: string;
 }
 /**
  * The configuration that is contained inside the file `rome.json`
  */
 export interface Configuration {
+	/**
+	 * A list of paths to other configuration files, relative to this file. The configuration of this file is merged on top of the extended files, in order.
+	 */
+	extends?: string[];
 	/**
 	 * The configuration of the filesystem
 	 */
@@ -43,17 +51,37 @@ export interface Configuration {
 	 * The configuration for the linter
 	 */
 	linter?: LinterConfiguration;
+	/**
+	 * A list of configurations applied to specific files. When several overrides match a file, they are applied in order.
+	 */
+	overrides?: OverridePattern[];
+	/**
+	 * When `true`, the configuration files of the parent directories are ignored. A configuration file that isn't the root is merged on top of the configuration files of the parent directories.
+	 */
+	root?: boolean;
 }
 /**
  * The configuration of the filesystem
  */
 export interface FilesConfiguration {
+	/**
+	 * Whether the JavaScript and TypeScript code blocks of the Markdown files are formatted and linted. Defaults to `false`
+	 */
+	markdown?: boolean;
 	/**
 	 * The maximum allowed size for source code files in bytes. Files above this limit will be ignored for performance reason. Defaults to 1 MiB
 	 */
 	maxSize?: number;
 }
 export interface FormatterConfiguration {
+	/**
+	 * Stores whether the CLI should remember which files are already formatted, and skip them in subsequent runs of `rome format` and `rome ci`
+	 */
+	cache?: boolean;
+	/**
+	 * The directory where the formatter cache is stored. Defaults to `node_modules/.cache/rome`
+	 */
+	cacheDirectory?: string;
 	enabled?: boolean;
 	/**
 	 * Stores whether formatting should be allowed to proceed if a given file has syntax errors
@@ -86,19 +114,70 @@ If defined here, they should not emit diagnostics.
 	globals?: string[];
 }
 export interface LinterConfiguration {
+	/**
+	 * Lint rules written as code patterns, by name of the rule
+	 */
+	customRules?: {};
+	/**
+	 * If set, the exports that aren't imported by any file and the files that can't be reached from the entry points are reported once all the files are checked
+	 */
+	deadCode?: DeadCodeConfiguration;
 	/**
 	 * if `false`, it disables the feature and the linter won't be executed. `true` by default
 	 */
 	enabled?: boolean;
+	/**
+	 * If `true`, the `eslint-disable` comments suppress the equivalent Rome rules. `false` by default
+	 */
+	eslintSuppressionCompat?: boolean;
 	/**
 	 * A list of Unix shell style patterns. The formatter will ignore files/folders that will match these patterns.
 	 */
 	ignore?: string[];
+	/**
+	 * WebAssembly modules providing lint rules, by name of the plugin. The name of the plugin is the group of its rules
+	 */
+	plugins?: {};
 	/**
 	 * List of rules
 	 */
 	rules?: Rules;
 }
+export interface DeadCodeConfiguration {
+	/**
+	 * A list of Unix shell style patterns matching the entry points of the project. The files that aren't imported by an entry point, directly or not, are reported
+	 */
+	entryPoints?: string[];
+	/**
+	 * A list of Unix shell style patterns matching the files whose exports are the public API of the project, they are never reported
+	 */
+	publicApi?: string[];
+}
+/**
+ * A configuration applied to the files that match the `include` patterns and don't match the `ignore` patterns
+ */
+export interface OverridePattern {
+	/**
+	 * Specific configuration for the formatter
+	 */
+	formatter?: OverrideFormatterConfiguration;
+	/**
+	 * A list of Unix shell style patterns. The override isn't applied to the files that match these patterns.
+	 */
+	ignore?: string[];
+	/**
+	 * A list of Unix shell style patterns. The override is applied to the files that match at least one of these patterns.
+	 */
+	include?: string[];
+	/**
+	 * Specific configuration for the JavaScript language. The options of the `formatter` of this section are merged over the ones of the top-level configuration, its `globals` replace the top-level ones.
+	 */
+	javascript?: OverrideJavascriptConfiguration;
+	/**
+	 * Specific configuration for the linter
+	 */
+	linter?: OverrideLinterConfiguration;
+}
 export type PlainIndentStyle = "tab" | "space";
 /**
 	* Validated value for the `line_width` formatter options
@@ -107,6 +186,10 @@ The allowed range of values is 1..=320
 	 */
 export type LineWidth = number;
 export interface JavascriptFormatter {
+	/**
+	 * Whether objects written on multiple lines are kept expanded, even if they fit on a single line. Defaults to "preserve".
+	 */
+	objectWrap?: ObjectWrap;
 	/**
 	 * When properties in objects are quoted. Defaults to asNeeded.
 	 */
@@ -124,6 +207,10 @@ export interface Rules {
 	a11y?: A11y;
 	complexity?: Complexity;
 	correctness?: Correctness;
+	/**
+	 * The level of the custom rules of `linter.customRules`, by name of the rule. The custom rules are emitting errors by default
+	 */
+	custom?: {};
 	nursery?: Nursery;
 	/**
 	 * It enables the lint rules recommended by Rome. `true` by default.
@@ -132,9 +219,78 @@ export interface Rules {
 	security?: Security;
 	style?: Style;
 }
+/**
+ * The options of the formatter that can be changed by an override. The options that are omitted keep the value of the top-level configuration.
+ */
+export interface OverrideFormatterConfiguration {
+	enabled?: boolean;
+	/**
+	 * Stores whether formatting should be allowed to proceed if a given file has syntax errors
+	 */
+	formatWithErrors?: boolean;
+	/**
+	 * The size of the indentation
+	 */
+	indentSize?: number;
+	/**
+	 * The indent style.
+	 */
+	indentStyle?: PlainIndentStyle;
+	/**
+	 * What's the max width of a line.
+	 */
+	lineWidth?: LineWidth;
+}
+/**
+ * The options of the JavaScript language that can be changed by an override
+ */
+export interface OverrideJavascriptConfiguration {
+	formatter?: OverrideJavascriptFormatter;
+	/**
+	* A list of global bindings that should be ignored by the analyzers
+
+If defined here, they replace the ones of the top-level configuration. 
+	 */
+	globals?: string[];
+}
+/**
+ * The options of the linter that can be changed by an override
+ */
+export interface OverrideLinterConfiguration {
+	/**
+	 * if `false`, it disables the linter
+	 */
+	enabled?: boolean;
+	/**
+	 * List of rules. They are merged rule by rule with the rules of the top-level configuration
+	 */
+	rules?: Rules;
+}
+export type ObjectWrap = "preserve" | "collapse";
 export type QuoteProperties = "asNeeded" | "preserve";
 export type QuoteStyle = "double" | "single";
 export type TrailingComma = "all" | "es5" | "none";
+/**
+ * The options of the JavaScript formatter that can be changed by an override. The options that are omitted keep the value of the top-level configuration.
+ */
+export interface OverrideJavascriptFormatter {
+	/**
+	 * Whether objects written on multiple lines are kept expanded, even if they fit on a single line.
+	 */
+	objectWrap?: ObjectWrap;
+	/**
+	 * When properties in objects are quoted.
+	 */
+	quoteProperties?: QuoteProperties;
+	/**
+	 * The style for quotes.
+	 */
+	quoteStyle?: QuoteStyle;
+	/**
+	 * Print trailing commas wherever possible in multi-line comma-separated syntactic structures.
+	 */
+	trailingComma?: TrailingComma;
+}
 /**
  * A list of rules that belong to this group
  */
@@ -208,11 +364,20 @@ export interface Correctness {
  * A list of rules that belong to this group
  */
 export interface Nursery {
+	noAsyncArrayCallback?: RuleConfiguration;
+	noAwaitInLoop?: RuleConfiguration;
 	noBannedTypes?: RuleConfiguration;
 	noConstAssign?: RuleConfiguration;
 	noDupeKeys?: RuleConfiguration;
+	noExcessiveComplexity?: RuleConfiguration;
 	noExplicitAny?: RuleConfiguration;
+	noFloatingPromises?: RuleConfiguration;
+	noImportCycles?: RuleConfiguration;
 	noInvalidConstructorSuper?: RuleConfiguration;
+	noPromiseInCondition?: RuleConfiguration;
+	noSelfAssign?: RuleConfiguration;
+	noUseBeforeAssign?: RuleConfiguration;
+	noUselessAssignment?: RuleConfiguration;
 	/**
 	 * It enables the recommended rules for this group
 	 */
@@ -220,7 +385,9 @@ export interface Nursery {
 	useCamelCase?: RuleConfiguration;
 	useExhaustiveDependencies?: RuleConfiguration;
 	useFlatMap?: RuleConfiguration;
+	useHookAtTopLevel?: RuleConfiguration;
 	useNumericLiterals?: RuleConfiguration;
+	useSortedPackageJson?: RuleConfiguration;
 	useValidForDirection?: RuleConfiguration;
 }
 /**
@@ -275,6 +442,7 @@ export type Language =
 	| "TypeScript"
 	| "TypeScriptReact"
 	| "Json"
+	| "Markdown"
 	| "Unknown";
 export interface ChangeFileParams {
 	content: string;
@@ -387,16 +555,29 @@ export type Category =
 	| "lint/a11y/useAltText"
 	| "lint/security/noDangerouslySetInnerHtml"
 	| "lint/security/noDangerouslySetInnerHtmlWithChildren"
+	| "lint/nursery/noAsyncArrayCallback"
+	| "lint/nursery/noAwaitInLoop"
 	| "lint/nursery/noBannedTypes"
 	| "lint/nursery/noConstAssign"
 	| "lint/nursery/noDupeKeys"
+	| "lint/nursery/noExcessiveComplexity"
 	| "lint/nursery/noExplicitAny"
+	| "lint/nursery/noFloatingPromises"
+	| "lint/nursery/noImportCycles"
 	| "lint/nursery/noInvalidConstructorSuper"
+	| "lint/nursery/noPromiseInCondition"
+	| "lint/nursery/noSelfAssign"
+	| "lint/nursery/noUseBeforeAssign"
+	| "lint/nursery/noUselessAssignment"
 	| "lint/nursery/useCamelCase"
 	| "lint/nursery/useExhaustiveDependencies"
 	| "lint/nursery/useFlatMap"
+	| "lint/nursery/useHookAtTopLevel"
 	| "lint/nursery/useNumericLiterals"
+	| "lint/nursery/useSortedPackageJson"
 	| "lint/nursery/useValidForDirection"
+	| "deadCode/unreachableFile"
+	| "deadCode/unusedExport"
 	| "files/missingHandler"
 	| "format"
 	| "internalError/io"
@@ -407,6 +588,7 @@ export type Category =
 	| "parse/noSuperWithoutExtends"
 	| "suppressions/unknownGroup"
 	| "suppressions/unknownRule"
+	| "suppressions/unmatchedRange"
 	| "suppressions/unused"
 	| "args/fileNotFound"
 	| "flags/invalid"
@@ -508,6 +690,26 @@ export interface BacktraceSymbol {
 	lineno?: number;
 	name?: string;
 }
+export interface PullProjectDiagnosticsParams {
+	/**
+	 * The files of the project, that were all checked
+	 */
+	paths: RomePath[];
+}
+export interface PullProjectDiagnosticsResult {
+	/**
+	 * The files of the project having diagnostics
+	 */
+	files: ProjectFileDiagnostics[];
+}
+export interface ProjectFileDiagnostics {
+	/**
+	 * The content of the file the diagnostics point to
+	 */
+	content: string;
+	diagnostics: Diagnostic[];
+	path: RomePath;
+}
 export interface PullActionsParams {
 	path: RomePath;
 	range: TextRange;
@@ -517,6 +719,10 @@ export interface PullActionsResult {
 }
 export interface CodeAction {
 	category: ActionCategory;
+	/**
+	 * The range of the diagnostic emitted along with this action, the actions of a rule sharing the same range are alternative fixes
+	 */
+	diagnostic_range?: TextRange;
 	rule_name: string;
 	suggestion: CodeSuggestion;
 }
@@ -552,6 +758,7 @@ export interface Printed {
 	code: string;
 	range?: TextRange;
 	sourcemap: SourceMarker[];
+	unmatched_suppressions: UnmatchedSuppression[];
 	verbatim_ranges: TextRange[];
 }
 /**
@@ -567,6 +774,10 @@ export interface SourceMarker {
 	 */
 	source: TextSize;
 }
+/**
+	* A suppression range comment without a matching opening or closing comment. The range is the range of the comment in the source text.
+	 */
+export type UnmatchedSuppression = { Start: TextRange } | { End: TextRange };
 export interface FormatRangeParams {
 	path: RomePath;
 	range: TextRange;
@@ -613,6 +824,10 @@ export interface RenameParams {
 	symbol_at: TextSize;
 }
 export interface RenameResult {
+	/**
+	 * The edits of the other files of the project, when the renamed binding is exported and imported by these files
+	 */
+	files: FileTextEdit[];
 	/**
 	 * List of text edit operations to apply on the source code
 	 */
@@ -622,6 +837,13 @@ export interface RenameResult {
 	 */
 	range: TextRange;
 }
+export interface FileTextEdit {
+	/**
+	 * List of text edit operations to apply on the content of the file
+	 */
+	indels: TextEdit;
+	path: RomePath;
+}
 export interface Workspace {
 	supportsFeature(
 		params: SupportsFeatureParams,
@@ -636,6 +858,9 @@ export interface Workspace {
 	pullDiagnostics(
 		params: PullDiagnosticsParams,
 	): Promise<PullDiagnosticsResult>;
+	pullProjectDiagnostics(
+		params: PullProjectDiagnosticsParams,
+	): Promise<PullProjectDiagnosticsResult>;
 	pullActions(params: PullActionsParams): Promise<PullActionsResult>;
 	formatFile(params: FormatFileParams): Promise<Printed>;
 	formatRange(params: FormatRangeParams): Promise<Printed>;
@@ -673,6 +898,9 @@ export function createWorkspace(transport: Transport): Workspace {
 		pullDiagnostics(params) {
 			return transport.request("rome/pull_diagnostics", params);
 		},
+		pullProjectDiagnostics(params) {
+			return transport.request("rome/pull_project_diagnostics", params);
+		},
 		pullActions(params) {
 			return transport.request("rome/pull_actions", params);
 		},
@@ -1,4 +1,5 @@
 use std::collections::BTreeMap;
+use std::path::Path;
 
 use anyhow::{Context, Ok, Result};
 use case::CaseExt;
@@ -7,26 +8,56 @@ use quote::{format_ident, quote};
 use xtask::{glue::fs2, project_root};
 
 pub fn generate_analyzer() -> Result<()> {
+    generate_js_analyzer()?;
+    generate_json_analyzer()
+}
+
+fn generate_js_analyzer() -> Result<()> {
+    let base_path = project_root().join("crates/rome_js_analyze/src");
+
     let mut analyzers = BTreeMap::new();
-    generate_category("analyzers", &mut analyzers)?;
+    generate_category("analyzers", &mut analyzers, &base_path)?;
 
     let mut semantic_analyzers = BTreeMap::new();
-    generate_category("semantic_analyzers", &mut semantic_analyzers)?;
+    generate_category("semantic_analyzers", &mut semantic_analyzers, &base_path)?;
+
+    let mut project_analyzers = BTreeMap::new();
+    generate_category("project_analyzers", &mut project_analyzers, &base_path)?;
 
     let mut assists = BTreeMap::new();
-    generate_category("assists", &mut assists)?;
+    generate_category("assists", &mut assists, &base_path)?;
 
     let mut syntax = BTreeMap::new();
-    generate_category("syntax", &mut syntax)?;
+    generate_category("syntax", &mut syntax, &base_path)?;
+
+    let categories = analyzers
+        .into_iter()
+        .chain(semantic_analyzers)
+        .chain(project_analyzers)
+        .chain(assists)
+        .chain(syntax)
+        .map(|(_, tokens)| tokens);
 
-    update_registry_builder(analyzers, semantic_analyzers, assists, syntax)
+    update_registry_builder(&base_path, "rome_js_syntax", "JsLanguage", categories)
+}
+
+fn generate_json_analyzer() -> Result<()> {
+    let base_path = project_root().join("crates/rome_json_analyze/src");
+
+    let mut analyzers = BTreeMap::new();
+    generate_category("analyzers", &mut analyzers, &base_path)?;
+
+    let categories = analyzers.into_iter().map(|(_, tokens)| tokens);
+
+    update_registry_builder(&base_path, "rome_json_syntax", "JsonLanguage", categories)
 }
 
 fn generate_category(
     name: &'static str,
     entries: &mut BTreeMap<&'static str, TokenStream>,
+    base_path: &Path,
 ) -> Result<()> {
-    let path = project_root().join("crates/rome_js_analyze/src").join(name);
+    let path = base_path.join(name);
 
     let mut groups = BTreeMap::new();
     for entry in fs2::read_dir(path)? {
@@ -42,7 +73,7 @@ fn generate_category(
             .to_str()
             .context("could not convert file name to string")?;
 
-        generate_group(name, file_name)?;
+        generate_group(name, file_name, base_path)?;
 
         let module_name = format_ident!("{}", file_name);
         let group_name = format_ident!("{}", to_camel_case(file_name)?);
@@ -68,7 +99,7 @@ fn generate_category(
 
     let kind = match name {
         "syntax" => format_ident!("Syntax"),
-        "analyzers" | "semantic_analyzers" => format_ident!("Lint"),
+        "analyzers" | "semantic_analyzers" | "project_analyzers" => format_ident!("Lint"),
         "assists" => format_ident!("Action"),
         _ => panic!("unimplemented analyzer category {name:?}"),
     };
@@ -93,21 +124,13 @@ fn generate_category(
         }
     })?;
 
-    fs2::write(
-        project_root()
-            .join("crates/rome_js_analyze/src")
-            .join(format!("{name}.rs")),
-        tokens,
-    )?;
+    fs2::write(base_path.join(format!("{name}.rs")), tokens)?;
 
     Ok(())
 }
 
-fn generate_group(category: &'static str, group: &str) -> Result<()> {
-    let path = project_root()
-        .join("crates/rome_js_analyze/src")
-        .join(category)
-        .join(group);
+fn generate_group(category: &'static str, group: &str, base_path: &Path) -> Result<()> {
+    let path = base_path.join(category).join(group);
 
     let mut rules = BTreeMap::new();
     for entry in fs2::read_dir(path)? {
@@ -157,13 +180,7 @@ fn generate_group(category: &'static str, group: &str) -> Result<()> {
         }
     })?;
 
-    fs2::write(
-        project_root()
-            .join("crates/rome_js_analyze/src")
-            .join(category)
-            .join(format!("{group}.rs")),
-        tokens,
-    )?;
+    fs2::write(base_path.join(category).join(format!("{group}.rs")), tokens)?;
 
     Ok(())
 }
@@ -189,25 +206,21 @@ fn to_camel_case(input: &str) -> Result<String> {
 }
 
 fn update_registry_builder(
-    analyzers: BTreeMap<&'static str, TokenStream>,
-    semantic_analyzers: BTreeMap<&'static str, TokenStream>,
-    assists: BTreeMap<&'static str, TokenStream>,
-    syntax: BTreeMap<&'static str, TokenStream>,
+    base_path: &Path,
+    syntax_crate: &str,
+    language: &str,
+    categories: impl Iterator<Item = TokenStream>,
 ) -> Result<()> {
-    let path = project_root().join("crates/rome_js_analyze/src/registry.rs");
+    let path = base_path.join("registry.rs");
 
-    let categories = analyzers
-        .into_iter()
-        .chain(semantic_analyzers)
-        .chain(assists)
-        .chain(syntax)
-        .map(|(_, tokens)| tokens);
+    let syntax_crate = format_ident!("{syntax_crate}");
+    let language = format_ident!("{language}");
 
     let tokens = xtask::reformat(quote! {
         use rome_analyze::RegistryVisitor;
-        use rome_js_syntax::JsLanguage;
+        use #syntax_crate::#language;
 
-        pub fn visit_registry<V: RegistryVisitor<JsLanguage>>(registry: &mut V) {
+        pub fn visit_registry<V: RegistryVisitor<#language>>(registry: &mut V) {
             #( #categories )*
         }
     })?;
@@ -2,8 +2,8 @@ use case::CaseExt;
 use proc_macro2::{Ident, Literal, Span};
 use quote::quote;
 use rome_analyze::{GroupCategory, Queryable, RegistryVisitor, Rule, RuleCategory, RuleGroup};
-use rome_js_analyze::visit_registry;
 use rome_js_syntax::JsLanguage;
+use rome_json_syntax::JsonLanguage;
 use std::collections::BTreeMap;
 use xtask::*;
 use xtask_codegen::{to_lower_snake_case, update};
@@ -36,8 +36,30 @@ pub(crate) fn generate_rules_configuration(mode: Mode) -> Result<()> {
         }
     }
 
+    // The rules of all the languages share the same configuration
+    impl RegistryVisitor<JsonLanguage> for LintRulesVisitor {
+        fn record_category<C: GroupCategory<Language = JsonLanguage>>(&mut self) {
+            if matches!(C::CATEGORY, RuleCategory::Lint) {
+                C::record_groups(self);
+            }
+        }
+
+        fn record_rule<R>(&mut self)
+        where
+            R: Rule + 'static,
+            R::Query: Queryable<Language = JsonLanguage>,
+            <R::Query as Queryable>::Output: Clone,
+        {
+            self.groups
+                .entry(<R::Group as RuleGroup>::NAME)
+                .or_insert_with(BTreeMap::new)
+                .insert(R::METADATA.name, R::METADATA.recommended);
+        }
+    }
+
     let mut visitor = LintRulesVisitor::default();
-    visit_registry(&mut visitor);
+    rome_js_analyze::visit_registry(&mut visitor);
+    rome_json_analyze::visit_registry(&mut visitor);
 
     let LintRulesVisitor { groups } = visitor;
 
@@ -47,6 +69,7 @@ pub(crate) fn generate_rules_configuration(mode: Mode) -> Result<()> {
     let mut group_rules_union = Vec::new();
     let mut group_match_code = Vec::new();
     let mut group_get_severity = Vec::new();
+    let mut group_merge = Vec::new();
     for (group, rules) in groups {
         let mut lines_recommended_rule = Vec::new();
         let mut lines_recommended_rule_as_filter = Vec::new();
@@ -176,6 +199,15 @@ pub(crate) fn generate_rules_configuration(mode: Mode) -> Result<()> {
                 pub(crate) fn recommended_rules_as_filters() -> [RuleFilter<'static>; #number_of_recommended_rules] {
                     Self::RECOMMENDED_RULES_AS_FILTERS
                 }
+
+                /// Merges `other` into this group. The rules configured in `other` replace
+                /// the configuration of the same rules in this group
+                pub(crate) fn merge_with(&mut self, other: Self) {
+                    if other.recommended.is_some() {
+                        self.recommended = other.recommended;
+                    }
+                    self.rules.extend(other.rules);
+                }
             }
 
             fn #deserialize_function_ident<'de, D>(
@@ -230,6 +262,14 @@ pub(crate) fn generate_rules_configuration(mode: Mode) -> Result<()> {
                     }
                 })
         });
+        group_merge.push(quote! {
+            if let Some(group) = other.#property_group_name {
+                match self.#property_group_name.as_mut() {
+                    Some(current) => current.merge_with(group),
+                    None => self.#property_group_name = Some(group),
+                }
+            }
+        });
         group_match_code.push(quote! {
            #group => #group_struct_name::has_rule(rule_name).then_some((category, rule_name))
         });
@@ -240,6 +280,7 @@ pub(crate) fn generate_rules_configuration(mode: Mode) -> Result<()> {
         #[cfg(feature = "schemars")]
         use schemars::JsonSchema;
         use crate::{ConfigurationError, RomeError, RuleConfiguration};
+        use crate::configuration::linter::RulePlainConfiguration;
         use rome_analyze::RuleFilter;
         use indexmap::{IndexMap, IndexSet};
         use rome_diagnostics::v2::{Category, Severity};
@@ -252,14 +293,20 @@ pub(crate) fn generate_rules_configuration(mode: Mode) -> Result<()> {
             #[serde(skip_serializing_if = "Option::is_none")]
             pub recommended: Option<bool>,
 
-            #( #line_groups ),*
+            #( #line_groups, )*
+
+            /// The level of the custom rules of `linter.customRules`, by name of the rule.
+            /// The custom rules are emitting errors by default
+            #[serde(skip_serializing_if = "Option::is_none")]
+            pub custom: Option<IndexMap<String, RulePlainConfiguration>>,
         }
 
         impl Default for Rules {
             fn default() -> Self {
                 Self {
                     recommended: Some(true),
-                    #( #default_for_groups ),*
+                    #( #default_for_groups, )*
+                    custom: None,
                 }
             }
         }
@@ -331,6 +378,20 @@ pub(crate) fn generate_rules_configuration(mode: Mode) -> Result<()> {
 
                 enabled_rules.difference(&disabled_rules).cloned().collect()
             }
+
+            /// Merges `other` into these rules. The groups are merged rule by rule,
+            /// and the values of `other` take precedence
+            pub fn merge_with(&mut self, other: Rules) {
+                if other.recommended.is_some() {
+                    self.recommended = other.recommended;
+                }
+
+                #( #group_merge )*
+
+                if let Some(custom) = other.custom {
+                    self.custom.get_or_insert_with(IndexMap::new).extend(custom);
+                }
+            }
         }
 
         #( #struct_groups )*
@@ -1,7 +1,7 @@
 use pulldown_cmark::{html::write_html, CodeBlockKind, Event, LinkType, Parser, Tag};
 use rome_analyze::{
-    AnalysisFilter, AnalyzerOptions, ControlFlow, GroupCategory, Queryable, RegistryVisitor, Rule,
-    RuleCategory, RuleFilter, RuleGroup, RuleMetadata,
+    AnalysisFilter, AnalyzerDiagnostic, AnalyzerOptions, ControlFlow, GroupCategory, Queryable,
+    RegistryVisitor, Rule, RuleCategory, RuleFilter, RuleGroup, RuleMetadata,
 };
 use rome_console::fmt::Termcolor;
 use rome_console::{
@@ -10,9 +10,10 @@ use rome_console::{
 };
 use rome_diagnostics::file::FileId;
 use rome_diagnostics::termcolor::NoColor;
+use rome_diagnostics::v2::advice::CodeSuggestionAdvice;
 use rome_diagnostics::v2::{Diagnostic, DiagnosticExt, PrintDiagnostic};
-use rome_js_analyze::{analyze, visit_registry};
 use rome_js_syntax::{JsLanguage, Language, LanguageVariant, ModuleKind, SourceType};
+use rome_json_syntax::JsonLanguage;
 use rome_service::settings::WorkspaceSettings;
 use std::{
     collections::BTreeMap,
@@ -87,8 +88,29 @@ fn main() -> Result<()> {
         }
     }
 
+    impl RegistryVisitor<JsonLanguage> for LintRulesVisitor {
+        fn record_category<C: GroupCategory<Language = JsonLanguage>>(&mut self) {
+            if matches!(C::CATEGORY, RuleCategory::Lint) {
+                C::record_groups(self);
+            }
+        }
+
+        fn record_rule<R>(&mut self)
+        where
+            R: Rule + 'static,
+            R::Query: Queryable<Language = JsonLanguage>,
+            <R::Query as Queryable>::Output: Clone,
+        {
+            self.groups
+                .entry(<R::Group as RuleGroup>::NAME)
+                .or_insert_with(BTreeMap::new)
+                .insert(R::METADATA.name, R::METADATA);
+        }
+    }
+
     let mut visitor = LintRulesVisitor::default();
-    visit_registry(&mut visitor);
+    rome_js_analyze::visit_registry(&mut visitor);
+    rome_json_analyze::visit_registry(&mut visitor);
 
     let LintRulesVisitor { mut groups } = visitor;
 
@@ -236,13 +258,18 @@ fn parse_documentation(
                 // re-generating the language ID from the source type
                 write!(content, "```")?;
                 if !meta.is_empty() {
-                    match test.source_type.language() {
-                        Language::JavaScript => write!(content, "js")?,
-                        Language::TypeScript { .. } => write!(content, "ts")?,
-                    }
-                    match test.source_type.variant() {
-                        LanguageVariant::Standard => {}
-                        LanguageVariant::Jsx => write!(content, "x")?,
+                    match test.language {
+                        BlockLanguage::Js(source_type) => {
+                            match source_type.language() {
+                                Language::JavaScript => write!(content, "js")?,
+                                Language::TypeScript { .. } => write!(content, "ts")?,
+                            }
+                            match source_type.variant() {
+                                LanguageVariant::Standard => {}
+                                LanguageVariant::Jsx => write!(content, "x")?,
+                            }
+                        }
+                        BlockLanguage::Json => write!(content, "json")?,
                     }
                 }
                 writeln!(content)?;
@@ -391,8 +418,15 @@ fn parse_documentation(
     Ok(summary)
 }
 
+/// The language of a code block, and the analyzer used to check it
+#[derive(Clone, Copy)]
+enum BlockLanguage {
+    Js(SourceType),
+    Json,
+}
+
 struct CodeBlockTest {
-    source_type: SourceType,
+    language: BlockLanguage,
     expect_diagnostic: bool,
 }
 
@@ -408,7 +442,7 @@ impl FromStr for CodeBlockTest {
             .filter(|token| !token.is_empty());
 
         let mut test = CodeBlockTest {
-            source_type: SourceType::default(),
+            language: BlockLanguage::Js(SourceType::default()),
             expect_diagnostic: false,
         };
 
@@ -416,19 +450,25 @@ impl FromStr for CodeBlockTest {
             match token {
                 // Determine the language, using the same list of extensions as `compute_source_type_from_path_or_extension`
                 "cjs" => {
-                    test.source_type = SourceType::js_module().with_module_kind(ModuleKind::Script);
+                    test.language = BlockLanguage::Js(
+                        SourceType::js_module().with_module_kind(ModuleKind::Script),
+                    );
                 }
                 "js" | "mjs" | "jsx" => {
-                    test.source_type = SourceType::jsx();
+                    test.language = BlockLanguage::Js(SourceType::jsx());
                 }
                 "ts" | "mts" => {
-                    test.source_type = SourceType::ts();
+                    test.language = BlockLanguage::Js(SourceType::ts());
                 }
                 "cts" => {
-                    test.source_type = SourceType::ts().with_module_kind(ModuleKind::Script);
+                    test.language =
+                        BlockLanguage::Js(SourceType::ts().with_module_kind(ModuleKind::Script));
                 }
                 "tsx" => {
-                    test.source_type = SourceType::tsx();
+                    test.language = BlockLanguage::Js(SourceType::tsx());
+                }
+                "json" => {
+                    test.language = BlockLanguage::Json;
                 }
 
                 // Other attributes
@@ -456,7 +496,11 @@ fn assert_lint(
     code: &str,
     content: &mut Vec<u8>,
 ) -> Result<()> {
-    let file = format!("{group}/{rule}.js");
+    let extension = match test.language {
+        BlockLanguage::Js(_) => "js",
+        BlockLanguage::Json => "json",
+    };
+    let file = format!("{group}/{rule}.{extension}");
 
     let mut write = HTML(content);
     let mut diagnostic_count = 0;
@@ -512,57 +556,101 @@ fn assert_lint(
         Ok(())
     };
 
-    let parse = rome_js_parser::parse(code, FileId::zero(), test.source_type);
+    let settings = WorkspaceSettings::default();
 
-    if parse.has_errors() {
-        for diag in parse.into_diagnostics() {
-            let error = diag
-                .with_file_path((file.clone(), FileId::zero()))
-                .with_file_source_code(code);
-            write_diagnostic(code, error)?;
-        }
-    } else {
-        let root = parse.tree();
+    let rule_filter = RuleFilter::Rule(group, rule);
+    let filter = AnalysisFilter {
+        enabled_rules: Some(slice::from_ref(&rule_filter)),
+        ..AnalysisFilter::default()
+    };
 
-        let settings = WorkspaceSettings::default();
+    let options = AnalyzerOptions::default();
 
-        let rule_filter = RuleFilter::Rule(group, rule);
-        let filter = AnalysisFilter {
-            enabled_rules: Some(slice::from_ref(&rule_filter)),
-            ..AnalysisFilter::default()
-        };
+    // Emits the diagnostic of a signal, along with the code suggestions of its actions
+    let mut write_signal = |mut diag: AnalyzerDiagnostic,
+                            suggestions: Vec<CodeSuggestionAdvice<MarkupBuf>>|
+     -> ControlFlow<Error> {
+        let category = diag.category().expect("linter diagnostic has no code");
+        let severity = settings
+            .get_severity_from_rule_code(category)
+            .expect("If you see this error, it means you need to run cargo codegen-configuration");
+        diag.set_severity(severity);
 
-        let options = AnalyzerOptions::default();
-        let result = analyze(FileId::zero(), &root, filter, &options, |signal| {
-            if let Some(mut diag) = signal.diagnostic() {
-                let category = diag.category().expect("linter diagnostic has no code");
-                let severity = settings.get_severity_from_rule_code(category).expect(
-                    "If you see this error, it means you need to run cargo codegen-configuration",
-                );
-                diag.set_severity(severity);
+        for suggestion in suggestions {
+            diag.add_code_suggestion(suggestion);
+        }
 
-                if let Some(action) = signal.action() {
-                    diag.add_code_suggestion(action.into());
-                }
+        let error = diag
+            .with_file_path((file.clone(), FileId::zero()))
+            .with_file_source_code(code);
+        let res = write_diagnostic(code, error);
 
-                let error = diag
-                    .with_file_path((file.clone(), FileId::zero()))
-                    .with_file_source_code(code);
-                let res = write_diagnostic(code, error);
+        // Abort the analysis on error
+        if let Err(err) = res {
+            return ControlFlow::Break(err);
+        }
+
+        ControlFlow::Continue(())
+    };
 
-                // Abort the analysis on error
-                if let Err(err) = res {
-                    return ControlFlow::Break(err);
+    let result = match test.language {
+        BlockLanguage::Js(source_type) => {
+            let parse = rome_js_parser::parse(code, FileId::zero(), source_type);
+
+            if parse.has_errors() {
+                for diag in parse.into_diagnostics() {
+                    let error = diag
+                        .with_file_path((file.clone(), FileId::zero()))
+                        .with_file_source_code(code);
+                    write_diagnostic(code, error)?;
                 }
-            }
 
-            ControlFlow::Continue(())
-        });
+                None
+            } else {
+                let root = parse.tree();
+
+                rome_js_analyze::analyze(FileId::zero(), &root, filter, &options, |signal| {
+                    match signal.diagnostic() {
+                        Some(diag) => write_signal(
+                            diag,
+                            signal.actions().into_iter().map(Into::into).collect(),
+                        ),
+                        None => ControlFlow::Continue(()),
+                    }
+                })
+            }
+        }
+        BlockLanguage::Json => {
+            let parse = rome_json_parser::parse_json(code, FileId::zero());
+
+            if parse.has_errors() {
+                for diag in parse.into_diagnostics() {
+                    let error = diag
+                        .with_file_path((file.clone(), FileId::zero()))
+                        .with_file_source_code(code);
+                    write_diagnostic(code, error)?;
+                }
 
-        // Result is Some(_) if analysis aborted with an error
-        if let Some(err) = result {
-            return Err(err);
+                None
+            } else {
+                let root = parse.tree();
+
+                rome_json_analyze::analyze(FileId::zero(), &root, filter, &options, |signal| {
+                    match signal.diagnostic() {
+                        Some(diag) => write_signal(
+                            diag,
+                            signal.actions().into_iter().map(Into::into).collect(),
+                        ),
+                        None => ControlFlow::Continue(()),
+                    }
+                })
+            }
         }
+    };
+
+    // Result is Some(_) if analysis aborted with an error
+    if let Some(err) = result {
+        return Err(err);
     }
 
     if test.expect_diagnostic {